@@ -0,0 +1,111 @@
+//! Compares kinetic energy, wind deflection and remaining velocity calculated one call at a
+//! time through the builders and in batches over slices. Run with `cargo bench --bench batch`.
+
+use std::hint::black_box;
+
+use ballistics_rs::{
+    BulletWeight, Distance, KineticEnergy, LagTime, PejsaTrajectory, RetardationCoefficient,
+    Velocity, WindDeflection, WindSpeed,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const ELEMENTS: usize = 10_000;
+
+fn energies(c: &mut Criterion) {
+    let weights: Vec<BulletWeight> = (0..ELEMENTS)
+        .map(|i| BulletWeight(100.0 + (i as f64 * 0.618_033_988_75).fract() * 150.0))
+        .collect();
+    let velocities: Vec<Velocity> = (0..ELEMENTS)
+        .map(|i| Velocity(2000.0 + (i as f64 * 0.414_213_562_37).fract() * 1500.0))
+        .collect();
+    let mut out = vec![0.0; ELEMENTS];
+
+    let mut group = c.benchmark_group("energy");
+    group.bench_function("per call", |b| {
+        b.iter(|| {
+            weights
+                .iter()
+                .zip(&velocities)
+                .map(|(&bullet_weight, &velocity)| {
+                    KineticEnergy::calculate()
+                        .bullet_weight(black_box(bullet_weight))
+                        .velocity(velocity)
+                        .solve()
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| KineticEnergy::calculate_batch(black_box(&weights), &velocities))
+    });
+    group.bench_function("batch into", |b| {
+        b.iter(|| KineticEnergy::calculate_batch_into(black_box(&weights), &velocities, &mut out))
+    });
+    group.finish();
+}
+
+fn deflections(c: &mut Criterion) {
+    let lag_times: Vec<LagTime> = (0..ELEMENTS)
+        .map(|i| LagTime((i as f64 * 0.618_033_988_75).fract() * 0.5))
+        .collect();
+    let crosswinds: Vec<WindSpeed> = (0..ELEMENTS)
+        .map(|i| WindSpeed((i as f64 * 0.414_213_562_37).fract() * 20.0))
+        .collect();
+    let mut out = vec![0.0; ELEMENTS];
+
+    let mut group = c.benchmark_group("deflection");
+    group.bench_function("per call", |b| {
+        b.iter(|| {
+            lag_times
+                .iter()
+                .zip(&crosswinds)
+                .map(|(&lag_time, &crosswind_speed)| {
+                    WindDeflection::calculate()
+                        .lag_time(black_box(lag_time))
+                        .crosswind_speed(crosswind_speed)
+                        .solve()
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| WindDeflection::calculate_batch(black_box(&lag_times), &crosswinds))
+    });
+    group.bench_function("batch into", |b| {
+        b.iter(|| {
+            WindDeflection::calculate_batch_into(black_box(&lag_times), &crosswinds, &mut out)
+        })
+    });
+    group.finish();
+}
+
+fn velocities(c: &mut Criterion) {
+    let distances: Vec<Distance> = (0..ELEMENTS)
+        .map(|i| Distance::from_yards((i as f64 * 0.618_033_988_75).fract() * 800.0))
+        .collect();
+    let pejsa = PejsaTrajectory::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .retardation_coefficient(RetardationCoefficient(2800.0))
+        .build();
+    let mut out = vec![0.0; ELEMENTS];
+
+    let mut group = c.benchmark_group("velocity");
+    group.bench_function("per call", |b| {
+        b.iter(|| {
+            distances
+                .iter()
+                .map(|&distance| black_box(&pejsa).velocity_at(distance))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| black_box(&pejsa).velocity_at_batch(&distances))
+    });
+    group.bench_function("batch into", |b| {
+        b.iter(|| black_box(&pejsa).velocity_at_batch_into(&distances, &mut out))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, energies, deflections, velocities);
+criterion_main!(benches);
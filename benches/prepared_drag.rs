@@ -0,0 +1,60 @@
+//! Compares drag coefficient lookups and trajectories through a standard drag model and
+//! through its `PreparedDrag` resampling. Run with `cargo bench --bench prepared_drag`.
+
+use std::hint::black_box;
+
+use ballistics_rs::{
+    BallisticCoefficient, Distance, DragCurve, DragModel, MachNumber, PreparedDrag,
+    TrajectorySolver, Velocity,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+const LOOKUPS: usize = 10_000;
+
+fn lookups(c: &mut Criterion) {
+    let model = DragModel::G7;
+    let prepared = PreparedDrag::from(model);
+    let machs: Vec<MachNumber> = (0..LOOKUPS)
+        .map(|i| MachNumber((i as f64 * 0.618_033_988_75).fract() * 5.0))
+        .collect();
+
+    let mut group = c.benchmark_group("lookup");
+    group.bench_function("table", |b| {
+        b.iter(|| {
+            machs
+                .iter()
+                .map(|&mach| black_box(model).drag_coefficient(mach).0)
+                .sum::<f64>()
+        })
+    });
+    group.bench_function("prepared", |b| {
+        b.iter(|| {
+            machs
+                .iter()
+                .map(|&mach| black_box(&prepared).drag_coefficient(mach).0)
+                .sum::<f64>()
+        })
+    });
+    group.finish();
+}
+
+fn trajectories(c: &mut Criterion) {
+    let model = DragModel::G7;
+    let prepared = PreparedDrag::from(model);
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2800.0))
+        .ballistic_coefficient(BallisticCoefficient(0.243))
+        .drag_model(model)
+        .build();
+    let distance = Distance::from_yards(1000.0);
+
+    let mut group = c.benchmark_group("trajectory");
+    group.bench_function("table", |b| b.iter(|| black_box(solver).point_at(distance)));
+    group.bench_function("prepared", |b| {
+        b.iter(|| black_box(solver).point_at_with(distance, &prepared))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lookups, trajectories);
+criterion_main!(benches);
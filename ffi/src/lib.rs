@@ -0,0 +1,7 @@
+//! C shared library
+//!
+//! Builds the `extern "C"` functions of `ballistics_rs::ffi` into a shared library, declared in
+//! `include/ballistics.h`. The functions live in the main crate behind its `ffi` feature; this
+//! crate only gives them a `cdylib` to be linked from C, C++ or Swift.
+
+pub use ballistics_rs::ffi::*;
@@ -0,0 +1,74 @@
+/*
+ * C bindings of the ballistics_rs crate, built into a shared library by the ballistics_ffi
+ * crate in ffi/ (`cargo build --release -p ballistics_ffi`).
+ *
+ * Every function takes plain doubles in the units of the Rust API it wraps (grains, inches,
+ * feet, ft/s, mph, seconds and degrees Fahrenheit), writes its result through `out` and
+ * returns a status code. On any code other than BALLISTICS_OK, `out` is left untouched.
+ *
+ * This header is maintained by hand alongside src/ffi.rs.
+ */
+
+#ifndef BALLISTICS_H
+#define BALLISTICS_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The calculation succeeded and its result was written through `out`. */
+#define BALLISTICS_OK 0
+/* An input is NaN, infinite or outside the range the calculation is defined for. */
+#define BALLISTICS_INVALID_INPUT 1
+/* The inputs are valid but have no solution, e.g. the bullet does not reach the distance. */
+#define BALLISTICS_NO_SOLUTION 2
+/* The `out` pointer is null. */
+#define BALLISTICS_NULL_POINTER 3
+/* The calculation panicked. */
+#define BALLISTICS_PANIC 4
+
+/* Drag model codes of ballistics_time_of_flight. */
+#define BALLISTICS_DRAG_G1 1
+#define BALLISTICS_DRAG_G7 7
+
+/* Speed of sound in ft/s at a temperature in degrees Fahrenheit. */
+int32_t ballistics_speed_of_sound(double temperature, double *out);
+
+/* Kinetic energy in ft-lbs of a bullet weighing `bullet_weight` grains at `velocity` ft/s. */
+int32_t ballistics_kinetic_energy(double bullet_weight, double velocity, double *out);
+
+/* Ballistic coefficient from a weight in grains, a diameter in inches and a form factor. */
+int32_t ballistics_ballistic_coefficient(double bullet_weight, double bullet_diameter,
+                                         double form_factor, double *out);
+
+/* Lag time in seconds from a time of flight in seconds, a distance in feet and a muzzle
+ * velocity in ft/s. */
+int32_t ballistics_lag_time(double actual_time_of_flight, double distance,
+                            double muzzle_velocity, double *out);
+
+/* Wind deflection in inches from a lag time in seconds and a crosswind speed in mph. */
+int32_t ballistics_wind_deflection(double lag_time, double crosswind_speed, double *out);
+
+/* Miller's gyroscopic stability factor from a weight in grains, a twist in calibers per turn,
+ * a diameter in inches and a length in calibers. */
+int32_t ballistics_gyroscopic_stability(double bullet_weight, double rifling_twist,
+                                        double bullet_diameter, double bullet_length,
+                                        double *out);
+
+/* Spin drift in inches, positive to the right, from a gyroscopic stability factor and a time
+ * of flight in seconds for a right-hand twist barrel; negate it for left-hand twist. */
+int32_t ballistics_spin_drift(double gyro_stability, double actual_time_of_flight, double *out);
+
+/* Time of flight in seconds to a distance in feet from a muzzle velocity in ft/s and a
+ * ballistic coefficient relative to BALLISTICS_DRAG_G1 or BALLISTICS_DRAG_G7, in the ICAO
+ * standard atmosphere. */
+int32_t ballistics_time_of_flight(double muzzle_velocity, double ballistic_coefficient,
+                                  int32_t drag_model, double distance, double *out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* BALLISTICS_H */
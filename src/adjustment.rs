@@ -0,0 +1,191 @@
+use bon::bon;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{Angle, BallisticsError, Deflection, Distance, TrajectorySolver};
+
+/// Sight adjustment
+///
+/// This struct represents the scope correction that moves the point of impact back onto the
+/// point of aim. Positive values mean dialing up or right, negative values dialing down or left,
+/// so a bullet striking low or left of the point of aim yields a positive adjustment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SightAdjustment {
+    /// The exact correction in MOA, available in milliradians through `Angle::mils`.
+    pub angle: Angle,
+    /// The correction rounded to the nearest whole click.
+    pub clicks: i64,
+    /// The deflection in inches that remains after dialing the rounded clicks.
+    pub residual: Deflection,
+}
+
+#[bon]
+impl SightAdjustment {
+    /// Calculates the sight adjustment correcting a deflection at a known distance.
+    ///
+    /// The correction is rounded to whole clicks like `TurretSolution`, a correction exactly
+    /// halfway between two clicks dialing the smaller one.
+    ///
+    /// # Parameters
+    /// - `deflection`: The displacement of the point of impact from the point of aim in inches.
+    /// - `distance`: The distance to the target in feet.
+    /// - `click`: The click value of the turret.
+    ///
+    /// # Returns
+    /// A `SightAdjustment` instance representing the correction in MOA and whole clicks.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] deflection: Deflection,
+        distance: Distance,
+        click: ClickValue,
+    ) -> Self {
+        let angle = Angle(-Angle::from_subtension(deflection.0, distance).0);
+        let turret = TurretSolution::calculate()
+            .correction(angle)
+            .click(click)
+            .solve();
+
+        SightAdjustment {
+            angle,
+            clicks: turret.clicks,
+            residual: Deflection(Angle(-turret.residual.0).subtension_at(distance)),
+        }
+    }
+}
+
+/// Turret click value
+///
+/// This enum represents the angular value of one click of a scope turret.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClickValue {
+    /// 1/4 MOA per click.
+    QuarterMoa,
+    /// 1/8 MOA per click.
+    EighthMoa,
+    /// 0.1 mil per click.
+    TenthMil,
+    /// 0.05 mil per click.
+    TwentiethMil,
+    /// Any other click value.
+    Custom(Angle),
+}
+
+impl ClickValue {
+    /// Returns the angle of one click.
+    pub fn angle(&self) -> Angle {
+        match self {
+            ClickValue::QuarterMoa => Angle(0.25),
+            ClickValue::EighthMoa => Angle(0.125),
+            ClickValue::TenthMil => Angle::from_mils(0.1),
+            ClickValue::TwentiethMil => Angle::from_mils(0.05),
+            ClickValue::Custom(angle) => *angle,
+        }
+    }
+}
+
+/// Turret solution
+///
+/// This struct represents an angular correction dialed on a turret in whole clicks, and the
+/// error left over because the correction falls between clicks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TurretSolution {
+    /// The correction rounded to the nearest whole click, ties rounding toward zero.
+    pub clicks: i64,
+    /// The angle the rounded clicks dial in MOA.
+    pub dialed: Angle,
+    /// The correction left undialed in MOA, the exact correction minus the dialed angle.
+    pub residual: Angle,
+    /// The undialed correction in inches at the distance, if one was given.
+    pub residual_inches: Option<Deflection>,
+}
+
+#[bon]
+impl TurretSolution {
+    /// Calculates the whole clicks that dial an angular correction most closely.
+    ///
+    /// A correction exactly halfway between two clicks rounds toward zero, dialing the
+    /// smaller of the two.
+    ///
+    /// # Parameters
+    /// - `correction`: The exact correction in MOA, available from mils through `Angle::from_mils`.
+    /// - `click`: The click value of the turret.
+    /// - `distance`: The distance to the target in feet, to express the residual in inches.
+    ///
+    /// # Returns
+    /// A `TurretSolution` instance representing the clicks to dial and the residual error.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] correction: Angle,
+        click: ClickValue,
+        distance: Option<Distance>,
+    ) -> Self {
+        let click = click.angle();
+        let exact = correction.0 / click.0;
+        let clicks = if exact >= 0.0 {
+            -(0.5 - exact).floor()
+        } else {
+            (exact + 0.5).floor()
+        } as i64;
+        let dialed = Angle(clicks as f64 * click.0);
+        let residual = Angle(correction.0 - dialed.0);
+
+        TurretSolution {
+            clicks,
+            dialed,
+            residual,
+            residual_inches: distance.map(|distance| Deflection(residual.subtension_at(distance))),
+        }
+    }
+}
+
+/// Zero transfer
+///
+/// This struct represents the elevation change that moves a rifle's zero from one distance to
+/// another, found as the difference between the zero angles of the two distances. Moving the
+/// zero back reverses the sign.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZeroTransfer {
+    /// The elevation change in MOA, positive values meaning dialing up.
+    pub elevation: Angle,
+    /// The elevation change in whole clicks, with the residual in inches at the new zero.
+    pub turret: TurretSolution,
+}
+
+#[bon]
+impl ZeroTransfer {
+    /// Calculates the elevation change that moves the zero from one distance to another.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, sight height and atmosphere.
+    /// - `current_zero`: The distance the rifle is zeroed at in feet.
+    /// - `new_zero`: The distance the rifle should be zeroed at in feet.
+    /// - `click`: The click value of the turret.
+    ///
+    /// # Returns
+    /// A `ZeroTransfer` instance, or `BallisticsError::UnreachableZero` if either zero cannot
+    /// be reached.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        solver: TrajectorySolver,
+        current_zero: Distance,
+        new_zero: Distance,
+        click: ClickValue,
+    ) -> Result<Self, BallisticsError> {
+        let current_angle = solver.zero_angle().zero_range(current_zero).solve()?;
+        let new_angle = solver.zero_angle().zero_range(new_zero).solve()?;
+        let elevation = Angle(new_angle.0 - current_angle.0);
+
+        Ok(ZeroTransfer {
+            elevation,
+            turret: TurretSolution::calculate()
+                .correction(elevation)
+                .click(click)
+                .distance(new_zero)
+                .solve(),
+        })
+    }
+}
@@ -0,0 +1,131 @@
+use core::f64::consts::PI;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{units::CENTIMETERS_PER_INCH, Angle, Distance};
+
+/// Number of minutes of angle in one radian.
+pub(crate) const MOA_PER_RADIAN: f64 = 10800.0 / PI;
+
+/// Number of inches in one foot.
+const INCHES_PER_FOOT: f64 = 12.0;
+
+/// Number of centimeters in one meter.
+const CENTIMETERS_PER_METER: f64 = 100.0;
+
+impl Angle {
+    /// Creates an angle from radians.
+    pub fn from_radians(radians: f64) -> Self {
+        Angle(radians * MOA_PER_RADIAN)
+    }
+
+    /// Creates an angle from milliradians.
+    pub fn from_mils(mils: f64) -> Self {
+        Angle::from_radians(mils / 1000.0)
+    }
+
+    /// Creates an angle from degrees.
+    pub fn from_degrees(degrees: f64) -> Self {
+        Angle(degrees * 60.0)
+    }
+
+    /// Returns the angle in degrees.
+    pub fn degrees(&self) -> f64 {
+        self.0 / 60.0
+    }
+
+    /// Returns the angle in radians.
+    pub fn radians(&self) -> f64 {
+        self.0 / MOA_PER_RADIAN
+    }
+
+    /// Returns the angle in milliradians.
+    pub fn mils(&self) -> f64 {
+        self.radians() * 1000.0
+    }
+
+    /// Creates the angle subtended by a linear size in inches at a distance.
+    ///
+    /// # Parameters
+    /// - `inches`: The linear size in inches.
+    /// - `distance`: The distance in feet.
+    pub fn from_subtension(inches: f64, distance: Distance) -> Self {
+        Angle::from_radians((inches / (distance.0 * INCHES_PER_FOOT)).atan())
+    }
+
+    /// Creates the angle subtended by a linear size in centimeters at a distance.
+    ///
+    /// # Parameters
+    /// - `centimeters`: The linear size in centimeters.
+    /// - `distance`: The distance in feet.
+    pub fn from_subtension_cm(centimeters: f64, distance: Distance) -> Self {
+        Angle::from_radians((centimeters / (distance.meters() * CENTIMETERS_PER_METER)).atan())
+    }
+
+    /// Creates the angle subtended by a target of a linear size in inches centered on the line
+    /// of sight at a distance, e.g. to dial or hold for a steel plate.
+    ///
+    /// The angle is exact, twice the angle from the line of sight to either edge, where
+    /// `Angle::from_subtension` measures from the line of sight to one edge. Both depart from the
+    /// small-angle approximation, the size over the distance, only for close or large targets.
+    ///
+    /// # Parameters
+    /// - `inches`: The size of the target in inches.
+    /// - `distance`: The distance to the target in feet.
+    pub fn of_target(inches: f64, distance: Distance) -> Self {
+        Angle::from_radians(2.0 * (inches / 2.0 / (distance.0 * INCHES_PER_FOOT)).atan())
+    }
+
+    /// Creates the angle subtended by a target of a linear size in centimeters centered on the
+    /// line of sight at a distance.
+    ///
+    /// # Parameters
+    /// - `centimeters`: The size of the target in centimeters.
+    /// - `distance`: The distance to the target in feet.
+    pub fn of_target_cm(centimeters: f64, distance: Distance) -> Self {
+        Angle::of_target(centimeters / CENTIMETERS_PER_INCH, distance)
+    }
+
+    /// Returns the linear size in inches subtended by the angle at a distance,
+    /// about 1.047 inches per MOA at 100 yards.
+    pub fn subtension_at(&self, distance: Distance) -> f64 {
+        distance.0 * INCHES_PER_FOOT * self.radians().tan()
+    }
+
+    /// Returns the linear size in centimeters subtended by the angle at a distance,
+    /// about 10 centimeters per mil at 100 meters.
+    pub fn subtension_cm_at(&self, distance: Distance) -> f64 {
+        distance.meters() * CENTIMETERS_PER_METER * self.radians().tan()
+    }
+}
+
+impl Distance {
+    /// Ranges a target of known size from its size read in a reticle, the inverse of
+    /// `Angle::from_subtension`, so a 36 inch target reading 2 mils is 500 yards away.
+    ///
+    /// # Parameters
+    /// - `inches`: The known size of the target in inches.
+    /// - `reading`: The size of the target read in the reticle in MOA, available from mils
+    ///   through `Angle::from_mils`.
+    ///
+    /// # Returns
+    /// The distance to the target in feet, available in yards and meters through
+    /// `Distance::yards` and `Distance::meters`.
+    pub fn from_ranging(inches: f64, reading: Angle) -> Self {
+        Distance(inches / INCHES_PER_FOOT / reading.radians().tan())
+    }
+
+    /// Ranges a target of known size in centimeters from its size read in a reticle, the
+    /// inverse of `Angle::from_subtension_cm`, so a 1 meter target reading 2 mils is 500 meters
+    /// away.
+    ///
+    /// # Parameters
+    /// - `centimeters`: The known size of the target in centimeters.
+    /// - `reading`: The size of the target read in the reticle in MOA.
+    ///
+    /// # Returns
+    /// The distance to the target in feet.
+    pub fn from_ranging_cm(centimeters: f64, reading: Angle) -> Self {
+        Distance::from_meters(centimeters / CENTIMETERS_PER_METER / reading.radians().tan())
+    }
+}
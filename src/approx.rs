@@ -0,0 +1,104 @@
+use crate::{
+    AerodynamicJump, AirDensity, Altitude, Angle, ApertureSightCalibration, BallisticCoefficient,
+    BarrelLength, BarrelVelocityEstimate, BulletDiameter, BulletDrop, BulletLength, BulletWeight,
+    Deflection, DensityRatio, Distance, DragCoefficient, FormFactor, Gravity, GyroscopicStability,
+    HitProbability, InclineCorrection, KineticEnergy, LagTime, MachNumber, Pressure,
+    ProjectileLength, RelativeHumidity, RetardationCoefficient, RiflingTwist, SectionalDensity,
+    SightCalibration, SightHeight, SightRadius, SpeedOfSound, SpinDrift, SpinRate, TargetSize,
+    Temperature, ThresholdDistance, TimeOfFlight, TwistRate, Velocity, VelocityProjection,
+    VerticalWindDeflection, WindDeflection, WindSpeed, YawOfRepose,
+};
+
+/// Approximate equality
+///
+/// This trait compares floating point quantities within a tolerance, since results computed
+/// along different paths rarely agree to the last bit. NaN is never equal to anything, itself
+/// included, and an infinity is only equal to the same infinity, whatever the tolerance.
+pub trait ApproxEq {
+    /// Returns whether two values differ by at most `epsilon`, in the unit of the quantity.
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool;
+
+    /// Returns whether two values differ by at most `max_relative` times the larger of their
+    /// magnitudes, e.g. `1e-3` for agreement within 0.1%.
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool;
+}
+
+impl ApproxEq for f64 {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self == other || (self.is_finite() && other.is_finite() && (self - other).abs() <= epsilon)
+    }
+
+    fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+        self == other
+            || (self.is_finite()
+                && other.is_finite()
+                && (self - other).abs() <= max_relative * self.abs().max(other.abs()))
+    }
+}
+
+/// Implements `ApproxEq` for newtypes by comparing their inner `f64`.
+macro_rules! impl_approx_eq {
+    ($($newtype:ident),* $(,)?) => {
+        $(
+            impl ApproxEq for $newtype {
+                fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+                    self.0.approx_eq(&other.0, epsilon)
+                }
+
+                fn relative_eq(&self, other: &Self, max_relative: f64) -> bool {
+                    self.0.relative_eq(&other.0, max_relative)
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_eq!(
+    Gravity,
+    SpeedOfSound,
+    TimeOfFlight,
+    Distance,
+    WindSpeed,
+    SpinDrift,
+    DragCoefficient,
+    RiflingTwist,
+    BulletLength,
+    BulletDiameter,
+    SightCalibration,
+    SightRadius,
+    AirDensity,
+    LagTime,
+    WindDeflection,
+    VerticalWindDeflection,
+    VelocityProjection,
+    ApertureSightCalibration,
+    FormFactor,
+    AerodynamicJump,
+    BulletWeight,
+    Temperature,
+    Pressure,
+    Velocity,
+    GyroscopicStability,
+    KineticEnergy,
+    BallisticCoefficient,
+    MachNumber,
+    BulletDrop,
+    Angle,
+    SightHeight,
+    TargetSize,
+    Deflection,
+    InclineCorrection,
+    ThresholdDistance,
+    BarrelLength,
+    BarrelVelocityEstimate,
+    RelativeHumidity,
+    Altitude,
+    ProjectileLength,
+    SectionalDensity,
+    SpinRate,
+    YawOfRepose,
+    TwistRate,
+    HitProbability,
+    DensityRatio,
+    RetardationCoefficient,
+);
@@ -0,0 +1,57 @@
+use bon::bon;
+
+use crate::{Distance, SpeedOfSound, TimeOfFlight, SPEED_OF_SOUND_SEA_LEVEL};
+
+/// Sound arrival
+///
+/// This struct compares the arrival at the target of a bullet and of the muzzle report, which
+/// travels at the speed of sound. A supersonic bullet also carries its own crack with it; only
+/// the muzzle report is modeled here.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoundArrival {
+    /// The time the muzzle report takes to reach the target in seconds.
+    pub sound_time: TimeOfFlight,
+    /// The time by which the bullet arrives before the report in seconds, negative when it
+    /// arrives after it.
+    pub lead: TimeOfFlight,
+}
+
+#[bon]
+impl SoundArrival {
+    /// Calculates when the muzzle report reaches the target relative to the bullet.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance to the target in feet.
+    /// - `time_of_flight`: The time of flight of the bullet to the target in seconds.
+    /// - `speed_of_sound`: The speed of sound in ft/s (`SPEED_OF_SOUND_SEA_LEVEL` by default).
+    ///
+    /// # Returns
+    /// A `SoundArrival` instance.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        distance: Distance,
+        #[builder(into)] time_of_flight: TimeOfFlight,
+        #[builder(default = SPEED_OF_SOUND_SEA_LEVEL, into)] speed_of_sound: SpeedOfSound,
+    ) -> Self {
+        let sound_time = distance.0 / speed_of_sound.0;
+
+        SoundArrival {
+            sound_time: TimeOfFlight(sound_time),
+            lead: TimeOfFlight(sound_time - time_of_flight.0),
+        }
+    }
+}
+
+impl SoundArrival {
+    /// Returns the time by which the bullet arrives before the report in milliseconds,
+    /// negative when it arrives after it.
+    pub fn lead_ms(&self) -> f64 {
+        self.lead.0 * 1000.0
+    }
+
+    /// Returns whether the bullet reaches the target before the report.
+    pub fn bullet_first(&self) -> bool {
+        self.lead.0 > 0.0
+    }
+}
@@ -0,0 +1,253 @@
+use bon::{bon, Builder};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    AirDensity, Altitude, BallisticCoefficient, DensityRatio, GyroscopicStability, Pressure,
+    RelativeHumidity, SpeedOfSound, Temperature, AIR_DENSITY_SEA_LEVEL,
+    ARMY_STANDARD_METRO_HUMIDITY, ARMY_STANDARD_METRO_PRESSURE, STANDARD_PRESSURE,
+    STANDARD_TEMPERATURE,
+};
+
+/// Temperature lapse rate of the ICAO standard atmosphere in the troposphere (°F/ft).
+const LAPSE_RATE: f64 = 0.00356616;
+
+/// Ratio of the molecular weights of water vapor and dry air subtracted from one, the share of
+/// a partial pressure of water vapor that does not contribute to the density of the air.
+const VAPOR_DENSITY_DEFICIT: f64 = 0.3783;
+
+/// Number of inches of Mercury in one hectopascal.
+const INHG_PER_HPA: f64 = 0.029530;
+
+/// Atmosphere
+///
+/// This struct represents the air a bullet flies through. Every field defaults to the ICAO
+/// standard atmosphere at sea level: 59 °F, 29.92 inHg and dry air.
+#[derive(Debug, Clone, Copy, PartialEq, Builder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Atmosphere {
+    /// The air temperature in degrees Fahrenheit.
+    #[builder(default = STANDARD_TEMPERATURE)]
+    pub temperature: Temperature,
+    /// The station (absolute) air pressure in inches of Mercury, not corrected to sea level.
+    #[builder(default = STANDARD_PRESSURE)]
+    pub pressure: Pressure,
+    /// The relative humidity in percent.
+    #[builder(default = RelativeHumidity(0.0))]
+    pub relative_humidity: RelativeHumidity,
+    /// The altitude at which the conditions were taken in feet, if known.
+    #[builder(into)]
+    pub altitude: Option<Altitude>,
+}
+
+/// Reference atmosphere of a ballistic coefficient
+///
+/// This enum represents the standard conditions a published ballistic coefficient was
+/// normalized to. Most modern data is referenced to the ICAO atmosphere, while older data, such
+/// as Sierra's, is referenced to the Army Standard Metro atmosphere, whose air is about 1.8%
+/// thinner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReferenceAtmosphere {
+    /// ICAO standard atmosphere: 59 °F, 29.92 inHg and dry air (the default).
+    #[default]
+    Icao,
+    /// Army Standard Metro atmosphere: 59 °F, 29.5275 inHg and 78% relative humidity.
+    ArmyStandardMetro,
+}
+
+impl ReferenceAtmosphere {
+    /// Returns the conditions of this reference atmosphere.
+    pub fn atmosphere(&self) -> Atmosphere {
+        match self {
+            ReferenceAtmosphere::Icao => Atmosphere::icao(),
+            ReferenceAtmosphere::ArmyStandardMetro => Atmosphere::army_standard_metro(),
+        }
+    }
+}
+
+impl Default for Atmosphere {
+    fn default() -> Self {
+        Atmosphere::icao()
+    }
+}
+
+impl Atmosphere {
+    /// Returns the ICAO standard atmosphere at sea level: 59 °F, 29.92 inHg and 0% humidity.
+    pub fn icao() -> Self {
+        Atmosphere {
+            temperature: STANDARD_TEMPERATURE,
+            pressure: STANDARD_PRESSURE,
+            relative_humidity: RelativeHumidity(0.0),
+            altitude: None,
+        }
+    }
+
+    /// Returns the Army Standard Metro atmosphere: 59 °F, 29.5275 inHg and 78% humidity.
+    pub fn army_standard_metro() -> Self {
+        Atmosphere {
+            temperature: STANDARD_TEMPERATURE,
+            pressure: ARMY_STANDARD_METRO_PRESSURE,
+            relative_humidity: ARMY_STANDARD_METRO_HUMIDITY,
+            altitude: None,
+        }
+    }
+
+    /// Returns the ICAO standard atmosphere at an altitude within the troposphere (below
+    /// 36,089 ft), with dry air.
+    ///
+    /// # Parameters
+    /// - `altitude`: The altitude above mean sea level in feet.
+    pub fn standard_at(altitude: Altitude) -> Self {
+        let temperature = STANDARD_TEMPERATURE.0 - LAPSE_RATE * altitude.0;
+        let pressure = STANDARD_PRESSURE.0 * (1.0 - 6.8753e-6 * altitude.0).powf(5.2559);
+
+        Atmosphere {
+            temperature: Temperature(temperature),
+            pressure: Pressure(pressure),
+            relative_humidity: RelativeHumidity(0.0),
+            altitude: Some(altitude),
+        }
+    }
+
+    /// Returns the speed of sound in the air.
+    ///
+    /// Humid air carries sound slightly faster than dry air at the same temperature; the speed
+    /// is taken at the virtual temperature, the temperature at which dry air would have the
+    /// same density at the same pressure.
+    pub fn speed_of_sound(&self) -> SpeedOfSound {
+        let virtual_temperature = (self.temperature.0 + 459.67)
+            / (1.0 - VAPOR_DENSITY_DEFICIT * self.vapor_pressure() / self.pressure.0)
+            - 459.67;
+
+        SpeedOfSound::calculate()
+            .temperature(Temperature(virtual_temperature))
+            .solve()
+    }
+
+    /// Returns the density of the air in pounds per cubic foot.
+    pub fn air_density(&self) -> AirDensity {
+        AirDensity(AIR_DENSITY_SEA_LEVEL.0 * self.density_ratio().0)
+    }
+
+    /// Returns the density of the air relative to the ICAO standard atmosphere, which is
+    /// exactly 1.0 for `Atmosphere::icao()`.
+    ///
+    /// Humid air is lighter than dry air at the same pressure, since water vapor displaces
+    /// heavier nitrogen and oxygen; the vapor pressure is derived from the relative humidity
+    /// with the Magnus formula.
+    pub fn density_ratio(&self) -> DensityRatio {
+        DensityRatio(
+            (self.pressure.0 / STANDARD_PRESSURE.0)
+                * ((STANDARD_TEMPERATURE.0 + 459.67) / (self.temperature.0 + 459.67))
+                * (1.0 - VAPOR_DENSITY_DEFICIT * self.vapor_pressure() / self.pressure.0),
+        )
+    }
+
+    /// Returns the density altitude in feet, the altitude in the ICAO standard atmosphere at
+    /// which the air has the same density, accounting for the humidity.
+    pub fn density_altitude(&self) -> Altitude {
+        Altitude((1.0 - self.density_ratio().0.powf(1.0 / 4.2559)) / 6.8753e-6)
+    }
+
+    /// Returns the density of the air relative to a reference atmosphere, which is exactly 1.0
+    /// for the reference's own conditions.
+    ///
+    /// # Parameters
+    /// - `reference`: The reference atmosphere the ballistic coefficient in use is normalized to.
+    pub fn density_ratio_to(&self, reference: ReferenceAtmosphere) -> DensityRatio {
+        match reference {
+            ReferenceAtmosphere::Icao => self.density_ratio(),
+            _ => DensityRatio(self.density_ratio().0 / reference.atmosphere().density_ratio().0),
+        }
+    }
+
+    /// Returns the partial pressure of water vapor in the air in inches of Mercury.
+    fn vapor_pressure(&self) -> f64 {
+        self.relative_humidity.fraction() * self.saturation_vapor_pressure()
+    }
+
+    /// Returns the saturation vapor pressure of water at the air temperature in inches of Mercury.
+    fn saturation_vapor_pressure(&self) -> f64 {
+        let celsius = (self.temperature.0 - 32.0) * 5.0 / 9.0;
+
+        6.1078 * 10f64.powf(7.5 * celsius / (celsius + 237.3)) * INHG_PER_HPA
+    }
+}
+
+#[bon]
+impl GyroscopicStability {
+    /// Applies an atmospheric correction to the gyroscopic stability factor accounting for
+    /// the temperature and pressure of an atmosphere.
+    ///
+    /// # Parameters
+    /// - `atmosphere`: The atmosphere the bullet is fired in.
+    /// - `gyro_stability`: The initial gyroscopic stability factor calculated at 2800 ft/s.
+    ///
+    /// # Returns
+    /// A `GyroscopicStability` instance representing the corrected gyroscopic stability factor
+    /// of the bullet, unchanged in the ICAO standard atmosphere.
+    #[builder(finish_fn = solve)]
+    pub fn atmospheric_correction_for(
+        atmosphere: Atmosphere,
+        #[builder(into)] gyro_stability: GyroscopicStability,
+    ) -> Self {
+        GyroscopicStability::atmospheric_correction()
+            .air_temp(atmosphere.temperature)
+            .air_pressure(atmosphere.pressure)
+            .gyro_stability(gyro_stability)
+            .solve()
+    }
+}
+
+impl BallisticCoefficient {
+    /// Converts a ballistic coefficient referenced to the ICAO standard atmosphere into the
+    /// effective ballistic coefficient in air of another density.
+    ///
+    /// Drag is proportional to the density of the air, so a bullet in thin air behaves as if its
+    /// ballistic coefficient were higher by the inverse of the density ratio. `TrajectorySolver`
+    /// already accounts for its own atmosphere; use this with calculations that assume
+    /// standard air.
+    ///
+    /// # Parameters
+    /// - `density_ratio`: The density of the air relative to the ICAO standard atmosphere.
+    ///
+    /// # Returns
+    /// The effective `BallisticCoefficient` in the given air.
+    pub fn density_corrected(self, density_ratio: DensityRatio) -> Self {
+        BallisticCoefficient(self.0 / density_ratio.0)
+    }
+
+    /// Converts a ballistic coefficient measured in air of some density back to the ICAO
+    /// standard atmosphere, the inverse of `density_corrected`.
+    ///
+    /// # Parameters
+    /// - `density_ratio`: The density of the air the ballistic coefficient was measured in,
+    ///   relative to the ICAO standard atmosphere.
+    ///
+    /// # Returns
+    /// The `BallisticCoefficient` referenced to the ICAO standard atmosphere.
+    pub fn density_standardized(self, density_ratio: DensityRatio) -> Self {
+        BallisticCoefficient(self.0 * density_ratio.0)
+    }
+
+    /// Converts a ballistic coefficient from one reference atmosphere to another.
+    ///
+    /// Trajectory calculations scale the drag by the density of the air relative to the
+    /// reference, so the same bullet is described by a coefficient inversely proportional to the
+    /// density of the reference air. A ballistic coefficient referenced to
+    /// Army Standard Metro is about 1.8% lower in ICAO terms, and one referenced to ICAO about
+    /// 1.8% higher in Army Standard Metro terms.
+    ///
+    /// # Parameters
+    /// - `from`: The reference atmosphere the ballistic coefficient is quoted in.
+    /// - `to`: The reference atmosphere to convert it to.
+    ///
+    /// # Returns
+    /// The `BallisticCoefficient` referenced to `to`.
+    pub fn convert_reference(self, from: ReferenceAtmosphere, to: ReferenceAtmosphere) -> Self {
+        BallisticCoefficient(
+            self.0 * from.atmosphere().density_ratio().0 / to.atmosphere().density_ratio().0,
+        )
+    }
+}
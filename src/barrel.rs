@@ -0,0 +1,69 @@
+use bon::bon;
+
+use crate::{
+    validation::positive, BallisticsError, BarrelLength, BarrelVelocityEstimate, Velocity,
+};
+
+/// Cartridge class
+///
+/// This enum represents broad classes of cartridges sharing a typical change in muzzle
+/// velocity per inch of barrel length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CartridgeClass {
+    /// Magnum rifle cartridges, about 30 ft/s per inch.
+    MagnumRifle,
+    /// Standard rifle cartridges, about 25 ft/s per inch (the default).
+    #[default]
+    StandardRifle,
+    /// Pistol cartridges, about 10 ft/s per inch.
+    Pistol,
+}
+
+impl CartridgeClass {
+    /// Returns the typical change in muzzle velocity per inch of barrel length in ft/s.
+    pub fn velocity_per_inch(&self) -> f64 {
+        match self {
+            CartridgeClass::MagnumRifle => 30.0,
+            CartridgeClass::StandardRifle => 25.0,
+            CartridgeClass::Pistol => 10.0,
+        }
+    }
+}
+
+#[bon]
+impl BarrelVelocityEstimate {
+    /// Projects the muzzle velocity of a load fired from a barrel of a different length.
+    ///
+    /// The velocity changes linearly with the difference in barrel length, so a shorter
+    /// barrel loses velocity and a longer one gains it.
+    ///
+    /// # Parameters
+    /// - `known_velocity`: The muzzle velocity from the known barrel in feet per second (ft/s).
+    /// - `known_length`: The length of the known barrel in inches, greater than 0.
+    /// - `target_length`: The length of the barrel to project for in inches, greater than 0.
+    /// - `cartridge_class`: The class of the cartridge (standard rifle by default).
+    /// - `velocity_per_inch`: The change in velocity per inch in ft/s, overriding the cartridge class.
+    ///
+    /// # Returns
+    /// A `BarrelVelocityEstimate` instance representing the projected muzzle velocity, or
+    /// `BallisticsError::OutOfRange` if either barrel length is not positive.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] known_velocity: Velocity,
+        #[builder(into)] known_length: BarrelLength,
+        #[builder(into)] target_length: BarrelLength,
+        #[builder(default)] cartridge_class: CartridgeClass,
+        velocity_per_inch: Option<f64>,
+    ) -> Result<Self, BallisticsError> {
+        positive("known_length", known_length.0)?;
+        positive("target_length", target_length.0)?;
+
+        let velocity_per_inch =
+            velocity_per_inch.unwrap_or_else(|| cartridge_class.velocity_per_inch());
+
+        Ok(BarrelVelocityEstimate(
+            known_velocity.0 + velocity_per_inch * (target_length.0 - known_length.0),
+        ))
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    BallisticsError, BulletWeight, Distance, KineticEnergy, LagTime, PejsaTrajectory, Velocity,
+    WindDeflection, WindSpeed,
+};
+
+/// Checks that a batch input holds as many elements as the first one.
+fn same_length(param: &'static str, len: usize, expected: usize) -> Result<(), BallisticsError> {
+    if len == expected {
+        Ok(())
+    } else {
+        Err(BallisticsError::OutOfRange {
+            param,
+            value: len as f64,
+            allowed: "as long as the first input",
+        })
+    }
+}
+
+impl KineticEnergy {
+    /// Calculates the kinetic energies of many bullets at once, pairing each weight with the
+    /// velocity at the same index.
+    ///
+    /// This skips the builder of `calculate` for hot loops such as Monte Carlo simulations and
+    /// gives identical results under standard gravity.
+    ///
+    /// # Parameters
+    /// - `bullet_weights`: The weights of the bullets in grains.
+    /// - `velocities`: The velocities of the bullets in feet per second (ft/s).
+    ///
+    /// # Returns
+    /// The kinetic energies in foot-pounds, or `BallisticsError::OutOfRange` if the slices differ
+    /// in length.
+    #[cfg(feature = "std")]
+    pub fn calculate_batch(
+        bullet_weights: &[BulletWeight],
+        velocities: &[Velocity],
+    ) -> Result<Vec<KineticEnergy>, BallisticsError> {
+        same_length("velocities", velocities.len(), bullet_weights.len())?;
+
+        Ok(bullet_weights
+            .iter()
+            .zip(velocities)
+            .map(|(&bullet_weight, &velocity)| {
+                KineticEnergy::calculate_const(bullet_weight, velocity)
+            })
+            .collect())
+    }
+
+    /// Calculates the kinetic energies of many bullets at once into a caller-provided buffer,
+    /// without allocating.
+    ///
+    /// # Parameters
+    /// - `bullet_weights`: The weights of the bullets in grains.
+    /// - `velocities`: The velocities of the bullets in feet per second (ft/s).
+    /// - `out`: The buffer receiving the kinetic energies in foot-pounds.
+    ///
+    /// # Returns
+    /// `Ok(())`, or `BallisticsError::OutOfRange` if the slices differ in length, in which case
+    /// `out` is left untouched.
+    pub fn calculate_batch_into(
+        bullet_weights: &[BulletWeight],
+        velocities: &[Velocity],
+        out: &mut [f64],
+    ) -> Result<(), BallisticsError> {
+        same_length("velocities", velocities.len(), bullet_weights.len())?;
+        same_length("out", out.len(), bullet_weights.len())?;
+
+        for ((out, &bullet_weight), &velocity) in out.iter_mut().zip(bullet_weights).zip(velocities)
+        {
+            *out = KineticEnergy::calculate_const(bullet_weight, velocity).0;
+        }
+
+        Ok(())
+    }
+}
+
+impl WindDeflection {
+    /// Calculates the wind deflections of many bullets at once, pairing each lag time with the
+    /// crosswind speed at the same index.
+    ///
+    /// # Parameters
+    /// - `lag_times`: The lag times of the bullets in seconds.
+    /// - `crosswind_speeds`: The speeds of the crosswinds in miles per hour (mph).
+    ///
+    /// # Returns
+    /// The wind deflections in inches, or `BallisticsError::OutOfRange` if the slices differ in
+    /// length.
+    #[cfg(feature = "std")]
+    pub fn calculate_batch(
+        lag_times: &[LagTime],
+        crosswind_speeds: &[WindSpeed],
+    ) -> Result<Vec<WindDeflection>, BallisticsError> {
+        same_length("crosswind_speeds", crosswind_speeds.len(), lag_times.len())?;
+
+        Ok(lag_times
+            .iter()
+            .zip(crosswind_speeds)
+            .map(|(&lag_time, &crosswind_speed)| {
+                WindDeflection::calculate_const(lag_time, crosswind_speed)
+            })
+            .collect())
+    }
+
+    /// Calculates the wind deflections of many bullets at once into a caller-provided buffer,
+    /// without allocating.
+    ///
+    /// # Parameters
+    /// - `lag_times`: The lag times of the bullets in seconds.
+    /// - `crosswind_speeds`: The speeds of the crosswinds in miles per hour (mph).
+    /// - `out`: The buffer receiving the wind deflections in inches.
+    ///
+    /// # Returns
+    /// `Ok(())`, or `BallisticsError::OutOfRange` if the slices differ in length, in which case
+    /// `out` is left untouched.
+    pub fn calculate_batch_into(
+        lag_times: &[LagTime],
+        crosswind_speeds: &[WindSpeed],
+        out: &mut [f64],
+    ) -> Result<(), BallisticsError> {
+        same_length("crosswind_speeds", crosswind_speeds.len(), lag_times.len())?;
+        same_length("out", out.len(), lag_times.len())?;
+
+        for ((out, &lag_time), &crosswind_speed) in
+            out.iter_mut().zip(lag_times).zip(crosswind_speeds)
+        {
+            *out = WindDeflection::calculate_const(lag_time, crosswind_speed).0;
+        }
+
+        Ok(())
+    }
+}
+
+impl PejsaTrajectory {
+    /// Returns the remaining velocities of the bullet at many distances at once.
+    ///
+    /// # Parameters
+    /// - `distances`: The distances downrange in feet.
+    #[cfg(feature = "std")]
+    pub fn velocity_at_batch(&self, distances: &[Distance]) -> Vec<Velocity> {
+        distances
+            .iter()
+            .map(|&distance| self.velocity_at(distance))
+            .collect()
+    }
+
+    /// Writes the remaining velocities of the bullet at many distances into a caller-provided
+    /// buffer, without allocating.
+    ///
+    /// # Parameters
+    /// - `distances`: The distances downrange in feet.
+    /// - `out`: The buffer receiving the velocities in feet per second (ft/s).
+    ///
+    /// # Returns
+    /// `Ok(())`, or `BallisticsError::OutOfRange` if the slices differ in length, in which case
+    /// `out` is left untouched.
+    pub fn velocity_at_batch_into(
+        &self,
+        distances: &[Distance],
+        out: &mut [f64],
+    ) -> Result<(), BallisticsError> {
+        same_length("out", out.len(), distances.len())?;
+
+        for (out, &distance) in out.iter_mut().zip(distances) {
+            *out = self.velocity_at(distance).0;
+        }
+
+        Ok(())
+    }
+}
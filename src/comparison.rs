@@ -0,0 +1,297 @@
+use core::fmt;
+use std::io;
+
+use bon::bon;
+
+use crate::{
+    dope::{increasing_distances, DOPE_CARD_WIND},
+    BallisticsError, BulletDrop, Distance, KineticEnergy, Load, QuantityFormatter, RangeUnit,
+    StopCondition, TimeOfFlight, TrajectorySolver, Units, Velocity, WindDeflection, WindSpeed,
+};
+
+/// Values of one load at one distance of a load comparison
+///
+/// This struct represents what sets a load apart at a distance: how far the bullet drops and
+/// drifts, and how fast and hard it arrives. In the deltas of a comparison row each field holds
+/// the second load's value minus the first's.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComparedValues {
+    /// The height of the bullet relative to the line of sight in inches, negative below it.
+    pub drop: BulletDrop,
+    /// The deflection of the bullet in a 10 mph full-value crosswind from the left in inches,
+    /// positive to the right.
+    pub windage: WindDeflection,
+    /// The remaining velocity of the bullet in feet per second (ft/s).
+    pub velocity: Velocity,
+    /// The remaining kinetic energy of the bullet in foot-pounds.
+    pub energy: KineticEnergy,
+    /// The time of flight of the bullet in seconds.
+    pub time_of_flight: TimeOfFlight,
+}
+
+impl ComparedValues {
+    /// Returns the signed differences of another load's values minus these.
+    fn delta_to(&self, other: &ComparedValues) -> ComparedValues {
+        ComparedValues {
+            drop: other.drop - self.drop,
+            windage: other.windage - self.windage,
+            velocity: other.velocity - self.velocity,
+            energy: other.energy - self.energy,
+            time_of_flight: other.time_of_flight - self.time_of_flight,
+        }
+    }
+}
+
+/// Load comparison row
+///
+/// This struct represents both loads of a comparison side by side at one distance, along with
+/// the signed deltas of the second load over the first: a positive drop delta means the second
+/// bullet strikes higher, a negative windage delta that it drifts less.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoadComparisonRow {
+    /// The distance to the target in feet.
+    pub distance: Distance,
+    /// The values of the first load.
+    pub load_a: ComparedValues,
+    /// The values of the second load.
+    pub load_b: ComparedValues,
+    /// The values of the second load minus those of the first.
+    pub delta: ComparedValues,
+}
+
+/// Reads one quantity of a load as a number for a column of the table.
+type Reading<'a> = &'a dyn Fn(&ComparedValues) -> f64;
+
+/// Load comparison
+///
+/// This struct represents a side-by-side table of two loads at the same distances, to choose
+/// between them: the drop, the windage in a 10 mph full-value crosswind, the remaining
+/// velocity and energy, and the time of flight of each, with the deltas of the second load
+/// over the first.
+///
+/// Each load keeps its own zero range, sight height and atmosphere, so drops are measured from
+/// each load's own line of sight and zero. Loads zeroed at different distances differ in drop
+/// even where their bullets fly alike; zero both at the same distance to compare the bullets
+/// alone.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoadComparison {
+    rows: Vec<LoadComparisonRow>,
+}
+
+#[bon]
+impl LoadComparison {
+    /// Generates the comparison of two loads at a list of distances.
+    ///
+    /// # Parameters
+    /// - `load_a`: The first load, zeroed in its own atmosphere.
+    /// - `load_b`: The second load, zeroed in its own atmosphere, compared against the first.
+    /// - `distances`: The distances of the rows in feet, each greater than 0 and beyond the one
+    ///   before it.
+    ///
+    /// # Returns
+    /// A `LoadComparison` instance, `BallisticsError::OutOfRange` if a distance is not positive
+    /// or out of order, or `BallisticsError::BeyondMaxRange` if either bullet does not reach a
+    /// distance.
+    #[builder(finish_fn = solve)]
+    pub fn generate(
+        load_a: &Load,
+        load_b: &Load,
+        distances: &[Distance],
+    ) -> Result<Self, BallisticsError> {
+        let last = increasing_distances(distances)?;
+        let values_a = compared_values(load_a, distances, last)?;
+        let values_b = compared_values(load_b, distances, last)?;
+
+        let rows = distances
+            .iter()
+            .zip(values_a.into_iter().zip(values_b))
+            .map(|(&distance, (load_a, load_b))| LoadComparisonRow {
+                distance,
+                load_a,
+                load_b,
+                delta: load_a.delta_to(&load_b),
+            })
+            .collect();
+
+        Ok(LoadComparison { rows })
+    }
+
+    /// Writes the comparison as CSV, one row per distance after a header row naming each
+    /// column with its unit: the range, then the first load's value, the second's and the delta
+    /// of the drop (`drop_a_in,drop_b_in,drop_delta_in` by default), the windage, the velocity
+    /// (`_fps`), the energy (`_ftlb`) and the time of flight (`_s`).
+    ///
+    /// Metric units report drops and windage in centimeters (`_cm`), velocities in m/s (`_mps`)
+    /// and energies in joules (`_j`), with ranges in meters unless a range unit is given. The
+    /// windage columns are for a 10 mph full-value crosswind.
+    ///
+    /// # Parameters
+    /// - `writer`: The destination of the CSV data.
+    /// - `units`: The units of the linear columns (imperial by default).
+    /// - `range_unit`: The unit of the range column (yards or meters to match `units` by default).
+    /// - `precision`: The number of decimal places of every value (2 by default).
+    /// - `formatter`: The formatter of every value, overriding `precision`, e.g. for
+    ///   significant figures.
+    ///
+    /// # Returns
+    /// An `io::Result` reporting any error raised by the writer.
+    #[builder(finish_fn = write)]
+    pub fn to_csv<W: io::Write>(
+        &self,
+        mut writer: W,
+        #[builder(default)] units: Units,
+        range_unit: Option<RangeUnit>,
+        #[builder(default = 2)] precision: usize,
+        formatter: Option<QuantityFormatter>,
+    ) -> io::Result<()> {
+        let range_unit = range_unit.unwrap_or(units.range_unit());
+        let formatter = formatter.unwrap_or(QuantityFormatter::decimals(precision));
+        let (length, velocity, energy) = match units {
+            Units::Imperial => ("in", "fps", "ftlb"),
+            Units::Metric => ("cm", "mps", "j"),
+        };
+
+        let columns: [(&str, &str, Reading); 5] = [
+            ("drop", length, &|values| {
+                units.convert_length(values.drop.0)
+            }),
+            ("windage", length, &|values| {
+                units.convert_length(values.windage.0)
+            }),
+            ("velocity", velocity, &|values| {
+                units.convert_velocity(values.velocity)
+            }),
+            ("energy", energy, &|values| {
+                units.convert_energy(values.energy)
+            }),
+            ("tof", "s", &|values| values.time_of_flight.0),
+        ];
+
+        write!(writer, "range")?;
+        for (name, unit, _) in columns {
+            write!(
+                writer,
+                ",{name}_a_{unit},{name}_b_{unit},{name}_delta_{unit}"
+            )?;
+        }
+        writeln!(writer)?;
+
+        for row in &self.rows {
+            write!(
+                writer,
+                "{}",
+                formatter.format_value(range_unit.convert(row.distance))
+            )?;
+            for (_, _, value) in columns {
+                for values in [&row.load_a, &row.load_b, &row.delta] {
+                    write!(writer, ",{}", formatter.format_value(value(values)))?;
+                }
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LoadComparison {
+    /// Returns the rows in order of increasing distance.
+    pub fn rows(&self) -> &[LoadComparisonRow] {
+        &self.rows
+    }
+}
+
+impl<'a> IntoIterator for &'a LoadComparison {
+    type Item = &'a LoadComparisonRow;
+    type IntoIter = core::slice::Iter<'a, LoadComparisonRow>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.iter()
+    }
+}
+
+/// Prints the comparison as an aligned text table in imperial units, with ranges in yards and
+/// a column each for the first load, the second and the signed delta of every quantity.
+impl fmt::Display for LoadComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let columns: [(&str, usize, Reading); 5] = [
+            ("drop in", 1, &|values| values.drop.0),
+            ("wind in", 1, &|values| values.windage.0),
+            ("vel ft/s", 0, &|values| values.velocity.0),
+            ("energy ft-lb", 0, &|values| values.energy.0),
+            ("tof s", 3, &|values| values.time_of_flight.0),
+        ];
+
+        write!(f, "{:>8}", "range yd")?;
+        for (name, _, _) in columns {
+            write!(f, " | {name:^26}")?;
+        }
+        writeln!(f)?;
+        write!(f, "{:>8}", "")?;
+        for _ in columns {
+            write!(f, " | {:>8} {:>8} {:>8}", "a", "b", "delta")?;
+        }
+        writeln!(f)?;
+
+        for row in &self.rows {
+            write!(f, "{:>8.0}", row.distance.yards())?;
+            for (_, decimals, value) in columns {
+                write!(
+                    f,
+                    " | {:>8.decimals$} {:>8.decimals$} {:>+8.decimals$}",
+                    value(&row.load_a),
+                    value(&row.load_b),
+                    value(&row.delta),
+                )?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Integrates the trajectory of a load once in still air and once in the reference crosswind,
+/// stopping at each distance on the way out to the last one.
+fn compared_values(
+    load: &Load,
+    distances: &[Distance],
+    last: Distance,
+) -> Result<Vec<ComparedValues>, BallisticsError> {
+    let trajectory = |crosswind_speed: WindSpeed| {
+        TrajectorySolver {
+            crosswind_speed,
+            headwind_speed: WindSpeed(0.0),
+            max_range: last,
+            stop_condition: StopCondition::MaxRange,
+            ..load.solver()
+        }
+        .iter_points(last)
+    };
+    let mut still = trajectory(WindSpeed(0.0));
+    let mut windy = trajectory(DOPE_CARD_WIND);
+
+    distances
+        .iter()
+        .map(|&distance| {
+            let point = still
+                .point_at(distance)
+                .ok_or(BallisticsError::BeyondMaxRange(distance))?;
+            let windage = windy
+                .point_at(distance)
+                .ok_or(BallisticsError::BeyondMaxRange(distance))?
+                .windage;
+
+            Ok(ComparedValues {
+                drop: point.drop,
+                windage,
+                velocity: point.velocity,
+                energy: load.projectile().kinetic_energy(point.velocity),
+                time_of_flight: point.time_of_flight,
+            })
+        })
+        .collect()
+}
@@ -1,160 +1,563 @@
-use synonym::Synonym;
+use core::{
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::Float;
+
+/// Implements the conversions of quantities to their values in either precision, and parsing
+/// and borrowing the value.
+macro_rules! impl_value {
+    ($($quantity:ident),* $(,)?) => {
+        $(
+            impl From<$quantity<f64>> for f64 {
+                fn from(quantity: $quantity<f64>) -> Self {
+                    quantity.0
+                }
+            }
+
+            impl From<$quantity<f32>> for f32 {
+                fn from(quantity: $quantity<f32>) -> Self {
+                    quantity.0
+                }
+            }
+
+            impl<T> AsRef<T> for $quantity<T> {
+                fn as_ref(&self) -> &T {
+                    &self.0
+                }
+            }
+
+            impl<T: FromStr> FromStr for $quantity<T> {
+                type Err = T::Err;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    value.parse().map($quantity)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements unchecked conversions of values in either precision to quantities, for the
+/// quantities without a physical constraint to validate.
+macro_rules! impl_from_value {
+    ($($quantity:ident),* $(,)?) => {
+        $(
+            impl From<f64> for $quantity<f64> {
+                fn from(value: f64) -> Self {
+                    $quantity(value)
+                }
+            }
+
+            impl From<f32> for $quantity<f32> {
+                fn from(value: f32) -> Self {
+                    $quantity(value)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements addition, subtraction, multiplication and division of quantities by quantities of
+/// the same kind.
+macro_rules! impl_arithmetic {
+    ($($quantity:ident),* $(,)?) => {
+        $(
+            impl<T: Float> Add for $quantity<T> {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    $quantity(self.0 + rhs.0)
+                }
+            }
+
+            impl<T: Float> AddAssign for $quantity<T> {
+                fn add_assign(&mut self, rhs: Self) {
+                    self.0 += rhs.0;
+                }
+            }
+
+            impl<T: Float> Sub for $quantity<T> {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    $quantity(self.0 - rhs.0)
+                }
+            }
+
+            impl<T: Float> SubAssign for $quantity<T> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.0 -= rhs.0;
+                }
+            }
+
+            impl<T: Float> Mul for $quantity<T> {
+                type Output = Self;
+
+                fn mul(self, rhs: Self) -> Self {
+                    $quantity(self.0 * rhs.0)
+                }
+            }
+
+            impl<T: Float> MulAssign for $quantity<T> {
+                fn mul_assign(&mut self, rhs: Self) {
+                    self.0 *= rhs.0;
+                }
+            }
+
+            impl<T: Float> Div for $quantity<T> {
+                type Output = Self;
+
+                fn div(self, rhs: Self) -> Self {
+                    $quantity(self.0 / rhs.0)
+                }
+            }
+
+            impl<T: Float> DivAssign for $quantity<T> {
+                fn div_assign(&mut self, rhs: Self) {
+                    self.0 /= rhs.0;
+                }
+            }
+        )*
+    };
+}
 
 /// Gravitational constant (ft/s²)
 ///
 /// This struct represents the gravitational constant, which is the acceleration
 /// due to gravity on Earth's surface.
-#[derive(Synonym)]
-pub struct Gravity(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Gravity<T = f64>(pub T);
 
 /// Speed of sound given temperature (ft/s)
 ///
 /// This struct represents the speed of sound in air, which varies with temperature.
-#[derive(Synonym)]
-pub struct SpeedOfSound(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SpeedOfSound<T = f64>(pub T);
 
 /// Time of Flight (s)
 ///
 /// This struct represents the time of flight (either actual or theoretical) in seconds of the projectile.
-#[derive(Synonym)]
-pub struct TimeOfFlight(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct TimeOfFlight<T = f64>(pub T);
 
 /// Distance (ft)
 ///
 /// This struct represents distance traveled in feet.
-#[derive(Synonym)]
-pub struct Distance(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Distance<T = f64>(pub T);
 
 /// Wind Speed (mph)
 ///
-/// This struct represents the wind speed in miles per hour.
-#[derive(Synonym)]
-pub struct WindSpeed(pub f64);
+/// This struct represents the wind speed in miles per hour. Crosswinds are signed, positive
+/// values blowing from the shooter's left.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct WindSpeed<T = f64>(pub T);
 
 /// Spin Drift (in)
 ///
-/// This struct represents the spin drift in inches in the direction of rifling twist.
-#[derive(Synonym)]
-pub struct SpinDrift(pub f64);
+/// This struct represents the spin drift in inches, positive values being to the right as for
+/// right-hand twist and negative values to the left as for left-hand twist.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SpinDrift<T = f64>(pub T);
 
 /// Drag Coefficient
 ///
 /// This struct represents the drag coefficient of a bullet at some speed.
-#[derive(Synonym)]
-pub struct DragCoefficient(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct DragCoefficient<T = f64>(pub T);
 
 /// Rifling Twist (calibers per turn)
 ///
 /// This struct represents the rifling twist of the barrel in calibers per turn.
-#[derive(Synonym)]
-pub struct RiflingTwist(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct RiflingTwist<T = f64>(pub T);
+
+/// Twist Rate (in/turn)
+///
+/// This struct represents the rifling twist of the barrel in inches per turn, e.g. 10 for a 1:10" twist.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct TwistRate<T = f64>(pub T);
 
 /// Bullet Length (calibers)
 ///
 /// This struct represents the bullet's length in calibers.
-#[derive(Synonym)]
-pub struct BulletLength(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct BulletLength<T = f64>(pub T);
 
 /// Bullet Diameter (in)
 ///
 /// This struct represents the diameter (caliber) of the bullet in inches.
-#[derive(Synonym)]
-pub struct BulletDiameter(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct BulletDiameter<T = f64>(pub T);
 
 /// Sight Calibration (in)
 ///
-/// This struct represents either the sight movement for 20 clicks or the sight radius in inches.
-#[derive(Synonym)]
-pub struct SightCalibration(pub f64);
+/// This struct represents the movement of an aperture sight in inches.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SightCalibration<T = f64>(pub T);
+
+/// Sight Radius (in)
+///
+/// This struct represents the distance between the front and rear sights in inches.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SightRadius<T = f64>(pub T);
 
 /// Air density at sea level (lb/ft³)
 ///
 /// This struct represents the the air density in pounds per cubic feet.
-#[derive(Synonym)]
-pub struct AirDensity(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct AirDensity<T = f64>(pub T);
 
 /// Lag time of a bullet in seconds (s)
 ///
 /// This struct represents the bullet's lag time, used to determine wind deflection sensitivity.
-#[derive(Synonym)]
-pub struct LagTime(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct LagTime<T = f64>(pub T);
 
 /// Wind deflection of a bullet in inches (in)
 ///
-/// This struct represents the bullet's wind deflection.
-#[derive(Synonym)]
-pub struct WindDeflection(pub f64);
+/// This struct represents the bullet's wind deflection, positive values being to the right.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct WindDeflection<T = f64>(pub T);
+
+/// Vertical wind deflection of a bullet in inches (in)
+///
+/// This struct represents the bullet's deflection by an updraft or downdraft, positive values being high.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct VerticalWindDeflection<T = f64>(pub T);
 
 /// Get the velocity (ft/s) of a second bullet using the weight and velocity of another bullet.
 ///
 /// This struct represents the second bullet's velocity projection.
-#[derive(Synonym)]
-pub struct VelocityProjection(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct VelocityProjection<T = f64>(pub T);
 
 /// Aperture sight calibration value
 ///
 /// This struct represents the calibration value for an aperture sight.
-#[derive(Synonym)]
-pub struct ApertureSightCalibration(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct ApertureSightCalibration<T = f64>(pub T);
 
 /// Form factor of a projectile
 ///
 /// This struct represents the form factor of a projectile, which is a measure
 /// of how streamlined the projectile is. It affects the projectile's aerodynamic properties.
-#[derive(Synonym)]
-pub struct FormFactor(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct FormFactor<T = f64>(pub T);
 
 /// Aerodynamic jump of a projectile
 ///
 /// This struct represents the aerodynamic jump, which is the vertical deflection
 /// of a projectile's path as it leaves the muzzle, caused by aerodynamic forces.
-#[derive(Synonym)]
-pub struct AerodynamicJump(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct AerodynamicJump<T = f64>(pub T);
 
 /// Bullet weight (grains)
 ///
 /// This struct represents the weight of the bullet in grains.
-#[derive(Synonym)]
-pub struct BulletWeight(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct BulletWeight<T = f64>(pub T);
 
 /// Temperature (F)
 ///
 /// This struct represents the temperature in Fahrenheit.
-#[derive(Synonym)]
-pub struct Temperature(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Temperature<T = f64>(pub T);
 
 /// Pressure (inHg)
 ///
 /// This struct represents air pressure in inches of Mercury
-#[derive(Synonym)]
-pub struct Pressure(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Pressure<T = f64>(pub T);
 
 /// Velocity (ft/s)
 ///
 /// This struct represents the bullet velocity in feet per second.
-#[derive(Synonym)]
-pub struct Velocity(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Velocity<T = f64>(pub T);
 
 /// Miller's Stability Formula (dimensionless)
 ///
 /// This struct represents the gyroscopic stability factor of a projectile,
 /// calculated using Miller's stability formula.
-#[derive(Synonym)]
-pub struct GyroscopicStability(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct GyroscopicStability<T = f64>(pub T);
 
 /// Kinetic Energy (ft-lb)
 ///
 /// This struct represents the kinetic energy of a projectile, which is the
 /// energy it possesses due to its motion.
-#[derive(Synonym)]
-pub struct KineticEnergy(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct KineticEnergy<T = f64>(pub T);
 
 /// Ballistic Coefficient (dimensionless)
 ///
 /// This struct represents the ballistic coefficient of a projectile, which
 /// is a measure of its ability to overcome air resistance in flight.
-#[derive(Synonym)]
-pub struct BallisticCoefficient(pub f64);
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct BallisticCoefficient<T = f64>(pub T);
+
+/// Mach Number (dimensionless)
+///
+/// This struct represents the velocity of a projectile as a multiple of the local speed of sound.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct MachNumber<T = f64>(pub T);
+
+/// Bullet Drop (in)
+///
+/// This struct represents the height of the bullet relative to the line of sight in inches,
+/// negative values being below it.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct BulletDrop<T = f64>(pub T);
+
+/// Angle (MOA)
+///
+/// This struct represents an angle in (true) minutes of angle, 1/60 of a degree.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Angle<T = f64>(pub T);
+
+/// Sight Height (in)
+///
+/// This struct represents the height of the line of sight above the center of the bore in inches.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SightHeight<T = f64>(pub T);
+
+/// Target Size (in)
+///
+/// This struct represents a linear dimension of a target in inches, such as its height or the radius of its vital zone.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct TargetSize<T = f64>(pub T);
+
+/// Deflection (in)
+///
+/// This struct represents the linear displacement of the point of impact from the point of aim in inches,
+/// positive values being up or to the right.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Deflection<T = f64>(pub T);
+
+/// Incline Correction (ft)
+///
+/// This struct represents the horizontal distance to dial for when shooting uphill or downhill.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct InclineCorrection<T = f64>(pub T);
+
+/// Threshold Distance (ft)
+///
+/// This struct represents the farthest distance in feet at which a bullet keeps a minimum velocity or energy.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct ThresholdDistance<T = f64>(pub T);
+
+/// Barrel Length (in)
+///
+/// This struct represents the length of a barrel in inches.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct BarrelLength<T = f64>(pub T);
+
+/// Barrel Velocity Estimate (ft/s)
+///
+/// This struct represents the muzzle velocity projected for a different barrel length in feet per second.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct BarrelVelocityEstimate<T = f64>(pub T);
+
+/// Relative Humidity (%)
+///
+/// This struct represents the relative humidity of the air in percent, from 0 to 100.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct RelativeHumidity<T = f64>(pub T);
+
+/// Altitude (ft)
+///
+/// This struct represents the altitude above mean sea level in feet.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Altitude<T = f64>(pub T);
+
+/// Projectile Length (in)
+///
+/// This struct represents the overall length of a bullet in inches.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct ProjectileLength<T = f64>(pub T);
+
+/// Sectional Density (lb/in²)
+///
+/// This struct represents the weight of a bullet in pounds divided by the square of its diameter in inches.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SectionalDensity<T = f64>(pub T);
+
+/// Spin Rate (rpm)
+///
+/// This struct represents the rate at which a bullet spins about its axis in revolutions per minute.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct SpinRate<T = f64>(pub T);
+
+/// Yaw of Repose (°)
+///
+/// This struct represents the equilibrium yaw of a spinning bullet in degrees, positive values
+/// pointing the nose to the right.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct YawOfRepose<T = f64>(pub T);
+
+/// Hit Probability
+///
+/// This struct represents the probability of a shot striking a target, from 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct HitProbability<T = f64>(pub T);
+
+/// Air Density Ratio
+///
+/// This struct represents the density of the air relative to the ICAO standard atmosphere, 1.0
+/// at sea level on a standard day.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct DensityRatio<T = f64>(pub T);
+
+/// Retardation Coefficient (ft)
+///
+/// This struct represents Pejsa's retardation coefficient, the distance in feet over which a
+/// bullet would lose all of its velocity at its current rate of deceleration.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct RetardationCoefficient<T = f64>(pub T);
+
+/// Gun Number
+///
+/// This struct represents the constant of the quick wind formula for a load, the range in
+/// hundreds of yards times the crosswind speed in mph divided by which gives the deflection in MOA.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct GunNumber<T = f64>(pub T);
+
+impl_value!(
+    Gravity,
+    SpeedOfSound,
+    TimeOfFlight,
+    Distance,
+    WindSpeed,
+    SpinDrift,
+    DragCoefficient,
+    RiflingTwist,
+    TwistRate,
+    BulletLength,
+    BulletDiameter,
+    SightCalibration,
+    SightRadius,
+    AirDensity,
+    LagTime,
+    WindDeflection,
+    VerticalWindDeflection,
+    VelocityProjection,
+    ApertureSightCalibration,
+    FormFactor,
+    AerodynamicJump,
+    BulletWeight,
+    Temperature,
+    Pressure,
+    Velocity,
+    GyroscopicStability,
+    KineticEnergy,
+    BallisticCoefficient,
+    MachNumber,
+    BulletDrop,
+    Angle,
+    SightHeight,
+    TargetSize,
+    Deflection,
+    InclineCorrection,
+    ThresholdDistance,
+    BarrelLength,
+    BarrelVelocityEstimate,
+    RelativeHumidity,
+    Altitude,
+    ProjectileLength,
+    SectionalDensity,
+    SpinRate,
+    YawOfRepose,
+    HitProbability,
+    DensityRatio,
+    RetardationCoefficient,
+    GunNumber,
+);
+
+impl_from_value!(
+    Gravity,
+    SpeedOfSound,
+    TimeOfFlight,
+    WindSpeed,
+    SpinDrift,
+    RiflingTwist,
+    TwistRate,
+    BulletLength,
+    SightCalibration,
+    SightRadius,
+    AirDensity,
+    LagTime,
+    WindDeflection,
+    VerticalWindDeflection,
+    VelocityProjection,
+    ApertureSightCalibration,
+    FormFactor,
+    AerodynamicJump,
+    Velocity,
+    GyroscopicStability,
+    KineticEnergy,
+    BallisticCoefficient,
+    MachNumber,
+    BulletDrop,
+    Angle,
+    SightHeight,
+    TargetSize,
+    Deflection,
+    InclineCorrection,
+    ThresholdDistance,
+    BarrelLength,
+    BarrelVelocityEstimate,
+    Altitude,
+    ProjectileLength,
+    SectionalDensity,
+    SpinRate,
+    YawOfRepose,
+    HitProbability,
+    DensityRatio,
+    RetardationCoefficient,
+    GunNumber,
+);
+
+impl_arithmetic!(
+    Gravity,
+    SpeedOfSound,
+    DragCoefficient,
+    RiflingTwist,
+    TwistRate,
+    BulletLength,
+    BulletDiameter,
+    SightCalibration,
+    SightRadius,
+    AirDensity,
+    LagTime,
+    VelocityProjection,
+    ApertureSightCalibration,
+    FormFactor,
+    AerodynamicJump,
+    BulletWeight,
+    Temperature,
+    GyroscopicStability,
+    BallisticCoefficient,
+    MachNumber,
+    SightHeight,
+    TargetSize,
+    InclineCorrection,
+    ThresholdDistance,
+    BarrelLength,
+    BarrelVelocityEstimate,
+    RelativeHumidity,
+    Altitude,
+    ProjectileLength,
+    SectionalDensity,
+    SpinRate,
+    YawOfRepose,
+    HitProbability,
+    DensityRatio,
+    RetardationCoefficient,
+    GunNumber,
+);
 
 /// Standard gravitational constant (ft/s²)
 ///
@@ -181,3 +584,15 @@ pub const STANDARD_PRESSURE: Pressure = Pressure(29.92);
 ///
 /// This constant represents standard temperature.
 pub const STANDARD_TEMPERATURE: Temperature = Temperature(59.0);
+
+/// Army Standard Metro definition of standard pressure (inHg)
+///
+/// This constant represents the standard air pressure older published ballistic coefficients
+/// are referenced to.
+pub const ARMY_STANDARD_METRO_PRESSURE: Pressure = Pressure(29.5275);
+
+/// Army Standard Metro definition of standard relative humidity (%)
+///
+/// This constant represents the standard relative humidity older published ballistic
+/// coefficients are referenced to.
+pub const ARMY_STANDARD_METRO_HUMIDITY: RelativeHumidity = RelativeHumidity(78.0);
@@ -0,0 +1,98 @@
+use core::ops::Add;
+
+use crate::{
+    units::CENTIMETERS_PER_INCH, Angle, BulletDrop, Deflection, Distance, JumpDeflection,
+    SpinDrift, VerticalWindDeflection, WindDeflection,
+};
+
+/// Correction
+///
+/// This struct represents a linear displacement of the point of impact at a known distance,
+/// positive values being up or to the right, from which its angular size follows. Corrections
+/// for the same distance add up, so the vertical or horizontal effects of drop, wind, spin
+/// drift and aerodynamic jump can be combined into a single hold or dial.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Correction {
+    /// The displacement in inches.
+    pub deflection: Deflection,
+    /// The distance the displacement applies at in feet.
+    pub distance: Distance,
+}
+
+impl Correction {
+    /// Creates a correction from a displacement in inches at a distance in feet.
+    pub fn new(deflection: Deflection, distance: Distance) -> Self {
+        Correction {
+            deflection,
+            distance,
+        }
+    }
+
+    /// Returns the displacement in inches.
+    pub fn as_inches(&self) -> f64 {
+        self.deflection.0
+    }
+
+    /// Returns the displacement in centimeters.
+    pub fn as_cm(&self) -> f64 {
+        self.deflection.0 * CENTIMETERS_PER_INCH
+    }
+
+    /// Returns the angle subtended by the displacement in true minutes of angle.
+    pub fn as_moa(&self) -> Angle {
+        Angle::from_subtension(self.deflection.0, self.distance)
+    }
+
+    /// Returns the angle subtended by the displacement in milliradians.
+    pub fn as_mil(&self) -> f64 {
+        self.as_moa().mils()
+    }
+}
+
+impl Add for Correction {
+    type Output = Correction;
+
+    /// Adds two corrections applying at the same distance.
+    fn add(self, other: Correction) -> Correction {
+        debug_assert_eq!(
+            self.distance, other.distance,
+            "corrections must apply at the same distance"
+        );
+
+        Correction::new(
+            Deflection(self.deflection.0 + other.deflection.0),
+            self.distance,
+        )
+    }
+}
+
+/// Implements `correction_at` for newtypes holding a displacement in inches.
+macro_rules! impl_correction_at {
+    ($($newtype:ident),* $(,)?) => {
+        $(
+            impl $newtype {
+                /// Returns the displacement as a `Correction` applying at a distance in feet.
+                pub fn correction_at(&self, distance: Distance) -> Correction {
+                    Correction::new(Deflection(self.0), distance)
+                }
+            }
+        )*
+    };
+}
+
+impl_correction_at!(
+    BulletDrop,
+    Deflection,
+    WindDeflection,
+    VerticalWindDeflection,
+    SpinDrift,
+);
+
+impl JumpDeflection {
+    /// Returns the vertical deflection as a `Correction` applying at the distance it was
+    /// calculated for, in feet.
+    pub fn correction_at(&self, distance: Distance) -> Correction {
+        self.deflection.correction_at(distance)
+    }
+}
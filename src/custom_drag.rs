@@ -0,0 +1,183 @@
+use std::{
+    fmt,
+    io::{self, BufRead, BufReader},
+};
+
+use bon::bon;
+
+use crate::{
+    DragCoefficient, DragCurve, Interpolation, MachNumber, SpeedOfSound, SPEED_OF_SOUND_SEA_LEVEL,
+};
+
+/// Quantity in the first column of a drag table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DragTableSpeed {
+    /// Mach numbers (the default).
+    #[default]
+    Mach,
+    /// Velocities in feet per second (ft/s), converted with the speed of sound.
+    Velocity,
+}
+
+/// Custom drag curve
+///
+/// This struct represents a measured drag curve, such as one derived from Doppler radar, as
+/// (Mach number, drag coefficient) points in increasing order of Mach number. Drag coefficients
+/// between points are interpolated, linearly by default, and clamped to the first or last point
+/// outside them, like the standard drag models.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomDragCurve {
+    points: Vec<(f64, f64)>,
+    interpolation: Interpolation,
+}
+
+/// Errors returned when reading a drag curve from CSV
+#[derive(Debug)]
+pub enum DragCsvError {
+    /// The reader failed.
+    Io(io::Error),
+    /// A row is not a speed and a drag coefficient, both finite and at least 0.
+    InvalidRow {
+        /// The line number of the row, starting at 1.
+        line: usize,
+        /// The content of the row.
+        row: String,
+    },
+    /// The data holds fewer than the two distinct points a curve requires.
+    TooFewPoints(usize),
+}
+
+impl fmt::Display for DragCsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DragCsvError::Io(error) => write!(f, "failed to read the drag table: {}", error),
+            DragCsvError::InvalidRow { line, row } => write!(
+                f,
+                "line {}: expected a speed and a drag coefficient, found `{}`",
+                line, row
+            ),
+            DragCsvError::TooFewPoints(points) => write!(
+                f,
+                "a drag curve needs at least 2 distinct points, found {}",
+                points
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DragCsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DragCsvError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for DragCsvError {
+    fn from(error: io::Error) -> Self {
+        DragCsvError::Io(error)
+    }
+}
+
+#[bon]
+impl CustomDragCurve {
+    /// Reads a drag curve from two-column CSV data of speed and drag coefficient.
+    ///
+    /// Blank lines are skipped, and so is the first non-blank line if it does not parse as
+    /// numbers, as a header. The points are sorted by Mach number, keeping the first of any points with the
+    /// same Mach number.
+    ///
+    /// # Parameters
+    /// - `reader`: The source of the CSV data.
+    /// - `speed`: Whether the first column holds Mach numbers or velocities (Mach by default).
+    /// - `speed_of_sound`: The speed of sound converting velocities to Mach numbers in ft/s
+    ///   (`SPEED_OF_SOUND_SEA_LEVEL` by default).
+    /// - `interpolation`: The interpolation between the points (linear by default).
+    ///
+    /// # Returns
+    /// A `CustomDragCurve` instance, or a `DragCsvError` naming the line of the first invalid
+    /// row.
+    #[builder(finish_fn = read)]
+    pub fn from_csv_reader<R: io::Read>(
+        reader: R,
+        #[builder(default)] speed: DragTableSpeed,
+        #[builder(default = SPEED_OF_SOUND_SEA_LEVEL, into)] speed_of_sound: SpeedOfSound,
+        #[builder(default)] interpolation: Interpolation,
+    ) -> Result<Self, DragCsvError> {
+        let mut points = Vec::new();
+        let mut first_row = true;
+
+        for (index, row) in BufReader::new(reader).lines().enumerate() {
+            let row = row?;
+
+            if row.trim().is_empty() {
+                continue;
+            }
+
+            let header_allowed = first_row;
+            first_row = false;
+
+            match parse_row(&row) {
+                Some((value, drag_coefficient)) => {
+                    let mach = match speed {
+                        DragTableSpeed::Mach => value,
+                        DragTableSpeed::Velocity => value / speed_of_sound.0,
+                    };
+
+                    points.push((mach, drag_coefficient));
+                }
+                None if header_allowed => continue,
+                None => {
+                    return Err(DragCsvError::InvalidRow {
+                        line: index + 1,
+                        row,
+                    })
+                }
+            }
+        }
+
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        points.dedup_by(|later, earlier| later.0 == earlier.0);
+
+        if points.len() < 2 {
+            return Err(DragCsvError::TooFewPoints(points.len()));
+        }
+
+        Ok(CustomDragCurve {
+            points,
+            interpolation,
+        })
+    }
+}
+
+impl CustomDragCurve {
+    /// Returns the (Mach number, drag coefficient) points in increasing order of Mach number,
+    /// e.g. as the table of a `PreparedDrag`.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+}
+
+impl DragCurve for CustomDragCurve {
+    fn drag_coefficient(&self, mach: MachNumber) -> DragCoefficient {
+        self.interpolation.drag_coefficient(&self.points, mach)
+    }
+}
+
+/// Parses a row of two comma-separated numbers, both finite and at least 0.
+fn parse_row(row: &str) -> Option<(f64, f64)> {
+    let mut columns = row.split(',').map(|column| column.trim().parse::<f64>());
+    let (Some(Ok(speed)), Some(Ok(drag_coefficient)), None) =
+        (columns.next(), columns.next(), columns.next())
+    else {
+        return None;
+    };
+
+    [speed, drag_coefficient]
+        .iter()
+        .all(|value| value.is_finite() && *value >= 0.0)
+        .then_some((speed, drag_coefficient))
+}
@@ -0,0 +1,190 @@
+use bon::bon;
+
+use crate::{
+    Angle, BallisticsError, Deflection, Distance, StopCondition, TrajectorySolver, Velocity,
+};
+
+/// Vertical dispersion
+///
+/// This struct represents the vertical spread at a distance caused by shot-to-shot variation
+/// of the muzzle velocity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VerticalDispersion {
+    /// The one standard deviation vertical dispersion in inches.
+    pub dispersion: Deflection,
+    /// The change in height relative to the line of sight per ft/s of muzzle velocity in inches.
+    pub drop_per_velocity: f64,
+}
+
+#[bon]
+impl VerticalDispersion {
+    /// Calculates the vertical dispersion caused by the muzzle velocity standard deviation of a load.
+    ///
+    /// The drops at one standard deviation above and below the muzzle velocity are differenced,
+    /// keeping the solver's launch angle.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, zero and atmosphere.
+    /// - `velocity_sd`: The standard deviation of the muzzle velocity in feet per second (ft/s).
+    /// - `distance`: The distance to the target in feet.
+    ///
+    /// # Returns
+    /// A `VerticalDispersion` instance, or `BallisticsError::BeyondMaxRange` if the slower
+    /// trajectory does not reach the distance within the solver's maximum range.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        solver: TrajectorySolver,
+        #[builder(into)] velocity_sd: Velocity,
+        distance: Distance,
+    ) -> Result<Self, BallisticsError> {
+        let drop_at = |muzzle_velocity: f64| {
+            TrajectorySolver {
+                muzzle_velocity: Velocity(muzzle_velocity),
+                stop_condition: StopCondition::MaxRange,
+                ..solver
+            }
+            .point_at(distance)
+            .map(|point| point.drop.0)
+            .ok_or(BallisticsError::BeyondMaxRange(solver.max_range))
+        };
+
+        let fast = drop_at(solver.muzzle_velocity.0 + velocity_sd.0)?;
+        let slow = drop_at(solver.muzzle_velocity.0 - velocity_sd.0)?;
+        let spread = (fast - slow) / 2.0;
+
+        Ok(VerticalDispersion {
+            dispersion: Deflection(spread.abs()),
+            drop_per_velocity: if velocity_sd.0 != 0.0 {
+                spread / velocity_sd.0
+            } else {
+                0.0
+            },
+        })
+    }
+}
+
+/// Shot group
+///
+/// This struct represents a group of impacts measured in inches from the point of aim,
+/// x being positive to the right and y positive upwards.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Group {
+    shots: Vec<(f64, f64)>,
+}
+
+/// Angular size of a shot group
+///
+/// This struct represents the statistics of a `Group` as angles at its distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupAngles {
+    /// The extreme spread in MOA.
+    pub extreme_spread: Angle,
+    /// The mean radius in MOA.
+    pub mean_radius: Angle,
+    /// The horizontal and vertical offsets of the group center in MOA.
+    pub center: (Angle, Angle),
+    /// The horizontal and vertical standard deviations in MOA.
+    pub standard_deviation: (Angle, Angle),
+}
+
+#[cfg(feature = "std")]
+impl Group {
+    /// Creates a group from impact coordinates in inches.
+    ///
+    /// # Parameters
+    /// - `shots`: The (x, y) coordinates of the impacts relative to the point of aim in inches.
+    ///
+    /// # Returns
+    /// A `Group` instance, or `BallisticsError::TooFewShots` if there are fewer than 2 shots.
+    pub fn new(shots: &[(f64, f64)]) -> Result<Self, BallisticsError> {
+        if shots.len() < 2 {
+            return Err(BallisticsError::TooFewShots(shots.len()));
+        }
+
+        Ok(Group {
+            shots: shots.to_vec(),
+        })
+    }
+
+    /// Returns the impact coordinates in inches.
+    pub fn shots(&self) -> &[(f64, f64)] {
+        &self.shots
+    }
+
+    /// Returns the largest center-to-center distance between two impacts in inches.
+    pub fn extreme_spread(&self) -> f64 {
+        self.shots
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| self.shots[i + 1..].iter().map(move |b| distance(*a, *b)))
+            .fold(0.0, f64::max)
+    }
+
+    /// Returns the mean distance of the impacts from the group center in inches.
+    pub fn mean_radius(&self) -> f64 {
+        let center = self.center();
+
+        self.shots
+            .iter()
+            .map(|shot| distance(*shot, center))
+            .sum::<f64>()
+            / self.count()
+    }
+
+    /// Returns the horizontal and vertical offsets of the group center from the point of aim
+    /// in inches.
+    pub fn center(&self) -> (f64, f64) {
+        let (x, y) = self
+            .shots
+            .iter()
+            .fold((0.0, 0.0), |(x, y), shot| (x + shot.0, y + shot.1));
+
+        (x / self.count(), y / self.count())
+    }
+
+    /// Returns the horizontal and vertical sample standard deviations of the impacts in inches.
+    pub fn standard_deviation(&self) -> (f64, f64) {
+        let center = self.center();
+        let (x, y) = self.shots.iter().fold((0.0, 0.0), |(x, y), shot| {
+            (
+                x + (shot.0 - center.0).powi(2),
+                y + (shot.1 - center.1).powi(2),
+            )
+        });
+
+        (
+            (x / (self.count() - 1.0)).sqrt(),
+            (y / (self.count() - 1.0)).sqrt(),
+        )
+    }
+
+    /// Returns the group statistics as angles at a distance.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance to the target in feet.
+    pub fn angles(&self, distance: Distance) -> GroupAngles {
+        let angle = |inches: f64| Angle::from_subtension(inches, distance);
+        let center = self.center();
+        let standard_deviation = self.standard_deviation();
+
+        GroupAngles {
+            extreme_spread: angle(self.extreme_spread()),
+            mean_radius: angle(self.mean_radius()),
+            center: (angle(center.0), angle(center.1)),
+            standard_deviation: (angle(standard_deviation.0), angle(standard_deviation.1)),
+        }
+    }
+
+    fn count(&self) -> f64 {
+        self.shots.len() as f64
+    }
+}
+
+#[cfg(feature = "std")]
+fn distance(a: (f64, f64), b: (f64, f64)) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
@@ -0,0 +1,280 @@
+use core::fmt::{self, Alignment, Arguments, Display, Formatter, Write};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    AerodynamicJump, AirDensity, Altitude, Angle, ApertureSightCalibration, BallisticCoefficient,
+    BarrelLength, BarrelVelocityEstimate, BulletDiameter, BulletDrop, BulletLength, BulletWeight,
+    Deflection, DensityRatio, Distance, DragCoefficient, FormFactor, Gravity, GunNumber,
+    GyroscopicStability, HitProbability, InclineCorrection, KineticEnergy, LagTime, MachNumber,
+    Pressure, ProjectileLength, RelativeHumidity, RetardationCoefficient, RiflingTwist,
+    SectionalDensity, SightCalibration, SightHeight, SightRadius, SpeedOfSound, SpinDrift,
+    SpinRate, TargetSize, Temperature, ThresholdDistance, TimeOfFlight, TwistRate, Velocity,
+    VelocityProjection, VerticalWindDeflection, WindDeflection, WindSpeed, YawOfRepose,
+};
+
+/// Implements `Display` for newtypes as their value followed by a unit suffix, along with
+/// `format_with` to print them through a `QuantityFormatter`.
+///
+/// Without a precision the value is printed with the fewest digits that represent it exactly,
+/// always keeping a decimal point (`2800.0 ft/s`); `{:.1}` sets the number of decimals, and the
+/// width, fill and alignment flags apply to the whole string including the unit.
+macro_rules! impl_display {
+    ($($newtype:ident => $unit:literal),* $(,)?) => {
+        $(
+            impl Display for $newtype {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    match f.precision() {
+                        Some(precision) => pad(f, format_args!("{:.*} {}", precision, self.0, $unit)),
+                        None => pad(f, format_args!("{:?} {}", self.0, $unit)),
+                    }
+                }
+            }
+
+            impl $newtype {
+                /// Returns the quantity formatted with the precision and unit style of a
+                /// formatter, ready to print with `Display`.
+                pub fn format_with(&self, formatter: QuantityFormatter) -> FormattedQuantity {
+                    formatter.format(self.0, $unit)
+                }
+            }
+        )*
+    };
+}
+
+impl_display!(
+    Gravity => "ft/s²",
+    SpeedOfSound => "ft/s",
+    TimeOfFlight => "s",
+    Distance => "ft",
+    WindSpeed => "mph",
+    SpinDrift => "in",
+    DragCoefficient => "(Cd)",
+    RiflingTwist => "cal/turn",
+    BulletLength => "cal",
+    BulletDiameter => "in",
+    SightCalibration => "in",
+    SightRadius => "in",
+    AirDensity => "lb/ft³",
+    LagTime => "s",
+    WindDeflection => "in",
+    VerticalWindDeflection => "in",
+    VelocityProjection => "ft/s",
+    ApertureSightCalibration => "MOA/click",
+    FormFactor => "(i)",
+    AerodynamicJump => "MOA/mph",
+    BulletWeight => "gr",
+    Temperature => "°F",
+    Pressure => "inHg",
+    Velocity => "ft/s",
+    GyroscopicStability => "(SG)",
+    KineticEnergy => "ft-lb",
+    BallisticCoefficient => "(BC)",
+    MachNumber => "Mach",
+    BulletDrop => "in",
+    Angle => "MOA",
+    SightHeight => "in",
+    TargetSize => "in",
+    Deflection => "in",
+    InclineCorrection => "ft",
+    ThresholdDistance => "ft",
+    BarrelLength => "in",
+    BarrelVelocityEstimate => "ft/s",
+    RelativeHumidity => "%",
+    Altitude => "ft",
+    ProjectileLength => "in",
+    SectionalDensity => "lb/in²",
+    SpinRate => "rpm",
+    YawOfRepose => "°",
+    TwistRate => "in/turn",
+    HitProbability => "(P)",
+    DensityRatio => "(σ)",
+    RetardationCoefficient => "ft",
+    GunNumber => "(gun)",
+);
+
+/// Counts the characters written to it, measuring formatted text without allocating.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes `text` padded to the formatter's width with its fill and alignment, right-aligned
+/// by default like other numbers.
+fn pad(f: &mut Formatter<'_>, text: Arguments<'_>) -> fmt::Result {
+    let mut count = CharCount(0);
+    count.write_fmt(text)?;
+    let padding = f.width().unwrap_or(0).saturating_sub(count.0);
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+
+    for _ in 0..before {
+        write!(f, "{}", fill)?;
+    }
+
+    f.write_fmt(text)?;
+
+    for _ in 0..after {
+        write!(f, "{}", fill)?;
+    }
+
+    Ok(())
+}
+
+/// Precision of a formatted value
+///
+/// This enum selects how many digits a `QuantityFormatter` keeps: a fixed number of decimal
+/// places, as for velocities in whole ft/s or mils to two decimals, or a number of significant
+/// figures, which suits values spanning several orders of magnitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// A fixed number of digits after the decimal point.
+    Decimals(usize),
+    /// A number of significant figures, rounding to tens, hundreds and so on when the value has
+    /// more integer digits than figures (at least 1).
+    SignificantFigures(usize),
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Precision::Decimals(2)
+    }
+}
+
+impl Precision {
+    /// Returns the value rounded to this precision and the number of decimals to print it
+    /// with.
+    fn apply(self, value: f64) -> (f64, usize) {
+        match self {
+            Precision::Decimals(decimals) => (value, decimals),
+            Precision::SignificantFigures(_) if value == 0.0 || !value.is_finite() => (value, 0),
+            Precision::SignificantFigures(figures) => {
+                let figures = figures.max(1) as i32;
+                let magnitude = |value: f64| value.abs().log10().floor() as i32;
+                let mut decimals = figures - 1 - magnitude(value);
+
+                // Rounding can carry into a new leading digit, 9.996 becoming 10.0.
+                if magnitude(round_to(value, decimals)) > figures - 1 - decimals {
+                    decimals -= 1;
+                }
+
+                if decimals >= 0 {
+                    (value, decimals as usize)
+                } else {
+                    (round_to(value, decimals), 0)
+                }
+            }
+        }
+    }
+}
+
+/// Rounds a value to a number of decimals, negative numbers rounding to tens, hundreds and so
+/// on.
+fn round_to(value: f64, decimals: i32) -> f64 {
+    let scale = 10.0_f64.powi(decimals);
+
+    (value * scale).round() / scale
+}
+
+/// Quantity formatter
+///
+/// This struct formats quantities consistently, with a precision and an optional unit suffix,
+/// through the `format_with` method every quantity provides. The CSV writers of trajectories
+/// and DOPE cards accept one to render every value the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct QuantityFormatter {
+    /// The precision of the value (2 decimal places by default).
+    pub precision: Precision,
+    /// Whether the value is followed by its unit (`false` by default, printing the unit).
+    pub hide_unit: bool,
+}
+
+impl QuantityFormatter {
+    /// Creates a formatter printing a fixed number of decimal places with the unit.
+    ///
+    /// # Parameters
+    /// - `decimals`: The number of digits after the decimal point.
+    pub const fn decimals(decimals: usize) -> Self {
+        QuantityFormatter {
+            precision: Precision::Decimals(decimals),
+            hide_unit: false,
+        }
+    }
+
+    /// Creates a formatter printing a number of significant figures with the unit.
+    ///
+    /// # Parameters
+    /// - `figures`: The number of significant figures, at least 1.
+    pub const fn significant_figures(figures: usize) -> Self {
+        QuantityFormatter {
+            precision: Precision::SignificantFigures(figures),
+            hide_unit: false,
+        }
+    }
+
+    /// Returns the same formatter printing bare values, without their unit.
+    pub const fn without_unit(self) -> Self {
+        QuantityFormatter {
+            hide_unit: true,
+            ..self
+        }
+    }
+
+    /// Formats a value in a unit, for values the quantity types do not carry themselves, such
+    /// as an angle in mils.
+    ///
+    /// # Parameters
+    /// - `value`: The value to format.
+    /// - `unit`: The unit suffix, left out if the formatter hides units.
+    pub fn format(&self, value: f64, unit: &'static str) -> FormattedQuantity {
+        let (value, decimals) = self.precision.apply(value);
+
+        FormattedQuantity {
+            value,
+            decimals,
+            unit: (!self.hide_unit).then_some(unit),
+        }
+    }
+
+    /// Formats a bare value, without a unit whatever the formatter's unit style.
+    ///
+    /// # Parameters
+    /// - `value`: The value to format.
+    pub fn format_value(&self, value: f64) -> FormattedQuantity {
+        let (value, decimals) = self.precision.apply(value);
+
+        FormattedQuantity {
+            value,
+            decimals,
+            unit: None,
+        }
+    }
+}
+
+/// Formatted quantity
+///
+/// This struct is a value rounded by a `QuantityFormatter`, printed with `Display`. The width,
+/// fill and alignment flags apply to the whole string including the unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormattedQuantity {
+    value: f64,
+    decimals: usize,
+    unit: Option<&'static str>,
+}
+
+impl Display for FormattedQuantity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            Some(unit) => pad(f, format_args!("{:.*} {}", self.decimals, self.value, unit)),
+            None => pad(f, format_args!("{:.*}", self.decimals, self.value)),
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use std::io;
+
+use bon::bon;
+
+use crate::{
+    validation::positive, Angle, AngularUnit, BallisticsError, ClickValue, Distance, KineticEnergy,
+    Load, QuantityFormatter, RangeUnit, StopCondition, TimeOfFlight, TrajectorySolver,
+    TurretSolution, Units, Velocity, WindSpeed,
+};
+
+/// Full-value crosswind the wind holds of a DOPE card are given for (mph).
+pub(crate) const DOPE_CARD_WIND: WindSpeed = WindSpeed(10.0);
+
+/// DOPE card row
+///
+/// This struct represents the data a shooter needs to engage a target at one distance of a
+/// DOPE card. Angles are given in MOA, available in milliradians through `Angle::mils`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DopeRow {
+    /// The distance to the target in feet.
+    pub distance: Distance,
+    /// The elevation to dial or hold over the zero in MOA, positive values being up.
+    pub elevation: Angle,
+    /// The elevation in whole turret clicks.
+    pub elevation_clicks: i64,
+    /// The windage to dial or hold into a 10 mph full-value crosswind in MOA.
+    pub windage: Angle,
+    /// The windage in whole turret clicks.
+    pub windage_clicks: i64,
+    /// The remaining velocity of the bullet in feet per second (ft/s).
+    pub velocity: Velocity,
+    /// The remaining kinetic energy of the bullet in foot-pounds.
+    pub energy: KineticEnergy,
+    /// The time of flight of the bullet in seconds.
+    pub time_of_flight: TimeOfFlight,
+}
+
+/// DOPE card
+///
+/// This struct represents a "data on previous engagements" card for a load: one row per
+/// distance with the elevation and windage to dial, the remaining velocity and energy, and the
+/// time of flight. The trajectory is integrated once, in still air and in the reference
+/// crosswind, stopping at each distance on the way out, so long cards with fine steps stay
+/// cheap.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DopeCard {
+    rows: Vec<DopeRow>,
+    click: ClickValue,
+}
+
+#[bon]
+impl DopeCard {
+    /// Generates a DOPE card for a load at a list of distances.
+    ///
+    /// # Parameters
+    /// - `load`: The load, zeroed in its own atmosphere.
+    /// - `distances`: The distances of the rows in feet, each greater than 0 and beyond the one
+    ///   before it.
+    /// - `click`: The click value of the turret.
+    ///
+    /// # Returns
+    /// A `DopeCard` instance, `BallisticsError::OutOfRange` if a distance is not positive or
+    /// out of order, or `BallisticsError::BeyondMaxRange` if the bullet does not reach a
+    /// distance.
+    #[builder(finish_fn = solve)]
+    pub fn generate(
+        load: &Load,
+        distances: &[Distance],
+        click: ClickValue,
+    ) -> Result<Self, BallisticsError> {
+        let previous = increasing_distances(distances)?;
+        let trajectory = |crosswind_speed: WindSpeed| {
+            TrajectorySolver {
+                crosswind_speed,
+                headwind_speed: WindSpeed(0.0),
+                max_range: previous,
+                stop_condition: StopCondition::MaxRange,
+                ..load.solver()
+            }
+            .iter_points(previous)
+        };
+        let mut still = trajectory(WindSpeed(0.0));
+        let mut windy = trajectory(DOPE_CARD_WIND);
+        let clicks = |angle: Angle| {
+            TurretSolution::calculate()
+                .correction(angle)
+                .click(click)
+                .solve()
+                .clicks
+        };
+
+        let rows = distances
+            .iter()
+            .map(|&distance| {
+                let point = still
+                    .point_at(distance)
+                    .ok_or(BallisticsError::BeyondMaxRange(distance))?;
+                let windage = windy
+                    .point_at(distance)
+                    .ok_or(BallisticsError::BeyondMaxRange(distance))?
+                    .windage;
+                let elevation = Angle(-Angle::from_subtension(point.drop.0, distance).0);
+                let windage = Angle(Angle::from_subtension(windage.0, distance).0.abs());
+
+                Ok(DopeRow {
+                    distance,
+                    elevation,
+                    elevation_clicks: clicks(elevation),
+                    windage,
+                    windage_clicks: clicks(windage),
+                    velocity: point.velocity,
+                    energy: load.projectile().kinetic_energy(point.velocity),
+                    time_of_flight: point.time_of_flight,
+                })
+            })
+            .collect::<Result<Vec<_>, BallisticsError>>()?;
+
+        Ok(DopeCard { rows, click })
+    }
+
+    /// Generates a DOPE card for a load at regular steps from a start to an end distance.
+    ///
+    /// # Parameters
+    /// - `load`: The load, zeroed in its own atmosphere.
+    /// - `start`: The distance of the first row in feet, greater than 0.
+    /// - `end`: The distance of the last row in feet, beyond `start`; it is only included when
+    ///   it falls on a step.
+    /// - `step`: The distance between two rows in feet, greater than 0.
+    /// - `click`: The click value of the turret.
+    ///
+    /// # Returns
+    /// A `DopeCard` instance, `BallisticsError::OutOfRange` if the range or step is invalid,
+    /// or `BallisticsError::BeyondMaxRange` if the bullet does not reach a distance.
+    #[builder(finish_fn = solve)]
+    pub fn over_range(
+        load: &Load,
+        start: Distance,
+        end: Distance,
+        step: Distance,
+        click: ClickValue,
+    ) -> Result<Self, BallisticsError> {
+        DopeCard::generate()
+            .load(load)
+            .distances(&range_steps(start, end, step)?)
+            .click(click)
+            .solve()
+    }
+
+    /// Writes the card as CSV, one row per distance after a header row naming each column
+    /// with its unit: the range, the elevation in MOA, mils and clicks, the windage in MOA,
+    /// mils and clicks, then `velocity_fps`, `energy_ftlb` and `tof_s` by default.
+    ///
+    /// Metric units report velocities in m/s (`velocity_mps`) and energies in joules
+    /// (`energy_j`), with ranges in meters unless a range unit is given. The windage columns
+    /// are for a 10 mph full-value crosswind.
+    ///
+    /// # Parameters
+    /// - `writer`: The destination of the CSV data.
+    /// - `units`: The units of the velocity and energy columns (imperial by default).
+    /// - `range_unit`: The unit of the range column (yards or meters to match `units` by default).
+    /// - `precision`: The number of decimal places of every value but the clicks (2 by default).
+    /// - `formatter`: The formatter of every value but the clicks, overriding `precision`, e.g.
+    ///   for significant figures.
+    ///
+    /// # Returns
+    /// An `io::Result` reporting any error raised by the writer.
+    #[builder(finish_fn = write)]
+    pub fn to_csv<W: io::Write>(
+        &self,
+        mut writer: W,
+        #[builder(default)] units: Units,
+        range_unit: Option<RangeUnit>,
+        #[builder(default = 2)] precision: usize,
+        formatter: Option<QuantityFormatter>,
+    ) -> io::Result<()> {
+        let range_unit = range_unit.unwrap_or(units.range_unit());
+        let formatter = formatter.unwrap_or(QuantityFormatter::decimals(precision));
+        let (velocity, energy) = match units {
+            Units::Imperial => ("fps", "ftlb"),
+            Units::Metric => ("mps", "j"),
+        };
+
+        writeln!(
+            writer,
+            "range,elevation_moa,elevation_mil,elevation_clicks,windage_moa,windage_mil,windage_clicks,velocity_{velocity},energy_{energy},tof_s"
+        )?;
+
+        for row in &self.rows {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{},{},{}",
+                formatter.format_value(range_unit.convert(row.distance)),
+                formatter.format_value(AngularUnit::Moa.convert(row.elevation)),
+                formatter.format_value(AngularUnit::Mil.convert(row.elevation)),
+                row.elevation_clicks,
+                formatter.format_value(AngularUnit::Moa.convert(row.windage)),
+                formatter.format_value(AngularUnit::Mil.convert(row.windage)),
+                row.windage_clicks,
+                formatter.format_value(units.convert_velocity(row.velocity)),
+                formatter.format_value(units.convert_energy(row.energy)),
+                formatter.format_value(row.time_of_flight.0),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DopeCard {
+    /// Returns the rows in order of increasing distance.
+    pub fn rows(&self) -> &[DopeRow] {
+        &self.rows
+    }
+
+    /// Returns the click value of the turret the clicks are counted in.
+    pub fn click(&self) -> ClickValue {
+        self.click
+    }
+}
+
+impl<'a> IntoIterator for &'a DopeCard {
+    type Item = &'a DopeRow;
+    type IntoIter = core::slice::Iter<'a, DopeRow>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.iter()
+    }
+}
+
+/// How the entries of a come-up list are expressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComeUpMode {
+    /// The elevation from the zero at each distance (the default).
+    #[default]
+    Absolute,
+    /// The elevation from the previous distance, or from the zero for the first one, as
+    /// taped to a stock to dial from one distance to the next.
+    Delta,
+}
+
+/// Come-ups
+///
+/// This struct represents the minimal range card of a load: the elevation to dial at regular
+/// distances, such as every 50 yards from 200 to 800, in the shooter's angular unit. Entries
+/// are either absolute elevations from the zero or deltas from the previous entry, which sum
+/// to the absolute elevations.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComeUps {
+    entries: Vec<(Distance, Angle)>,
+    angular_unit: AngularUnit,
+    mode: ComeUpMode,
+}
+
+#[bon]
+impl ComeUps {
+    /// Generates the come-ups of a load at regular steps from a start to an end distance.
+    ///
+    /// # Parameters
+    /// - `load`: The load, zeroed in its own atmosphere.
+    /// - `start`: The distance of the first entry in feet, greater than 0.
+    /// - `end`: The distance of the last entry in feet, beyond `start`; it is only included
+    ///   when it falls on a step.
+    /// - `step`: The distance between two entries in feet, greater than 0.
+    /// - `angular_unit`: The unit the entries are read in (MOA by default).
+    /// - `mode`: Whether entries are absolute or deltas from the previous one (absolute by
+    ///   default).
+    ///
+    /// # Returns
+    /// A `ComeUps` instance, `BallisticsError::OutOfRange` if the range or step is invalid, or
+    /// `BallisticsError::BeyondMaxRange` if the bullet does not reach a distance.
+    #[builder(finish_fn = solve)]
+    pub fn generate(
+        load: &Load,
+        start: Distance,
+        end: Distance,
+        step: Distance,
+        #[builder(default)] angular_unit: AngularUnit,
+        #[builder(default)] mode: ComeUpMode,
+    ) -> Result<Self, BallisticsError> {
+        let distances = range_steps(start, end, step)?;
+        let last = distances[distances.len() - 1];
+        let mut points = TrajectorySolver {
+            crosswind_speed: WindSpeed(0.0),
+            headwind_speed: WindSpeed(0.0),
+            max_range: last,
+            stop_condition: StopCondition::MaxRange,
+            ..load.solver()
+        }
+        .iter_points(step);
+        let mut previous = Angle(0.0);
+
+        let entries = distances
+            .into_iter()
+            .map(|distance| {
+                let point = points
+                    .point_at(distance)
+                    .ok_or(BallisticsError::BeyondMaxRange(distance))?;
+                let elevation = Angle(-Angle::from_subtension(point.drop.0, distance).0);
+                let entry = match mode {
+                    ComeUpMode::Absolute => elevation,
+                    ComeUpMode::Delta => Angle(elevation.0 - previous.0),
+                };
+                previous = elevation;
+
+                Ok((distance, entry))
+            })
+            .collect::<Result<Vec<_>, BallisticsError>>()?;
+
+        Ok(ComeUps {
+            entries,
+            angular_unit,
+            mode,
+        })
+    }
+}
+
+impl ComeUps {
+    /// Returns the distances in feet and elevations in MOA, positive values being up, in order
+    /// of increasing distance.
+    pub fn entries(&self) -> &[(Distance, Angle)] {
+        &self.entries
+    }
+
+    /// Returns the distances in feet and elevations converted to the angular unit.
+    pub fn values(&self) -> impl Iterator<Item = (Distance, f64)> + '_ {
+        self.entries
+            .iter()
+            .map(|&(distance, elevation)| (distance, self.angular_unit.convert(elevation)))
+    }
+
+    /// Returns the unit the entries are read in.
+    pub fn angular_unit(&self) -> AngularUnit {
+        self.angular_unit
+    }
+
+    /// Returns whether the entries are absolute or deltas from the previous one.
+    pub fn mode(&self) -> ComeUpMode {
+        self.mode
+    }
+}
+
+/// Checks that distances are positive and increasing.
+///
+/// # Returns
+/// The last distance, 0 for an empty list, or `BallisticsError::OutOfRange` if a distance is
+/// not positive or out of order.
+pub(crate) fn increasing_distances(distances: &[Distance]) -> Result<Distance, BallisticsError> {
+    let mut previous = Distance(0.0);
+
+    for &distance in distances {
+        positive("distance", distance.0)?;
+
+        if distance.0 <= previous.0 {
+            return Err(BallisticsError::OutOfRange {
+                param: "distance",
+                value: distance.0,
+                allowed: "greater than the previous distance",
+            });
+        }
+
+        previous = distance;
+    }
+
+    Ok(previous)
+}
+
+/// Returns the distances from a start to an end distance at regular steps, including the end
+/// when it falls on a step.
+///
+/// # Returns
+/// The distances, or `BallisticsError::OutOfRange` if the start or step is not positive or
+/// the end is not beyond the start.
+fn range_steps(
+    start: Distance,
+    end: Distance,
+    step: Distance,
+) -> Result<Vec<Distance>, BallisticsError> {
+    positive("start", start.0)?;
+    positive("end", end.0)?;
+    positive("step", step.0)?;
+
+    if end.0 <= start.0 {
+        return Err(BallisticsError::OutOfRange {
+            param: "end",
+            value: end.0,
+            allowed: "greater than start",
+        });
+    }
+
+    let count = ((end.0 - start.0) / step.0 + 1e-9).floor() as u64;
+
+    Ok((0..=count)
+        .map(|i| Distance(start.0 + i as f64 * step.0))
+        .collect())
+}
@@ -0,0 +1,534 @@
+use bon::bon;
+
+use crate::{
+    Atmosphere, BallisticCoefficient, BallisticsError, BulletWeight, Distance, DragModel,
+    Interpolation, KineticEnergy, LagTime, MachNumber, StopCondition, ThresholdDistance,
+    TimeOfFlight, TrajectorySolver, Velocity, WindDeflection, WindSpeed, WindZones,
+};
+
+/// Maximum number of bisections applied to a threshold distance search.
+const MAX_BISECTIONS: usize = 60;
+
+/// Width of the distance bracket at which a threshold distance search stops (ft).
+const DISTANCE_TOLERANCE: f64 = 0.01;
+
+/// Range increment at which trajectories are sampled when searching for a Mach number (ft).
+const MACH_SEARCH_STEP: Distance = Distance(1.0);
+
+/// Convergence threshold of the muzzle velocity search (ft/s).
+const VELOCITY_TOLERANCE: f64 = 1e-6;
+
+/// Maximum number of corrections applied by the muzzle velocity search.
+const MAX_VELOCITY_ITERATIONS: usize = 50;
+
+/// Minimum a bullet must retain downrange
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MinimumThreshold {
+    /// A minimum remaining velocity in feet per second (ft/s), e.g. for reliable expansion.
+    Velocity(Velocity),
+    /// A minimum remaining kinetic energy in foot-pounds (ft-lb), e.g. for ethical hunting.
+    Energy(KineticEnergy),
+}
+
+impl MinimumThreshold {
+    fn is_met(&self, bullet_weight: BulletWeight, velocity: Velocity) -> bool {
+        match self {
+            MinimumThreshold::Velocity(minimum) => velocity.0 >= minimum.0,
+            MinimumThreshold::Energy(minimum) => {
+                KineticEnergy::calculate()
+                    .bullet_weight(bullet_weight)
+                    .velocity(velocity)
+                    .solve()
+                    .0
+                    >= minimum.0
+            }
+        }
+    }
+}
+
+#[bon]
+impl KineticEnergy {
+    /// Calculates the kinetic energy a bullet retains at a distance.
+    ///
+    /// The remaining velocity is found by integrating the trajectory with a `TrajectorySolver`.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `interpolation`: The interpolation between the entries of the drag model's table (linear
+    ///   by default).
+    /// - `distance`: The distance downrange in feet.
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `KineticEnergy` instance representing the retained energy in foot-pounds, or
+    /// `BallisticsError::BeyondMaxRange` if the bullet does not reach the distance.
+    #[builder(finish_fn = solve)]
+    pub fn at_range(
+        bullet_weight: BulletWeight,
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] interpolation: Interpolation,
+        distance: Distance,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        let point = TrajectorySolver::builder()
+            .muzzle_velocity(muzzle_velocity)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(drag_model)
+            .interpolation(interpolation)
+            .temperature(atmosphere.temperature)
+            .pressure(atmosphere.pressure)
+            .relative_humidity(atmosphere.relative_humidity)
+            .max_range(distance)
+            .stop_condition(StopCondition::MaxRange)
+            .build()
+            .point_at(distance)
+            .ok_or(BallisticsError::BeyondMaxRange(distance))?;
+
+        Ok(KineticEnergy::calculate()
+            .bullet_weight(bullet_weight)
+            .velocity(point.velocity)
+            .solve())
+    }
+}
+
+#[bon]
+impl TimeOfFlight {
+    /// Calculates the time a bullet takes to reach a distance.
+    ///
+    /// The time of flight is found by integrating the trajectory with a `TrajectorySolver`.
+    ///
+    /// # Parameters
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `interpolation`: The interpolation between the entries of the drag model's table (linear
+    ///   by default).
+    /// - `distance`: The distance downrange in feet.
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `TimeOfFlight` instance representing the time of flight in seconds, or
+    /// `BallisticsError::BeyondMaxRange` if the bullet does not reach the distance.
+    #[builder(finish_fn = solve)]
+    pub fn at_range(
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] interpolation: Interpolation,
+        distance: Distance,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        let point = TrajectorySolver::builder()
+            .muzzle_velocity(muzzle_velocity)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(drag_model)
+            .interpolation(interpolation)
+            .temperature(atmosphere.temperature)
+            .pressure(atmosphere.pressure)
+            .relative_humidity(atmosphere.relative_humidity)
+            .max_range(distance)
+            .stop_condition(StopCondition::MaxRange)
+            .build()
+            .point_at(distance)
+            .ok_or(BallisticsError::BeyondMaxRange(distance))?;
+
+        Ok(point.time_of_flight)
+    }
+}
+
+#[bon]
+impl LagTime {
+    /// Calculates the lag time of a bullet at a distance from its ballistic coefficient,
+    /// integrating the actual time of flight instead of requiring a measured one.
+    ///
+    /// # Parameters
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `interpolation`: The interpolation between the entries of the drag model's table (linear
+    ///   by default).
+    /// - `distance`: The distance downrange in feet.
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `LagTime` instance representing the lag time in seconds, or
+    /// `BallisticsError::BeyondMaxRange` if the bullet does not reach the distance.
+    #[builder(finish_fn = solve)]
+    pub fn from_ballistics(
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] interpolation: Interpolation,
+        distance: Distance,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        let actual_time_of_flight = TimeOfFlight::at_range()
+            .muzzle_velocity(muzzle_velocity)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(drag_model)
+            .interpolation(interpolation)
+            .distance(distance)
+            .atmosphere(atmosphere)
+            .solve()?;
+
+        Ok(LagTime::calculate()
+            .actual_time_of_flight(actual_time_of_flight)
+            .distance(distance)
+            .muzzle_velocity(muzzle_velocity)
+            .solve())
+    }
+}
+
+#[bon]
+impl WindDeflection {
+    /// Calculates the wind deflection of a bullet at a distance from its ballistic coefficient.
+    ///
+    /// # Parameters
+    /// - `crosswind_speed`: The speed of the crosswind in miles per hour (mph), positive blowing
+    ///   from the left.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `interpolation`: The interpolation between the entries of the drag model's table (linear
+    ///   by default).
+    /// - `distance`: The distance downrange in feet.
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `WindDeflection` instance representing the wind deflection in inches, positive to the
+    /// right, or `BallisticsError::BeyondMaxRange` if the bullet does not reach the distance.
+    #[builder(finish_fn = solve)]
+    pub fn from_ballistics(
+        #[builder(into)] crosswind_speed: WindSpeed,
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] interpolation: Interpolation,
+        distance: Distance,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        let lag_time = LagTime::from_ballistics()
+            .muzzle_velocity(muzzle_velocity)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(drag_model)
+            .interpolation(interpolation)
+            .distance(distance)
+            .atmosphere(atmosphere)
+            .solve()?;
+
+        Ok(WindDeflection::calculate()
+            .lag_time(lag_time)
+            .crosswind_speed(crosswind_speed)
+            .solve())
+    }
+
+    /// Calculates the wind deflection of a bullet at a distance through wind zones.
+    ///
+    /// A wind blowing from a point to the target deflects the bullet by the lag time it
+    /// accumulates from that point on, measured against the velocity it had there. The
+    /// crosswind of each zone is weighted by the lag time accumulated from the start of the
+    /// zone less the lag time accumulated from its end, so that a single zone from the muzzle
+    /// gives the same result as `from_ballistics`.
+    ///
+    /// # Parameters
+    /// - `zones`: The wind zones along the range.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `interpolation`: The interpolation between the entries of the drag model's table (linear
+    ///   by default).
+    /// - `distance`: The distance downrange in feet.
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `WindDeflection` instance representing the wind deflection in inches, or
+    /// `BallisticsError::BeyondMaxRange` if the bullet does not reach the distance.
+    #[builder(finish_fn = solve)]
+    pub fn from_wind_zones(
+        zones: WindZones<'_>,
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] interpolation: Interpolation,
+        distance: Distance,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        let solver = TrajectorySolver::builder()
+            .muzzle_velocity(muzzle_velocity)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(drag_model)
+            .interpolation(interpolation)
+            .temperature(atmosphere.temperature)
+            .pressure(atmosphere.pressure)
+            .relative_humidity(atmosphere.relative_humidity)
+            .max_range(distance)
+            .stop_condition(StopCondition::MaxRange)
+            .build();
+        let target = solver
+            .point_at(distance)
+            .ok_or(BallisticsError::BeyondMaxRange(distance))?;
+        let mut points = solver.iter_points(distance);
+        let mut lag_time_from = |range: Distance| {
+            points
+                .point_at(range)
+                .map(|point| {
+                    LagTime::calculate()
+                        .actual_time_of_flight(target.time_of_flight.0 - point.time_of_flight.0)
+                        .distance(Distance(distance.0 - range.0))
+                        .muzzle_velocity(point.velocity)
+                        .solve()
+                })
+                .ok_or(BallisticsError::BeyondMaxRange(distance))
+        };
+
+        zones
+            .segments(distance)
+            .try_fold(WindDeflection(0.0), |deflection, (start, end, wind)| {
+                let start_lag_time = lag_time_from(start)?;
+                let lag_time = LagTime(start_lag_time.0 - lag_time_from(end)?.0);
+
+                Ok(WindDeflection(
+                    deflection.0
+                        + WindDeflection::from_wind_vector()
+                            .lag_time(lag_time)
+                            .wind(wind)
+                            .solve()
+                            .0,
+                ))
+            })
+    }
+}
+
+#[bon]
+impl ThresholdDistance {
+    /// Calculates the farthest distance at which a bullet still meets a minimum velocity or energy.
+    ///
+    /// The distance is found by bisection over the remaining velocity, which is obtained by
+    /// integrating the trajectory with a `TrajectorySolver`.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `interpolation`: The interpolation between the entries of the drag model's table (linear
+    ///   by default).
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    /// - `threshold`: The minimum velocity or energy the bullet must retain.
+    /// - `max_range`: The farthest distance searched in feet (1000 yards by default).
+    ///
+    /// # Returns
+    /// A `ThresholdDistance` instance representing the distance in feet, zero if the threshold
+    /// is not met at the muzzle, or `BallisticsError::BeyondMaxRange` if it is still met at the
+    /// maximum range.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        bullet_weight: BulletWeight,
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] interpolation: Interpolation,
+        #[builder(default)] atmosphere: Atmosphere,
+        threshold: MinimumThreshold,
+        #[builder(default = Distance(3000.0))] max_range: Distance,
+    ) -> Result<Self, BallisticsError> {
+        let solver = TrajectorySolver::builder()
+            .muzzle_velocity(muzzle_velocity)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(drag_model)
+            .interpolation(interpolation)
+            .temperature(atmosphere.temperature)
+            .pressure(atmosphere.pressure)
+            .relative_humidity(atmosphere.relative_humidity)
+            .max_range(max_range)
+            .stop_condition(StopCondition::MaxRange)
+            .build();
+        let is_met = |distance: f64| {
+            solver
+                .point_at(Distance(distance))
+                .is_some_and(|point| threshold.is_met(bullet_weight, point.velocity))
+        };
+
+        if !is_met(0.0) {
+            return Ok(ThresholdDistance(0.0));
+        }
+
+        if is_met(max_range.0) {
+            return Err(BallisticsError::BeyondMaxRange(max_range));
+        }
+
+        let mut low = 0.0;
+        let mut high = max_range.0;
+
+        for _ in 0..MAX_BISECTIONS {
+            if high - low < DISTANCE_TOLERANCE {
+                break;
+            }
+
+            let middle = (low + high) / 2.0;
+
+            if is_met(middle) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        Ok(ThresholdDistance(low))
+    }
+}
+
+/// Supersonic range
+///
+/// This struct represents the distances at which a bullet enters the transonic region and
+/// falls below the local speed of sound.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SupersonicRange {
+    /// The distance at which the bullet slows to Mach 1.2 in feet.
+    pub transonic_range: Distance,
+    /// The distance at which the bullet slows to Mach 1.0 in feet.
+    pub supersonic_range: Distance,
+}
+
+#[bon]
+impl SupersonicRange {
+    /// Calculates the distances at which a bullet slows to Mach 1.2 and Mach 1.0.
+    ///
+    /// The Mach number is relative to the speed of sound in the given atmosphere.
+    ///
+    /// # Parameters
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `interpolation`: The interpolation between the entries of the drag model's table (linear
+    ///   by default).
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    /// - `max_range`: The farthest distance searched in feet (5000 yards by default).
+    ///
+    /// # Returns
+    /// A `SupersonicRange` instance, with zero distances for a bullet that leaves the muzzle
+    /// below the corresponding Mach number, or `BallisticsError::BeyondMaxRange` if the bullet
+    /// is still supersonic at the maximum range.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] interpolation: Interpolation,
+        #[builder(default)] atmosphere: Atmosphere,
+        #[builder(default = Distance(15000.0))] max_range: Distance,
+    ) -> Result<Self, BallisticsError> {
+        let solver = TrajectorySolver::builder()
+            .muzzle_velocity(muzzle_velocity)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(drag_model)
+            .interpolation(interpolation)
+            .temperature(atmosphere.temperature)
+            .pressure(atmosphere.pressure)
+            .relative_humidity(atmosphere.relative_humidity)
+            .max_range(max_range)
+            .stop_condition(StopCondition::MaxRange)
+            .build();
+        let mut transonic_range = None;
+        let mut previous: Option<(Distance, MachNumber)> = None;
+
+        for point in solver.iter_points(MACH_SEARCH_STEP) {
+            let crossing = |mach: f64| match previous {
+                None if point.mach.0 < mach => Some(Distance(0.0)),
+                Some((distance, previous_mach))
+                    if previous_mach.0 >= mach && point.mach.0 < mach =>
+                {
+                    let fraction = (previous_mach.0 - mach) / (previous_mach.0 - point.mach.0);
+
+                    Some(Distance(
+                        distance.0 + fraction * (point.distance.0 - distance.0),
+                    ))
+                }
+                _ => None,
+            };
+
+            transonic_range = transonic_range.or(crossing(1.2));
+
+            if let (Some(transonic_range), Some(supersonic_range)) =
+                (transonic_range, crossing(1.0))
+            {
+                return Ok(SupersonicRange {
+                    transonic_range,
+                    supersonic_range,
+                });
+            }
+
+            previous = Some((point.distance, point.mach));
+        }
+
+        Err(BallisticsError::BeyondMaxRange(max_range))
+    }
+}
+
+#[bon]
+impl Velocity {
+    /// Calculates the true muzzle velocity from a chronograph reading taken downrange.
+    ///
+    /// The muzzle velocity is refined until the velocity remaining at the chronograph
+    /// matches the reading, making this the inverse of integrating the trajectory forward.
+    ///
+    /// # Parameters
+    /// - `instrumental_velocity`: The velocity measured by the chronograph in feet per second (ft/s).
+    /// - `distance`: The distance from the muzzle to the middle of the chronograph screens in feet.
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `interpolation`: The interpolation between the entries of the drag model's table (linear
+    ///   by default).
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `Velocity` instance representing the muzzle velocity, or
+    /// `BallisticsError::BeyondMaxRange` if no muzzle velocity carries the bullet to the
+    /// chronograph at the measured velocity.
+    #[builder(finish_fn = solve)]
+    pub fn muzzle_from_instrumental(
+        #[builder(into)] instrumental_velocity: Velocity,
+        distance: Distance,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] interpolation: Interpolation,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        let remaining_velocity = |muzzle_velocity: f64| {
+            TrajectorySolver::builder()
+                .muzzle_velocity(Velocity(muzzle_velocity))
+                .ballistic_coefficient(ballistic_coefficient)
+                .drag_model(drag_model)
+                .interpolation(interpolation)
+                .temperature(atmosphere.temperature)
+                .pressure(atmosphere.pressure)
+                .relative_humidity(atmosphere.relative_humidity)
+                .max_range(distance)
+                .stop_condition(StopCondition::MaxRange)
+                .build()
+                .point_at(distance)
+                .map(|point| point.velocity.0)
+                .ok_or(BallisticsError::BeyondMaxRange(distance))
+        };
+        let mut muzzle_velocity = instrumental_velocity.0;
+
+        for _ in 0..MAX_VELOCITY_ITERATIONS {
+            let correction = instrumental_velocity.0 - remaining_velocity(muzzle_velocity)?;
+
+            muzzle_velocity += correction;
+
+            if correction.abs() < VELOCITY_TOLERANCE {
+                return Ok(Velocity(muzzle_velocity));
+            }
+        }
+
+        Err(BallisticsError::BeyondMaxRange(distance))
+    }
+}
@@ -0,0 +1,512 @@
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+#[cfg(feature = "std")]
+use crate::{validation::positive, BallisticsError};
+use crate::{DragCoefficient, MachNumber};
+
+/// Drag curve
+///
+/// This trait represents anything that yields the drag coefficient of a projectile at a Mach
+/// number, so the trajectory solver can integrate through a standard drag model or a
+/// `PreparedDrag` lookup alike.
+pub trait DragCurve {
+    /// Returns the drag coefficient at the given Mach number.
+    fn drag_coefficient(&self, mach: MachNumber) -> DragCoefficient;
+}
+
+impl<T: DragCurve + ?Sized> DragCurve for &T {
+    fn drag_coefficient(&self, mach: MachNumber) -> DragCoefficient {
+        (**self).drag_coefficient(mach)
+    }
+}
+
+/// Standard drag models
+///
+/// This enum represents the standard reference projectiles whose drag curves
+/// ballistic coefficients are published against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DragModel {
+    /// The G1 (Ingalls) flat-based reference projectile.
+    #[default]
+    G1,
+    /// The G7 long boat-tail reference projectile.
+    G7,
+}
+
+impl DragModel {
+    /// Looks up the drag coefficient of the reference projectile at the given Mach number.
+    ///
+    /// Values between table entries are linearly interpolated, and values outside
+    /// the table are clamped to its first or last entry.
+    ///
+    /// # Parameters
+    /// - `mach`: The Mach number of the projectile.
+    ///
+    /// # Returns
+    /// A `DragCoefficient` instance representing the drag coefficient of the reference projectile.
+    pub fn drag_coefficient(&self, mach: MachNumber) -> DragCoefficient {
+        DragCoefficient(interpolate(self.table(), mach.0))
+    }
+
+    /// Returns the (Mach number, drag coefficient) table of the reference projectile.
+    pub fn table(&self) -> &'static [(f64, f64)] {
+        match self {
+            DragModel::G1 => &G1_TABLE,
+            DragModel::G7 => &G7_TABLE,
+        }
+    }
+}
+
+impl DragCurve for DragModel {
+    fn drag_coefficient(&self, mach: MachNumber) -> DragCoefficient {
+        DragModel::drag_coefficient(self, mach)
+    }
+}
+
+/// Interpolation between drag table entries
+///
+/// Linear interpolation leaves kinks at the table entries, which are sparse in the transonic
+/// region. A monotone cubic (Fritsch–Carlson) interpolant is smooth instead and, unlike an
+/// ordinary cubic spline, never overshoots the entries on either side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Straight lines between entries (the default).
+    #[default]
+    Linear,
+    /// Monotone cubic Hermite curves between entries.
+    MonotoneCubic,
+}
+
+impl Interpolation {
+    /// Looks up the drag coefficient at the given Mach number in a drag table.
+    ///
+    /// Both modes pass through every table entry, and values outside the table are clamped to
+    /// its first or last entry.
+    ///
+    /// # Parameters
+    /// - `table`: The (Mach number, drag coefficient) pairs in increasing order of Mach number.
+    /// - `mach`: The Mach number of the projectile.
+    ///
+    /// # Returns
+    /// A `DragCoefficient` instance representing the interpolated drag coefficient.
+    pub fn drag_coefficient(&self, table: &[(f64, f64)], mach: MachNumber) -> DragCoefficient {
+        DragCoefficient(match self {
+            Interpolation::Linear => interpolate(table, mach.0),
+            Interpolation::MonotoneCubic => monotone_cubic(table, mach.0),
+        })
+    }
+}
+
+/// Interpolated drag model
+///
+/// This struct pairs a standard drag model with the interpolation between its table entries.
+/// It is the drag curve of a `TrajectorySolver`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InterpolatedDrag {
+    /// The standard drag model.
+    pub drag_model: DragModel,
+    /// The interpolation between the entries of its table.
+    pub interpolation: Interpolation,
+}
+
+impl DragModel {
+    /// Pairs the drag model with an interpolation between its table entries.
+    pub fn interpolated(self, interpolation: Interpolation) -> InterpolatedDrag {
+        InterpolatedDrag {
+            drag_model: self,
+            interpolation,
+        }
+    }
+}
+
+impl DragCurve for InterpolatedDrag {
+    fn drag_coefficient(&self, mach: MachNumber) -> DragCoefficient {
+        self.interpolation
+            .drag_coefficient(self.drag_model.table(), mach)
+    }
+}
+
+/// Prepared drag curve
+///
+/// This struct represents a drag table resampled at uniformly spaced Mach numbers, so a lookup
+/// is a single index and linear interpolation instead of a search through the table. Mach
+/// numbers beyond the prepared range fall back to interpolating the original table.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PreparedDrag {
+    table: Vec<(f64, f64)>,
+    interpolation: Interpolation,
+    resolution: f64,
+    values: Vec<f64>,
+}
+
+/// The highest Mach number a `PreparedDrag` resamples by default.
+#[cfg(feature = "std")]
+const DEFAULT_MAX_MACH: MachNumber = MachNumber(5.0);
+
+/// The spacing of the Mach numbers a `PreparedDrag` resamples by default.
+#[cfg(feature = "std")]
+const DEFAULT_RESOLUTION: f64 = 0.001;
+
+#[cfg(feature = "std")]
+#[bon::bon]
+impl PreparedDrag {
+    /// Resamples a drag table at uniformly spaced Mach numbers.
+    ///
+    /// # Parameters
+    /// - `table`: The (Mach number, drag coefficient) pairs in increasing order of Mach number,
+    ///   e.g. `DragModel::G7.table()` or a custom curve.
+    /// - `max_mach`: The highest Mach number to resample (Mach 5 by default), greater than 0.
+    /// - `resolution`: The spacing of the resampled Mach numbers (0.001 by default), greater than 0.
+    /// - `interpolation`: The interpolation between the entries of `table` (linear by default).
+    ///
+    /// # Returns
+    /// A `PreparedDrag` instance covering Mach 0 to `max_mach`, or
+    /// `BallisticsError::OutOfRange` if `max_mach` or `resolution` is not greater than 0.
+    #[builder]
+    pub fn new(
+        table: &[(f64, f64)],
+        #[builder(default = DEFAULT_MAX_MACH, into)] max_mach: MachNumber,
+        #[builder(default = DEFAULT_RESOLUTION)] resolution: f64,
+        #[builder(default)] interpolation: Interpolation,
+    ) -> Result<Self, BallisticsError> {
+        positive("max_mach", max_mach.0)?;
+        positive("resolution", resolution)?;
+
+        Ok(PreparedDrag::resample(
+            table,
+            max_mach,
+            resolution,
+            interpolation,
+        ))
+    }
+}
+
+#[cfg(feature = "std")]
+impl PreparedDrag {
+    /// Resamples a drag table at a spacing already known to be positive.
+    fn resample(
+        table: &[(f64, f64)],
+        max_mach: MachNumber,
+        resolution: f64,
+        interpolation: Interpolation,
+    ) -> Self {
+        let count = (max_mach.0 / resolution).ceil() as usize + 1;
+
+        PreparedDrag {
+            table: table.to_vec(),
+            interpolation,
+            resolution,
+            values: (0..count)
+                .map(|index| {
+                    interpolation
+                        .drag_coefficient(table, MachNumber(index as f64 * resolution))
+                        .0
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<DragModel> for PreparedDrag {
+    /// Resamples a standard drag model from Mach 0 to 5 in steps of 0.001.
+    fn from(drag_model: DragModel) -> Self {
+        PreparedDrag::resample(
+            drag_model.table(),
+            DEFAULT_MAX_MACH,
+            DEFAULT_RESOLUTION,
+            Interpolation::default(),
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<InterpolatedDrag> for PreparedDrag {
+    /// Resamples an interpolated drag model from Mach 0 to 5 in steps of 0.001.
+    fn from(drag: InterpolatedDrag) -> Self {
+        PreparedDrag::resample(
+            drag.drag_model.table(),
+            DEFAULT_MAX_MACH,
+            DEFAULT_RESOLUTION,
+            drag.interpolation,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl DragCurve for PreparedDrag {
+    fn drag_coefficient(&self, mach: MachNumber) -> DragCoefficient {
+        let position = mach.0.max(0.0) / self.resolution;
+        let index = position.floor() as usize;
+
+        DragCoefficient(match (self.values.get(index), self.values.get(index + 1)) {
+            (Some(cd0), Some(cd1)) => cd0 + (cd1 - cd0) * (position - index as f64),
+            _ => self.interpolation.drag_coefficient(&self.table, mach).0,
+        })
+    }
+}
+
+fn interpolate(table: &[(f64, f64)], mach: f64) -> f64 {
+    let upper = table.partition_point(|&(m, _)| m < mach);
+
+    if upper == 0 {
+        return table[0].1;
+    }
+
+    if upper == table.len() {
+        return table[table.len() - 1].1;
+    }
+
+    let (m0, cd0) = table[upper - 1];
+    let (m1, cd1) = table[upper];
+
+    cd0 + (cd1 - cd0) * (mach - m0) / (m1 - m0)
+}
+
+fn monotone_cubic(table: &[(f64, f64)], mach: f64) -> f64 {
+    let upper = table.partition_point(|&(m, _)| m < mach);
+
+    if upper == 0 {
+        return table[0].1;
+    }
+
+    if upper == table.len() {
+        return table[table.len() - 1].1;
+    }
+
+    let (m0, cd0) = table[upper - 1];
+    let (m1, cd1) = table[upper];
+    let width = m1 - m0;
+    let t = (mach - m0) / width;
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    (2.0 * t3 - 3.0 * t2 + 1.0) * cd0
+        + (t3 - 2.0 * t2 + t) * width * tangent(table, upper - 1)
+        + (3.0 * t2 - 2.0 * t3) * cd1
+        + (t3 - t2) * width * tangent(table, upper)
+}
+
+/// Returns the slope of the table between entries `index` and `index + 1`.
+fn secant(table: &[(f64, f64)], index: usize) -> f64 {
+    (table[index + 1].1 - table[index].1) / (table[index + 1].0 - table[index].0)
+}
+
+/// Returns the Fritsch–Carlson tangent at entry `index`: the mean of the neighbouring secants,
+/// zero at a local extremum, scaled down wherever an adjacent interval would overshoot.
+fn tangent(table: &[(f64, f64)], index: usize) -> f64 {
+    let last = table.len() - 1;
+    let initial = |index: usize| {
+        let before = (index > 0).then(|| secant(table, index - 1));
+        let after = (index < last).then(|| secant(table, index));
+
+        match (before, after) {
+            (Some(before), Some(after)) if before * after > 0.0 => (before + after) / 2.0,
+            (Some(secant), None) | (None, Some(secant)) => secant,
+            _ => 0.0,
+        }
+    };
+    let scale = |left: usize| {
+        let secant = secant(table, left);
+
+        if secant == 0.0 {
+            return 1.0;
+        }
+
+        let radius_squared =
+            (initial(left) / secant).powi(2) + (initial(left + 1) / secant).powi(2);
+
+        if radius_squared > 9.0 {
+            3.0 / radius_squared.sqrt()
+        } else {
+            1.0
+        }
+    };
+
+    let mut factor: f64 = 1.0;
+
+    if index > 0 {
+        factor = factor.min(scale(index - 1));
+    }
+
+    if index < last {
+        factor = factor.min(scale(index));
+    }
+
+    initial(index) * factor
+}
+
+const G1_TABLE: [(f64, f64); 79] = [
+    (0.00, 0.2629),
+    (0.05, 0.2558),
+    (0.10, 0.2487),
+    (0.15, 0.2413),
+    (0.20, 0.2344),
+    (0.25, 0.2278),
+    (0.30, 0.2214),
+    (0.35, 0.2155),
+    (0.40, 0.2104),
+    (0.45, 0.2061),
+    (0.50, 0.2032),
+    (0.55, 0.2020),
+    (0.60, 0.2034),
+    (0.70, 0.2165),
+    (0.725, 0.2230),
+    (0.75, 0.2313),
+    (0.775, 0.2417),
+    (0.80, 0.2546),
+    (0.825, 0.2706),
+    (0.85, 0.2901),
+    (0.875, 0.3136),
+    (0.90, 0.3415),
+    (0.925, 0.3734),
+    (0.95, 0.4084),
+    (0.975, 0.4448),
+    (1.00, 0.4805),
+    (1.025, 0.5136),
+    (1.05, 0.5427),
+    (1.075, 0.5677),
+    (1.10, 0.5883),
+    (1.125, 0.6053),
+    (1.15, 0.6191),
+    (1.20, 0.6393),
+    (1.25, 0.6518),
+    (1.30, 0.6589),
+    (1.35, 0.6621),
+    (1.40, 0.6625),
+    (1.45, 0.6607),
+    (1.50, 0.6573),
+    (1.55, 0.6528),
+    (1.60, 0.6474),
+    (1.65, 0.6413),
+    (1.70, 0.6347),
+    (1.75, 0.6280),
+    (1.80, 0.6210),
+    (1.85, 0.6141),
+    (1.90, 0.6072),
+    (1.95, 0.6003),
+    (2.00, 0.5934),
+    (2.05, 0.5867),
+    (2.10, 0.5804),
+    (2.15, 0.5743),
+    (2.20, 0.5685),
+    (2.25, 0.5630),
+    (2.30, 0.5577),
+    (2.35, 0.5527),
+    (2.40, 0.5481),
+    (2.45, 0.5438),
+    (2.50, 0.5397),
+    (2.60, 0.5325),
+    (2.70, 0.5264),
+    (2.80, 0.5211),
+    (2.90, 0.5168),
+    (3.00, 0.5133),
+    (3.10, 0.5105),
+    (3.20, 0.5084),
+    (3.30, 0.5067),
+    (3.40, 0.5054),
+    (3.50, 0.5040),
+    (3.60, 0.5030),
+    (3.70, 0.5022),
+    (3.80, 0.5016),
+    (3.90, 0.5010),
+    (4.00, 0.5006),
+    (4.20, 0.4998),
+    (4.40, 0.4995),
+    (4.60, 0.4992),
+    (4.80, 0.4990),
+    (5.00, 0.4988),
+];
+
+#[allow(clippy::approx_constant)]
+const G7_TABLE: [(f64, f64); 84] = [
+    (0.00, 0.1198),
+    (0.05, 0.1197),
+    (0.10, 0.1196),
+    (0.15, 0.1194),
+    (0.20, 0.1193),
+    (0.25, 0.1194),
+    (0.30, 0.1194),
+    (0.35, 0.1194),
+    (0.40, 0.1193),
+    (0.45, 0.1193),
+    (0.50, 0.1194),
+    (0.55, 0.1193),
+    (0.60, 0.1194),
+    (0.65, 0.1197),
+    (0.70, 0.1202),
+    (0.725, 0.1207),
+    (0.75, 0.1215),
+    (0.775, 0.1226),
+    (0.80, 0.1242),
+    (0.825, 0.1266),
+    (0.85, 0.1306),
+    (0.875, 0.1368),
+    (0.90, 0.1464),
+    (0.925, 0.1660),
+    (0.95, 0.2054),
+    (0.975, 0.2993),
+    (1.00, 0.3803),
+    (1.025, 0.4015),
+    (1.05, 0.4043),
+    (1.075, 0.4034),
+    (1.10, 0.4014),
+    (1.125, 0.3987),
+    (1.15, 0.3955),
+    (1.20, 0.3884),
+    (1.25, 0.3810),
+    (1.30, 0.3732),
+    (1.35, 0.3657),
+    (1.40, 0.3580),
+    (1.50, 0.3440),
+    (1.55, 0.3376),
+    (1.60, 0.3315),
+    (1.65, 0.3260),
+    (1.70, 0.3209),
+    (1.75, 0.3160),
+    (1.80, 0.3117),
+    (1.85, 0.3078),
+    (1.90, 0.3042),
+    (1.95, 0.3010),
+    (2.00, 0.2980),
+    (2.05, 0.2951),
+    (2.10, 0.2922),
+    (2.15, 0.2892),
+    (2.20, 0.2864),
+    (2.25, 0.2835),
+    (2.30, 0.2807),
+    (2.35, 0.2779),
+    (2.40, 0.2752),
+    (2.45, 0.2725),
+    (2.50, 0.2697),
+    (2.55, 0.2670),
+    (2.60, 0.2643),
+    (2.65, 0.2615),
+    (2.70, 0.2588),
+    (2.75, 0.2561),
+    (2.80, 0.2533),
+    (2.85, 0.2506),
+    (2.90, 0.2479),
+    (2.95, 0.2451),
+    (3.00, 0.2424),
+    (3.10, 0.2368),
+    (3.20, 0.2313),
+    (3.30, 0.2258),
+    (3.40, 0.2205),
+    (3.50, 0.2154),
+    (3.60, 0.2106),
+    (3.70, 0.2060),
+    (3.80, 0.2017),
+    (3.90, 0.1975),
+    (4.00, 0.1935),
+    (4.20, 0.1861),
+    (4.40, 0.1793),
+    (4.60, 0.1730),
+    (4.80, 0.1672),
+    (5.00, 0.1618),
+];
@@ -0,0 +1,166 @@
+use bon::bon;
+
+use crate::{
+    stability::MINIMUM_STABILITY, validation::positive, Atmosphere, BallisticsError, Distance,
+    GyroscopicStability, KineticEnergy, MinimumThreshold, Projectile, RiflingTwist,
+    SupersonicRange, ThresholdDistance, Velocity,
+};
+
+/// Factor limiting the effective range of a load
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LimitingFactor {
+    /// The bullet is not gyroscopically stable, so the load has no effective range.
+    Stability,
+    /// The bullet slows below the local speed of sound.
+    SupersonicRange,
+    /// The bullet slows below the velocity it needs to expand.
+    ExpansionVelocity,
+    /// The kinetic energy of the bullet falls below the floor.
+    Energy,
+    /// Every requirement is still met at the maximum range searched.
+    MaxRange,
+}
+
+/// Effective range
+///
+/// This struct represents what a load is good for: how stable its bullet is and how far it
+/// stays supersonic, above its expansion velocity and above an energy floor. The effective
+/// range is the shortest of these distances, and the factor it comes from is the limit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EffectiveRange {
+    /// The gyroscopic stability factor corrected for the muzzle velocity and atmosphere.
+    pub gyro_stability: GyroscopicStability,
+    /// The distance at which the bullet slows to Mach 1.0 in feet, if within the maximum range.
+    pub supersonic_range: Option<Distance>,
+    /// The distance at which the bullet slows below the expansion velocity in feet, if within
+    /// the maximum range.
+    pub expansion_range: Option<Distance>,
+    /// The distance at which the energy falls below the floor in feet, if within the maximum
+    /// range.
+    pub energy_range: Option<Distance>,
+    /// The effective range in feet, the shortest of the distances above.
+    pub range: Distance,
+    /// The factor limiting the effective range.
+    pub limiting_factor: LimitingFactor,
+}
+
+#[bon]
+impl EffectiveRange {
+    /// Calculates the effective range of a load.
+    ///
+    /// A bullet with a stability factor below 1.0 limits the load to a range of zero. Otherwise
+    /// the distances are found by integrating the trajectory with a `TrajectorySolver`, and the
+    /// first factor in declaration order wins a tie.
+    ///
+    /// # Parameters
+    /// - `projectile`: The bullet, which must have a ballistic coefficient.
+    /// - `rifling_twist`: The rifling twist rate of the barrel in calibers per turn, greater than 0.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s), greater than 0.
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    /// - `expansion_velocity`: The minimum velocity for reliable expansion in ft/s (1800 ft/s by default).
+    /// - `energy_floor`: The minimum kinetic energy in foot-pounds (1000 ft-lb by default).
+    /// - `max_range`: The farthest distance searched in feet (1000 yards by default).
+    ///
+    /// # Returns
+    /// An `EffectiveRange` instance, or a `BallisticsError` if an input is invalid or the
+    /// projectile has no ballistic coefficient.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        projectile: Projectile,
+        #[builder(into)] rifling_twist: RiflingTwist,
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(default)] atmosphere: Atmosphere,
+        #[builder(default = Velocity(1800.0), into)] expansion_velocity: Velocity,
+        #[builder(default = KineticEnergy(1000.0), into)] energy_floor: KineticEnergy,
+        #[builder(default = Distance(3000.0))] max_range: Distance,
+    ) -> Result<Self, BallisticsError> {
+        positive("muzzle_velocity", muzzle_velocity.0)?;
+        positive("rifling_twist", rifling_twist.0)?;
+
+        let ballistic_coefficient = projectile
+            .ballistic_coefficient()
+            .ok_or(BallisticsError::MissingBallisticCoefficient)?;
+        let gyro_stability = GyroscopicStability::calculate_corrected()
+            .bullet_weight(projectile.bullet_weight())
+            .rifling_twist(rifling_twist)
+            .bullet_diameter(projectile.bullet_diameter())
+            .bullet_length(projectile.length_calibers())
+            .muzzle_velocity(muzzle_velocity)
+            .atmosphere(atmosphere)
+            .solve()
+            .gyro_stability;
+
+        let supersonic_range = within_max_range(
+            SupersonicRange::calculate()
+                .muzzle_velocity(muzzle_velocity)
+                .ballistic_coefficient(ballistic_coefficient)
+                .drag_model(projectile.drag_model())
+                .interpolation(projectile.interpolation())
+                .atmosphere(atmosphere)
+                .max_range(max_range)
+                .solve()
+                .map(|range| range.supersonic_range),
+        )?;
+        let threshold_range = |threshold: MinimumThreshold| {
+            within_max_range(
+                ThresholdDistance::calculate()
+                    .bullet_weight(projectile.bullet_weight())
+                    .muzzle_velocity(muzzle_velocity)
+                    .ballistic_coefficient(ballistic_coefficient)
+                    .drag_model(projectile.drag_model())
+                    .interpolation(projectile.interpolation())
+                    .atmosphere(atmosphere)
+                    .threshold(threshold)
+                    .max_range(max_range)
+                    .solve()
+                    .map(|range| Distance(range.0)),
+            )
+        };
+        let expansion_range = threshold_range(MinimumThreshold::Velocity(expansion_velocity))?;
+        let energy_range = threshold_range(MinimumThreshold::Energy(energy_floor))?;
+
+        let (range, limiting_factor) = if gyro_stability.0 < MINIMUM_STABILITY {
+            (Distance(0.0), LimitingFactor::Stability)
+        } else {
+            [
+                (supersonic_range, LimitingFactor::SupersonicRange),
+                (expansion_range, LimitingFactor::ExpansionVelocity),
+                (energy_range, LimitingFactor::Energy),
+            ]
+            .into_iter()
+            .filter_map(|(range, factor)| range.map(|range| (range, factor)))
+            .fold(
+                (max_range, LimitingFactor::MaxRange),
+                |shortest, candidate| {
+                    if candidate.0 .0 < shortest.0 .0 {
+                        candidate
+                    } else {
+                        shortest
+                    }
+                },
+            )
+        };
+
+        Ok(EffectiveRange {
+            gyro_stability,
+            supersonic_range,
+            expansion_range,
+            energy_range,
+            range,
+            limiting_factor,
+        })
+    }
+}
+
+/// Turns a distance still beyond the maximum range into `None`, passing other errors on.
+fn within_max_range(
+    range: Result<Distance, BallisticsError>,
+) -> Result<Option<Distance>, BallisticsError> {
+    match range {
+        Ok(range) => Ok(Some(range)),
+        Err(BallisticsError::BeyondMaxRange(_)) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
@@ -1,15 +1,24 @@
 use bon::bon;
 
 use crate::{
+    angle::MOA_PER_RADIAN,
     constants::{GyroscopicStability, KineticEnergy, SpeedOfSound},
-    AerodynamicJump, ApertureSightCalibration, BallisticCoefficient, BulletDiameter, BulletLength,
-    BulletWeight, Distance, DragCoefficient, FormFactor, LagTime, Pressure, RiflingTwist,
-    SightCalibration, SpinDrift, Temperature, TimeOfFlight, Velocity, VelocityProjection,
-    WindDeflection, WindSpeed,
+    units::GRAINS_PER_POUND,
+    validation::{above_absolute_zero, divisor, finite, non_negative, positive},
+    AerodynamicJump, Angle, ApertureSightCalibration, BallisticCoefficient, BallisticsError,
+    BulletDiameter, BulletLength, BulletWeight, Distance, DragCoefficient, Float, FormFactor,
+    Gravity, LagTime, Pressure, ProjectileLength, RiflingTwist, SightCalibration, SightRadius,
+    SpinDrift, Temperature, TimeOfFlight, TwistDirection, TwistRate, Velocity, VelocityProjection,
+    WindDeflection, WindSpeed, STANDARD_GRAVITY,
 };
 
+/// Returns `STANDARD_GRAVITY` in the precision of an equation.
+fn standard_gravity<T: Float>() -> Gravity<T> {
+    Gravity(T::from_f64(STANDARD_GRAVITY.0))
+}
+
 #[bon]
-impl SpeedOfSound {
+impl<T: Float> SpeedOfSound<T> {
     /// Calculates the speed of sound in air given the temperature.
     ///
     /// # Parameters
@@ -18,49 +27,352 @@ impl SpeedOfSound {
     /// # Returns
     /// A `SpeedOfSound` instance representing the speed of sound at the given temperature.
     #[builder(finish_fn = solve)]
-    pub fn calculate(temperature: Temperature) -> Self {
-        SpeedOfSound(49.0223 * (temperature.0 + 459.67).sqrt())
+    pub fn calculate(temperature: Temperature<T>) -> Self {
+        SpeedOfSound(T::from_f64(49.0223) * (temperature.0 + T::from_f64(459.67)).sqrt())
+    }
+
+    /// Calculates the speed of sound in air given the temperature, validating the input first.
+    ///
+    /// # Parameters
+    /// - `temperature`: The temperature in degrees Fahrenheit, above absolute zero.
+    ///
+    /// # Returns
+    /// A `SpeedOfSound` instance, or a `BallisticsError` describing the invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(temperature: Temperature<T>) -> Result<Self, BallisticsError> {
+        above_absolute_zero("temperature", temperature.0)?;
+
+        Ok(SpeedOfSound::calculate().temperature(temperature).solve())
     }
 }
 
 #[bon]
-impl KineticEnergy {
+impl<T: Float> KineticEnergy<T> {
     /// Calculates the kinetic energy of a bullet given its weight and velocity.
     ///
+    /// The weight in grains is converted to a mass in slugs through the gravitational
+    /// acceleration, so the energy is `weight × velocity² / (2 × 7000 × gravity)`.
+    ///
     /// # Parameters
     /// - `bullet_weight`: The weight of the bullet in grains.
     /// - `velocity`: The velocity of the bullet in feet per second (ft/s).
+    /// - `gravity`: The gravitational acceleration in ft/s² (`STANDARD_GRAVITY` by default),
+    ///   e.g. `Gravity(32.2)` to compare with the older 450800 divisor.
     ///
     /// # Returns
     /// A `KineticEnergy` instance representing the kinetic energy of the bullet.
     #[builder(finish_fn = solve)]
-    pub fn calculate(bullet_weight: BulletWeight, velocity: Velocity) -> Self {
-        KineticEnergy((bullet_weight.0 * velocity.0.powi(2)) / 450800.0)
+    pub fn calculate(
+        bullet_weight: BulletWeight<T>,
+        #[builder(into)] velocity: Velocity<T>,
+        #[builder(default = standard_gravity(), into)] gravity: Gravity<T>,
+    ) -> Self {
+        KineticEnergy(
+            (bullet_weight.0 * velocity.0 * velocity.0)
+                / (T::from_f64(2.0) * T::from_f64(GRAINS_PER_POUND) * gravity.0),
+        )
+    }
+
+    /// Calculates the kinetic energy of a bullet given its weight and velocity, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains, greater than 0.
+    /// - `velocity`: The velocity of the bullet in feet per second (ft/s), finite.
+    /// - `gravity`: The gravitational acceleration in ft/s² (`STANDARD_GRAVITY` by default), greater than 0.
+    ///
+    /// # Returns
+    /// A `KineticEnergy` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        bullet_weight: BulletWeight<T>,
+        #[builder(into)] velocity: Velocity<T>,
+        #[builder(default = standard_gravity(), into)] gravity: Gravity<T>,
+    ) -> Result<Self, BallisticsError> {
+        positive("bullet_weight", bullet_weight.0)?;
+        finite("velocity", velocity.0)?;
+        divisor("gravity", gravity.0)?;
+
+        Ok(KineticEnergy::calculate()
+            .bullet_weight(bullet_weight)
+            .velocity(velocity)
+            .gravity(gravity)
+            .solve())
+    }
+}
+
+impl KineticEnergy {
+    /// Calculates the kinetic energy of a bullet given its weight and velocity, usable in
+    /// constant expressions.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains.
+    /// - `velocity`: The velocity of the bullet in feet per second (ft/s).
+    ///
+    /// # Returns
+    /// A `KineticEnergy` instance representing the kinetic energy of the bullet under standard gravity.
+    pub const fn calculate_const(bullet_weight: BulletWeight, velocity: Velocity) -> Self {
+        KineticEnergy(
+            (bullet_weight.0 * velocity.0 * velocity.0)
+                / (2.0 * GRAINS_PER_POUND * STANDARD_GRAVITY.0),
+        )
     }
 }
 
 #[bon]
-impl ApertureSightCalibration {
+impl<T: Float> Velocity<T> {
+    /// Calculates the velocity a bullet needs to carry a given kinetic energy.
+    ///
+    /// This is the inverse of `KineticEnergy::calculate`.
+    ///
+    /// # Parameters
+    /// - `energy`: The target kinetic energy in foot-pounds, greater than 0.
+    /// - `bullet_weight`: The weight of the bullet in grains, greater than 0.
+    /// - `gravity`: The gravitational acceleration in ft/s² (`STANDARD_GRAVITY` by default), greater than 0.
+    ///
+    /// # Returns
+    /// The required `Velocity` in feet per second, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn for_energy(
+        #[builder(into)] energy: KineticEnergy<T>,
+        bullet_weight: BulletWeight<T>,
+        #[builder(default = standard_gravity(), into)] gravity: Gravity<T>,
+    ) -> Result<Self, BallisticsError> {
+        positive("energy", energy.0)?;
+        divisor("bullet_weight", bullet_weight.0)?;
+        divisor("gravity", gravity.0)?;
+
+        Ok(Velocity(
+            (energy.0 * T::from_f64(2.0) * T::from_f64(GRAINS_PER_POUND) * gravity.0
+                / bullet_weight.0)
+                .sqrt(),
+        ))
+    }
+}
+
+#[bon]
+impl<T: Float> BulletWeight<T> {
+    /// Calculates the bullet weight needed to carry a given kinetic energy at a velocity.
+    ///
+    /// This is the inverse of `KineticEnergy::calculate`.
+    ///
+    /// # Parameters
+    /// - `energy`: The target kinetic energy in foot-pounds, greater than 0.
+    /// - `velocity`: The velocity of the bullet in feet per second (ft/s), greater than 0.
+    /// - `gravity`: The gravitational acceleration in ft/s² (`STANDARD_GRAVITY` by default), greater than 0.
+    ///
+    /// # Returns
+    /// The required `BulletWeight` in grains, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn for_energy(
+        #[builder(into)] energy: KineticEnergy<T>,
+        #[builder(into)] velocity: Velocity<T>,
+        #[builder(default = standard_gravity(), into)] gravity: Gravity<T>,
+    ) -> Result<Self, BallisticsError> {
+        positive("energy", energy.0)?;
+        divisor("velocity", velocity.0)?;
+        divisor("gravity", gravity.0)?;
+
+        Ok(BulletWeight(
+            energy.0 * T::from_f64(2.0) * T::from_f64(GRAINS_PER_POUND) * gravity.0
+                / (velocity.0 * velocity.0),
+        ))
+    }
+}
+
+#[bon]
+impl<T: Float> ApertureSightCalibration<T> {
     /// Determines the movement of your point of aim for each click of an aperture
-    /// based on the sight radius and the sight movement over 20 clicks (caliper measured).
+    /// based on the sight radius and the sight movement over a number of clicks (caliper measured).
+    ///
+    /// Measuring over more clicks makes the caliper reading more precise; 20 clicks is the
+    /// conventional span.
     ///
     /// # Parameters
-    /// - `sight_movement_twenty_clicks`: The sight movement for 20 clicks (inches).
+    /// - `sight_movement`: The sight movement over `clicks` clicks (inches).
     /// - `sight_radius`: The sight radius (inches).
+    /// - `clicks`: The number of clicks the sight movement spans (20 by default).
     ///
     /// # Returns
     /// A `ApertureSightCalibration` instance representing MOA per click.
     #[builder(finish_fn = solve)]
     pub fn calculate(
-        sight_movement_twenty_clicks: SightCalibration,
-        sight_radius: SightCalibration,
+        #[builder(into)] sight_movement: SightCalibration<T>,
+        #[builder(into)] sight_radius: SightRadius<T>,
+        #[builder(default = 20)] clicks: u32,
     ) -> Self {
-        ApertureSightCalibration(171.89 * (sight_movement_twenty_clicks.0 / sight_radius.0))
+        ApertureSightCalibration(
+            T::from_f64(MOA_PER_RADIAN) * sight_movement.0
+                / (sight_radius.0 * T::from_f64(f64::from(clicks))),
+        )
+    }
+
+    /// Determines the movement of your point of aim for each click of an aperture, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `sight_movement`: The sight movement over `clicks` clicks (inches), finite.
+    /// - `sight_radius`: The sight radius (inches), greater than 0.
+    /// - `clicks`: The number of clicks the sight movement spans (20 by default), greater than 0.
+    ///
+    /// # Returns
+    /// A `ApertureSightCalibration` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        #[builder(into)] sight_movement: SightCalibration<T>,
+        #[builder(into)] sight_radius: SightRadius<T>,
+        #[builder(default = 20)] clicks: u32,
+    ) -> Result<Self, BallisticsError> {
+        finite("sight_movement", sight_movement.0)?;
+        divisor("sight_radius", sight_radius.0)?;
+        divisor("clicks", f64::from(clicks))?;
+
+        Ok(ApertureSightCalibration::calculate()
+            .sight_movement(sight_movement)
+            .sight_radius(sight_radius)
+            .clicks(clicks)
+            .solve())
+    }
+}
+
+impl ApertureSightCalibration {
+    /// Determines the movement of your point of aim for each click of an aperture, usable in
+    /// constant expressions.
+    ///
+    /// # Parameters
+    /// - `sight_movement`: The sight movement for 20 clicks (inches).
+    /// - `sight_radius`: The sight radius (inches).
+    ///
+    /// # Returns
+    /// A `ApertureSightCalibration` instance representing MOA per click.
+    pub const fn calculate_const(
+        sight_movement: SightCalibration,
+        sight_radius: SightRadius,
+    ) -> Self {
+        ApertureSightCalibration(MOA_PER_RADIAN * sight_movement.0 / (sight_radius.0 * 20.0))
     }
 }
 
 #[bon]
-impl FormFactor {
+impl<T: Float> SightCalibration<T> {
+    /// Determines how far an aperture must move to shift the point of aim by a desired
+    /// correction, based on the sight radius.
+    ///
+    /// This is the inverse of `ApertureSightCalibration::calculate`.
+    ///
+    /// # Parameters
+    /// - `correction`: The desired correction in MOA.
+    /// - `sight_radius`: The sight radius (inches).
+    ///
+    /// # Returns
+    /// A `SightCalibration` instance representing the sight movement in inches.
+    #[builder(finish_fn = solve)]
+    pub fn movement_for_correction(
+        #[builder(into)] correction: Angle<T>,
+        #[builder(into)] sight_radius: SightRadius<T>,
+    ) -> Self {
+        SightCalibration(correction.0 * sight_radius.0 / T::from_f64(MOA_PER_RADIAN))
+    }
+
+    /// Determines how far an aperture must move to shift the point of aim by a desired correction, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `correction`: The desired correction in MOA, finite.
+    /// - `sight_radius`: The sight radius (inches), greater than 0.
+    ///
+    /// # Returns
+    /// A `SightCalibration` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_movement_for_correction(
+        #[builder(into)] correction: Angle<T>,
+        #[builder(into)] sight_radius: SightRadius<T>,
+    ) -> Result<Self, BallisticsError> {
+        finite("correction", correction.0)?;
+        positive("sight_radius", sight_radius.0)?;
+
+        Ok(SightCalibration::movement_for_correction()
+            .correction(correction)
+            .sight_radius(sight_radius)
+            .solve())
+    }
+
+    /// Determines how far an aperture must move per click to achieve a target click value,
+    /// based on the sight radius.
+    ///
+    /// # Parameters
+    /// - `click_value`: The target MOA per click.
+    /// - `sight_radius`: The sight radius (inches).
+    ///
+    /// # Returns
+    /// A `SightCalibration` instance representing the sight movement per click in inches.
+    #[builder(finish_fn = solve)]
+    pub fn movement_per_click(
+        #[builder(into)] click_value: ApertureSightCalibration<T>,
+        #[builder(into)] sight_radius: SightRadius<T>,
+    ) -> Self {
+        SightCalibration::movement_for_correction()
+            .correction(Angle(click_value.0))
+            .sight_radius(sight_radius)
+            .solve()
+    }
+
+    /// Determines how far an aperture must move per click to achieve a target click value, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `click_value`: The target MOA per click, finite.
+    /// - `sight_radius`: The sight radius (inches), greater than 0.
+    ///
+    /// # Returns
+    /// A `SightCalibration` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_movement_per_click(
+        #[builder(into)] click_value: ApertureSightCalibration<T>,
+        #[builder(into)] sight_radius: SightRadius<T>,
+    ) -> Result<Self, BallisticsError> {
+        finite("click_value", click_value.0)?;
+        positive("sight_radius", sight_radius.0)?;
+
+        Ok(SightCalibration::movement_per_click()
+            .click_value(click_value)
+            .sight_radius(sight_radius)
+            .solve())
+    }
+}
+
+impl SightCalibration {
+    /// Determines how far an aperture must move to shift the point of aim by a desired
+    /// correction, usable in constant expressions.
+    ///
+    /// # Parameters
+    /// - `correction`: The desired correction in MOA.
+    /// - `sight_radius`: The sight radius (inches).
+    ///
+    /// # Returns
+    /// A `SightCalibration` instance representing the sight movement in inches.
+    pub const fn movement_for_correction_const(
+        correction: Angle,
+        sight_radius: SightRadius,
+    ) -> Self {
+        SightCalibration(correction.0 * sight_radius.0 / MOA_PER_RADIAN)
+    }
+
+    /// Determines how far an aperture must move per click to achieve a target click value,
+    /// usable in constant expressions.
+    ///
+    /// # Parameters
+    /// - `click_value`: The target MOA per click.
+    /// - `sight_radius`: The sight radius (inches).
+    ///
+    /// # Returns
+    /// A `SightCalibration` instance representing the sight movement per click in inches.
+    pub const fn movement_per_click_const(
+        click_value: ApertureSightCalibration,
+        sight_radius: SightRadius,
+    ) -> Self {
+        SightCalibration::movement_for_correction_const(Angle(click_value.0), sight_radius)
+    }
+}
+
+#[bon]
+impl<T: Float> FormFactor<T> {
     /// Determines the relation of drag between a bullet and a standard bullet.
     ///
     /// # Parameters
@@ -71,6 +383,49 @@ impl FormFactor {
     /// A `FormFactor` instance representing a unitless form factor.
     #[builder(finish_fn = solve)]
     pub fn calculate(
+        drag_coefficient: DragCoefficient<T>,
+        standard_bullet_drag_coefficient: DragCoefficient<T>,
+    ) -> Self {
+        FormFactor(drag_coefficient.0 / standard_bullet_drag_coefficient.0)
+    }
+
+    /// Determines the relation of drag between a bullet and a standard bullet, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `drag_coefficient`: The drag coefficient of a bullet at some speed, at least 0.
+    /// - `standard_bullet_drag_coefficient`: The drag coefficient of a standard bullet at the same speed, greater than 0.
+    ///
+    /// # Returns
+    /// A `FormFactor` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        drag_coefficient: DragCoefficient<T>,
+        standard_bullet_drag_coefficient: DragCoefficient<T>,
+    ) -> Result<Self, BallisticsError> {
+        non_negative("drag_coefficient", drag_coefficient.0)?;
+        divisor(
+            "standard_bullet_drag_coefficient",
+            standard_bullet_drag_coefficient.0,
+        )?;
+
+        Ok(FormFactor::calculate()
+            .drag_coefficient(drag_coefficient)
+            .standard_bullet_drag_coefficient(standard_bullet_drag_coefficient)
+            .solve())
+    }
+}
+
+impl FormFactor {
+    /// Determines the relation of drag between a bullet and a standard bullet, usable in
+    /// constant expressions.
+    ///
+    /// # Parameters
+    /// - `drag_coefficient`: The drag coefficient of a bullet at some speed.
+    /// - `standard_bullet_drag_coefficient`: The drag coefficient of a standard (G1, G7, etc.) bullet at the same speed.
+    ///
+    /// # Returns
+    /// A `FormFactor` instance representing a unitless form factor.
+    pub const fn calculate_const(
         drag_coefficient: DragCoefficient,
         standard_bullet_drag_coefficient: DragCoefficient,
     ) -> Self {
@@ -79,7 +434,7 @@ impl FormFactor {
 }
 
 #[bon]
-impl VelocityProjection {
+impl<T: Float> VelocityProjection<T> {
     /// Projects the velocity of a second bullet based on the weight and velocity of a first bullet.
     ///
     /// This function uses the square root of the ratio of bullet weights to estimate
@@ -94,16 +449,42 @@ impl VelocityProjection {
     /// A `VelocityProjection` instance representing the projected velocity of the second bullet in ft/s.
     #[builder(finish_fn = solve)]
     pub fn calculate(
-        bullet_weight_1: BulletWeight,
-        bullet_weight_2: BulletWeight,
-        bullet_velocity_1: Velocity,
+        bullet_weight_1: BulletWeight<T>,
+        bullet_weight_2: BulletWeight<T>,
+        #[builder(into)] bullet_velocity_1: Velocity<T>,
     ) -> Self {
         VelocityProjection(bullet_velocity_1.0 * (bullet_weight_1.0 / bullet_weight_2.0).sqrt())
     }
+
+    /// Projects the velocity of a second bullet based on the weight and velocity of a first bullet, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `bullet_weight_1`: The weight of the first bullet in grains, greater than 0.
+    /// - `bullet_weight_2`: The weight of the second bullet in grains, greater than 0.
+    /// - `bullet_velocity_1`: The velocity of the first bullet in feet per second (ft/s), finite.
+    ///
+    /// # Returns
+    /// A `VelocityProjection` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        bullet_weight_1: BulletWeight<T>,
+        bullet_weight_2: BulletWeight<T>,
+        #[builder(into)] bullet_velocity_1: Velocity<T>,
+    ) -> Result<Self, BallisticsError> {
+        positive("bullet_weight_1", bullet_weight_1.0)?;
+        divisor("bullet_weight_2", bullet_weight_2.0)?;
+        finite("bullet_velocity_1", bullet_velocity_1.0)?;
+
+        Ok(VelocityProjection::calculate()
+            .bullet_weight_1(bullet_weight_1)
+            .bullet_weight_2(bullet_weight_2)
+            .bullet_velocity_1(bullet_velocity_1)
+            .solve())
+    }
 }
 
 #[bon]
-impl LagTime {
+impl<T: Float> LagTime<T> {
     /// Calculates the Lag Time of a bullet.
     ///
     /// Lag Time is the difference between the actual time of flight and
@@ -118,6 +499,55 @@ impl LagTime {
     /// A `LagTime` instance representing the lag time in seconds.
     #[builder(finish_fn = solve)]
     pub fn calculate(
+        #[builder(into)] actual_time_of_flight: TimeOfFlight<T>,
+        distance: Distance<T>,
+        #[builder(into)] muzzle_velocity: Velocity<T>,
+    ) -> Self {
+        let vacuum_time_of_flight = distance.0 / muzzle_velocity.0;
+
+        let lag_time = actual_time_of_flight.0 - vacuum_time_of_flight;
+
+        LagTime(lag_time)
+    }
+
+    /// Calculates the Lag Time of a bullet, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `actual_time_of_flight`: The actual time of flight of the bullet in seconds, at least 0.
+    /// - `distance`: The distance the bullet travels in feet, at least 0.
+    /// - `muzzle_velocity`: The initial velocity of the bullet in feet per second (ft/s), greater than 0.
+    ///
+    /// # Returns
+    /// A `LagTime` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        #[builder(into)] actual_time_of_flight: TimeOfFlight<T>,
+        distance: Distance<T>,
+        #[builder(into)] muzzle_velocity: Velocity<T>,
+    ) -> Result<Self, BallisticsError> {
+        non_negative("actual_time_of_flight", actual_time_of_flight.0)?;
+        non_negative("distance", distance.0)?;
+        divisor("muzzle_velocity", muzzle_velocity.0)?;
+
+        Ok(LagTime::calculate()
+            .actual_time_of_flight(actual_time_of_flight)
+            .distance(distance)
+            .muzzle_velocity(muzzle_velocity)
+            .solve())
+    }
+}
+
+impl LagTime {
+    /// Calculates the Lag Time of a bullet, usable in constant expressions.
+    ///
+    /// # Parameters
+    /// - `actual_time_of_flight`: The actual time of flight of the bullet in seconds.
+    /// - `distance`: The distance the bullet travels in feet.
+    /// - `muzzle_velocity`: The initial velocity of the bullet in feet per second (ft/s).
+    ///
+    /// # Returns
+    /// A `LagTime` instance representing the lag time in seconds.
+    pub const fn calculate_const(
         actual_time_of_flight: TimeOfFlight,
         distance: Distance,
         muzzle_velocity: Velocity,
@@ -131,26 +561,72 @@ impl LagTime {
 }
 
 #[bon]
-impl WindDeflection {
+impl<T: Float> WindDeflection<T> {
     /// Calculates the wind deflection of a bullet.
     ///
     /// This function determines how much a crosswind will deflect a bullet
-    /// from its path during flight.
+    /// from its path during flight. The sign of the crosswind carries through, so a wind from
+    /// the left deflects the bullet to the right and the same wind from the right deflects it
+    /// exactly as far to the left; deflections then sum with spin drift and other corrections.
     ///
     /// # Parameters
     /// - `lag_time`: The lag time of the bullet in seconds.
-    /// - `crosswind_speed`: The speed of the crosswind in miles per hour (mph).
+    /// - `crosswind_speed`: The speed of the crosswind in miles per hour (mph), positive blowing
+    ///   from the left.
+    ///
+    /// # Returns
+    /// A `WindDeflection` instance representing the wind deflection in inches, positive to the
+    /// right.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] lag_time: LagTime<T>,
+        #[builder(into)] crosswind_speed: WindSpeed<T>,
+    ) -> Self {
+        WindDeflection(T::from_f64(17.6) * crosswind_speed.0 * lag_time.0)
+    }
+
+    /// Calculates the wind deflection of a bullet, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `lag_time`: The lag time of the bullet in seconds, at least 0.
+    /// - `crosswind_speed`: The speed of the crosswind in miles per hour (mph), positive blowing
+    ///   from the left, finite.
     ///
     /// # Returns
-    /// A `WindDeflection` instance representing the wind deflection in inches.
+    /// A `WindDeflection` instance, or a `BallisticsError` describing the first invalid input.
     #[builder(finish_fn = solve)]
-    pub fn calculate(lag_time: LagTime, crosswind_speed: WindSpeed) -> Self {
+    pub fn try_calculate(
+        #[builder(into)] lag_time: LagTime<T>,
+        #[builder(into)] crosswind_speed: WindSpeed<T>,
+    ) -> Result<Self, BallisticsError> {
+        non_negative("lag_time", lag_time.0)?;
+        finite("crosswind_speed", crosswind_speed.0)?;
+
+        Ok(WindDeflection::calculate()
+            .lag_time(lag_time)
+            .crosswind_speed(crosswind_speed)
+            .solve())
+    }
+}
+
+impl WindDeflection {
+    /// Calculates the wind deflection of a bullet, usable in constant expressions.
+    ///
+    /// # Parameters
+    /// - `lag_time`: The lag time of the bullet in seconds.
+    /// - `crosswind_speed`: The speed of the crosswind in miles per hour (mph), positive blowing
+    ///   from the left.
+    ///
+    /// # Returns
+    /// A `WindDeflection` instance representing the wind deflection in inches, positive to the
+    /// right.
+    pub const fn calculate_const(lag_time: LagTime, crosswind_speed: WindSpeed) -> Self {
         WindDeflection(17.6 * crosswind_speed.0 * lag_time.0)
     }
 }
 
 #[bon]
-impl AerodynamicJump {
+impl<T: Float> AerodynamicJump<T> {
     /// Calculates the aerodynamic jump (vertical deflection in MOA of a 1 MPH crosswind) based
     /// on gyroscopic stability and bullet length.
     ///
@@ -164,15 +640,64 @@ impl AerodynamicJump {
     /// # Returns
     /// Returns an `AerodynamicJump` instance containing the calculated value.
     #[builder(finish_fn = solve)]
-    pub fn calculate(gyro_stability: GyroscopicStability, bullet_length: BulletLength) -> Self {
+    pub fn calculate(
+        #[builder(into)] gyro_stability: GyroscopicStability<T>,
+        #[builder(into)] bullet_length: BulletLength<T>,
+    ) -> Self {
+        AerodynamicJump(
+            T::from_f64(0.01) * gyro_stability.0 - T::from_f64(0.0024) * bullet_length.0
+                + T::from_f64(0.032),
+        )
+    }
+
+    /// Calculates the aerodynamic jump based on gyroscopic stability and bullet length, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `gyro_stability`: The gyroscopic stability factor of the projectile, at least 0.
+    /// - `bullet_length`: The length of the bullet in calibers, greater than 0.
+    ///
+    /// # Returns
+    /// An `AerodynamicJump` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        #[builder(into)] gyro_stability: GyroscopicStability<T>,
+        #[builder(into)] bullet_length: BulletLength<T>,
+    ) -> Result<Self, BallisticsError> {
+        non_negative("gyro_stability", gyro_stability.0)?;
+        positive("bullet_length", bullet_length.0)?;
+
+        Ok(AerodynamicJump::calculate()
+            .gyro_stability(gyro_stability)
+            .bullet_length(bullet_length)
+            .solve())
+    }
+}
+
+impl AerodynamicJump {
+    /// Calculates the aerodynamic jump based on gyroscopic stability and bullet length, usable
+    /// in constant expressions.
+    ///
+    /// # Parameters
+    /// - `gyro_stability`: The gyroscopic stability factor of the projectile.
+    /// - `bullet_length`: The length of the bullet in calibers.
+    ///
+    /// # Returns
+    /// An `AerodynamicJump` instance containing the calculated value.
+    pub const fn calculate_const(
+        gyro_stability: GyroscopicStability,
+        bullet_length: BulletLength,
+    ) -> Self {
         AerodynamicJump(0.01 * gyro_stability.0 - 0.0024 * bullet_length.0 + 0.032)
     }
 }
 
 #[bon]
-impl GyroscopicStability {
+impl<T: Float> GyroscopicStability<T> {
     /// Calculates the gyroscopic stability factor of a bullet using Miller's stability formula.
     ///
+    /// The twist and bullet length are in calibers, not inches; use `calculate_from_inches` to
+    /// pass a twist in inches per turn and a bullet length in inches.
+    ///
     /// # Parameters
     /// - `bullet_weight`: The weight of the bullet in grains.
     /// - `rifling_twist`: The rifling twist rate of the barrel in calibers per turn.
@@ -183,36 +708,111 @@ impl GyroscopicStability {
     /// A `GyroscopicStability` instance representing the gyroscopic stability factor of the bullet at 2800 ft/s.
     #[builder(finish_fn = solve)]
     pub fn calculate(
-        bullet_weight: BulletWeight,
-        rifling_twist: RiflingTwist,
-        bullet_diameter: BulletDiameter,
-        bullet_length: BulletLength,
+        bullet_weight: BulletWeight<T>,
+        #[builder(into)] rifling_twist: RiflingTwist<T>,
+        bullet_diameter: BulletDiameter<T>,
+        #[builder(into)] bullet_length: BulletLength<T>,
     ) -> Self {
+        let twist = rifling_twist.0;
+        let diameter = bullet_diameter.0;
+        let length = bullet_length.0;
+
         GyroscopicStability(
-            (30.0 * bullet_weight.0)
-                / (rifling_twist.0.powi(2)
-                    * bullet_diameter.0.powi(3)
-                    * bullet_length.0
-                    * (1.0 + bullet_length.0.powi(2))),
+            (T::from_f64(30.0) * bullet_weight.0)
+                / (twist
+                    * twist
+                    * (diameter * diameter * diameter)
+                    * length
+                    * (T::from_f64(1.0) + length * length)),
         )
     }
 
+    /// Calculates the gyroscopic stability factor of a bullet using Miller's stability formula
+    /// from a twist in inches per turn and a bullet length in inches.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains.
+    /// - `twist_rate`: The rifling twist rate of the barrel in inches per turn.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches.
+    /// - `bullet_length`: The overall length of the bullet in inches.
+    ///
+    /// # Returns
+    /// A `GyroscopicStability` instance representing the gyroscopic stability factor of the bullet at 2800 ft/s.
+    #[builder(finish_fn = solve)]
+    pub fn calculate_from_inches(
+        bullet_weight: BulletWeight<T>,
+        #[builder(into)] twist_rate: TwistRate<T>,
+        bullet_diameter: BulletDiameter<T>,
+        #[builder(into)] bullet_length: ProjectileLength<T>,
+    ) -> Self {
+        GyroscopicStability::calculate()
+            .bullet_weight(bullet_weight)
+            .rifling_twist(RiflingTwist(twist_rate.0 / bullet_diameter.0))
+            .bullet_diameter(bullet_diameter)
+            .bullet_length(BulletLength(bullet_length.0 / bullet_diameter.0))
+            .solve()
+    }
+
+    /// Calculates the gyroscopic stability factor of a bullet using Miller's stability formula, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains, greater than 0.
+    /// - `rifling_twist`: The rifling twist rate of the barrel in calibers per turn, greater than 0.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches, greater than 0.
+    /// - `bullet_length`: The length of the bullet in calibers, greater than 0.
+    ///
+    /// # Returns
+    /// A `GyroscopicStability` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        bullet_weight: BulletWeight<T>,
+        #[builder(into)] rifling_twist: RiflingTwist<T>,
+        bullet_diameter: BulletDiameter<T>,
+        #[builder(into)] bullet_length: BulletLength<T>,
+    ) -> Result<Self, BallisticsError> {
+        positive("bullet_weight", bullet_weight.0)?;
+        divisor("rifling_twist", rifling_twist.0)?;
+        divisor("bullet_diameter", bullet_diameter.0)?;
+        divisor("bullet_length", bullet_length.0)?;
+
+        Ok(GyroscopicStability::calculate()
+            .bullet_weight(bullet_weight)
+            .rifling_twist(rifling_twist)
+            .bullet_diameter(bullet_diameter)
+            .bullet_length(bullet_length)
+            .solve())
+    }
+
     /// Applies a velocity correction to the gyroscopic stability factor for bullet velocities other than 2800 ft/s.
     ///
-    /// This function adjusts the gyroscopic stability factor based on the actual muzzle velocity of the bullet.
+    /// This function adjusts the gyroscopic stability factor based on the actual muzzle velocity of the bullet,
+    /// scaling it by the cube root of the velocity ratio. Miller's correction is an approximation for
+    /// supersonic rifle velocities, roughly 1200 to 4000 ft/s.
     ///
     /// # Parameters
-    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
-    /// - `gyro_stability`: The initial gyroscopic stability factor calculated at 2800 ft/s.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s), greater than 0.
+    /// - `gyro_stability`: The initial gyroscopic stability factor calculated at 2800 ft/s, at least 0.
     ///
     /// # Returns
-    /// A `GyroscopicStability` instance representing the corrected gyroscopic stability factor of the bullet.
+    /// A `GyroscopicStability` instance representing the corrected gyroscopic stability factor of the bullet,
+    /// or a `BallisticsError` describing the first invalid input.
     #[builder(finish_fn = solve)]
     pub fn velocity_correction(
-        muzzle_velocity: Velocity,
-        gyro_stability: GyroscopicStability,
-    ) -> Self {
-        GyroscopicStability((gyro_stability.0) * (muzzle_velocity.0 / 2800.0).powi(1 / 3))
+        #[builder(into)] muzzle_velocity: Velocity<T>,
+        #[builder(into)] gyro_stability: GyroscopicStability<T>,
+    ) -> Result<Self, BallisticsError> {
+        positive("muzzle_velocity", muzzle_velocity.0)?;
+        non_negative("gyro_stability", gyro_stability.0)?;
+
+        Ok(gyro_stability.corrected_for_velocity(muzzle_velocity))
+    }
+
+    /// Scales the stability factor by the cube root of the ratio of the muzzle velocity to
+    /// 2800 ft/s, without validating either.
+    pub(crate) fn corrected_for_velocity(self, muzzle_velocity: Velocity<T>) -> Self {
+        GyroscopicStability(
+            self.0 * (muzzle_velocity.0 / T::from_f64(2800.0)).powf(T::from_f64(1.0 / 3.0)),
+        )
     }
 
     /// Applies an atmospheric correction to the gyroscopic stability factor accounting for
@@ -228,6 +828,87 @@ impl GyroscopicStability {
     /// A `GyroscopicStability` instance representing the corrected gyroscopic stability factor of the bullet.
     #[builder(finish_fn = solve)]
     pub fn atmospheric_correction(
+        air_temp: Temperature<T>,
+        air_pressure: Pressure<T>,
+        #[builder(into)] gyro_stability: GyroscopicStability<T>,
+    ) -> Self {
+        GyroscopicStability(
+            (gyro_stability.0)
+                * ((air_temp.0 + T::from_f64(460.0)) / T::from_f64(59.0 + 460.0)
+                    * (T::from_f64(29.92) / air_pressure.0)),
+        )
+    }
+
+    /// Applies an atmospheric correction to the gyroscopic stability factor, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `air_temp`: The air temperature in degrees Fahrenheit, above absolute zero.
+    /// - `air_pressure`: The air pressure in inches of Mercury, greater than 0.
+    /// - `gyro_stability`: The initial gyroscopic stability factor calculated at 2800 ft/s, at least 0.
+    ///
+    /// # Returns
+    /// A `GyroscopicStability` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_atmospheric_correction(
+        air_temp: Temperature<T>,
+        air_pressure: Pressure<T>,
+        #[builder(into)] gyro_stability: GyroscopicStability<T>,
+    ) -> Result<Self, BallisticsError> {
+        above_absolute_zero("air_temp", air_temp.0)?;
+        divisor("air_pressure", air_pressure.0)?;
+        non_negative("gyro_stability", gyro_stability.0)?;
+
+        Ok(GyroscopicStability::atmospheric_correction()
+            .air_temp(air_temp)
+            .air_pressure(air_pressure)
+            .gyro_stability(gyro_stability)
+            .solve())
+    }
+}
+
+impl GyroscopicStability {
+    /// Calculates the gyroscopic stability factor of a bullet using Miller's stability formula,
+    /// usable in constant expressions.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains.
+    /// - `rifling_twist`: The rifling twist rate of the barrel in calibers per turn.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches.
+    /// - `bullet_length`: The length of the bullet in calibers.
+    ///
+    /// # Returns
+    /// A `GyroscopicStability` instance representing the gyroscopic stability factor of the bullet at 2800 ft/s.
+    pub const fn calculate_const(
+        bullet_weight: BulletWeight,
+        rifling_twist: RiflingTwist,
+        bullet_diameter: BulletDiameter,
+        bullet_length: BulletLength,
+    ) -> Self {
+        let twist = rifling_twist.0;
+        let diameter = bullet_diameter.0;
+        let length = bullet_length.0;
+
+        GyroscopicStability(
+            (30.0 * bullet_weight.0)
+                / (twist
+                    * twist
+                    * (diameter * diameter * diameter)
+                    * length
+                    * (1.0 + length * length)),
+        )
+    }
+
+    /// Applies an atmospheric correction to the gyroscopic stability factor, usable in constant
+    /// expressions.
+    ///
+    /// # Parameters
+    /// - `air_temp`: The air temperature in degrees Fahrenheit.
+    /// - `air_pressure`: The air pressure in inches of Mercury.
+    /// - `gyro_stability`: The initial gyroscopic stability factor calculated at 2800 ft/s.
+    ///
+    /// # Returns
+    /// A `GyroscopicStability` instance representing the corrected gyroscopic stability factor of the bullet.
+    pub const fn atmospheric_correction_const(
         air_temp: Temperature,
         air_pressure: Pressure,
         gyro_stability: GyroscopicStability,
@@ -239,30 +920,64 @@ impl GyroscopicStability {
 }
 
 #[bon]
-impl SpinDrift {
+impl<T: Float> SpinDrift<T> {
     /// Calculates the spin drift of a bullet.
     ///
     /// Spin drift is the lateral deviation of a bullet's trajectory due to the gyroscopic effects
     /// of the bullet's spin. This function calculates the spin drift based on the gyroscopic stability
-    /// factor and the actual time of flight.
+    /// factor and the actual time of flight. The bullet drifts in the direction of the rifling
+    /// twist, right for right-hand twist and left for left-hand twist.
     ///
     /// # Parameters
     /// - `gyro_stability`: The gyroscopic stability factor of the bullet.
     /// - `actual_time_of_flight`: The actual time of flight of the bullet.
+    /// - `twist_direction`: The direction of the rifling twist (right-hand by default).
     ///
     /// # Returns
-    /// A `SpinDrift` instance representing the calculated spin drift of the bullet.
+    /// A `SpinDrift` instance representing the calculated spin drift of the bullet, positive to
+    /// the right.
     #[builder(finish_fn = solve)]
     pub fn calculate(
-        gyro_stability: GyroscopicStability,
-        actual_time_of_flight: TimeOfFlight,
+        #[builder(into)] gyro_stability: GyroscopicStability<T>,
+        #[builder(into)] actual_time_of_flight: TimeOfFlight<T>,
+        #[builder(default)] twist_direction: TwistDirection,
     ) -> Self {
-        SpinDrift(1.25 * (gyro_stability.0 + 1.2) * actual_time_of_flight.0.powf(1.83))
+        SpinDrift(
+            T::from_f64(1.25)
+                * (gyro_stability.0 + T::from_f64(1.2))
+                * actual_time_of_flight.0.powf(T::from_f64(1.83))
+                * T::from_f64(twist_direction.sign()),
+        )
+    }
+
+    /// Calculates the spin drift of a bullet, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `gyro_stability`: The gyroscopic stability factor of the bullet, at least 0.
+    /// - `actual_time_of_flight`: The actual time of flight of the bullet, at least 0.
+    /// - `twist_direction`: The direction of the rifling twist (right-hand by default).
+    ///
+    /// # Returns
+    /// A `SpinDrift` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        #[builder(into)] gyro_stability: GyroscopicStability<T>,
+        #[builder(into)] actual_time_of_flight: TimeOfFlight<T>,
+        #[builder(default)] twist_direction: TwistDirection,
+    ) -> Result<Self, BallisticsError> {
+        non_negative("gyro_stability", gyro_stability.0)?;
+        non_negative("actual_time_of_flight", actual_time_of_flight.0)?;
+
+        Ok(SpinDrift::calculate()
+            .gyro_stability(gyro_stability)
+            .actual_time_of_flight(actual_time_of_flight)
+            .twist_direction(twist_direction)
+            .solve())
     }
 }
 
 #[bon]
-impl BallisticCoefficient {
+impl<T: Float> BallisticCoefficient<T> {
     /// Calculates the ballistic coefficient of a bullet.
     ///
     /// The ballistic coefficient (BC) is a measure of a bullet's ability to overcome air resistance in flight.
@@ -277,12 +992,60 @@ impl BallisticCoefficient {
     /// A `BallisticCoefficient` instance representing the ballistic coefficient of the bullet.
     #[builder(finish_fn = solve)]
     pub fn calculate(
+        bullet_weight: BulletWeight<T>,
+        bullet_diameter: BulletDiameter<T>,
+        #[builder(into)] form_factor: FormFactor<T>,
+    ) -> Self {
+        BallisticCoefficient(
+            (bullet_weight.0 / T::from_f64(7000.0))
+                / (bullet_diameter.0 * bullet_diameter.0 * form_factor.0),
+        )
+    }
+
+    /// Calculates the ballistic coefficient of a bullet, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains, greater than 0.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches, greater than 0.
+    /// - `form_factor`: The form factor of the bullet, greater than 0.
+    ///
+    /// # Returns
+    /// A `BallisticCoefficient` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        bullet_weight: BulletWeight<T>,
+        bullet_diameter: BulletDiameter<T>,
+        #[builder(into)] form_factor: FormFactor<T>,
+    ) -> Result<Self, BallisticsError> {
+        positive("bullet_weight", bullet_weight.0)?;
+        divisor("bullet_diameter", bullet_diameter.0)?;
+        divisor("form_factor", form_factor.0)?;
+
+        Ok(BallisticCoefficient::calculate()
+            .bullet_weight(bullet_weight)
+            .bullet_diameter(bullet_diameter)
+            .form_factor(form_factor)
+            .solve())
+    }
+}
+
+impl BallisticCoefficient {
+    /// Calculates the ballistic coefficient of a bullet, usable in constant expressions.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches.
+    /// - `form_factor`: The form factor of the bullet.
+    ///
+    /// # Returns
+    /// A `BallisticCoefficient` instance representing the ballistic coefficient of the bullet.
+    pub const fn calculate_const(
         bullet_weight: BulletWeight,
         bullet_diameter: BulletDiameter,
         form_factor: FormFactor,
     ) -> Self {
         BallisticCoefficient(
-            (bullet_weight.0 / 7000.0) / (bullet_diameter.0.powi(2) * form_factor.0),
+            (bullet_weight.0 / 7000.0) / (bullet_diameter.0 * bullet_diameter.0 * form_factor.0),
         )
     }
 }
@@ -0,0 +1,100 @@
+use core::fmt;
+
+use crate::Distance;
+
+/// Errors returned by calculations that cannot produce a meaningful result
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BallisticsError {
+    /// The trajectory ends before reaching the requested zero range (ft).
+    UnreachableZero(Distance),
+    /// The trajectory reaches the solver's maximum range (ft) before the calculation completes.
+    BeyondMaxRange(Distance),
+    /// The requested zero (ft) lies on the other side of the trajectory's apex than requested.
+    WrongSideOfApex(Distance),
+    /// The group holds fewer than the two shots its statistics require.
+    TooFewShots(usize),
+    /// The projectile has no ballistic coefficient, which the trajectory solver requires.
+    MissingBallisticCoefficient,
+    /// A wind zone starts (ft) at or before the zone preceding it.
+    UnsortedWindZones(Distance),
+    /// An input is NaN or infinite.
+    NonFinite {
+        /// The name of the input.
+        param: &'static str,
+        /// The value of the input.
+        value: f64,
+    },
+    /// An input lies outside the range the calculation is defined for.
+    OutOfRange {
+        /// The name of the input.
+        param: &'static str,
+        /// The value of the input.
+        value: f64,
+        /// A description of the allowed values.
+        allowed: &'static str,
+    },
+    /// No value of a trued input within its search bracket reproduces the observation.
+    NoTruingSolution {
+        /// The name of the trued input.
+        param: &'static str,
+        /// The observed value.
+        observed: f64,
+    },
+    /// An input the calculation divides by is zero.
+    DivisionByZero {
+        /// The name of the input.
+        param: &'static str,
+    },
+}
+
+impl fmt::Display for BallisticsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BallisticsError::UnreachableZero(range) => {
+                write!(f, "no launch angle zeroes the trajectory at {} ft", range.0)
+            }
+            BallisticsError::BeyondMaxRange(range) => {
+                write!(
+                    f,
+                    "the calculation extends beyond the maximum range of {} ft",
+                    range.0
+                )
+            }
+            BallisticsError::WrongSideOfApex(range) => write!(
+                f,
+                "the zero at {} ft lies on the other side of the trajectory's apex than requested",
+                range.0
+            ),
+            BallisticsError::TooFewShots(shots) => {
+                write!(f, "a group needs at least 2 shots, found {}", shots)
+            }
+            BallisticsError::MissingBallisticCoefficient => {
+                write!(f, "the projectile has no ballistic coefficient")
+            }
+            BallisticsError::UnsortedWindZones(start) => write!(
+                f,
+                "the wind zone starting at {} ft does not start after the zone before it",
+                start.0
+            ),
+            BallisticsError::NonFinite { param, value } => {
+                write!(f, "`{}` must be finite, found {}", param, value)
+            }
+            BallisticsError::OutOfRange {
+                param,
+                value,
+                allowed,
+            } => write!(f, "`{}` must be {}, found {}", param, allowed, value),
+            BallisticsError::NoTruingSolution { param, observed } => write!(
+                f,
+                "no `{}` within the search bracket reproduces the observed {}",
+                param, observed
+            ),
+            BallisticsError::DivisionByZero { param } => {
+                write!(f, "`{}` must not be zero", param)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BallisticsError {}
@@ -0,0 +1,188 @@
+use bon::bon;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    units::GRAINS_PER_POUND, validation::positive, Atmosphere, BallisticCoefficient,
+    BallisticsError, BulletDiameter, BulletWeight, DragCoefficient, DragModel, KineticEnergy,
+    MachNumber, Velocity, STANDARD_GRAVITY,
+};
+
+/// Number of times the Mach number of a falling bullet is refined when its drag coefficient
+/// follows from a ballistic coefficient.
+const MACH_ITERATIONS: usize = 10;
+
+/// Attitude of a falling bullet
+///
+/// This enum represents how a bullet falls once it has lost its forward velocity, such as after
+/// being fired straight up, as a multiplier applied to its point-first drag coefficient. A
+/// bullet stable enough to keep its spin tends to fall base-first, presenting its blunt base
+/// to the air; one that has lost its spin tumbles, presenting its side part of the time.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FallAttitude {
+    /// Falling point-first, as in flight (a multiplier of 1).
+    PointFirst,
+    /// Falling base-first (a multiplier of 1.5).
+    BaseFirst,
+    /// Tumbling end over end (a multiplier of 3, the default).
+    #[default]
+    Tumbling,
+    /// A custom multiplier of the point-first drag coefficient, greater than 0.
+    Custom(f64),
+}
+
+impl FallAttitude {
+    /// Returns the multiplier of the point-first drag coefficient.
+    pub fn drag_multiplier(&self) -> f64 {
+        match self {
+            FallAttitude::PointFirst => 1.0,
+            FallAttitude::BaseFirst => 1.5,
+            FallAttitude::Tumbling => 3.0,
+            FallAttitude::Custom(multiplier) => *multiplier,
+        }
+    }
+}
+
+/// Terminal velocity of a falling bullet
+///
+/// This struct represents the speed at which the drag on a falling bullet balances its weight,
+/// the speed a bullet fired into the air comes back down at, along with the energy it carries.
+/// Rifle bullets typically fall at 150 to 250 ft/s when tumbling, and around 300 ft/s
+/// base-first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TerminalVelocity {
+    /// The terminal velocity in feet per second (ft/s).
+    pub velocity: Velocity,
+    /// The kinetic energy of the bullet at the terminal velocity in foot-pounds.
+    pub energy: KineticEnergy,
+    /// The drag coefficient of the bullet in its falling attitude.
+    pub drag_coefficient: DragCoefficient,
+}
+
+#[bon]
+impl TerminalVelocity {
+    /// Calculates the terminal velocity of a bullet from its drag coefficient at low subsonic
+    /// speed, `√(2 × W / (ρ × Cd × A))` with the weight `W` in pounds-force, the air density
+    /// `ρ` in slugs per cubic foot and the frontal area `A` in square feet.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains, greater than 0.
+    /// - `bullet_diameter`: The diameter of the bullet in inches, greater than 0.
+    /// - `drag_coefficient`: The point-first drag coefficient of the bullet at low subsonic
+    ///   speed, greater than 0; about 0.3 for a spitzer rifle bullet.
+    /// - `attitude`: How the bullet falls (tumbling by default).
+    /// - `atmosphere`: The air the bullet falls through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `TerminalVelocity` instance, or `BallisticsError::OutOfRange` if the weight, diameter,
+    /// drag coefficient or attitude multiplier is not positive.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        bullet_weight: BulletWeight,
+        bullet_diameter: BulletDiameter,
+        drag_coefficient: DragCoefficient,
+        #[builder(default)] attitude: FallAttitude,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        positive("bullet_weight", bullet_weight.0)?;
+        positive("bullet_diameter", bullet_diameter.0)?;
+        positive("drag_coefficient", drag_coefficient.0)?;
+        let multiplier = positive("attitude", attitude.drag_multiplier())?;
+
+        Ok(TerminalVelocity::balance(
+            bullet_weight,
+            bullet_diameter,
+            DragCoefficient(drag_coefficient.0 * multiplier),
+            atmosphere,
+        ))
+    }
+
+    /// Calculates the terminal velocity of a bullet from its ballistic coefficient.
+    ///
+    /// The point-first drag coefficient is the standard projectile's at the Mach number of the
+    /// fall, scaled by the bullet's form factor, its sectional density over its ballistic
+    /// coefficient. Ballistic coefficients are mostly measured at supersonic speeds, where a
+    /// bullet is relatively more streamlined than at a walking pace, so this tends to understate
+    /// the drag and overstate the terminal velocity compared to a measured subsonic drag
+    /// coefficient.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains, greater than 0.
+    /// - `bullet_diameter`: The diameter of the bullet in inches, greater than 0.
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to
+    ///   `drag_model`, greater than 0.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `attitude`: How the bullet falls (tumbling by default).
+    /// - `atmosphere`: The air the bullet falls through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `TerminalVelocity` instance, or `BallisticsError::OutOfRange` if the weight, diameter,
+    /// ballistic coefficient or attitude multiplier is not positive.
+    #[builder(finish_fn = solve)]
+    pub fn from_ballistic_coefficient(
+        bullet_weight: BulletWeight,
+        bullet_diameter: BulletDiameter,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] attitude: FallAttitude,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        positive("bullet_weight", bullet_weight.0)?;
+        positive("bullet_diameter", bullet_diameter.0)?;
+        positive("ballistic_coefficient", ballistic_coefficient.0)?;
+        let multiplier = positive("attitude", attitude.drag_multiplier())?;
+
+        let sectional_density =
+            bullet_weight.0 / GRAINS_PER_POUND / (bullet_diameter.0 * bullet_diameter.0);
+        let form_factor = sectional_density / ballistic_coefficient.0;
+        let speed_of_sound = atmosphere.speed_of_sound().0;
+        let drag_at = |mach: MachNumber| {
+            DragCoefficient(form_factor * drag_model.drag_coefficient(mach).0 * multiplier)
+        };
+        let mut terminal = TerminalVelocity::balance(
+            bullet_weight,
+            bullet_diameter,
+            drag_at(MachNumber(0.0)),
+            atmosphere,
+        );
+
+        for _ in 0..MACH_ITERATIONS {
+            let mach = MachNumber(terminal.velocity.0 / speed_of_sound);
+            terminal = TerminalVelocity::balance(
+                bullet_weight,
+                bullet_diameter,
+                drag_at(mach),
+                atmosphere,
+            );
+        }
+
+        Ok(terminal)
+    }
+}
+
+impl TerminalVelocity {
+    /// Returns the velocity at which the drag on a bullet with a drag coefficient balances its
+    /// weight.
+    fn balance(
+        bullet_weight: BulletWeight,
+        bullet_diameter: BulletDiameter,
+        drag_coefficient: DragCoefficient,
+        atmosphere: Atmosphere,
+    ) -> Self {
+        let weight = bullet_weight.0 / GRAINS_PER_POUND;
+        let area = core::f64::consts::FRAC_PI_4 * (bullet_diameter.0 / 12.0).powi(2);
+        let density = atmosphere.air_density().0 / STANDARD_GRAVITY.0;
+        let velocity = Velocity((2.0 * weight / (density * drag_coefficient.0 * area)).sqrt());
+
+        TerminalVelocity {
+            velocity,
+            energy: KineticEnergy::calculate()
+                .bullet_weight(bullet_weight)
+                .velocity(velocity)
+                .solve(),
+            drag_coefficient,
+        }
+    }
+}
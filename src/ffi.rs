@@ -0,0 +1,280 @@
+//! C bindings
+//!
+//! `extern "C"` entry points for the core calculations, for embedding the crate in C, C++ or
+//! Swift through the header `include/ballistics.h`. The `ballistics_ffi` crate in `ffi/` builds
+//! them into a shared library with `cargo build --release -p ballistics_ffi`. Every function
+//! takes plain `f64` inputs in the units of the safe API it wraps (grains, inches, feet, ft/s,
+//! mph, seconds and °F), writes its result through the `out` pointer and returns a status code:
+//! `BALLISTICS_OK` on success, or one of the other `BALLISTICS_*` codes, in which case `out` is
+//! left untouched. Panics are caught at the boundary and reported as `BALLISTICS_PANIC`, which
+//! needs the default unwinding panics: with `panic = "abort"` a panic aborts the host process
+//! instead.
+
+use std::panic::{self, UnwindSafe};
+
+use crate::{
+    validation::positive, BallisticCoefficient, BallisticsError, BulletDiameter, BulletLength,
+    BulletWeight, Distance, DragModel, FormFactor, GyroscopicStability, KineticEnergy, LagTime,
+    RiflingTwist, SpeedOfSound, SpinDrift, Temperature, TimeOfFlight, Velocity, WindDeflection,
+    WindSpeed,
+};
+
+/// The calculation succeeded and its result was written through `out`.
+pub const BALLISTICS_OK: i32 = 0;
+
+/// An input is NaN, infinite or outside the range the calculation is defined for.
+pub const BALLISTICS_INVALID_INPUT: i32 = 1;
+
+/// The inputs are valid but have no solution, e.g. the bullet does not reach the distance.
+pub const BALLISTICS_NO_SOLUTION: i32 = 2;
+
+/// The `out` pointer is null.
+pub const BALLISTICS_NULL_POINTER: i32 = 3;
+
+/// The calculation panicked.
+pub const BALLISTICS_PANIC: i32 = 4;
+
+/// Drag model code of the G1 standard projectile.
+pub const BALLISTICS_DRAG_G1: i32 = 1;
+
+/// Drag model code of the G7 standard projectile.
+pub const BALLISTICS_DRAG_G7: i32 = 7;
+
+/// Returns the status code reporting an error.
+fn status(error: BallisticsError) -> i32 {
+    match error {
+        BallisticsError::UnreachableZero(_)
+        | BallisticsError::BeyondMaxRange(_)
+        | BallisticsError::WrongSideOfApex(_)
+        | BallisticsError::NoTruingSolution { .. } => BALLISTICS_NO_SOLUTION,
+        BallisticsError::TooFewShots(_)
+        | BallisticsError::MissingBallisticCoefficient
+        | BallisticsError::UnsortedWindZones(_)
+        | BallisticsError::NonFinite { .. }
+        | BallisticsError::OutOfRange { .. }
+        | BallisticsError::DivisionByZero { .. } => BALLISTICS_INVALID_INPUT,
+    }
+}
+
+/// Runs a calculation, catching any panic, and writes its result through `out`.
+///
+/// # Safety
+/// `out` must be null or valid for writing an `f64`.
+unsafe fn call(
+    out: *mut f64,
+    calculation: impl FnOnce() -> Result<f64, BallisticsError> + UnwindSafe,
+) -> i32 {
+    if out.is_null() {
+        return BALLISTICS_NULL_POINTER;
+    }
+
+    match panic::catch_unwind(calculation) {
+        Ok(Ok(value)) => {
+            *out = value;
+            BALLISTICS_OK
+        }
+        Ok(Err(error)) => status(error),
+        Err(_) => BALLISTICS_PANIC,
+    }
+}
+
+/// Calculates the speed of sound in ft/s at a temperature in °F.
+///
+/// Wraps `SpeedOfSound::try_calculate`.
+///
+/// # Safety
+/// `out` must be null or valid for writing an `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn ballistics_speed_of_sound(temperature: f64, out: *mut f64) -> i32 {
+    call(out, || {
+        SpeedOfSound::try_calculate()
+            .temperature(Temperature(temperature))
+            .solve()
+            .map(|speed_of_sound| speed_of_sound.0)
+    })
+}
+
+/// Calculates the kinetic energy in ft-lbs of a bullet weighing `bullet_weight` grains at
+/// `velocity` ft/s.
+///
+/// Wraps `KineticEnergy::try_calculate` under standard gravity.
+///
+/// # Safety
+/// `out` must be null or valid for writing an `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn ballistics_kinetic_energy(
+    bullet_weight: f64,
+    velocity: f64,
+    out: *mut f64,
+) -> i32 {
+    call(out, || {
+        KineticEnergy::try_calculate()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .velocity(Velocity(velocity))
+            .solve()
+            .map(|energy| energy.0)
+    })
+}
+
+/// Calculates the ballistic coefficient of a bullet weighing `bullet_weight` grains with a
+/// diameter of `bullet_diameter` inches and a form factor.
+///
+/// Wraps `BallisticCoefficient::try_calculate`.
+///
+/// # Safety
+/// `out` must be null or valid for writing an `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn ballistics_ballistic_coefficient(
+    bullet_weight: f64,
+    bullet_diameter: f64,
+    form_factor: f64,
+    out: *mut f64,
+) -> i32 {
+    call(out, || {
+        BallisticCoefficient::try_calculate()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .bullet_diameter(BulletDiameter(bullet_diameter))
+            .form_factor(FormFactor(form_factor))
+            .solve()
+            .map(|ballistic_coefficient| ballistic_coefficient.0)
+    })
+}
+
+/// Calculates the lag time in seconds of a bullet flying `distance` feet in
+/// `actual_time_of_flight` seconds from a muzzle velocity in ft/s.
+///
+/// Wraps `LagTime::try_calculate`.
+///
+/// # Safety
+/// `out` must be null or valid for writing an `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn ballistics_lag_time(
+    actual_time_of_flight: f64,
+    distance: f64,
+    muzzle_velocity: f64,
+    out: *mut f64,
+) -> i32 {
+    call(out, || {
+        LagTime::try_calculate()
+            .actual_time_of_flight(TimeOfFlight(actual_time_of_flight))
+            .distance(Distance(distance))
+            .muzzle_velocity(Velocity(muzzle_velocity))
+            .solve()
+            .map(|lag_time| lag_time.0)
+    })
+}
+
+/// Calculates the wind deflection in inches of a bullet with a lag time in seconds in a
+/// crosswind of `crosswind_speed` mph.
+///
+/// Wraps `WindDeflection::try_calculate`.
+///
+/// # Safety
+/// `out` must be null or valid for writing an `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn ballistics_wind_deflection(
+    lag_time: f64,
+    crosswind_speed: f64,
+    out: *mut f64,
+) -> i32 {
+    call(out, || {
+        WindDeflection::try_calculate()
+            .lag_time(LagTime(lag_time))
+            .crosswind_speed(WindSpeed(crosswind_speed))
+            .solve()
+            .map(|deflection| deflection.0)
+    })
+}
+
+/// Calculates Miller's gyroscopic stability factor of a bullet weighing `bullet_weight` grains
+/// with a diameter of `bullet_diameter` inches and a length of `bullet_length` calibers, in a
+/// barrel twisting one turn in `rifling_twist` calibers.
+///
+/// Wraps `GyroscopicStability::try_calculate`.
+///
+/// # Safety
+/// `out` must be null or valid for writing an `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn ballistics_gyroscopic_stability(
+    bullet_weight: f64,
+    rifling_twist: f64,
+    bullet_diameter: f64,
+    bullet_length: f64,
+    out: *mut f64,
+) -> i32 {
+    call(out, || {
+        GyroscopicStability::try_calculate()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .rifling_twist(RiflingTwist(rifling_twist))
+            .bullet_diameter(BulletDiameter(bullet_diameter))
+            .bullet_length(BulletLength(bullet_length))
+            .solve()
+            .map(|gyro_stability| gyro_stability.0)
+    })
+}
+
+/// Calculates the spin drift in inches of a bullet with a gyroscopic stability factor after
+/// `actual_time_of_flight` seconds, fired from a right-hand twist barrel.
+///
+/// Wraps `SpinDrift::try_calculate`; negate the result for left-hand twist.
+///
+/// # Safety
+/// `out` must be null or valid for writing an `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn ballistics_spin_drift(
+    gyro_stability: f64,
+    actual_time_of_flight: f64,
+    out: *mut f64,
+) -> i32 {
+    call(out, || {
+        SpinDrift::try_calculate()
+            .gyro_stability(GyroscopicStability(gyro_stability))
+            .actual_time_of_flight(TimeOfFlight(actual_time_of_flight))
+            .solve()
+            .map(|spin_drift| spin_drift.0)
+    })
+}
+
+/// Integrates the time of flight in seconds of a bullet to `distance` feet from a muzzle
+/// velocity in ft/s and a ballistic coefficient relative to `drag_model`
+/// (`BALLISTICS_DRAG_G1` or `BALLISTICS_DRAG_G7`) in the ICAO standard atmosphere.
+///
+/// Wraps `TimeOfFlight::at_range`, reporting `BALLISTICS_NO_SOLUTION` if the bullet does not
+/// reach the distance.
+///
+/// # Safety
+/// `out` must be null or valid for writing an `f64`.
+#[no_mangle]
+pub unsafe extern "C" fn ballistics_time_of_flight(
+    muzzle_velocity: f64,
+    ballistic_coefficient: f64,
+    drag_model: i32,
+    distance: f64,
+    out: *mut f64,
+) -> i32 {
+    call(out, || {
+        positive("muzzle_velocity", muzzle_velocity)?;
+        positive("ballistic_coefficient", ballistic_coefficient)?;
+        positive("distance", distance)?;
+
+        let drag_model = match drag_model {
+            BALLISTICS_DRAG_G1 => DragModel::G1,
+            BALLISTICS_DRAG_G7 => DragModel::G7,
+            _ => {
+                return Err(BallisticsError::OutOfRange {
+                    param: "drag_model",
+                    value: f64::from(drag_model),
+                    allowed: "BALLISTICS_DRAG_G1 or BALLISTICS_DRAG_G7",
+                })
+            }
+        };
+
+        TimeOfFlight::at_range()
+            .muzzle_velocity(Velocity(muzzle_velocity))
+            .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+            .drag_model(drag_model)
+            .distance(Distance(distance))
+            .solve()
+            .map(|time_of_flight| time_of_flight.0)
+    })
+}
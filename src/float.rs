@@ -0,0 +1,155 @@
+//! Floating point precision of the quantities and closed-form equations.
+//!
+//! The quantity newtypes and the closed-form equations are generic over `Float`, defaulting to
+//! `f64`. Microcontrollers with a single-precision FPU, such as the Cortex-M4F, compute in `f32`
+//! in hardware but emulate `f64` in software, so they can use `Velocity<f32>` and solve the same
+//! builders in `f32`. The trajectory solver and the other integrators stay in `f64`: their
+//! errors accumulate over thousands of steps.
+//!
+//! Without the standard library, `f64` and `f32` lack their transcendental and rounding
+//! methods; this trait restores the ones the crate relies on through `libm`, so call sites read
+//! the same with or without the `std` feature.
+
+use core::{
+    fmt::Debug,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// A floating point type the quantities and equations can compute in, `f64` or `f32`.
+pub trait Float:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// The relative difference allowed between the result of a closed-form equation in this
+    /// type and in `f64`, for inputs in their physical range.
+    const RELATIVE_TOLERANCE: f64;
+
+    /// Converts a constant or an `f64` value to this type, rounding to the nearest value.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts the value to `f64` exactly.
+    fn to_f64(self) -> f64;
+
+    fn is_finite(self) -> bool;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn atan(self) -> Self;
+    fn exp(self) -> Self;
+    fn log10(self) -> Self;
+    fn floor(self) -> Self;
+    fn round(self) -> Self;
+}
+
+/// Forwards methods to the inherent methods of the standard library, or to `libm` without it.
+macro_rules! forward {
+    ($($method:ident($($arg:ident: $type:ty),*) => $libm:path;)*) => {
+        $(
+            #[cfg(feature = "std")]
+            fn $method(self, $($arg: $type),*) -> Self {
+                Self::$method(self, $($arg),*)
+            }
+
+            #[cfg(not(feature = "std"))]
+            fn $method(self, $($arg: $type),*) -> Self {
+                $libm(self, $($arg),*)
+            }
+        )*
+    };
+}
+
+impl Float for f64 {
+    const RELATIVE_TOLERANCE: f64 = 0.0;
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn is_finite(self) -> bool {
+        Self::is_finite(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn powi(self, n: i32) -> Self {
+        Self::powi(self, n)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn powi(self, n: i32) -> Self {
+        libm::pow(self, n as f64)
+    }
+
+    forward! {
+        sqrt() => libm::sqrt;
+        powf(n: Self) => libm::pow;
+        sin() => libm::sin;
+        cos() => libm::cos;
+        tan() => libm::tan;
+        asin() => libm::asin;
+        atan() => libm::atan;
+        exp() => libm::exp;
+        log10() => libm::log10;
+        floor() => libm::floor;
+        round() => libm::round;
+    }
+}
+
+impl Float for f32 {
+    const RELATIVE_TOLERANCE: f64 = 1e-5;
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn is_finite(self) -> bool {
+        Self::is_finite(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn powi(self, n: i32) -> Self {
+        Self::powi(self, n)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn powi(self, n: i32) -> Self {
+        libm::powf(self, n as f32)
+    }
+
+    forward! {
+        sqrt() => libm::sqrtf;
+        powf(n: Self) => libm::powf;
+        sin() => libm::sinf;
+        cos() => libm::cosf;
+        tan() => libm::tanf;
+        asin() => libm::asinf;
+        atan() => libm::atanf;
+        exp() => libm::expf;
+        log10() => libm::log10f;
+        floor() => libm::floorf;
+        round() => libm::roundf;
+    }
+}
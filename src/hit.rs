@@ -0,0 +1,186 @@
+use core::f64::consts::SQRT_2;
+
+use bon::bon;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    validation::{non_negative, positive},
+    Angle, BallisticsError, Distance, HitProbability, TargetSize,
+};
+
+/// Ratio of the mean radius to the standard deviation of a circular normal distribution, `√(π / 2)`.
+const MEAN_RADIUS_PER_SIGMA: f64 = 1.2533141373155003;
+
+/// Impact dispersion
+///
+/// This enum represents the spread of impacts about the point of aim, modeled as a bivariate
+/// normal distribution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImpactDispersion {
+    /// The same standard deviation horizontally and vertically in MOA.
+    Circular(Angle),
+    /// Separate horizontal and vertical standard deviations in MOA.
+    Elliptical {
+        /// The horizontal standard deviation in MOA.
+        horizontal: Angle,
+        /// The vertical standard deviation in MOA.
+        vertical: Angle,
+    },
+    /// The mean radius of a circular group in MOA, `√(π / 2)` times its standard deviation.
+    MeanRadius(Angle),
+}
+
+impl ImpactDispersion {
+    /// Returns the horizontal and vertical standard deviations in MOA.
+    pub fn standard_deviations(&self) -> (Angle, Angle) {
+        match self {
+            ImpactDispersion::Circular(sigma) => (*sigma, *sigma),
+            ImpactDispersion::Elliptical {
+                horizontal,
+                vertical,
+            } => (*horizontal, *vertical),
+            ImpactDispersion::MeanRadius(mean_radius) => {
+                let sigma = Angle(mean_radius.0 / MEAN_RADIUS_PER_SIGMA);
+
+                (sigma, sigma)
+            }
+        }
+    }
+}
+
+/// Target shape
+///
+/// This enum represents the outline of a target centered on the point of aim.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TargetShape {
+    /// A circle of a diameter in inches.
+    Circle {
+        /// The diameter in inches.
+        diameter: TargetSize,
+    },
+    /// A rectangle of a width and height in inches.
+    Rectangle {
+        /// The width in inches.
+        width: TargetSize,
+        /// The height in inches.
+        height: TargetSize,
+    },
+}
+
+#[bon]
+impl HitProbability {
+    /// Calculates the probability of hitting a target centered on the point of aim.
+    ///
+    /// A circle uses the Rayleigh distribution `1 - exp(-r² / 2σ²)`, approximating an
+    /// elliptical dispersion by the circular standard deviation `√((σx² + σy²) / 2)`. A
+    /// rectangle multiplies the probabilities of landing within its width and its height,
+    /// `erf(w / 2√2σx) × erf(h / 2√2σy)`. Without dispersion every shot strikes the point of
+    /// aim, so the probability is 1.
+    ///
+    /// # Parameters
+    /// - `dispersion`: The spread of the impacts.
+    /// - `target`: The shape and size of the target.
+    /// - `distance`: The distance to the target in feet.
+    ///
+    /// # Returns
+    /// A `HitProbability` instance representing the probability of a hit, from 0 to 1.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        dispersion: ImpactDispersion,
+        target: TargetShape,
+        distance: Distance,
+    ) -> Self {
+        let (horizontal, vertical) = dispersion.standard_deviations();
+        let sigma_x = horizontal.subtension_at(distance);
+        let sigma_y = vertical.subtension_at(distance);
+
+        HitProbability(match target {
+            TargetShape::Circle { diameter } => {
+                let variance = (sigma_x * sigma_x + sigma_y * sigma_y) / 2.0;
+
+                if variance == 0.0 {
+                    1.0
+                } else {
+                    let radius = diameter.0 / 2.0;
+
+                    1.0 - (-radius * radius / (2.0 * variance)).exp()
+                }
+            }
+            TargetShape::Rectangle { width, height } => {
+                within(width.0, sigma_x) * within(height.0, sigma_y)
+            }
+        })
+    }
+
+    /// Calculates the probability of hitting a target centered on the point of aim, validating the inputs first.
+    ///
+    /// # Parameters
+    /// - `dispersion`: The spread of the impacts, with standard deviations of at least 0.
+    /// - `target`: The shape and size of the target, with dimensions of at least 0.
+    /// - `distance`: The distance to the target in feet, greater than 0.
+    ///
+    /// # Returns
+    /// A `HitProbability` instance, or a `BallisticsError` describing the first invalid input.
+    #[builder(finish_fn = solve)]
+    pub fn try_calculate(
+        dispersion: ImpactDispersion,
+        target: TargetShape,
+        distance: Distance,
+    ) -> Result<Self, BallisticsError> {
+        match dispersion {
+            ImpactDispersion::Circular(sigma) => {
+                non_negative("sigma", sigma.0)?;
+            }
+            ImpactDispersion::Elliptical {
+                horizontal,
+                vertical,
+            } => {
+                non_negative("horizontal", horizontal.0)?;
+                non_negative("vertical", vertical.0)?;
+            }
+            ImpactDispersion::MeanRadius(mean_radius) => {
+                non_negative("mean_radius", mean_radius.0)?;
+            }
+        }
+        match target {
+            TargetShape::Circle { diameter } => {
+                non_negative("diameter", diameter.0)?;
+            }
+            TargetShape::Rectangle { width, height } => {
+                non_negative("width", width.0)?;
+                non_negative("height", height.0)?;
+            }
+        }
+        positive("distance", distance.0)?;
+
+        Ok(HitProbability::calculate()
+            .dispersion(dispersion)
+            .target(target)
+            .distance(distance)
+            .solve())
+    }
+}
+
+/// Returns the probability of a normally distributed impact landing within a span centered on
+/// its mean.
+fn within(span: f64, sigma: f64) -> f64 {
+    if sigma == 0.0 {
+        1.0
+    } else {
+        erf(span / (2.0 * SQRT_2 * sigma))
+    }
+}
+
+/// Returns the error function of a non-negative argument, accurate to 1.5 × 10⁻⁷
+/// (Abramowitz and Stegun 7.1.26).
+fn erf(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let polynomial = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+
+    1.0 - polynomial * (-x * x).exp()
+}
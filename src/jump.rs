@@ -0,0 +1,49 @@
+use bon::bon;
+
+use crate::{AerodynamicJump, Angle, Deflection, Distance, TwistDirection, WindSpeed};
+
+/// Aerodynamic jump deflection
+///
+/// This struct represents the vertical deflection of the point of impact caused by the
+/// aerodynamic jump of a crosswind, positive values being high.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct JumpDeflection {
+    /// The vertical deflection in MOA, available in milliradians through `Angle::mils`.
+    pub angle: Angle,
+    /// The vertical deflection at the target distance in inches.
+    pub deflection: Deflection,
+}
+
+#[bon]
+impl AerodynamicJump {
+    /// Calculates the vertical deflection caused by the aerodynamic jump of a crosswind
+    /// at a target distance.
+    ///
+    /// A crosswind blowing from left to right raises the point of impact of a right-hand
+    /// twist bullet and lowers that of a left-hand twist bullet; reversing the wind reverses
+    /// the deflection. The angular deflection is the same at any distance.
+    ///
+    /// # Parameters
+    /// - `jump`: The aerodynamic jump in MOA per mph of crosswind.
+    /// - `crosswind_speed`: The speed of the crosswind in miles per hour (mph), positive blowing from left to right.
+    /// - `distance`: The distance to the target in feet.
+    /// - `twist_direction`: The direction of the rifling twist (right-hand by default).
+    ///
+    /// # Returns
+    /// A `JumpDeflection` instance representing the vertical deflection in MOA and inches.
+    #[builder(finish_fn = solve)]
+    pub fn deflection_at(
+        #[builder(into)] jump: AerodynamicJump,
+        #[builder(into)] crosswind_speed: WindSpeed,
+        distance: Distance,
+        #[builder(default)] twist_direction: TwistDirection,
+    ) -> JumpDeflection {
+        let angle = Angle(jump.0 * crosswind_speed.0 * twist_direction.sign());
+
+        JumpDeflection {
+            angle,
+            deflection: Deflection(angle.subtension_at(distance)),
+        }
+    }
+}
@@ -3,10 +3,130 @@
 //! This crate provides constants and equations for solving ballistics problems.
 //! It includes definitions for various physical constants and properties
 //! related to ballistics, such as gravitational constant, speed of sound,
-//! gyroscopic stability, kinetic energy, and ballistic coefficient.
+//! gyroscopic stability, kinetic energy, and ballistic coefficient, as well as
+//! a point-mass trajectory solver.
+//!
+//! The crate is `no_std` when the default `std` feature is disabled, in which case the `libm`
+//! feature must be enabled to provide the floating point functions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature must be enabled");
+
+mod adjustment;
+mod angle;
+mod approx;
+mod arrival;
+mod atmosphere;
+mod barrel;
+mod batch;
+mod cant;
+#[cfg(feature = "std")]
+mod comparison;
 mod constants;
+mod correction;
+#[cfg(feature = "std")]
+mod custom_drag;
+mod dispersion;
+mod display;
+#[cfg(feature = "std")]
+mod dope;
+mod downrange;
+mod drag;
+mod effective;
 mod equations;
+mod error;
+mod falling;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod float;
+mod hit;
+mod incline;
+mod jump;
+#[cfg(feature = "bullet-library")]
+mod library;
+mod load;
+mod max_range;
+#[cfg(feature = "std")]
+mod monte_carlo;
+mod ops;
+mod payload;
+mod pejsa;
+mod plausibility;
+mod powder;
+mod projectile;
+mod repose;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod siacci;
+mod stability;
+mod stepped;
+mod trajectory;
+mod truing;
+mod twist;
+mod uncertainty;
+mod units;
+mod vacuum;
+mod validation;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod wind;
+#[cfg(feature = "serde")]
+mod with_units;
+mod zeroing;
 
+pub use adjustment::*;
+pub use approx::*;
+pub use arrival::*;
+pub use atmosphere::*;
+pub use barrel::*;
+pub use cant::*;
+#[cfg(feature = "std")]
+pub use comparison::*;
 pub use constants::*;
-pub use equations::*;
\ No newline at end of file
+pub use correction::*;
+#[cfg(feature = "std")]
+pub use custom_drag::*;
+pub use dispersion::*;
+pub use display::*;
+#[cfg(feature = "std")]
+pub use dope::*;
+pub use downrange::*;
+pub use drag::*;
+pub use effective::*;
+pub use equations::*;
+pub use error::*;
+pub use falling::*;
+pub use float::*;
+pub use hit::*;
+pub use incline::*;
+pub use jump::*;
+#[cfg(feature = "bullet-library")]
+pub use library::*;
+pub use load::*;
+pub use max_range::*;
+#[cfg(feature = "std")]
+pub use monte_carlo::*;
+pub use payload::*;
+pub use pejsa::*;
+pub use plausibility::*;
+pub use powder::*;
+pub use projectile::*;
+pub use siacci::*;
+pub use stability::*;
+pub use stepped::*;
+pub use trajectory::*;
+pub use truing::*;
+pub use twist::*;
+pub use uncertainty::*;
+pub use vacuum::*;
+pub use wind::*;
+#[cfg(feature = "serde")]
+pub use with_units::*;
+pub use zeroing::*;
+
+/// Compiles and runs the examples of the README as doctests, so they cannot drift from the API.
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+pub struct ReadmeDoctests;
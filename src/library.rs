@@ -0,0 +1,177 @@
+use crate::{
+    BallisticCoefficient, BulletDiameter, BulletWeight, DragModel, Projectile, ProjectileLength,
+};
+
+/// Largest difference in inches between a bullet's diameter and a caliber it is listed under.
+const CALIBER_TOLERANCE: f64 = 0.0005;
+
+/// Bullet of the embedded library
+///
+/// This struct holds the nominal properties of a commercially available bullet, with its
+/// ballistic coefficients relative to the G1 and, where one is published, the G7 drag model.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LibraryBullet {
+    /// The name of the bullet: manufacturer, weight, product line and caliber.
+    pub name: &'static str,
+    /// The weight of the bullet in grains.
+    pub bullet_weight: BulletWeight,
+    /// The diameter (caliber) of the bullet in inches.
+    pub bullet_diameter: BulletDiameter,
+    /// The overall length of the bullet in inches.
+    pub length: ProjectileLength,
+    /// The ballistic coefficient of the bullet relative to the G1 drag model.
+    pub g1: BallisticCoefficient,
+    /// The ballistic coefficient of the bullet relative to the G7 drag model, if published.
+    pub g7: Option<BallisticCoefficient>,
+}
+
+impl LibraryBullet {
+    /// Returns the bullet as a projectile with the ballistic coefficient of a drag model.
+    ///
+    /// # Parameters
+    /// - `drag_model`: The drag model the ballistic coefficient refers to.
+    ///
+    /// # Returns
+    /// A `Projectile` instance, or `None` if no G7 ballistic coefficient is published.
+    pub fn projectile_with(&self, drag_model: DragModel) -> Option<Projectile> {
+        let ballistic_coefficient = match drag_model {
+            DragModel::G1 => self.g1,
+            DragModel::G7 => self.g7?,
+        };
+
+        Projectile::builder()
+            .bullet_weight(self.bullet_weight)
+            .bullet_diameter(self.bullet_diameter)
+            .length(self.length)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(drag_model)
+            .build()
+            .ok()
+    }
+
+    /// Returns the bullet as a projectile with its G7 ballistic coefficient if one is published,
+    /// as it describes a boat-tail bullet better across velocities, or else its G1 one.
+    pub fn projectile(&self) -> Projectile {
+        self.projectile_with(DragModel::G7)
+            .or_else(|| self.projectile_with(DragModel::G1))
+            .expect("library bullets have positive properties")
+    }
+
+    /// Checks whether the bullet's diameter matches a caliber.
+    pub fn is_caliber(&self, bullet_diameter: BulletDiameter) -> bool {
+        (self.bullet_diameter.0 - bullet_diameter.0).abs() <= CALIBER_TOLERANCE
+    }
+}
+
+/// Embedded bullet library
+///
+/// This struct gives access to a curated list of common match and hunting bullets with their
+/// nominal dimensions and published ballistic coefficients. Requires the `bullet-library`
+/// feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BulletLibrary;
+
+impl BulletLibrary {
+    /// Returns every bullet of the library, grouped by caliber.
+    pub fn bullets() -> &'static [LibraryBullet] {
+        &BULLETS
+    }
+
+    /// Looks up a bullet by its exact name, e.g. `"Sierra 168gr MatchKing .308"`.
+    ///
+    /// # Returns
+    /// The `Projectile` of the bullet as given by [`LibraryBullet::projectile`], or `None` if no
+    /// bullet has the name.
+    pub fn get(name: &str) -> Option<Projectile> {
+        Self::entry(name).map(|bullet| bullet.projectile())
+    }
+
+    /// Looks up the library entry of a bullet by its exact name.
+    pub fn entry(name: &str) -> Option<&'static LibraryBullet> {
+        BULLETS.iter().find(|bullet| bullet.name == name)
+    }
+
+    /// Returns the bullets whose name contains a query, ignoring ASCII case.
+    pub fn search(query: &str) -> impl Iterator<Item = &'static LibraryBullet> + '_ {
+        BULLETS
+            .iter()
+            .filter(move |bullet| contains_ignore_case(bullet.name, query))
+    }
+
+    /// Returns the bullets of a caliber, within half a thousandth of an inch.
+    pub fn by_caliber(
+        bullet_diameter: BulletDiameter,
+    ) -> impl Iterator<Item = &'static LibraryBullet> {
+        BULLETS
+            .iter()
+            .filter(move |bullet| bullet.is_caliber(bullet_diameter))
+    }
+}
+
+/// Checks whether `haystack` contains `needle`, ignoring ASCII case.
+fn contains_ignore_case(haystack: &str, needle: &str) -> bool {
+    needle.is_empty()
+        || haystack
+            .as_bytes()
+            .windows(needle.len())
+            .any(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+const fn bullet(
+    name: &'static str,
+    weight: f64,
+    diameter: f64,
+    length: f64,
+    g1: f64,
+    g7: Option<f64>,
+) -> LibraryBullet {
+    LibraryBullet {
+        name,
+        bullet_weight: BulletWeight(weight),
+        bullet_diameter: BulletDiameter(diameter),
+        length: ProjectileLength(length),
+        g1: BallisticCoefficient(g1),
+        g7: match g7 {
+            Some(g7) => Some(BallisticCoefficient(g7)),
+            None => None,
+        },
+    }
+}
+
+/// Name, weight (gr), diameter (in), length (in), G1 and G7 ballistic coefficients.
+#[rustfmt::skip]
+#[allow(clippy::approx_constant)] // 0.318 is a ballistic coefficient, not 1/π
+const BULLETS: [LibraryBullet; 32] = [
+    bullet("Hornady 55gr V-MAX .224", 55.0, 0.224, 0.750, 0.255, None),
+    bullet("Sierra 69gr MatchKing .224", 69.0, 0.224, 0.900, 0.301, None),
+    bullet("Hornady 73gr ELD Match .224", 73.0, 0.224, 1.010, 0.398, Some(0.200)),
+    bullet("Hornady 75gr BTHP Match .224", 75.0, 0.224, 0.990, 0.395, Some(0.200)),
+    bullet("Sierra 77gr MatchKing .224", 77.0, 0.224, 0.995, 0.372, None),
+    bullet("Hornady 88gr ELD Match .224", 88.0, 0.224, 1.250, 0.545, Some(0.274)),
+    bullet("Berger 105gr Hybrid Target .243", 105.0, 0.243, 1.221, 0.536, Some(0.275)),
+    bullet("Sierra 107gr MatchKing .243", 107.0, 0.243, 1.193, 0.547, None),
+    bullet("Hornady 108gr ELD Match .243", 108.0, 0.243, 1.230, 0.536, Some(0.270)),
+    bullet("Nosler 140gr AccuBond .264", 140.0, 0.264, 1.338, 0.509, None),
+    bullet("Berger 140gr Hybrid Target .264", 140.0, 0.264, 1.379, 0.607, Some(0.311)),
+    bullet("Hornady 140gr ELD Match .264", 140.0, 0.264, 1.395, 0.646, Some(0.326)),
+    bullet("Sierra 142gr MatchKing .264", 142.0, 0.264, 1.372, 0.595, None),
+    bullet("Hornady 143gr ELD-X .264", 143.0, 0.264, 1.400, 0.625, Some(0.315)),
+    bullet("Hornady 147gr ELD Match .264", 147.0, 0.264, 1.470, 0.697, Some(0.351)),
+    bullet("Nosler 130gr AccuBond .277", 130.0, 0.277, 1.240, 0.435, None),
+    bullet("Hornady 145gr ELD-X .277", 145.0, 0.277, 1.365, 0.536, Some(0.270)),
+    bullet("Hornady 162gr ELD-X .284", 162.0, 0.284, 1.465, 0.631, Some(0.318)),
+    bullet("Berger 180gr Hybrid Target .284", 180.0, 0.284, 1.570, 0.674, Some(0.345)),
+    bullet("Hornady 180gr ELD Match .284", 180.0, 0.284, 1.572, 0.796, Some(0.401)),
+    bullet("Hornady 150gr SST .308", 150.0, 0.308, 1.120, 0.415, None),
+    bullet("Nosler 165gr AccuBond .308", 165.0, 0.308, 1.250, 0.475, None),
+    bullet("Barnes 168gr TTSX .308", 168.0, 0.308, 1.335, 0.470, None),
+    bullet("Sierra 168gr MatchKing .308", 168.0, 0.308, 1.215, 0.462, None),
+    bullet("Sierra 175gr MatchKing .308", 175.0, 0.308, 1.240, 0.505, None),
+    bullet("Hornady 178gr ELD Match .308", 178.0, 0.308, 1.360, 0.547, Some(0.275)),
+    bullet("Hornady 178gr ELD-X .308", 178.0, 0.308, 1.380, 0.552, Some(0.278)),
+    bullet("Hornady 208gr ELD Match .308", 208.0, 0.308, 1.525, 0.670, Some(0.337)),
+    bullet("Berger 215gr Hybrid Target .308", 215.0, 0.308, 1.551, 0.691, Some(0.354)),
+    bullet("Hornady 285gr ELD Match .338", 285.0, 0.338, 1.740, 0.789, Some(0.404)),
+    bullet("Sierra 300gr MatchKing .338", 300.0, 0.338, 1.700, 0.768, None),
+    bullet("Berger 300gr Hybrid OTM Tactical .338", 300.0, 0.338, 1.840, 0.818, Some(0.419)),
+];
@@ -0,0 +1,286 @@
+use bon::bon;
+
+use crate::{
+    validation::positive, Angle, Atmosphere, BallisticsError, BulletDrop, Correction, Deflection,
+    Distance, GyroscopicStability, KineticEnergy, Projectile, RiflingTwist, SightHeight,
+    StabilityAssessment, StopCondition, TimeOfFlight, TrajectorySolver, Velocity, WindDeflection,
+    WindSpeed, WindVector,
+};
+
+/// Load
+///
+/// This struct represents a complete load: a projectile fired at a known muzzle velocity from a
+/// rifle zeroed at a known range, in a given atmosphere. The zero angle and the corrected
+/// gyroscopic stability factor are calculated once on construction, so asking for solutions at
+/// several distances only integrates the trajectory.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Load {
+    projectile: Projectile,
+    muzzle_velocity: Velocity,
+    rifling_twist: RiflingTwist,
+    sight_height: SightHeight,
+    zero_range: Distance,
+    atmosphere: Atmosphere,
+    solver: TrajectorySolver,
+    gyro_stability: GyroscopicStability,
+}
+
+/// Firing solution
+///
+/// This struct represents everything needed to engage a target at one distance with a load.
+/// Drop and windage are given both linearly and as angles, available in milliradians through
+/// `Angle::mils`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FiringSolution {
+    /// The distance to the target in feet.
+    pub distance: Distance,
+    /// The height of the bullet relative to the line of sight in inches, negative values being below it.
+    pub drop: BulletDrop,
+    /// The drop as an angle in MOA, negative values being below the line of sight.
+    pub drop_angle: Angle,
+    /// The lateral deflection of the bullet in inches, positive to the right.
+    pub windage: WindDeflection,
+    /// The windage as an angle in MOA, positive to the right.
+    pub windage_angle: Angle,
+    /// The remaining velocity of the bullet in feet per second (ft/s).
+    pub velocity: Velocity,
+    /// The remaining kinetic energy of the bullet in foot-pounds.
+    pub energy: KineticEnergy,
+    /// The time of flight of the bullet in seconds.
+    pub time_of_flight: TimeOfFlight,
+    /// The stability of the bullet at the distance.
+    pub stability: StabilityAssessment,
+}
+
+/// Zero shift
+///
+/// This struct represents how far the point of impact of a load moves at one distance when it
+/// is fired in a different atmosphere from the one it was zeroed in, keeping the original zero.
+/// Thinner air slows the bullet less, so impacts move up, by a tiny amount at the zero range
+/// and more with distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZeroShift {
+    /// The distance to the target in feet.
+    pub distance: Distance,
+    /// The height of the bullet relative to the line of sight in the atmosphere of the zero, in
+    /// inches.
+    pub zero_drop: BulletDrop,
+    /// The height of the bullet relative to the line of sight in the new atmosphere, in inches.
+    pub drop: BulletDrop,
+}
+
+impl ZeroShift {
+    /// Returns the movement of the point of impact, positive values being up.
+    pub fn shift(&self) -> Correction {
+        Correction::new(Deflection(self.drop.0 - self.zero_drop.0), self.distance)
+    }
+
+    /// Returns the elevation to dial in the new atmosphere to bring the impact back to the line
+    /// of sight, positive values being up.
+    pub fn come_up(&self) -> Correction {
+        Correction::new(Deflection(-self.drop.0), self.distance)
+    }
+}
+
+#[bon]
+impl ZeroShift {
+    /// Calculates the zero shift of a load at a distance by integrating its trajectory with the
+    /// original zero angle in both atmospheres.
+    ///
+    /// # Parameters
+    /// - `load`: The load, zeroed in its own atmosphere.
+    /// - `atmosphere`: The air the load is now fired through.
+    /// - `distance`: The distance to the target in feet, greater than 0.
+    ///
+    /// # Returns
+    /// A `ZeroShift` instance, or a `BallisticsError` if the distance is invalid.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        load: &Load,
+        atmosphere: Atmosphere,
+        distance: Distance,
+    ) -> Result<Self, BallisticsError> {
+        positive("distance", distance.0)?;
+
+        let drop_in = |atmosphere: Atmosphere| {
+            TrajectorySolver {
+                temperature: atmosphere.temperature,
+                pressure: atmosphere.pressure,
+                relative_humidity: atmosphere.relative_humidity,
+                max_range: distance,
+                stop_condition: StopCondition::MaxRange,
+                ..load.solver
+            }
+            .point_at(distance)
+            .map(|point| point.drop)
+            .ok_or(BallisticsError::BeyondMaxRange(distance))
+        };
+
+        Ok(ZeroShift {
+            distance,
+            zero_drop: drop_in(load.atmosphere)?,
+            drop: drop_in(atmosphere)?,
+        })
+    }
+}
+
+#[bon]
+impl Load {
+    /// Creates a load, zeroing the rifle and calculating the bullet's stability.
+    ///
+    /// # Parameters
+    /// - `projectile`: The bullet, which must have a ballistic coefficient.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s), greater than 0.
+    /// - `rifling_twist`: The rifling twist rate of the barrel in calibers per turn, greater than 0.
+    /// - `sight_height`: The height of the line of sight above the bore in inches.
+    /// - `zero_range`: The distance at which the rifle is zeroed in feet.
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `Load` instance, or a `BallisticsError` if an input is invalid, the projectile has no
+    /// ballistic coefficient or the zero range cannot be reached.
+    #[builder]
+    pub fn new(
+        projectile: Projectile,
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] rifling_twist: RiflingTwist,
+        #[builder(into)] sight_height: SightHeight,
+        zero_range: Distance,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        positive("muzzle_velocity", muzzle_velocity.0)?;
+        positive("rifling_twist", rifling_twist.0)?;
+
+        let ballistic_coefficient = projectile
+            .ballistic_coefficient()
+            .ok_or(BallisticsError::MissingBallisticCoefficient)?;
+        let solver = TrajectorySolver::builder()
+            .muzzle_velocity(muzzle_velocity)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(projectile.drag_model())
+            .interpolation(projectile.interpolation())
+            .sight_height(sight_height)
+            .bullet_weight(projectile.bullet_weight())
+            .temperature(atmosphere.temperature)
+            .pressure(atmosphere.pressure)
+            .relative_humidity(atmosphere.relative_humidity)
+            .build();
+        let launch_angle = solver.zero_angle().zero_range(zero_range).solve()?;
+
+        let gyro_stability = GyroscopicStability::calculate_corrected()
+            .bullet_weight(projectile.bullet_weight())
+            .rifling_twist(rifling_twist)
+            .bullet_diameter(projectile.bullet_diameter())
+            .bullet_length(projectile.length_calibers())
+            .muzzle_velocity(muzzle_velocity)
+            .atmosphere(atmosphere)
+            .solve()
+            .gyro_stability;
+
+        Ok(Load {
+            projectile,
+            muzzle_velocity,
+            rifling_twist,
+            sight_height,
+            zero_range,
+            atmosphere,
+            solver: solver.with_launch_angle(launch_angle),
+            gyro_stability,
+        })
+    }
+
+    /// Calculates the firing solution at a distance.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance to the target in feet, greater than 0.
+    /// - `wind`: The wind blowing across the range (none by default); its vertical component is ignored.
+    ///
+    /// # Returns
+    /// A `FiringSolution` instance, or a `BallisticsError` if the distance is invalid.
+    #[builder(finish_fn = solve)]
+    pub fn solution_at(
+        &self,
+        distance: Distance,
+        #[builder(default = WindVector::new(WindSpeed(0.0), WindSpeed(0.0), WindSpeed(0.0)))]
+        wind: WindVector,
+    ) -> Result<FiringSolution, BallisticsError> {
+        positive("distance", distance.0)?;
+
+        let point = TrajectorySolver {
+            crosswind_speed: wind.crosswind(),
+            headwind_speed: wind.headwind(),
+            max_range: distance,
+            stop_condition: StopCondition::MaxRange,
+            ..self.solver
+        }
+        .point_at(distance)
+        .ok_or(BallisticsError::BeyondMaxRange(distance))?;
+
+        Ok(FiringSolution {
+            distance,
+            drop: point.drop,
+            drop_angle: Angle::from_subtension(point.drop.0, distance),
+            windage: point.windage,
+            windage_angle: Angle::from_subtension(point.windage.0, distance),
+            velocity: point.velocity,
+            energy: self.projectile.kinetic_energy(point.velocity),
+            time_of_flight: point.time_of_flight,
+            stability: StabilityAssessment::evaluate()
+                .gyro_stability(self.gyro_stability)
+                .mach(point.mach)
+                .distance(distance)
+                .solve(),
+        })
+    }
+}
+
+impl Load {
+    /// Returns the projectile.
+    pub fn projectile(&self) -> Projectile {
+        self.projectile
+    }
+
+    /// Returns the muzzle velocity in feet per second (ft/s).
+    pub fn muzzle_velocity(&self) -> Velocity {
+        self.muzzle_velocity
+    }
+
+    /// Returns the rifling twist rate of the barrel in calibers per turn.
+    pub fn rifling_twist(&self) -> RiflingTwist {
+        self.rifling_twist
+    }
+
+    /// Returns the height of the line of sight above the bore in inches.
+    pub fn sight_height(&self) -> SightHeight {
+        self.sight_height
+    }
+
+    /// Returns the distance at which the rifle is zeroed in feet.
+    pub fn zero_range(&self) -> Distance {
+        self.zero_range
+    }
+
+    /// Returns the atmosphere.
+    pub fn atmosphere(&self) -> Atmosphere {
+        self.atmosphere
+    }
+
+    /// Returns the launch angle zeroing the rifle at the zero range in MOA.
+    pub fn zero_angle(&self) -> Angle {
+        self.solver.launch_angle
+    }
+
+    /// Returns the gyroscopic stability factor corrected for the muzzle velocity and atmosphere.
+    pub fn gyro_stability(&self) -> GyroscopicStability {
+        self.gyro_stability
+    }
+
+    /// Returns the trajectory solver launching the bullet at the zero angle.
+    #[cfg(feature = "std")]
+    pub(crate) fn solver(&self) -> TrajectorySolver {
+        self.solver
+    }
+}
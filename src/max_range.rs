@@ -0,0 +1,139 @@
+use bon::bon;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    Angle, BallisticsError, Distance, SightHeight, StopCondition, TimeOfFlight, TrajectoryPoint,
+    TrajectorySolver, Velocity,
+};
+
+/// Range increment at which trajectories are sampled when looking for the impact (ft).
+const IMPACT_STEP: Distance = Distance(1.0);
+
+/// Highest launch angle searched, 60 degrees (MOA).
+const MAX_LAUNCH_ANGLE: Angle = Angle(3600.0);
+
+/// Width of the launch angle bracket at which the search stops (MOA).
+const ANGLE_TOLERANCE: f64 = 0.1;
+
+/// Fraction of its bracket a golden-section search keeps at every step.
+const INVERSE_GOLDEN_RATIO: f64 = 0.618_033_988_749_895;
+
+/// Maximum range of a load
+///
+/// This struct represents the farthest a bullet can travel before returning to the height of
+/// the muzzle, the launch angle achieving it and the flight it takes to get there. It answers
+/// safety questions, such as how far a range's backstop must contain a stray shot. Drag pulls
+/// the optimal angle well below the 45 degrees of a vacuum, typically to 30 to 35 degrees for
+/// rifle bullets. The air keeps the same density all the way up, so trajectories with apexes
+/// of thousands of feet come out somewhat shorter than in the thinner air they really climb
+/// into.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxRange {
+    /// The maximum range in feet.
+    pub range: Distance,
+    /// The angle of the bore above the horizontal achieving the maximum range in MOA.
+    pub launch_angle: Angle,
+    /// The height of the highest point above the muzzle in feet.
+    pub apex_height: Distance,
+    /// The time of flight to the impact in seconds.
+    pub time_of_flight: TimeOfFlight,
+    /// The velocity of the bullet at the impact in feet per second (ft/s).
+    pub impact_velocity: Velocity,
+    /// The angle of the bullet's path below the horizontal at the impact in MOA.
+    pub impact_angle: Angle,
+}
+
+#[bon]
+impl MaxRange {
+    /// Calculates the maximum range of a load over level ground.
+    ///
+    /// The launch angle is found by a golden-section search between 0 and 60 degrees to within
+    /// 0.1 MOA, integrating the full trajectory of each candidate angle until the bullet falls
+    /// back through the height of the muzzle. The solver's launch angle, sight height, maximum
+    /// range and stop condition are ignored; its wind, atmosphere and integrator options apply.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load and atmosphere.
+    ///
+    /// # Returns
+    /// A `MaxRange` instance, or `BallisticsError::BeyondMaxRange` if a candidate trajectory is
+    /// still in flight when the solver's two-minute time limit ends its integration.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(solver: TrajectorySolver) -> Result<Self, BallisticsError> {
+        let solver = TrajectorySolver {
+            sight_height: SightHeight(0.0),
+            max_range: Distance(f64::INFINITY),
+            stop_condition: StopCondition::MaxRange,
+            ..solver
+        };
+        let range = |launch_angle: f64| {
+            flight(&solver, Angle(launch_angle)).map(|max_range| max_range.range.0)
+        };
+
+        let (mut low, mut high) = (0.0, MAX_LAUNCH_ANGLE.0);
+        let mut lower = high - INVERSE_GOLDEN_RATIO * (high - low);
+        let mut upper = low + INVERSE_GOLDEN_RATIO * (high - low);
+        let mut lower_range = range(lower)?;
+        let mut upper_range = range(upper)?;
+
+        while high - low > ANGLE_TOLERANCE {
+            if lower_range < upper_range {
+                low = lower;
+                (lower, lower_range) = (upper, upper_range);
+                upper = low + INVERSE_GOLDEN_RATIO * (high - low);
+                upper_range = range(upper)?;
+            } else {
+                high = upper;
+                (upper, upper_range) = (lower, lower_range);
+                lower = high - INVERSE_GOLDEN_RATIO * (high - low);
+                lower_range = range(lower)?;
+            }
+        }
+
+        flight(&solver, Angle((low + high) / 2.0))
+    }
+}
+
+/// Integrates the trajectory launched at an angle until it falls back through the height of
+/// the muzzle, interpolating the impact between the samples on either side of it.
+fn flight(solver: &TrajectorySolver, launch_angle: Angle) -> Result<MaxRange, BallisticsError> {
+    let points = solver
+        .with_launch_angle(launch_angle)
+        .iter_points(IMPACT_STEP);
+    let mut apex_height = 0.0_f64;
+    let mut previous: Option<TrajectoryPoint> = None;
+
+    for point in points {
+        apex_height = apex_height.max(point.drop.0);
+
+        if let Some(previous) = previous {
+            if previous.drop.0 >= 0.0 && point.drop.0 < 0.0 {
+                let fraction = previous.drop.0 / (previous.drop.0 - point.drop.0);
+                let lerp = |a: f64, b: f64| a + (b - a) * fraction;
+                let span = point.distance.0 - previous.distance.0;
+
+                return Ok(MaxRange {
+                    range: Distance(lerp(previous.distance.0, point.distance.0)),
+                    launch_angle,
+                    apex_height: Distance(apex_height / 12.0),
+                    time_of_flight: TimeOfFlight(lerp(
+                        previous.time_of_flight.0,
+                        point.time_of_flight.0,
+                    )),
+                    impact_velocity: Velocity(lerp(previous.velocity.0, point.velocity.0)),
+                    impact_angle: Angle::from_radians(
+                        ((previous.drop.0 - point.drop.0) / 12.0 / span).atan(),
+                    ),
+                });
+            }
+        }
+
+        previous = Some(point);
+    }
+
+    Err(BallisticsError::BeyondMaxRange(
+        previous.map_or(Distance(0.0), |point| point.distance),
+    ))
+}
@@ -0,0 +1,195 @@
+use core::f64::consts::TAU;
+
+use bon::bon;
+
+use crate::{
+    BallisticCoefficient, BallisticsError, Distance, StopCondition, TrajectorySolver, Velocity,
+    WindSpeed,
+};
+
+/// Source of uniformly distributed random numbers
+///
+/// This trait supplies the random numbers a `MonteCarlo` simulation samples its inputs from,
+/// so any generator can be plugged in, seeded for reproducible results.
+pub trait RandomSource {
+    /// Returns a random number uniformly distributed in `[0, 1)`.
+    fn next_f64(&mut self) -> f64;
+}
+
+/// SplitMix64 random number generator
+///
+/// This struct represents a small, fast, seedable generator; the same seed always yields the
+/// same sequence of numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+}
+
+impl RandomSource for SplitMix64 {
+    fn next_f64(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Monte Carlo simulation
+///
+/// This struct represents the impacts at a distance of a series of trajectories whose muzzle
+/// velocity, ballistic coefficient and crosswind are drawn from normal distributions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonteCarlo {
+    impacts: Vec<(f64, f64)>,
+}
+
+/// Distribution of impacts along one axis
+///
+/// This struct represents the horizontal or vertical impacts of a `MonteCarlo` simulation in
+/// inches, in increasing order.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ImpactDistribution {
+    values: Vec<f64>,
+}
+
+#[bon]
+impl MonteCarlo {
+    /// Simulates the impacts of a load whose inputs vary from shot to shot.
+    ///
+    /// Each shot draws a muzzle velocity, ballistic coefficient and crosswind speed from normal
+    /// distributions centered on the solver's values and integrates the trajectory with the
+    /// solver's launch angle. The standard deviations should be small compared to the values
+    /// they vary, so that no shot draws a ballistic coefficient of zero or less.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, zero and atmosphere.
+    /// - `distance`: The distance to the target in feet.
+    /// - `shots`: The number of shots to simulate, at least 2.
+    /// - `rng`: The source of the random numbers.
+    /// - `velocity_sd`: The standard deviation of the muzzle velocity in ft/s (none by default).
+    /// - `ballistic_coefficient_sd`: The standard deviation of the ballistic coefficient (none by default).
+    /// - `crosswind_sd`: The standard deviation of the crosswind speed in mph (none by default).
+    ///
+    /// # Returns
+    /// A `MonteCarlo` instance, `BallisticsError::TooFewShots` if fewer than 2 shots are
+    /// requested, or `BallisticsError::BeyondMaxRange` if a trajectory does not reach the
+    /// distance within the solver's maximum range.
+    #[builder(finish_fn = solve)]
+    pub fn simulate<R: RandomSource>(
+        solver: TrajectorySolver,
+        distance: Distance,
+        shots: usize,
+        rng: &mut R,
+        #[builder(default = Velocity(0.0), into)] velocity_sd: Velocity,
+        #[builder(default = BallisticCoefficient(0.0), into)]
+        ballistic_coefficient_sd: BallisticCoefficient,
+        #[builder(default = WindSpeed(0.0), into)] crosswind_sd: WindSpeed,
+    ) -> Result<Self, BallisticsError> {
+        if shots < 2 {
+            return Err(BallisticsError::TooFewShots(shots));
+        }
+
+        let mut sample = |mean: f64, sd: f64| mean + sd * standard_normal(rng);
+
+        (0..shots)
+            .map(|_| {
+                TrajectorySolver {
+                    muzzle_velocity: Velocity(sample(solver.muzzle_velocity.0, velocity_sd.0)),
+                    ballistic_coefficient: BallisticCoefficient(sample(
+                        solver.ballistic_coefficient.0,
+                        ballistic_coefficient_sd.0,
+                    )),
+                    crosswind_speed: WindSpeed(sample(solver.crosswind_speed.0, crosswind_sd.0)),
+                    stop_condition: StopCondition::MaxRange,
+                    ..solver
+                }
+                .point_at(distance)
+                .map(|point| (point.windage.0, point.drop.0))
+                .ok_or(BallisticsError::BeyondMaxRange(solver.max_range))
+            })
+            .collect::<Result<_, _>>()
+            .map(|impacts| MonteCarlo { impacts })
+    }
+}
+
+impl MonteCarlo {
+    /// Returns the (x, y) impacts relative to the line of sight in inches, x being positive to
+    /// the right and y positive upwards, in the order they were simulated.
+    pub fn impacts(&self) -> &[(f64, f64)] {
+        &self.impacts
+    }
+
+    /// Returns the distribution of the horizontal impacts in inches, positive to the right.
+    pub fn horizontal(&self) -> ImpactDistribution {
+        ImpactDistribution::new(self.impacts.iter().map(|impact| impact.0).collect())
+    }
+
+    /// Returns the distribution of the vertical impacts in inches, positive upwards.
+    pub fn vertical(&self) -> ImpactDistribution {
+        ImpactDistribution::new(self.impacts.iter().map(|impact| impact.1).collect())
+    }
+}
+
+impl ImpactDistribution {
+    fn new(mut values: Vec<f64>) -> Self {
+        values.sort_by(f64::total_cmp);
+
+        ImpactDistribution { values }
+    }
+
+    /// Returns the impacts in inches in increasing order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns the mean impact in inches.
+    pub fn mean(&self) -> f64 {
+        self.values.iter().sum::<f64>() / self.values.len() as f64
+    }
+
+    /// Returns the sample standard deviation of the impacts in inches.
+    pub fn standard_deviation(&self) -> f64 {
+        let mean = self.mean();
+        let squares = self
+            .values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>();
+
+        (squares / (self.values.len() as f64 - 1.0)).sqrt()
+    }
+
+    /// Returns the impact below which a percentage of the impacts fall in inches, interpolating
+    /// linearly between neighboring impacts.
+    ///
+    /// # Parameters
+    /// - `percent`: The percentage of impacts, from 0 (the lowest) to 100 (the highest).
+    pub fn percentile(&self, percent: f64) -> f64 {
+        let rank = percent.clamp(0.0, 100.0) / 100.0 * (self.values.len() - 1) as f64;
+        let lower = rank.floor() as usize;
+        let upper = (lower + 1).min(self.values.len() - 1);
+
+        self.values[lower] + (rank - lower as f64) * (self.values[upper] - self.values[lower])
+    }
+}
+
+/// Draws a number from the standard normal distribution through the Box-Muller transform.
+fn standard_normal<R: RandomSource>(rng: &mut R) -> f64 {
+    let radius = (-2.0 * (1.0 - rng.next_f64()).ln()).sqrt();
+
+    radius * (TAU * rng.next_f64()).cos()
+}
@@ -0,0 +1,126 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{
+    Angle, BulletDrop, Deflection, Distance, KineticEnergy, Pressure, SpinDrift, TimeOfFlight,
+    Velocity, VerticalWindDeflection, WindDeflection, WindSpeed,
+};
+
+/// Implements same-type addition and subtraction, scaling by an `f64`, and division by the
+/// same type into a plain `f64` ratio.
+macro_rules! impl_ops {
+    ($($newtype:ident),* $(,)?) => {
+        $(
+            impl Add for $newtype {
+                type Output = $newtype;
+
+                fn add(self, other: $newtype) -> $newtype {
+                    $newtype(self.0 + other.0)
+                }
+            }
+
+            impl AddAssign for $newtype {
+                fn add_assign(&mut self, other: $newtype) {
+                    self.0 += other.0;
+                }
+            }
+
+            impl Sub for $newtype {
+                type Output = $newtype;
+
+                fn sub(self, other: $newtype) -> $newtype {
+                    $newtype(self.0 - other.0)
+                }
+            }
+
+            impl SubAssign for $newtype {
+                fn sub_assign(&mut self, other: $newtype) {
+                    self.0 -= other.0;
+                }
+            }
+
+            impl Mul<f64> for $newtype {
+                type Output = $newtype;
+
+                fn mul(self, factor: f64) -> $newtype {
+                    $newtype(self.0 * factor)
+                }
+            }
+
+            impl Mul<$newtype> for f64 {
+                type Output = $newtype;
+
+                fn mul(self, quantity: $newtype) -> $newtype {
+                    $newtype(self * quantity.0)
+                }
+            }
+
+            impl MulAssign<f64> for $newtype {
+                fn mul_assign(&mut self, factor: f64) {
+                    self.0 *= factor;
+                }
+            }
+
+            impl Div<f64> for $newtype {
+                type Output = $newtype;
+
+                fn div(self, divisor: f64) -> $newtype {
+                    $newtype(self.0 / divisor)
+                }
+            }
+
+            impl DivAssign<f64> for $newtype {
+                fn div_assign(&mut self, divisor: f64) {
+                    self.0 /= divisor;
+                }
+            }
+
+            impl Div for $newtype {
+                type Output = f64;
+
+                fn div(self, other: $newtype) -> f64 {
+                    self.0 / other.0
+                }
+            }
+        )*
+    };
+}
+
+/// Implements negation for signed newtypes.
+macro_rules! impl_neg {
+    ($($newtype:ident),* $(,)?) => {
+        $(
+            impl Neg for $newtype {
+                type Output = $newtype;
+
+                fn neg(self) -> $newtype {
+                    $newtype(-self.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_ops!(
+    Velocity,
+    Distance,
+    TimeOfFlight,
+    WindDeflection,
+    VerticalWindDeflection,
+    KineticEnergy,
+    Pressure,
+    WindSpeed,
+    BulletDrop,
+    Deflection,
+    Angle,
+    SpinDrift,
+);
+
+impl_neg!(
+    WindDeflection,
+    VerticalWindDeflection,
+    WindSpeed,
+    BulletDrop,
+    Deflection,
+    Angle,
+    SpinDrift,
+);
@@ -0,0 +1,61 @@
+use bon::bon;
+
+use crate::{BallisticsError, BulletWeight, Gravity, KineticEnergy, Velocity, STANDARD_GRAVITY};
+
+/// Payload energy
+///
+/// This struct represents the kinetic energy of a load carrying several identical projectiles,
+/// such as the pellets of a shotshell or the bullets of a duplex load, all leaving the muzzle
+/// at the same velocity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PayloadEnergy {
+    /// The kinetic energy of one pellet in foot-pounds.
+    pub per_pellet: KineticEnergy,
+    /// The kinetic energy of the whole payload in foot-pounds.
+    pub total: KineticEnergy,
+    /// The weight of the whole payload in grains.
+    pub payload_weight: BulletWeight,
+}
+
+#[bon]
+impl PayloadEnergy {
+    /// Calculates the kinetic energy of each pellet and of the whole payload.
+    ///
+    /// # Parameters
+    /// - `pellet_weight`: The weight of one pellet in grains.
+    /// - `pellet_count`: The number of pellets, at least 1.
+    /// - `velocity`: The velocity of the pellets in feet per second (ft/s).
+    /// - `gravity`: The gravitational acceleration in ft/s² (`STANDARD_GRAVITY` by default).
+    ///
+    /// # Returns
+    /// A `PayloadEnergy` instance, or `BallisticsError::OutOfRange` if the pellet count is 0.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        pellet_weight: BulletWeight,
+        pellet_count: u32,
+        #[builder(into)] velocity: Velocity,
+        #[builder(default = STANDARD_GRAVITY, into)] gravity: Gravity,
+    ) -> Result<Self, BallisticsError> {
+        if pellet_count == 0 {
+            return Err(BallisticsError::OutOfRange {
+                param: "pellet_count",
+                value: 0.0,
+                allowed: "at least 1",
+            });
+        }
+
+        let count = f64::from(pellet_count);
+        let per_pellet = KineticEnergy::calculate()
+            .bullet_weight(pellet_weight)
+            .velocity(velocity)
+            .gravity(gravity)
+            .solve();
+
+        Ok(PayloadEnergy {
+            per_pellet,
+            total: KineticEnergy(per_pellet.0 * count),
+            payload_weight: BulletWeight(pellet_weight.0 * count),
+        })
+    }
+}
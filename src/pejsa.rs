@@ -0,0 +1,110 @@
+use bon::{bon, Builder};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    trajectory::DRAG_CONSTANT, BallisticCoefficient, BulletDrop, Distance, DragModel, MachNumber,
+    RetardationCoefficient, SpeedOfSound, TimeOfFlight, Velocity, STANDARD_GRAVITY,
+    STANDARD_TEMPERATURE,
+};
+
+/// Pejsa trajectory
+///
+/// This struct represents a flat-fire trajectory in Pejsa's closed-form model, in which the
+/// retardation coefficient shrinks linearly with range, `F = F₀ - N × R`, so that the velocity
+/// at a range is `V₀ × (1 - N × R / F₀)^(1 / N)`. Time of flight and drop follow from
+/// integrating that velocity in closed form. The model holds short of the range `F₀ / N` at
+/// which the velocity would reach zero, and the slope constant should lie between 0 and 1
+/// (exclusive); Pejsa uses 0.5 for supersonic rifle bullets.
+#[derive(Debug, Clone, Copy, PartialEq, Builder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PejsaTrajectory {
+    /// The muzzle velocity of the bullet in feet per second (ft/s).
+    #[builder(into)]
+    pub muzzle_velocity: Velocity,
+    /// The retardation coefficient at the muzzle in feet.
+    #[builder(into)]
+    pub retardation_coefficient: RetardationCoefficient,
+    /// The rate at which the retardation coefficient shrinks per foot of range (0.5 by default).
+    #[builder(default = 0.5)]
+    pub slope_constant: f64,
+}
+
+impl PejsaTrajectory {
+    /// Returns the velocity of the bullet at a distance.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    pub fn velocity_at(&self, distance: Distance) -> Velocity {
+        Velocity(self.muzzle_velocity.0 * self.remaining(distance).powf(1.0 / self.slope_constant))
+    }
+
+    /// Returns the time the bullet takes to reach a distance.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    pub fn time_of_flight_at(&self, distance: Distance) -> TimeOfFlight {
+        let n = self.slope_constant;
+        let exponent = 1.0 - 1.0 / n;
+
+        TimeOfFlight(
+            self.retardation_coefficient.0 * (1.0 - self.remaining(distance).powf(exponent))
+                / (self.muzzle_velocity.0 * n * exponent),
+        )
+    }
+
+    /// Returns the drop of the bullet below the bore line at a distance, for a level bore.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    ///
+    /// # Returns
+    /// The height of the bullet relative to the bore line in inches, negative below it.
+    pub fn drop_at(&self, distance: Distance) -> BulletDrop {
+        let n = self.slope_constant;
+        let f0 = self.retardation_coefficient.0;
+        let f = f0 - n * distance.0;
+        let a = 1.0 - 2.0 / n;
+        let integral =
+            distance.0 * f0.powf(a) - (f0.powf(a + 1.0) - f.powf(a + 1.0)) / (n * (a + 1.0));
+
+        BulletDrop(
+            -12.0 * STANDARD_GRAVITY.0 * f0.powf(2.0 / n) * integral
+                / (self.muzzle_velocity.0.powi(2) * n * a),
+        )
+    }
+
+    /// Returns the retardation coefficient at a distance as a fraction of the one at the muzzle.
+    fn remaining(&self, distance: Distance) -> f64 {
+        1.0 - self.slope_constant * distance.0 / self.retardation_coefficient.0
+    }
+}
+
+#[bon]
+impl RetardationCoefficient {
+    /// Calculates Pejsa's retardation coefficient of a bullet from its G1 ballistic coefficient.
+    ///
+    /// The coefficient is `BC / (K × Cd)`, where `Cd` is the G1 drag coefficient at the
+    /// velocity in the ICAO standard atmosphere and `K` the retardation of a unit ballistic
+    /// coefficient, the same drag the trajectory solver applies at that velocity.
+    ///
+    /// # Parameters
+    /// - `ballistic_coefficient`: The G1 ballistic coefficient of the bullet.
+    /// - `velocity`: The velocity of the bullet in feet per second (ft/s), usually the muzzle velocity.
+    ///
+    /// # Returns
+    /// A `RetardationCoefficient` instance representing the retardation coefficient in feet.
+    #[builder(finish_fn = solve)]
+    pub fn from_ballistic_coefficient(
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(into)] velocity: Velocity,
+    ) -> Self {
+        let speed_of_sound = SpeedOfSound::calculate()
+            .temperature(STANDARD_TEMPERATURE)
+            .solve();
+        let drag_coefficient =
+            DragModel::G1.drag_coefficient(MachNumber(velocity.0 / speed_of_sound.0));
+
+        RetardationCoefficient(ballistic_coefficient.0 / (DRAG_CONSTANT * drag_coefficient.0))
+    }
+}
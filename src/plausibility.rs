@@ -0,0 +1,228 @@
+use core::fmt;
+
+use crate::{DragModel, Load, Projectile, TrajectorySolver};
+
+/// Plausible range of an input
+///
+/// This struct represents the values an input takes for real firearms and conditions. A value
+/// outside it is still valid for the calculations, but almost always means the input was given
+/// in the wrong unit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlausibleRange {
+    /// The smallest plausible value.
+    pub min: f64,
+    /// The largest plausible value.
+    pub max: f64,
+    /// The unit of the values.
+    pub unit: &'static str,
+}
+
+impl PlausibleRange {
+    /// Checks whether a value lies within the range, bounds included.
+    pub fn contains(&self, value: f64) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Returns a warning about an input if its value lies outside the range.
+    ///
+    /// # Parameters
+    /// - `parameter`: The name of the input.
+    /// - `value`: The value of the input.
+    pub fn check(&self, parameter: &'static str, value: f64) -> Option<PlausibilityWarning> {
+        (!self.contains(value)).then_some(PlausibilityWarning {
+            parameter,
+            value,
+            range: *self,
+        })
+    }
+}
+
+impl fmt::Display for PlausibleRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} to {} {}", self.min, self.max, self.unit)
+    }
+}
+
+/// Warning about a physically suspicious input
+///
+/// This struct names an input whose value lies outside its plausible range, such as a muzzle
+/// velocity of 12,000 ft/s. Warnings are advisory: the calculations accept the value unchanged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlausibilityWarning {
+    /// The name of the input.
+    pub parameter: &'static str,
+    /// The value of the input.
+    pub value: f64,
+    /// The plausible range of the input.
+    pub range: PlausibleRange,
+}
+
+impl fmt::Display for PlausibilityWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is {} {}, outside the plausible range of {}; check its unit",
+            self.parameter, self.value, self.range.unit, self.range
+        )
+    }
+}
+
+/// Plausible bullet weights, from .17 caliber varmint bullets to .50 BMG match bullets.
+pub const PLAUSIBLE_BULLET_WEIGHT: PlausibleRange = PlausibleRange {
+    min: 15.0,
+    max: 800.0,
+    unit: "gr",
+};
+
+/// Plausible bullet diameters, from .17 caliber to .50 BMG.
+pub const PLAUSIBLE_BULLET_DIAMETER: PlausibleRange = PlausibleRange {
+    min: 0.17,
+    max: 0.52,
+    unit: "in",
+};
+
+/// Plausible bullet lengths, from wadcutters to very low drag bullets.
+pub const PLAUSIBLE_BULLET_LENGTH: PlausibleRange = PlausibleRange {
+    min: 1.0,
+    max: 7.0,
+    unit: "calibers",
+};
+
+/// Plausible ballistic coefficients relative to the G1 drag model.
+pub const PLAUSIBLE_G1_BALLISTIC_COEFFICIENT: PlausibleRange = PlausibleRange {
+    min: 0.02,
+    max: 1.2,
+    unit: "lb/in²",
+};
+
+/// Plausible ballistic coefficients relative to the G7 drag model.
+pub const PLAUSIBLE_G7_BALLISTIC_COEFFICIENT: PlausibleRange = PlausibleRange {
+    min: 0.01,
+    max: 0.6,
+    unit: "lb/in²",
+};
+
+/// Plausible muzzle velocities, from subsonic loads to the fastest varmint cartridges.
+pub const PLAUSIBLE_MUZZLE_VELOCITY: PlausibleRange = PlausibleRange {
+    min: 300.0,
+    max: 4500.0,
+    unit: "ft/s",
+};
+
+/// Plausible rifling twist rates, from fast twist barrels to round-ball muzzleloaders.
+pub const PLAUSIBLE_RIFLING_TWIST: PlausibleRange = PlausibleRange {
+    min: 15.0,
+    max: 150.0,
+    unit: "calibers/turn",
+};
+
+/// Plausible heights of the line of sight above the bore.
+pub const PLAUSIBLE_SIGHT_HEIGHT: PlausibleRange = PlausibleRange {
+    min: 0.0,
+    max: 4.0,
+    unit: "in",
+};
+
+/// Plausible zero ranges, from 10 to 1000 yards.
+pub const PLAUSIBLE_ZERO_RANGE: PlausibleRange = PlausibleRange {
+    min: 30.0,
+    max: 3000.0,
+    unit: "ft",
+};
+
+/// Plausible wind speeds in either direction.
+pub const PLAUSIBLE_WIND_SPEED: PlausibleRange = PlausibleRange {
+    min: -50.0,
+    max: 50.0,
+    unit: "mph",
+};
+
+/// Plausible air temperatures for shooting.
+pub const PLAUSIBLE_TEMPERATURE: PlausibleRange = PlausibleRange {
+    min: -60.0,
+    max: 130.0,
+    unit: "°F",
+};
+
+/// Plausible air pressures, from sea level storms to high mountain ranges.
+pub const PLAUSIBLE_PRESSURE: PlausibleRange = PlausibleRange {
+    min: 15.0,
+    max: 32.0,
+    unit: "inHg",
+};
+
+/// Returns the plausible ballistic coefficients relative to a drag model.
+fn plausible_ballistic_coefficient(drag_model: DragModel) -> PlausibleRange {
+    match drag_model {
+        DragModel::G1 => PLAUSIBLE_G1_BALLISTIC_COEFFICIENT,
+        DragModel::G7 => PLAUSIBLE_G7_BALLISTIC_COEFFICIENT,
+    }
+}
+
+impl Projectile {
+    /// Checks the projectile's properties against their plausible ranges.
+    ///
+    /// # Returns
+    /// A warning for each property outside its range: the bullet weight, diameter, length in
+    /// calibers and ballistic coefficient.
+    pub fn check_plausibility(&self) -> impl Iterator<Item = PlausibilityWarning> {
+        [
+            PLAUSIBLE_BULLET_WEIGHT.check("bullet_weight", self.bullet_weight().0),
+            PLAUSIBLE_BULLET_DIAMETER.check("bullet_diameter", self.bullet_diameter().0),
+            PLAUSIBLE_BULLET_LENGTH.check("length", self.length_calibers().0),
+            self.ballistic_coefficient()
+                .and_then(|ballistic_coefficient| {
+                    plausible_ballistic_coefficient(self.drag_model())
+                        .check("ballistic_coefficient", ballistic_coefficient.0)
+                }),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl TrajectorySolver {
+    /// Checks the solver's inputs against their plausible ranges.
+    ///
+    /// # Returns
+    /// A warning for each input outside its range: the muzzle velocity, ballistic coefficient,
+    /// sight height, wind speeds, temperature and pressure.
+    pub fn check_plausibility(&self) -> impl Iterator<Item = PlausibilityWarning> {
+        [
+            PLAUSIBLE_MUZZLE_VELOCITY.check("muzzle_velocity", self.muzzle_velocity.0),
+            plausible_ballistic_coefficient(self.drag_model)
+                .check("ballistic_coefficient", self.ballistic_coefficient.0),
+            PLAUSIBLE_SIGHT_HEIGHT.check("sight_height", self.sight_height.0),
+            PLAUSIBLE_WIND_SPEED.check("crosswind_speed", self.crosswind_speed.0),
+            PLAUSIBLE_WIND_SPEED.check("headwind_speed", self.headwind_speed.0),
+            PLAUSIBLE_TEMPERATURE.check("temperature", self.temperature.0),
+            PLAUSIBLE_PRESSURE.check("pressure", self.pressure.0),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl Load {
+    /// Checks the load's inputs against their plausible ranges.
+    ///
+    /// # Returns
+    /// A warning for each input outside its range: the projectile's properties, followed by the
+    /// muzzle velocity, rifling twist, sight height, zero range, temperature and pressure.
+    pub fn check_plausibility(&self) -> impl Iterator<Item = PlausibilityWarning> {
+        let atmosphere = self.atmosphere();
+
+        self.projectile().check_plausibility().chain(
+            [
+                PLAUSIBLE_MUZZLE_VELOCITY.check("muzzle_velocity", self.muzzle_velocity().0),
+                PLAUSIBLE_RIFLING_TWIST.check("rifling_twist", self.rifling_twist().0),
+                PLAUSIBLE_SIGHT_HEIGHT.check("sight_height", self.sight_height().0),
+                PLAUSIBLE_ZERO_RANGE.check("zero_range", self.zero_range().0),
+                PLAUSIBLE_TEMPERATURE.check("temperature", atmosphere.temperature.0),
+                PLAUSIBLE_PRESSURE.check("pressure", atmosphere.pressure.0),
+            ]
+            .into_iter()
+            .flatten(),
+        )
+    }
+}
@@ -0,0 +1,63 @@
+use bon::bon;
+
+use crate::{Temperature, Velocity};
+
+/// Powder type
+///
+/// This enum represents broad classes of propellants sharing a typical change in muzzle
+/// velocity per degree of powder temperature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowderType {
+    /// Extruded (stick) powders, about 0.5 ft/s per °F (the default).
+    #[default]
+    Extruded,
+    /// Ball (spherical) powders, about 1.5 ft/s per °F.
+    Ball,
+}
+
+impl PowderType {
+    /// Returns the typical change in muzzle velocity per degree Fahrenheit in ft/s.
+    pub fn velocity_per_degree(&self) -> f64 {
+        match self {
+            PowderType::Extruded => 0.5,
+            PowderType::Ball => 1.5,
+        }
+    }
+}
+
+#[bon]
+impl Velocity {
+    /// Projects the muzzle velocity of a load fired at a different powder temperature.
+    ///
+    /// The velocity changes linearly with the difference in temperature, so cold powder
+    /// loses velocity and warm powder gains it. Measure the sensitivity of a load by
+    /// chronographing it at two temperatures where possible; temperature-stable powders
+    /// can be well below the typical values.
+    ///
+    /// # Parameters
+    /// - `reference_velocity`: The muzzle velocity chronographed at the reference temperature in feet per second (ft/s).
+    /// - `reference_temperature`: The powder temperature the velocity was measured at in degrees Fahrenheit.
+    /// - `actual_temperature`: The powder temperature to project for in degrees Fahrenheit.
+    /// - `powder_type`: The type of the powder (extruded by default).
+    /// - `velocity_per_degree`: The change in velocity per °F in ft/s, overriding the powder type.
+    ///
+    /// # Returns
+    /// The projected muzzle `Velocity` in feet per second.
+    #[builder(finish_fn = solve)]
+    pub fn temperature_adjusted(
+        #[builder(into)] reference_velocity: Velocity,
+        reference_temperature: Temperature,
+        actual_temperature: Temperature,
+        #[builder(default)] powder_type: PowderType,
+        velocity_per_degree: Option<f64>,
+    ) -> Self {
+        let velocity_per_degree =
+            velocity_per_degree.unwrap_or_else(|| powder_type.velocity_per_degree());
+
+        Velocity(
+            reference_velocity.0
+                + velocity_per_degree * (actual_temperature.0 - reference_temperature.0),
+        )
+    }
+}
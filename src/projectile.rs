@@ -0,0 +1,136 @@
+use bon::bon;
+
+use crate::{
+    units::GRAINS_PER_POUND, validation::positive, BallisticCoefficient, BallisticsError,
+    BulletDiameter, BulletLength, BulletWeight, DragModel, GyroscopicStability, Interpolation,
+    KineticEnergy, ProjectileLength, RiflingTwist, SectionalDensity, Velocity,
+};
+
+/// Projectile
+///
+/// This struct represents a bullet's physical properties, with its length kept in inches so
+/// it cannot be mixed up with the length in calibers the stability equations expect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Projectile {
+    bullet_weight: BulletWeight,
+    bullet_diameter: BulletDiameter,
+    length: ProjectileLength,
+    ballistic_coefficient: Option<BallisticCoefficient>,
+    drag_model: DragModel,
+    interpolation: Interpolation,
+}
+
+#[bon]
+impl Projectile {
+    /// Creates a projectile, validating its properties.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains, greater than 0.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches, greater than 0.
+    /// - `length`: The overall length of the bullet in inches, greater than 0.
+    /// - `ballistic_coefficient`: The published ballistic coefficient of the bullet, greater than 0, if known.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `interpolation`: The interpolation between the entries of the drag model's table (linear
+    ///   by default).
+    ///
+    /// # Returns
+    /// A `Projectile` instance, or a `BallisticsError` describing the first invalid property.
+    #[builder]
+    pub fn new(
+        bullet_weight: BulletWeight,
+        bullet_diameter: BulletDiameter,
+        #[builder(into)] length: ProjectileLength,
+        #[builder(into)] ballistic_coefficient: Option<BallisticCoefficient>,
+        #[builder(default)] drag_model: DragModel,
+        #[builder(default)] interpolation: Interpolation,
+    ) -> Result<Self, BallisticsError> {
+        positive("bullet_weight", bullet_weight.0)?;
+        positive("bullet_diameter", bullet_diameter.0)?;
+        positive("length", length.0)?;
+
+        if let Some(ballistic_coefficient) = ballistic_coefficient {
+            positive("ballistic_coefficient", ballistic_coefficient.0)?;
+        }
+
+        Ok(Projectile {
+            bullet_weight,
+            bullet_diameter,
+            length,
+            ballistic_coefficient,
+            drag_model,
+            interpolation,
+        })
+    }
+}
+
+impl Projectile {
+    /// Returns the weight of the bullet in grains.
+    pub fn bullet_weight(&self) -> BulletWeight {
+        self.bullet_weight
+    }
+
+    /// Returns the diameter (caliber) of the bullet in inches.
+    pub fn bullet_diameter(&self) -> BulletDiameter {
+        self.bullet_diameter
+    }
+
+    /// Returns the overall length of the bullet in inches.
+    pub fn length(&self) -> ProjectileLength {
+        self.length
+    }
+
+    /// Returns the length of the bullet in calibers.
+    pub fn length_calibers(&self) -> BulletLength {
+        BulletLength(self.length.0 / self.bullet_diameter.0)
+    }
+
+    /// Returns the published ballistic coefficient of the bullet, if known.
+    pub fn ballistic_coefficient(&self) -> Option<BallisticCoefficient> {
+        self.ballistic_coefficient
+    }
+
+    /// Returns the standard drag model the ballistic coefficient refers to.
+    pub fn drag_model(&self) -> DragModel {
+        self.drag_model
+    }
+
+    /// Returns the interpolation between the entries of the drag model's table.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the sectional density of the bullet in pounds per square inch.
+    pub fn sectional_density(&self) -> SectionalDensity {
+        SectionalDensity(
+            self.bullet_weight.0
+                / GRAINS_PER_POUND
+                / (self.bullet_diameter.0 * self.bullet_diameter.0),
+        )
+    }
+
+    /// Returns the gyroscopic stability factor of the bullet at 2800 ft/s using Miller's
+    /// stability formula.
+    ///
+    /// # Parameters
+    /// - `rifling_twist`: The rifling twist rate of the barrel in calibers per turn.
+    pub fn gyroscopic_stability(&self, rifling_twist: RiflingTwist) -> GyroscopicStability {
+        GyroscopicStability::calculate()
+            .bullet_weight(self.bullet_weight)
+            .rifling_twist(rifling_twist)
+            .bullet_diameter(self.bullet_diameter)
+            .bullet_length(self.length_calibers())
+            .solve()
+    }
+
+    /// Returns the kinetic energy of the bullet at a velocity in foot-pounds.
+    ///
+    /// # Parameters
+    /// - `velocity`: The velocity of the bullet in feet per second (ft/s).
+    pub fn kinetic_energy(&self, velocity: Velocity) -> KineticEnergy {
+        KineticEnergy::calculate()
+            .bullet_weight(self.bullet_weight)
+            .velocity(velocity)
+            .solve()
+    }
+}
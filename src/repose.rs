@@ -0,0 +1,119 @@
+use core::f64::consts::PI;
+
+use bon::bon;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    BulletDiameter, BulletLength, Distance, Gravity, GyroscopicStability, RiflingTwist, SpinRate,
+    TwistDirection, Velocity, YawOfRepose, STANDARD_GRAVITY,
+};
+
+/// Fraction of its spin a typical jacketed rifle bullet loses per caliber traveled.
+const SPIN_DAMPING: f64 = 1.0e-6;
+
+#[bon]
+impl SpinRate {
+    /// Calculates the spin rate a barrel imparts to a bullet.
+    ///
+    /// # Parameters
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `rifling_twist`: The rifling twist rate of the barrel in calibers per turn.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches.
+    ///
+    /// # Returns
+    /// A `SpinRate` instance representing the spin rate in revolutions per minute.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] rifling_twist: RiflingTwist,
+        bullet_diameter: BulletDiameter,
+    ) -> Self {
+        let inches_per_turn = rifling_twist.0 * bullet_diameter.0;
+
+        SpinRate(muzzle_velocity.0 * 12.0 / inches_per_turn * 60.0)
+    }
+
+    /// Calculates the spin rate remaining downrange.
+    ///
+    /// Spin decays exponentially with the distance traveled in calibers, `p = p₀ × e^(−k × x / d)`.
+    /// The aerodynamic damping of the spin is far weaker than the drag, so a bullet keeps all
+    /// but a few percent of its spin over a flight in which it loses 40% of its velocity.
+    ///
+    /// # Parameters
+    /// - `muzzle_spin_rate`: The spin rate at the muzzle in revolutions per minute.
+    /// - `distance`: The distance downrange in feet.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches.
+    /// - `spin_damping`: The fraction of its spin the bullet loses per caliber traveled, `k`
+    ///   (10⁻⁶ by default, typical of jacketed rifle bullets).
+    ///
+    /// # Returns
+    /// A `SpinRate` instance representing the remaining spin rate in revolutions per minute.
+    #[builder(finish_fn = solve)]
+    pub fn at_range(
+        #[builder(into)] muzzle_spin_rate: SpinRate,
+        #[builder(into)] distance: Distance,
+        bullet_diameter: BulletDiameter,
+        #[builder(default = SPIN_DAMPING)] spin_damping: f64,
+    ) -> Self {
+        let calibers = distance.0 * 12.0 / bullet_diameter.0;
+
+        SpinRate(muzzle_spin_rate.0 * (-spin_damping * calibers).exp())
+    }
+}
+
+impl SpinRate {
+    /// Returns the rifling twist that would impart this spin rate at a velocity, so that a
+    /// stability formula can be evaluated downrange with the remaining spin and velocity.
+    ///
+    /// # Parameters
+    /// - `velocity`: The velocity of the bullet in feet per second (ft/s).
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches.
+    ///
+    /// # Returns
+    /// A `RiflingTwist` instance in calibers per turn.
+    pub fn effective_twist(
+        &self,
+        velocity: Velocity,
+        bullet_diameter: BulletDiameter,
+    ) -> RiflingTwist {
+        RiflingTwist(velocity.0 * 12.0 * 60.0 / (self.0 * bullet_diameter.0))
+    }
+}
+
+#[bon]
+impl YawOfRepose {
+    /// Estimates the yaw of repose, the small equilibrium yaw a spinning bullet settles at
+    /// while its trajectory curves downward, which is the source of spin drift.
+    ///
+    /// This is the linearized estimate `4 × SG × g × (Iy / Ix) / (p × V)` for level fire, with
+    /// the ratio of the transverse to the axial moment of inertia approximated by a solid
+    /// cylinder of the bullet's length. It is an estimate rather than a six degree of freedom
+    /// result; since the spin decays far slower than the velocity, the yaw grows downrange.
+    ///
+    /// # Parameters
+    /// - `gyro_stability`: The gyroscopic stability factor of the bullet at the point of interest.
+    /// - `spin_rate`: The spin rate of the bullet in revolutions per minute.
+    /// - `velocity`: The velocity of the bullet at the point of interest in feet per second (ft/s).
+    /// - `bullet_length`: The length of the bullet in calibers.
+    /// - `twist_direction`: The direction of the rifling twist (right-hand by default).
+    /// - `gravity`: The gravitational acceleration in ft/s² (`STANDARD_GRAVITY` by default).
+    ///
+    /// # Returns
+    /// A `YawOfRepose` instance representing the yaw in degrees, to the right for right-hand twist.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] gyro_stability: GyroscopicStability,
+        #[builder(into)] spin_rate: SpinRate,
+        #[builder(into)] velocity: Velocity,
+        #[builder(into)] bullet_length: BulletLength,
+        #[builder(default)] twist_direction: TwistDirection,
+        #[builder(default = STANDARD_GRAVITY, into)] gravity: Gravity,
+    ) -> Self {
+        let inertia_ratio = 0.5 + 2.0 / 3.0 * bullet_length.0 * bullet_length.0;
+        let spin = spin_rate.0 * 2.0 * PI / 60.0;
+        let yaw = 4.0 * gyro_stability.0 * gravity.0 * inertia_ratio / (spin * velocity.0);
+
+        YawOfRepose(yaw.to_degrees() * twist_direction.sign())
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    AerodynamicJump, AirDensity, Altitude, Angle, ApertureSightCalibration, BallisticCoefficient,
+    BarrelLength, BarrelVelocityEstimate, BulletDiameter, BulletDrop, BulletLength, BulletWeight,
+    Deflection, DensityRatio, Distance, DragCoefficient, FormFactor, Gravity, GunNumber,
+    GyroscopicStability, HitProbability, InclineCorrection, KineticEnergy, LagTime, MachNumber,
+    Pressure, ProjectileLength, RelativeHumidity, RetardationCoefficient, RiflingTwist,
+    SectionalDensity, SightCalibration, SightHeight, SightRadius, SpeedOfSound, SpinDrift,
+    SpinRate, TargetSize, Temperature, ThresholdDistance, TimeOfFlight, TwistRate, Velocity,
+    VelocityProjection, VerticalWindDeflection, WindDeflection, WindSpeed, YawOfRepose,
+};
+
+/// Implements `Serialize` and `Deserialize` for newtypes as their transparent inner `f64`,
+/// rejecting NaN and infinite values on deserialization.
+macro_rules! impl_serde {
+    ($($newtype:ident),* $(,)?) => {
+        $(
+            impl Serialize for $newtype {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.0.serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $newtype {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = f64::deserialize(deserializer)?;
+
+                    if value.is_finite() {
+                        Ok($newtype(value))
+                    } else {
+                        Err(D::Error::custom(format_args!(
+                            "invalid {}: expected a finite number, found {}",
+                            stringify!($newtype),
+                            value
+                        )))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_serde!(
+    Gravity,
+    SpeedOfSound,
+    TimeOfFlight,
+    Distance,
+    WindSpeed,
+    SpinDrift,
+    DragCoefficient,
+    RiflingTwist,
+    BulletLength,
+    BulletDiameter,
+    SightCalibration,
+    SightRadius,
+    AirDensity,
+    LagTime,
+    WindDeflection,
+    VelocityProjection,
+    ApertureSightCalibration,
+    FormFactor,
+    AerodynamicJump,
+    BulletWeight,
+    Temperature,
+    Pressure,
+    Velocity,
+    GyroscopicStability,
+    KineticEnergy,
+    BallisticCoefficient,
+    MachNumber,
+    BulletDrop,
+    Angle,
+    SightHeight,
+    TargetSize,
+    Deflection,
+    InclineCorrection,
+    VerticalWindDeflection,
+    ThresholdDistance,
+    BarrelLength,
+    BarrelVelocityEstimate,
+    RelativeHumidity,
+    Altitude,
+    ProjectileLength,
+    SectionalDensity,
+    SpinRate,
+    YawOfRepose,
+    TwistRate,
+    HitProbability,
+    DensityRatio,
+    RetardationCoefficient,
+    GunNumber,
+);
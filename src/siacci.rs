@@ -0,0 +1,277 @@
+use bon::Builder;
+
+use crate::{BallisticCoefficient, BulletDrop, Distance, TimeOfFlight, Velocity};
+
+/// Siacci functions
+///
+/// This struct represents the Ingalls space, time, inclination and altitude functions at a
+/// velocity, the integrals of the G1 (Ingalls) retardation from that velocity up to 4000 ft/s
+/// in the ICAO standard atmosphere. Siacci's method reads them at the muzzle velocity and at
+/// the velocity downrange, scaled by the ballistic coefficient, to solve a flat-fire
+/// trajectory. The table covers 500 to 4000 ft/s; velocities outside it are clamped to its ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SiacciFunctions {
+    /// The space function S(v) in feet per unit ballistic coefficient.
+    pub space: f64,
+    /// The time function T(v) in seconds per unit ballistic coefficient.
+    pub time: f64,
+    /// The inclination function I(v), the change in the tangent of the path angle per unit
+    /// ballistic coefficient, times 2.
+    pub inclination: f64,
+    /// The altitude function A(v) in feet per unit ballistic coefficient.
+    pub altitude: f64,
+}
+
+impl SiacciFunctions {
+    /// Looks up the Siacci functions at a velocity, interpolating linearly between table entries.
+    ///
+    /// # Parameters
+    /// - `velocity`: The velocity in feet per second (ft/s).
+    pub fn at(velocity: Velocity) -> Self {
+        let upper = INGALLS_TABLE
+            .partition_point(|row| row.0 < velocity.0)
+            .clamp(1, INGALLS_TABLE.len() - 1);
+        let (low, high) = (INGALLS_TABLE[upper - 1], INGALLS_TABLE[upper]);
+        let fraction = ((velocity.0 - low.0) / (high.0 - low.0)).clamp(0.0, 1.0);
+
+        SiacciFunctions::between(low, high, fraction)
+    }
+
+    /// Finds the velocity whose space function equals a value, interpolating linearly between
+    /// table entries.
+    fn velocity_for_space(space: f64) -> Velocity {
+        let upper = INGALLS_TABLE
+            .partition_point(|row| row.1 > space)
+            .clamp(1, INGALLS_TABLE.len() - 1);
+        let (low, high) = (INGALLS_TABLE[upper - 1], INGALLS_TABLE[upper]);
+        let fraction = ((low.1 - space) / (low.1 - high.1)).clamp(0.0, 1.0);
+
+        Velocity(low.0 + fraction * (high.0 - low.0))
+    }
+
+    fn between(low: IngallsRow, high: IngallsRow, fraction: f64) -> Self {
+        let lerp = |a: f64, b: f64| a + (b - a) * fraction;
+
+        SiacciFunctions {
+            space: lerp(low.1, high.1),
+            time: lerp(low.2, high.2),
+            inclination: lerp(low.3, high.3),
+            altitude: lerp(low.4, high.4),
+        }
+    }
+}
+
+/// Siacci trajectory
+///
+/// This struct represents a flat-fire trajectory solved with Siacci's method and the Ingalls
+/// functions, the reference method for historical and black-powder cartridges. It answers the
+/// same questions as `PejsaTrajectory`, so either can be used.
+#[derive(Debug, Clone, Copy, PartialEq, Builder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SiacciTrajectory {
+    /// The muzzle velocity of the bullet in feet per second (ft/s).
+    #[builder(into)]
+    pub muzzle_velocity: Velocity,
+    /// The ballistic coefficient C of the bullet in the Ingalls (G1) sense, in lb/in².
+    #[builder(into)]
+    pub ballistic_coefficient: BallisticCoefficient,
+}
+
+impl SiacciTrajectory {
+    /// Returns the velocity of the bullet at a distance.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    pub fn velocity_at(&self, distance: Distance) -> Velocity {
+        SiacciFunctions::velocity_for_space(self.muzzle().space + self.space(distance))
+    }
+
+    /// Returns the time the bullet takes to reach a distance.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    pub fn time_of_flight_at(&self, distance: Distance) -> TimeOfFlight {
+        let remaining = SiacciFunctions::at(self.velocity_at(distance));
+
+        TimeOfFlight(self.ballistic_coefficient.0 * (remaining.time - self.muzzle().time))
+    }
+
+    /// Returns the drop of the bullet below the bore line at a distance, for a level bore.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    ///
+    /// # Returns
+    /// The height of the bullet relative to the bore line in inches, negative below it.
+    pub fn drop_at(&self, distance: Distance) -> BulletDrop {
+        let muzzle = self.muzzle();
+        let remaining = SiacciFunctions::at(self.velocity_at(distance));
+        let space = remaining.space - muzzle.space;
+
+        if space <= 0.0 {
+            return BulletDrop(0.0);
+        }
+
+        let slope = (remaining.altitude - muzzle.altitude) / space - muzzle.inclination;
+
+        BulletDrop(-12.0 * self.ballistic_coefficient.0 * distance.0 * slope / 2.0)
+    }
+
+    fn muzzle(&self) -> SiacciFunctions {
+        SiacciFunctions::at(self.muzzle_velocity)
+    }
+
+    fn space(&self, distance: Distance) -> f64 {
+        distance.0 / self.ballistic_coefficient.0
+    }
+}
+
+/// A row of the Ingalls table: velocity (ft/s), S(v), T(v), I(v) and A(v).
+type IngallsRow = (f64, f64, f64, f64, f64);
+
+/// The Siacci functions of the G1 (Ingalls) retardation in the ICAO standard atmosphere, from
+/// 500 to 4000 ft/s in steps of 25 ft/s.
+const INGALLS_TABLE: [IngallsRow; 141] = [
+    (500.0, 26965.4993, 28.63427742, 2.4382359524, 17777.23972785),
+    (525.0, 25827.5239, 26.41301437, 2.1591819140, 15163.94744282),
+    (550.0, 24735.5819, 24.38081166, 1.9157666237, 12941.20429395),
+    (575.0, 23686.3873, 22.51498737, 1.7022203639, 11044.87758572),
+    (600.0, 22678.9802, 20.79975040, 1.5142696970, 9426.06311982),
+    (625.0, 21710.6611, 19.21838391, 1.3480655371, 8041.33079947),
+    (650.0, 20782.3493, 17.76182051, 1.2009848493, 6859.06648878),
+    (675.0, 19892.0416, 16.41762251, 1.0703759094, 5848.69264007),
+    (700.0, 19043.2843, 15.18274089, 0.9547513599, 4989.86664860),
+    (725.0, 18235.8439, 14.04921192, 0.8523432449, 4260.78957496),
+    (750.0, 17466.6090, 13.00594092, 0.7612865316, 3640.55517662),
+    (775.0, 16732.7870, 12.04334285, 0.6800272152, 3112.04709923),
+    (800.0, 16034.3564, 11.15624060, 0.6075175926, 2662.68465872),
+    (825.0, 15375.6317, 10.34531037, 0.5432736320, 2283.87428555),
+    (850.0, 14757.4120, 9.60696155, 0.4865260132, 1965.72757267),
+    (875.0, 14180.1586, 8.93752100, 0.4365659512, 1699.43785139),
+    (900.0, 13644.1552, 8.33342581, 0.3927525528, 1477.28928781),
+    (925.0, 13149.5579, 7.79126592, 0.3545083789, 1292.57904057),
+    (950.0, 12696.0264, 7.30737493, 0.3212846514, 1139.39926424),
+    (975.0, 12282.6617, 6.87779188, 0.2925556617, 1012.58070525),
+    (1000.0, 11907.9757, 6.49826095, 0.2678164895, 907.63801640),
+    (1025.0, 11569.7972, 6.16416816, 0.2465769405, 820.68915773),
+    (1050.0, 11265.1333, 5.87044007, 0.2283536827, 748.36433673),
+    (1075.0, 10990.3997, 5.61180377, 0.2126853696, 687.79708649),
+    (1100.0, 10741.6313, 5.38299908, 0.1991431834, 636.58502517),
+    (1125.0, 10514.8126, 5.17907474, 0.1873450684, 592.76366606),
+    (1150.0, 10306.1958, 4.99564118, 0.1769659405, 554.77089948),
+    (1175.0, 10112.4803, 4.82897680, 0.1677386748, 521.38999476),
+    (1200.0, 9930.9874, 4.67611855, 0.1594541214, 491.70367864),
+    (1225.0, 9759.5504, 4.53470942, 0.1519482555, 465.01515541),
+    (1250.0, 9596.4514, 4.40289754, 0.1450932530, 440.79533356),
+    (1275.0, 9440.3767, 4.27926168, 0.1387908560, 418.64502214),
+    (1300.0, 9290.2378, 4.16263854, 0.1329614426, 398.24755486),
+    (1325.0, 9145.1260, 4.05206800, 0.1275398800, 379.34915123),
+    (1350.0, 9004.6817, 3.94705480, 0.1224870888, 361.79393031),
+    (1375.0, 8868.1421, 3.84683527, 0.1177534708, 345.39472863),
+    (1400.0, 8735.2099, 3.75102192, 0.1133095251, 330.03864995),
+    (1425.0, 8605.4301, 3.65913694, 0.1091232452, 315.60660902),
+    (1450.0, 8478.5245, 3.57084972, 0.1051708446, 302.01050296),
+    (1475.0, 8354.2430, 3.48586651, 0.1014314151, 289.17340864),
+    (1500.0, 8232.3158, 3.40389455, 0.0978851157, 277.02356837),
+    (1525.0, 8112.6064, 3.32474415, 0.0945174973, 265.50847531),
+    (1550.0, 7994.8847, 3.24817381, 0.0913126351, 254.57138151),
+    (1575.0, 7879.0584, 3.17404182, 0.0882594845, 244.17273263),
+    (1600.0, 7764.9404, 3.10215363, 0.0853453803, 234.26788883),
+    (1625.0, 7652.4511, 3.03239014, 0.0825612545, 224.82484612),
+    (1650.0, 7541.4578, 2.96460555, 0.0798974107, 215.80968839),
+    (1675.0, 7431.8807, 2.89869220, 0.0773460606, 207.19524507),
+    (1700.0, 7323.6350, 2.83454451, 0.0748998462, 198.95591760),
+    (1725.0, 7216.6410, 2.77206429, 0.0725520061, 191.06829918),
+    (1750.0, 7110.8409, 2.71117029, 0.0702967008, 183.51216491),
+    (1775.0, 7006.1548, 2.65177222, 0.0681280073, 176.26713405),
+    (1800.0, 6902.5398, 2.59380416, 0.0660411297, 169.31666946),
+    (1825.0, 6799.9260, 2.53718812, 0.0640310418, 162.64354294),
+    (1850.0, 6698.2757, 2.48186682, 0.0620936474, 156.23368624),
+    (1875.0, 6597.5343, 2.42777610, 0.0602247781, 150.07283799),
+    (1900.0, 6497.6651, 2.37486400, 0.0584208472, 144.14871495),
+    (1925.0, 6398.6371, 2.32308346, 0.0566785730, 138.45005954),
+    (1950.0, 6300.4213, 2.27239030, 0.0549948970, 132.96636744),
+    (1975.0, 6202.9852, 2.22274022, 0.0533668735, 127.68752668),
+    (2000.0, 6106.2914, 2.17408822, 0.0517916451, 122.60375667),
+    (2025.0, 6010.3177, 2.12639846, 0.0502667542, 117.70660042),
+    (2050.0, 5915.0468, 2.07963880, 0.0487899500, 112.98827726),
+    (2075.0, 5820.4574, 2.03377638, 0.0473590418, 108.44121321),
+    (2100.0, 5726.5285, 1.98877964, 0.0459719569, 104.05823248),
+    (2125.0, 5633.2396, 1.94461842, 0.0446267398, 99.83255562),
+    (2150.0, 5540.5711, 1.90126396, 0.0433215448, 95.75777332),
+    (2175.0, 5448.5038, 1.85868876, 0.0420546282, 91.82782219),
+    (2200.0, 5357.0192, 1.81686653, 0.0408243418, 88.03696247),
+    (2225.0, 5266.0994, 1.77577213, 0.0396291264, 84.37975741),
+    (2250.0, 5175.7299, 1.73538291, 0.0384675468, 80.85117697),
+    (2275.0, 5085.9038, 1.69568011, 0.0373383262, 77.44668821),
+    (2300.0, 4996.6078, 1.65664303, 0.0362401748, 74.16173609),
+    (2325.0, 4907.8464, 1.61825915, 0.0351720721, 70.99258208),
+    (2350.0, 4819.6084, 1.58050971, 0.0341328593, 67.93508084),
+    (2375.0, 4731.8877, 1.54337871, 0.0331214889, 64.98543739),
+    (2400.0, 4644.6736, 1.50684875, 0.0321369088, 62.13986222),
+    (2425.0, 4557.9617, 1.47090547, 0.0311781811, 59.39491905),
+    (2450.0, 4471.7484, 1.43553541, 0.0302444194, 56.74733231),
+    (2475.0, 4386.0256, 1.40072369, 0.0293347284, 54.19382120),
+    (2500.0, 4300.7942, 1.36645933, 0.0284483402, 51.73147977),
+    (2525.0, 4216.0434, 1.33272724, 0.0275844044, 49.35719174),
+    (2550.0, 4131.7707, 1.29951592, 0.0267421871, 47.06818535),
+    (2575.0, 4047.9663, 1.26681132, 0.0259209117, 44.86159529),
+    (2600.0, 3964.6321, 1.23460448, 0.0251199505, 42.73497888),
+    (2625.0, 3881.7616, 1.20288335, 0.0243386179, 40.68575515),
+    (2650.0, 3799.3582, 1.17163995, 0.0235763470, 38.71167460),
+    (2675.0, 3717.4224, 1.14086560, 0.0228325700, 36.81049509),
+    (2700.0, 3635.9501, 1.11054997, 0.0221066959, 34.97993428),
+    (2725.0, 3554.9435, 1.08068543, 0.0213982133, 33.21792913),
+    (2750.0, 3474.3946, 1.05126085, 0.0207065429, 31.52226823),
+    (2775.0, 3394.3030, 1.02226810, 0.0200311914, 29.89097535),
+    (2800.0, 3314.6619, 0.99369700, 0.0193716306, 28.32201221),
+    (2825.0, 3235.4855, 0.96554509, 0.0187275235, 26.81381105),
+    (2850.0, 3156.7710, 0.93780398, 0.0180984080, 25.36451567),
+    (2875.0, 3078.5088, 0.91046320, 0.0174837864, 23.97221592),
+    (2900.0, 3000.6898, 0.88351261, 0.0168831819, 22.63508125),
+    (2925.0, 2923.3191, 0.85694726, 0.0162962445, 21.35158750),
+    (2950.0, 2846.4091, 0.83076487, 0.0157226916, 20.12036222),
+    (2975.0, 2769.9513, 0.80495608, 0.0151620940, 18.93973161),
+    (3000.0, 2693.9372, 0.77951177, 0.0146140383, 17.80808621),
+    (3025.0, 2618.3606, 0.75442387, 0.0140781439, 16.72391377),
+    (3050.0, 2543.2325, 0.72969008, 0.0135541624, 15.68598593),
+    (3075.0, 2468.5489, 0.70530338, 0.0130417511, 14.69289912),
+    (3100.0, 2394.3019, 0.68125555, 0.0125405520, 13.74324521),
+    (3125.0, 2320.4838, 0.65753867, 0.0120502206, 12.83567129),
+    (3150.0, 2247.1014, 0.63414963, 0.0115705203, 11.96904450),
+    (3175.0, 2174.1642, 0.61108626, 0.0111012387, 11.14228197),
+    (3200.0, 2101.6647, 0.58834112, 0.0106420621, 10.35413637),
+    (3225.0, 2029.5956, 0.56590697, 0.0101926885, 9.60340791),
+    (3250.0, 1957.9528, 0.54377773, 0.0097528455, 8.88897175),
+    (3275.0, 1886.7479, 0.52195227, 0.0093223645, 8.20988618),
+    (3300.0, 1815.9769, 0.50042479, 0.0089009900, 7.56508190),
+    (3325.0, 1745.6331, 0.47918874, 0.0084884570, 6.95349827),
+    (3350.0, 1675.7097, 0.45823773, 0.0080845099, 6.37411428),
+    (3375.0, 1606.2112, 0.43756887, 0.0076889658, 5.82603240),
+    (3400.0, 1537.1413, 0.41717905, 0.0073016415, 5.30836505),
+    (3425.0, 1468.4933, 0.39706227, 0.0069223035, 4.82017454),
+    (3450.0, 1400.2610, 0.37721272, 0.0065507268, 4.36055743),
+    (3475.0, 1332.4415, 0.35762568, 0.0061867116, 3.92866332),
+    (3500.0, 1265.0440, 0.33830008, 0.0058301298, 3.52373952),
+    (3525.0, 1198.0644, 0.31923101, 0.0054807858, 3.14496725),
+    (3550.0, 1131.4968, 0.30041316, 0.0051384803, 2.79154440),
+    (3575.0, 1065.3353, 0.28184136, 0.0048030214, 2.46269779),
+    (3600.0, 999.5808, 0.26351245, 0.0044742569, 2.15771169),
+    (3625.0, 934.2324, 0.24542280, 0.0041520296, 1.87587914),
+    (3650.0, 869.2845, 0.22756757, 0.0038361640, 1.61649438),
+    (3675.0, 804.7315, 0.20994205, 0.0035264902, 1.37887638),
+    (3700.0, 740.5702, 0.19254225, 0.0032228550, 1.16237503),
+    (3725.0, 676.8036, 0.17536592, 0.0029251381, 0.96637789),
+    (3750.0, 613.4270, 0.15840887, 0.0026331877, 0.79026479),
+    (3775.0, 550.4354, 0.14166678, 0.0023468538, 0.63343421),
+    (3800.0, 487.8234, 0.12513545, 0.0020659907, 0.49530516),
+    (3825.0, 425.5844, 0.10881036, 0.0017904502, 0.37531337),
+    (3850.0, 363.7125, 0.09268727, 0.0015200921, 0.27291671),
+    (3875.0, 302.2028, 0.07676233, 0.0012547851, 0.18759344),
+    (3900.0, 241.0507, 0.06103179, 0.0009944021, 0.11883926),
+    (3925.0, 180.2542, 0.04549264, 0.0007388307, 0.06616855),
+    (3950.0, 119.8164, 0.03014325, 0.0004879830, 0.02911160),
+    (3975.0, 59.7331, 0.01498019, 0.0002417440, 0.00720497),
+    (4000.0, 0.0000, 0.00000000, 0.0000000000, 0.00000000),
+];
@@ -0,0 +1,250 @@
+use bon::bon;
+
+use crate::{
+    Atmosphere, BallisticCoefficient, BallisticsError, BulletDiameter, BulletLength, BulletWeight,
+    Distance, GyroscopicStability, MachNumber, RiflingTwist, SpeedOfSound, SpinRate, Velocity,
+    SPEED_OF_SOUND_SEA_LEVEL,
+};
+
+/// Gyroscopic stability factor from which a bullet is comfortably stable.
+const COMFORTABLE_STABILITY: f64 = 1.5;
+
+/// Gyroscopic stability factor below which a bullet is unstable.
+pub(crate) const MINIMUM_STABILITY: f64 = 1.0;
+
+/// Fraction of its ballistic coefficient a bullet loses when flying at the minimum stability.
+const UNSTABLE_BC_LOSS: f64 = 0.1;
+
+/// Mach number range in which the flow around the bullet is transonic.
+const TRANSONIC_REGION: (f64, f64) = (0.9, 1.2);
+
+/// Stability rating
+///
+/// This enum represents how reliably a bullet keeps flying point first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StabilityRating {
+    /// The gyroscopic stability factor is at least 1.5.
+    Stable,
+    /// The gyroscopic stability factor is between 1.0 and 1.5, outside the transonic region.
+    MarginallyStable,
+    /// The gyroscopic stability factor is between 1.0 and 1.5 while the bullet is between
+    /// Mach 0.9 and 1.2, where marginally stable bullets are prone to tumbling.
+    TransonicRisk,
+    /// The gyroscopic stability factor is below 1.0.
+    Unstable,
+}
+
+/// Stability assessment
+///
+/// This struct represents the stability rating of a bullet along with the conditions it was
+/// evaluated at.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StabilityAssessment {
+    /// The stability rating.
+    pub rating: StabilityRating,
+    /// The gyroscopic stability factor evaluated.
+    pub gyro_stability: GyroscopicStability,
+    /// The Mach number evaluated.
+    pub mach: MachNumber,
+    /// The distance in feet the Mach number was taken at, if given.
+    pub distance: Option<Distance>,
+}
+
+#[bon]
+impl StabilityAssessment {
+    /// Rates the stability of a bullet from its gyroscopic stability factor and Mach number.
+    ///
+    /// # Parameters
+    /// - `gyro_stability`: The gyroscopic stability factor of the bullet.
+    /// - `mach`: The Mach number of the bullet at the range of interest.
+    /// - `distance`: The range of interest in feet, kept for context.
+    ///
+    /// # Returns
+    /// A `StabilityAssessment` instance holding the rating and its inputs.
+    #[builder(finish_fn = solve)]
+    pub fn evaluate(
+        #[builder(into)] gyro_stability: GyroscopicStability,
+        #[builder(into)] mach: MachNumber,
+        distance: Option<Distance>,
+    ) -> Self {
+        let transonic = (TRANSONIC_REGION.0..=TRANSONIC_REGION.1).contains(&mach.0);
+
+        let rating = if gyro_stability.0 < MINIMUM_STABILITY {
+            StabilityRating::Unstable
+        } else if gyro_stability.0 >= COMFORTABLE_STABILITY {
+            StabilityRating::Stable
+        } else if transonic {
+            StabilityRating::TransonicRisk
+        } else {
+            StabilityRating::MarginallyStable
+        };
+
+        StabilityAssessment {
+            rating,
+            gyro_stability,
+            mach,
+            distance,
+        }
+    }
+}
+
+/// Corrected stability
+///
+/// This struct represents a gyroscopic stability factor corrected for muzzle velocity and
+/// atmosphere, along with the intermediate factors for checking each step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CorrectedStability {
+    /// The fully corrected gyroscopic stability factor.
+    pub gyro_stability: GyroscopicStability,
+    /// The factor from Miller's formula at 2800 ft/s in the ICAO standard atmosphere.
+    pub uncorrected: GyroscopicStability,
+    /// The factor corrected for the muzzle velocity only.
+    pub velocity_corrected: GyroscopicStability,
+}
+
+#[bon]
+impl GyroscopicStability {
+    /// Calculates the gyroscopic stability factor of a bullet using Miller's stability formula,
+    /// then corrects it for the muzzle velocity and finally for the atmosphere.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains.
+    /// - `rifling_twist`: The rifling twist rate of the barrel in calibers per turn.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches.
+    /// - `bullet_length`: The length of the bullet in calibers.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `atmosphere`: The air the bullet is fired in (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `CorrectedStability` instance holding the corrected factor and the intermediate ones.
+    #[builder(finish_fn = solve)]
+    pub fn calculate_corrected(
+        bullet_weight: BulletWeight,
+        #[builder(into)] rifling_twist: RiflingTwist,
+        bullet_diameter: BulletDiameter,
+        #[builder(into)] bullet_length: BulletLength,
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> CorrectedStability {
+        let uncorrected = GyroscopicStability::calculate()
+            .bullet_weight(bullet_weight)
+            .rifling_twist(rifling_twist)
+            .bullet_diameter(bullet_diameter)
+            .bullet_length(bullet_length)
+            .solve();
+        let velocity_corrected = uncorrected.corrected_for_velocity(muzzle_velocity);
+        let gyro_stability = GyroscopicStability::atmospheric_correction_for()
+            .atmosphere(atmosphere)
+            .gyro_stability(velocity_corrected)
+            .solve();
+
+        CorrectedStability {
+            gyro_stability,
+            uncorrected,
+            velocity_corrected,
+        }
+    }
+}
+
+/// Downrange stability
+///
+/// This struct represents the gyroscopic stability factor of a bullet at a point downrange,
+/// along with its Mach number there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DownrangeStability {
+    /// The gyroscopic stability factor at the point.
+    pub gyro_stability: GyroscopicStability,
+    /// The Mach number at the point.
+    pub mach: MachNumber,
+    /// Whether the bullet is between Mach 0.9 and 1.2 at the point, where the stability factor
+    /// matters most.
+    pub transonic: bool,
+}
+
+#[bon]
+impl GyroscopicStability {
+    /// Calculates the gyroscopic stability factor of a bullet downrange from its value at the
+    /// muzzle.
+    ///
+    /// The factor grows in proportion to the muzzle velocity over the remaining velocity, since
+    /// the velocity decays much faster than the spin; with the spin rates given, it is further
+    /// scaled by the square of the fraction of spin retained.
+    ///
+    /// # Parameters
+    /// - `muzzle_gyro_stability`: The gyroscopic stability factor at the muzzle.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `velocity`: The remaining velocity of the bullet in feet per second (ft/s).
+    /// - `muzzle_spin_rate`: The spin rate at the muzzle in revolutions per minute, if known.
+    /// - `spin_rate`: The remaining spin rate in revolutions per minute, as given by
+    ///   `SpinRate::at_range` (no spin decay by default or without `muzzle_spin_rate`).
+    /// - `speed_of_sound`: The speed of sound in ft/s (`SPEED_OF_SOUND_SEA_LEVEL` by default).
+    ///
+    /// # Returns
+    /// A `DownrangeStability` instance, flagged when the bullet is transonic.
+    #[builder(finish_fn = solve)]
+    pub fn at_range(
+        #[builder(into)] muzzle_gyro_stability: GyroscopicStability,
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] velocity: Velocity,
+        #[builder(into)] muzzle_spin_rate: Option<SpinRate>,
+        #[builder(into)] spin_rate: Option<SpinRate>,
+        #[builder(default = SPEED_OF_SOUND_SEA_LEVEL, into)] speed_of_sound: SpeedOfSound,
+    ) -> DownrangeStability {
+        let spin_retained = match (muzzle_spin_rate, spin_rate) {
+            (Some(muzzle_spin_rate), Some(spin_rate)) => spin_rate.0 / muzzle_spin_rate.0,
+            _ => 1.0,
+        };
+        let mach = MachNumber(velocity.0 / speed_of_sound.0);
+
+        DownrangeStability {
+            gyro_stability: GyroscopicStability(
+                muzzle_gyro_stability.0 * muzzle_velocity.0 / velocity.0
+                    * spin_retained
+                    * spin_retained,
+            ),
+            mach,
+            transonic: (TRANSONIC_REGION.0..=TRANSONIC_REGION.1).contains(&mach.0),
+        }
+    }
+}
+
+#[bon]
+impl BallisticCoefficient {
+    /// Estimates the ballistic coefficient a bullet achieves with marginal gyroscopic stability.
+    ///
+    /// Bryan Litz measured that bullets flying with a stability factor below 1.5 yaw more and
+    /// lose part of their ballistic coefficient. This approximates that trend linearly, from no
+    /// loss at 1.5 to a 10% loss at 1.0.
+    ///
+    /// # Parameters
+    /// - `ballistic_coefficient`: The published ballistic coefficient of the bullet.
+    /// - `gyro_stability`: The gyroscopic stability factor of the bullet.
+    ///
+    /// # Returns
+    /// The degraded `BallisticCoefficient`, unchanged from 1.5 upward, or
+    /// `BallisticsError::OutOfRange` below 1.0 where the bullet does not fly point first.
+    #[builder(finish_fn = solve)]
+    pub fn stability_adjusted(
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(into)] gyro_stability: GyroscopicStability,
+    ) -> Result<Self, BallisticsError> {
+        if gyro_stability.0.is_nan() || gyro_stability.0 < MINIMUM_STABILITY {
+            return Err(BallisticsError::OutOfRange {
+                param: "gyro_stability",
+                value: gyro_stability.0,
+                allowed: "at least 1",
+            });
+        }
+
+        let shortfall = (COMFORTABLE_STABILITY - gyro_stability.0).max(0.0)
+            / (COMFORTABLE_STABILITY - MINIMUM_STABILITY);
+
+        Ok(BallisticCoefficient(
+            ballistic_coefficient.0 * (1.0 - UNSTABLE_BC_LOSS * shortfall),
+        ))
+    }
+}
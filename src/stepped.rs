@@ -0,0 +1,167 @@
+use crate::{
+    validation::{non_negative, positive},
+    BallisticCoefficient, BallisticsError, Distance, DragCoefficient, DragCurve, InterpolatedDrag,
+    MachNumber, SpeedOfSound, TrajectoryIter, TrajectoryPoint, TrajectorySolver, Velocity,
+};
+
+/// Width of the velocity window over which the ballistic coefficient blends from one band into
+/// the next (ft/s).
+const BLEND_WIDTH: f64 = 50.0;
+
+/// Velocity-banded ballistic coefficient
+///
+/// This struct represents a ballistic coefficient published in velocity bands, such as 0.465
+/// above 2600 ft/s, 0.458 down to 2100 ft/s and 0.447 below. Each band holds the velocity it
+/// applies down to and its ballistic coefficient, the fastest band first; the last band applies
+/// at any lower velocity.
+///
+/// A ballistic coefficient jumping at a band boundary would make the drag discontinuous, which
+/// an adaptive integrator can only resolve by shrinking its steps to the minimum. Instead the
+/// coefficient blends linearly from one band into the next over 50 ft/s centered on the
+/// boundary, keeping the drag continuous at the cost of a slight rounding of each step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteppedBC<'a> {
+    bands: &'a [(Velocity, BallisticCoefficient)],
+}
+
+impl<'a> SteppedBC<'a> {
+    /// Creates a velocity-banded ballistic coefficient from its bands.
+    ///
+    /// # Returns
+    /// A `SteppedBC` instance, or `BallisticsError::OutOfRange` if there is no band, a
+    /// ballistic coefficient is not positive, a velocity is negative or the velocities do not
+    /// decrease from band to band by more than the blending window.
+    pub fn new(bands: &'a [(Velocity, BallisticCoefficient)]) -> Result<Self, BallisticsError> {
+        if bands.is_empty() {
+            return Err(BallisticsError::OutOfRange {
+                param: "bands",
+                value: 0.0,
+                allowed: "at least one band",
+            });
+        }
+
+        for (index, &(velocity, ballistic_coefficient)) in bands.iter().enumerate() {
+            non_negative("velocity", velocity.0)?;
+            positive("ballistic_coefficient", ballistic_coefficient.0)?;
+
+            if index > 0 && bands[index - 1].0 .0 - velocity.0 <= BLEND_WIDTH {
+                return Err(BallisticsError::OutOfRange {
+                    param: "velocity",
+                    value: velocity.0,
+                    allowed: "more than 50 ft/s below the velocity of the band before it",
+                });
+            }
+        }
+
+        Ok(SteppedBC { bands })
+    }
+
+    /// Returns the bands, the fastest first.
+    pub fn bands(&self) -> &'a [(Velocity, BallisticCoefficient)] {
+        self.bands
+    }
+
+    /// Returns the ballistic coefficient at a velocity in feet per second (ft/s), blended
+    /// across the band boundaries.
+    pub fn at_velocity(&self, velocity: Velocity) -> BallisticCoefficient {
+        let slowest = self.bands[self.bands.len() - 1].1;
+
+        BallisticCoefficient(self.bands.windows(2).fold(slowest.0, |bc, pair| {
+            let ((boundary, faster), (_, slower)) = (pair[0], pair[1]);
+            let weight = ((velocity.0 - boundary.0) / BLEND_WIDTH + 0.5).clamp(0.0, 1.0);
+
+            bc + (faster.0 - slower.0) * weight
+        }))
+    }
+
+    /// Returns a drag curve that scales a drag model so that a solver with the `reference`
+    /// ballistic coefficient integrates with this banded one instead.
+    ///
+    /// # Parameters
+    /// - `drag`: The drag curve the ballistic coefficients refer to.
+    /// - `reference`: The ballistic coefficient the solver is built with.
+    /// - `speed_of_sound`: The speed of sound the solver converts Mach numbers with in ft/s.
+    pub fn drag_curve<D: DragCurve>(
+        self,
+        drag: D,
+        reference: BallisticCoefficient,
+        speed_of_sound: SpeedOfSound,
+    ) -> SteppedDrag<'a, D> {
+        SteppedDrag {
+            stepped: self,
+            drag,
+            reference,
+            speed_of_sound,
+        }
+    }
+}
+
+/// Drag curve of a velocity-banded ballistic coefficient
+///
+/// This struct scales the drag coefficient of a drag curve by the ratio of a solver's single
+/// ballistic coefficient to the banded one at the velocity the Mach number corresponds to. It
+/// is returned by [`SteppedBC::drag_curve`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteppedDrag<'a, D = InterpolatedDrag> {
+    stepped: SteppedBC<'a>,
+    drag: D,
+    reference: BallisticCoefficient,
+    speed_of_sound: SpeedOfSound,
+}
+
+impl<D: DragCurve> DragCurve for SteppedDrag<'_, D> {
+    fn drag_coefficient(&self, mach: MachNumber) -> DragCoefficient {
+        let ballistic_coefficient = self
+            .stepped
+            .at_velocity(Velocity(mach.0 * self.speed_of_sound.0));
+
+        DragCoefficient(
+            self.drag.drag_coefficient(mach).0 * self.reference.0 / ballistic_coefficient.0,
+        )
+    }
+}
+
+impl TrajectorySolver {
+    /// Lazily samples the trajectory at every multiple of `step` with a velocity-banded
+    /// ballistic coefficient in place of the solver's.
+    ///
+    /// # Parameters
+    /// - `step`: The range increment between two samples in feet.
+    /// - `stepped`: The banded ballistic coefficient, relative to the solver's drag model.
+    ///
+    /// # Returns
+    /// A `TrajectoryIter` yielding one `TrajectoryPoint` per range increment until the
+    /// maximum range or the stop condition is reached.
+    pub fn iter_points_stepped<'a>(
+        &self,
+        step: Distance,
+        stepped: SteppedBC<'a>,
+    ) -> TrajectoryIter<SteppedDrag<'a>> {
+        self.iter_points_with(
+            step,
+            stepped.drag_curve(
+                self.drag(),
+                self.ballistic_coefficient,
+                self.atmosphere().speed_of_sound(),
+            ),
+        )
+    }
+
+    /// Integrates the trajectory up to a single distance with a velocity-banded ballistic
+    /// coefficient in place of the solver's.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    /// - `stepped`: The banded ballistic coefficient, relative to the solver's drag model.
+    ///
+    /// # Returns
+    /// The `TrajectoryPoint` at `distance`, or `None` if the trajectory ends before it.
+    pub fn point_at_stepped(
+        &self,
+        distance: Distance,
+        stepped: SteppedBC<'_>,
+    ) -> Option<TrajectoryPoint> {
+        self.iter_points_stepped(distance, stepped)
+            .point_at(distance)
+    }
+}
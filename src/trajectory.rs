@@ -0,0 +1,862 @@
+#[cfg(feature = "std")]
+use std::io;
+
+use bon::{bon, Builder};
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+#[cfg(feature = "std")]
+use crate::QuantityFormatter;
+use crate::{
+    units::{CENTIMETERS_PER_INCH, JOULES_PER_FOOT_POUND, METERS_PER_FOOT},
+    Angle, Atmosphere, BallisticCoefficient, BallisticsError, BulletDrop, BulletWeight, Distance,
+    DragCurve, DragModel, InterpolatedDrag, Interpolation, KineticEnergy, MachNumber, Pressure,
+    ReferenceAtmosphere, RelativeHumidity, SightHeight, Temperature, TimeOfFlight, Velocity,
+    WindDeflection, WindSpeed, WindVector, WindZones, STANDARD_GRAVITY, STANDARD_PRESSURE,
+    STANDARD_TEMPERATURE,
+};
+
+/// Retardation (ft/s² per (ft/s)² of velocity) of a projectile with a ballistic coefficient
+/// of 1 lb/in² and a drag coefficient of 1 in standard air.
+pub(crate) const DRAG_CONSTANT: f64 = 2.08551e-4;
+
+/// Integration time step without adaptive step control (s).
+const TIME_STEP: f64 = 0.0005;
+
+/// Factor by which an adaptive step reaching for a requested range overshoots it, so that the
+/// step lands just past the range despite the bullet slowing down.
+const RANGE_OVERSHOOT: f64 = 1.001;
+
+/// Time of flight after which the integration gives up regardless of the stop condition (s).
+const MAX_TIME_OF_FLIGHT: f64 = 120.0;
+
+/// Conversion factor from miles per hour to feet per second.
+const MPH_TO_FPS: f64 = 5280.0 / 3600.0;
+
+/// Convergence threshold of the zero angle search (MOA).
+const ZERO_TOLERANCE: f64 = 0.01;
+
+/// Maximum number of corrections applied by the zero angle search.
+const MAX_ZERO_ITERATIONS: usize = 50;
+
+/// Unit in which ranges are reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RangeUnit {
+    /// Yards (the default).
+    #[default]
+    Yards,
+    /// Meters.
+    Meters,
+}
+
+impl RangeUnit {
+    /// Converts a distance into this unit.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance in feet.
+    ///
+    /// # Returns
+    /// The distance expressed in yards or meters.
+    pub fn convert(&self, distance: Distance) -> f64 {
+        match self {
+            RangeUnit::Yards => distance.yards(),
+            RangeUnit::Meters => distance.meters(),
+        }
+    }
+}
+
+/// System of units in which linear quantities are reported
+///
+/// Imperial reports drops and windage in inches, velocities in ft/s and energies in
+/// foot-pounds; metric reports them in centimeters, m/s and joules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Units {
+    /// Imperial units, with ranges in yards (the default).
+    #[default]
+    Imperial,
+    /// Metric units, with ranges in meters.
+    Metric,
+}
+
+impl Units {
+    /// Returns the unit ranges are reported in unless chosen otherwise.
+    pub fn range_unit(&self) -> RangeUnit {
+        match self {
+            Units::Imperial => RangeUnit::Yards,
+            Units::Metric => RangeUnit::Meters,
+        }
+    }
+
+    /// Converts a length into this system.
+    ///
+    /// # Parameters
+    /// - `inches`: The length in inches.
+    ///
+    /// # Returns
+    /// The length expressed in inches or centimeters.
+    pub fn convert_length(&self, inches: f64) -> f64 {
+        match self {
+            Units::Imperial => inches,
+            Units::Metric => inches * CENTIMETERS_PER_INCH,
+        }
+    }
+
+    /// Converts a velocity into this system.
+    ///
+    /// # Returns
+    /// The velocity expressed in ft/s or m/s.
+    pub fn convert_velocity(&self, velocity: Velocity) -> f64 {
+        match self {
+            Units::Imperial => velocity.0,
+            Units::Metric => velocity.0 * METERS_PER_FOOT,
+        }
+    }
+
+    /// Converts a kinetic energy into this system.
+    ///
+    /// # Returns
+    /// The energy expressed in foot-pounds or joules.
+    pub fn convert_energy(&self, energy: KineticEnergy) -> f64 {
+        match self {
+            Units::Imperial => energy.0,
+            Units::Metric => energy.0 * JOULES_PER_FOOT_POUND,
+        }
+    }
+}
+
+/// Unit in which angles are reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AngularUnit {
+    /// Minutes of angle (the default).
+    #[default]
+    Moa,
+    /// Milliradians.
+    Mil,
+}
+
+impl AngularUnit {
+    /// Converts an angle into this unit.
+    ///
+    /// # Returns
+    /// The angle expressed in minutes of angle or milliradians.
+    pub fn convert(&self, angle: Angle) -> f64 {
+        match self {
+            AngularUnit::Moa => angle.0,
+            AngularUnit::Mil => angle.mils(),
+        }
+    }
+}
+
+/// Condition on which the trajectory solver stops integrating
+///
+/// Integration always stops at the solver's maximum range; this enum selects an
+/// additional condition that may end the trajectory earlier.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StopCondition {
+    /// Integrate all the way to the maximum range.
+    #[default]
+    MaxRange,
+    /// Stop as soon as the bullet slows below the local speed of sound.
+    Subsonic,
+    /// Stop as soon as the bullet falls further below the muzzle than the given height
+    /// of the muzzle above the ground (ft).
+    Ground(Distance),
+}
+
+/// Adaptive step control of the trajectory integrator
+///
+/// These options make the solver estimate the error of every step by comparing one step with
+/// two half steps, shrinking the step where the drag changes rapidly, such as through the
+/// transonic region, and growing it where the trajectory is smooth. The error of each position
+/// (ft) and velocity (ft/s) component must stay below `absolute_tolerance` plus
+/// `relative_tolerance` times the magnitude of that component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolverOptions {
+    /// The error allowed per step in feet or feet per second, regardless of the magnitude.
+    pub absolute_tolerance: f64,
+    /// The error allowed per step as a fraction of the magnitude of the component.
+    pub relative_tolerance: f64,
+    /// The smallest step in seconds, taken even when its error exceeds the tolerance.
+    pub min_step: TimeOfFlight,
+    /// The largest step in seconds.
+    pub max_step: TimeOfFlight,
+}
+
+impl Default for SolverOptions {
+    /// Returns tolerances of 10⁻⁶ ft (or ft/s) and 10⁻⁹, with steps from 10 µs to 50 ms.
+    fn default() -> Self {
+        SolverOptions {
+            absolute_tolerance: 1e-6,
+            relative_tolerance: 1e-9,
+            min_step: TimeOfFlight(1e-5),
+            max_step: TimeOfFlight(0.05),
+        }
+    }
+}
+
+/// Point-mass trajectory solver
+///
+/// This struct holds the inputs of a point-mass trajectory: the bullet is launched
+/// along the bore line, elevated by the launch angle above the horizontal line of
+/// sight, and integrated through air whose drag follows the chosen standard drag
+/// model, scaled by the ballistic coefficient.
+#[derive(Debug, Clone, Copy, Builder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrajectorySolver {
+    /// The muzzle velocity of the bullet in feet per second (ft/s).
+    #[builder(into)]
+    pub(crate) muzzle_velocity: Velocity,
+    /// The ballistic coefficient of the bullet relative to `drag_model`.
+    #[builder(into)]
+    pub(crate) ballistic_coefficient: BallisticCoefficient,
+    /// The standard drag model the ballistic coefficient refers to (G1 by default).
+    #[builder(default)]
+    pub(crate) drag_model: DragModel,
+    /// The interpolation between the entries of the drag model's table (linear by default).
+    #[builder(default)]
+    pub(crate) interpolation: Interpolation,
+    /// The angle of the bore above the horizontal in MOA (level by default).
+    #[builder(default = Angle(0.0), into)]
+    pub(crate) launch_angle: Angle,
+    /// The height of the line of sight above the bore in inches (none by default).
+    #[builder(default = SightHeight(0.0), into)]
+    pub(crate) sight_height: SightHeight,
+    /// The weight of the bullet in grains, required for energy outputs.
+    pub(crate) bullet_weight: Option<BulletWeight>,
+    /// The speed of the crosswind in miles per hour (mph), positive blowing from left to right.
+    #[builder(default = WindSpeed(0.0), into)]
+    pub(crate) crosswind_speed: WindSpeed,
+    /// The speed of the headwind in miles per hour (mph), positive blowing into the shooter's
+    /// face and negative for a tailwind.
+    #[builder(default = WindSpeed(0.0), into)]
+    pub(crate) headwind_speed: WindSpeed,
+    /// The air temperature in degrees Fahrenheit (ICAO standard by default).
+    #[builder(default = STANDARD_TEMPERATURE)]
+    pub(crate) temperature: Temperature,
+    /// The air pressure in inches of Mercury (ICAO standard by default).
+    #[builder(default = STANDARD_PRESSURE)]
+    pub(crate) pressure: Pressure,
+    /// The relative humidity of the air in percent (dry air by default).
+    #[builder(default = RelativeHumidity(0.0))]
+    pub(crate) relative_humidity: RelativeHumidity,
+    /// The reference atmosphere the ballistic coefficient is normalized to (ICAO by default).
+    #[builder(default)]
+    pub(crate) reference_atmosphere: ReferenceAtmosphere,
+    /// The range in feet at which integration stops (1000 yards by default).
+    #[builder(default = Distance(3000.0))]
+    pub(crate) max_range: Distance,
+    /// An additional condition that may stop integration before the maximum range.
+    #[builder(default)]
+    pub(crate) stop_condition: StopCondition,
+    /// Adaptive step control of the integrator (fixed 0.5 ms steps by default).
+    pub(crate) solver_options: Option<SolverOptions>,
+}
+
+impl TrajectorySolver {
+    /// Lazily samples the trajectory at every multiple of `step`, starting at the muzzle.
+    ///
+    /// # Parameters
+    /// - `step`: The range increment between two samples in feet.
+    ///
+    /// # Returns
+    /// A `TrajectoryIter` yielding one `TrajectoryPoint` per range increment until the
+    /// maximum range or the stop condition is reached.
+    pub fn iter_points(&self, step: Distance) -> TrajectoryIter {
+        TrajectoryIter::new(*self, step, self.drag())
+    }
+
+    /// Returns the drag model and interpolation the solver integrates through.
+    pub fn drag(&self) -> InterpolatedDrag {
+        self.drag_model.interpolated(self.interpolation)
+    }
+
+    /// Lazily samples the trajectory at every multiple of `step` through a drag curve other
+    /// than the solver's drag model, such as a `PreparedDrag` lookup.
+    ///
+    /// # Parameters
+    /// - `step`: The range increment between two samples in feet.
+    /// - `drag`: The drag curve the ballistic coefficient refers to, replacing `drag_model`.
+    ///
+    /// # Returns
+    /// A `TrajectoryIter` yielding one `TrajectoryPoint` per range increment until the
+    /// maximum range or the stop condition is reached.
+    pub fn iter_points_with<D: DragCurve>(&self, step: Distance, drag: D) -> TrajectoryIter<D> {
+        TrajectoryIter::new(*self, step, drag)
+    }
+
+    /// Samples the whole trajectory at every multiple of `step`, starting at the muzzle.
+    ///
+    /// # Parameters
+    /// - `step`: The range increment between two samples in feet.
+    ///
+    /// # Returns
+    /// A `Trajectory` instance holding the same points `iter_points` would yield.
+    #[cfg(feature = "std")]
+    pub fn solve(&self, step: Distance) -> Trajectory {
+        self.iter_points(step).collect()
+    }
+
+    /// Integrates the trajectory up to a single distance.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    ///
+    /// # Returns
+    /// The `TrajectoryPoint` at `distance`, or `None` if the trajectory ends before it.
+    pub fn point_at(&self, distance: Distance) -> Option<TrajectoryPoint> {
+        self.iter_points(distance).point_at(distance)
+    }
+
+    /// Integrates the trajectory up to a single distance through a drag curve other than the
+    /// solver's drag model, such as a `PreparedDrag` lookup.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    /// - `drag`: The drag curve the ballistic coefficient refers to, replacing `drag_model`.
+    ///
+    /// # Returns
+    /// The `TrajectoryPoint` at `distance`, or `None` if the trajectory ends before it.
+    pub fn point_at_with<D: DragCurve>(
+        &self,
+        distance: Distance,
+        drag: D,
+    ) -> Option<TrajectoryPoint> {
+        self.iter_points_with(distance, drag).point_at(distance)
+    }
+
+    /// Integrates the trajectory up to a single distance through wind zones, which replace the
+    /// solver's crosswind and headwind.
+    ///
+    /// Each zone's wind applies from the first integration step beyond the start of the zone.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    /// - `zones`: The wind zones along the range.
+    ///
+    /// # Returns
+    /// The `TrajectoryPoint` at `distance`, or `None` if the trajectory ends before it.
+    pub fn point_at_in_wind(
+        &self,
+        distance: Distance,
+        zones: WindZones<'_>,
+    ) -> Option<TrajectoryPoint> {
+        let mut points = self.iter_points(distance);
+        points.set_wind(zones.wind_at(Distance(0.0)));
+
+        for &(start, wind) in zones.zones() {
+            if start.0 > 0.0 && start.0 < distance.0 {
+                points.point_at(start)?;
+                points.set_wind(wind);
+            }
+        }
+
+        points.point_at(distance)
+    }
+
+    /// Returns a copy of this solver launching the bullet at a different angle.
+    ///
+    /// # Parameters
+    /// - `launch_angle`: The angle of the bore above the horizontal in MOA.
+    ///
+    /// # Returns
+    /// A `TrajectorySolver` instance identical to this one except for the launch angle.
+    pub fn with_launch_angle(self, launch_angle: Angle) -> Self {
+        TrajectorySolver {
+            launch_angle,
+            ..self
+        }
+    }
+
+    pub(crate) fn atmosphere(&self) -> Atmosphere {
+        Atmosphere::builder()
+            .temperature(self.temperature)
+            .pressure(self.pressure)
+            .relative_humidity(self.relative_humidity)
+            .build()
+    }
+
+    fn density_ratio(&self) -> f64 {
+        self.atmosphere()
+            .density_ratio_to(self.reference_atmosphere)
+            .0
+    }
+}
+
+#[bon]
+impl TrajectorySolver {
+    /// Finds the launch angle at which the trajectory crosses the line of sight at the zero range.
+    ///
+    /// The line of sight is horizontal and sits the solver's sight height above the muzzle. The
+    /// angle is refined until successive corrections fall below 0.01 MOA; the solver's own launch
+    /// angle, maximum range and stop condition are ignored during the search.
+    ///
+    /// # Parameters
+    /// - `zero_range`: The distance at which the bullet should cross the line of sight in feet.
+    ///
+    /// # Returns
+    /// The launch angle in MOA, or `BallisticsError::UnreachableZero` if the bullet cannot be
+    /// made to reach the line of sight at the zero range.
+    #[builder(finish_fn = solve)]
+    pub fn zero_angle(&self, zero_range: Distance) -> Result<Angle, BallisticsError> {
+        self.launch_angle_through(zero_range, BulletDrop(0.0))
+    }
+}
+
+impl TrajectorySolver {
+    /// Finds the launch angle at which the trajectory passes a height relative to the line of
+    /// sight at a distance, refined like `zero_angle`.
+    pub(crate) fn launch_angle_through(
+        &self,
+        zero_range: Distance,
+        height: BulletDrop,
+    ) -> Result<Angle, BallisticsError> {
+        if zero_range.0 <= 0.0 || !zero_range.0.is_finite() {
+            return Err(BallisticsError::UnreachableZero(zero_range));
+        }
+
+        let solver = TrajectorySolver {
+            max_range: zero_range,
+            stop_condition: StopCondition::MaxRange,
+            ..*self
+        };
+        let mut launch_angle = Angle(0.0);
+
+        for _ in 0..MAX_ZERO_ITERATIONS {
+            let point = solver
+                .with_launch_angle(launch_angle)
+                .point_at(zero_range)
+                .ok_or(BallisticsError::UnreachableZero(zero_range))?;
+            let correction =
+                Angle::from_radians(((height.0 - point.drop.0) / 12.0 / zero_range.0).atan());
+
+            launch_angle = Angle(launch_angle.0 + correction.0);
+
+            if launch_angle.radians() >= core::f64::consts::FRAC_PI_4 {
+                break;
+            }
+
+            if correction.0.abs() < ZERO_TOLERANCE {
+                return Ok(launch_angle);
+            }
+        }
+
+        Err(BallisticsError::UnreachableZero(zero_range))
+    }
+}
+
+/// A single point along a trajectory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrajectoryPoint {
+    /// The distance downrange in feet.
+    pub distance: Distance,
+    /// The height of the bullet relative to the line of sight in inches.
+    pub drop: BulletDrop,
+    /// The lateral deflection of the bullet in inches, positive to the right.
+    pub windage: WindDeflection,
+    /// The remaining velocity of the bullet in feet per second (ft/s).
+    pub velocity: Velocity,
+    /// The remaining velocity of the bullet as a Mach number.
+    pub mach: MachNumber,
+    /// The remaining kinetic energy of the bullet, if its weight is known.
+    pub energy: Option<KineticEnergy>,
+    /// The time of flight of the bullet in seconds.
+    pub time_of_flight: TimeOfFlight,
+}
+
+/// A trajectory sampled at regular range increments.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trajectory {
+    points: Vec<TrajectoryPoint>,
+}
+
+#[cfg(feature = "std")]
+impl Trajectory {
+    /// Returns the sampled points in order of increasing distance.
+    pub fn points(&self) -> &[TrajectoryPoint] {
+        &self.points
+    }
+}
+
+#[cfg(feature = "std")]
+#[bon]
+impl Trajectory {
+    /// Writes the trajectory as CSV, one row per point after a header row naming each column
+    /// with its unit, `range,drop_in,drop_moa,windage_in,velocity_fps,energy_ftlb,tof_s` by
+    /// default.
+    ///
+    /// Metric units report drops and windage in centimeters (`drop_cm`, `windage_cm`),
+    /// velocities in m/s (`velocity_mps`) and energies in joules (`energy_j`), with ranges in
+    /// meters unless a range unit is given. The angular unit of the drop is chosen separately,
+    /// so a card can mix yards with mils. The energy column is left empty when the bullet
+    /// weight is unknown.
+    ///
+    /// # Parameters
+    /// - `writer`: The destination of the CSV data.
+    /// - `units`: The units of the linear columns (imperial by default).
+    /// - `angular_unit`: The unit of the drop angle column (MOA by default).
+    /// - `range_unit`: The unit of the range column (yards or meters to match `units` by default).
+    /// - `precision`: The number of decimal places of every value (2 by default).
+    /// - `formatter`: The formatter of every value, overriding `precision`, e.g. for
+    ///   significant figures.
+    ///
+    /// # Returns
+    /// An `io::Result` reporting any error raised by the writer.
+    #[builder(finish_fn = write)]
+    pub fn to_csv<W: io::Write>(
+        &self,
+        mut writer: W,
+        #[builder(default)] units: Units,
+        #[builder(default)] angular_unit: AngularUnit,
+        range_unit: Option<RangeUnit>,
+        #[builder(default = 2)] precision: usize,
+        formatter: Option<QuantityFormatter>,
+    ) -> io::Result<()> {
+        let range_unit = range_unit.unwrap_or(units.range_unit());
+        let formatter = formatter.unwrap_or(QuantityFormatter::decimals(precision));
+        let (length, velocity, energy) = match units {
+            Units::Imperial => ("in", "fps", "ftlb"),
+            Units::Metric => ("cm", "mps", "j"),
+        };
+        let angle = match angular_unit {
+            AngularUnit::Moa => "moa",
+            AngularUnit::Mil => "mil",
+        };
+
+        writeln!(
+            writer,
+            "range,drop_{length},drop_{angle},windage_{length},velocity_{velocity},energy_{energy},tof_s"
+        )?;
+
+        for point in &self.points {
+            let drop_angle = if point.distance.0 > 0.0 {
+                angular_unit.convert(Angle::from_subtension(point.drop.0, point.distance))
+            } else {
+                0.0
+            };
+            let energy = point
+                .energy
+                .map(|energy| {
+                    formatter
+                        .format_value(units.convert_energy(energy))
+                        .to_string()
+                })
+                .unwrap_or_default();
+
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{}",
+                formatter.format_value(range_unit.convert(point.distance)),
+                formatter.format_value(units.convert_length(point.drop.0)),
+                formatter.format_value(drop_angle),
+                formatter.format_value(units.convert_length(point.windage.0)),
+                formatter.format_value(units.convert_velocity(point.velocity)),
+                energy,
+                formatter.format_value(point.time_of_flight.0),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromIterator<TrajectoryPoint> for Trajectory {
+    fn from_iter<I: IntoIterator<Item = TrajectoryPoint>>(iter: I) -> Self {
+        Trajectory {
+            points: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a> IntoIterator for &'a Trajectory {
+    type Item = &'a TrajectoryPoint;
+    type IntoIter = core::slice::Iter<'a, TrajectoryPoint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.points.iter()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    time: f64,
+    position: [f64; 3],
+    velocity: [f64; 3],
+}
+
+/// Iterator over a trajectory sampled at regular range increments
+///
+/// The iterator integrates only as far as the next requested point, so it is cheap to
+/// create and can be resumed at any time. It is returned by [`TrajectorySolver::iter_points`],
+/// or by [`TrajectorySolver::iter_points_with`] for a drag curve other than the solver's.
+#[derive(Debug, Clone)]
+pub struct TrajectoryIter<D = InterpolatedDrag> {
+    solver: TrajectorySolver,
+    drag: D,
+    step: f64,
+    index: u64,
+    time_step: f64,
+    steps: u64,
+    speed_of_sound: f64,
+    density_ratio: f64,
+    wind: [f64; 3],
+    previous: State,
+    current: State,
+    finished: bool,
+}
+
+impl<D: DragCurve> TrajectoryIter<D> {
+    fn new(solver: TrajectorySolver, step: Distance, drag: D) -> Self {
+        let launch_angle = solver.launch_angle.radians();
+        let muzzle = State {
+            time: 0.0,
+            position: [0.0; 3],
+            velocity: [
+                solver.muzzle_velocity.0 * launch_angle.cos(),
+                solver.muzzle_velocity.0 * launch_angle.sin(),
+                0.0,
+            ],
+        };
+
+        TrajectoryIter {
+            solver,
+            drag,
+            step: step.0,
+            index: 0,
+            time_step: solver.solver_options.map_or(TIME_STEP, |options| {
+                options.min_step.0.max(TIME_STEP.min(options.max_step.0))
+            }),
+            steps: 0,
+            speed_of_sound: solver.atmosphere().speed_of_sound().0,
+            density_ratio: solver.density_ratio(),
+            wind: [
+                -solver.headwind_speed.0 * MPH_TO_FPS,
+                0.0,
+                solver.crosswind_speed.0 * MPH_TO_FPS,
+            ],
+            previous: muzzle,
+            current: muzzle,
+            finished: false,
+        }
+    }
+
+    /// Advances the iterator to a single distance, which does not need to be a multiple of the step.
+    ///
+    /// Subsequent calls to `next` resume with the first multiple of the step beyond `distance`.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    ///
+    /// # Returns
+    /// The `TrajectoryPoint` at `distance`, or `None` if the trajectory ends before it or
+    /// the iterator has already advanced past it.
+    pub fn point_at(&mut self, distance: Distance) -> Option<TrajectoryPoint> {
+        let point = self.advance_to(distance.0)?;
+
+        if self.step > 0.0 {
+            let next_index = (distance.0 / self.step).floor() as u64 + 1;
+            self.index = self.index.max(next_index);
+        }
+
+        Some(point)
+    }
+
+    fn advance_to(&mut self, range: f64) -> Option<TrajectoryPoint> {
+        if range > self.solver.max_range.0 || range < self.previous.position[0] {
+            return None;
+        }
+
+        while self.current.position[0] < range {
+            if self.finished {
+                return None;
+            }
+
+            self.previous = self.current;
+            self.current = self.advance(range);
+            self.finished = self.is_stopped(&self.current);
+
+            if self.finished && self.current.position[0] < range {
+                return None;
+            }
+        }
+
+        Some(self.interpolate(range))
+    }
+
+    /// Changes the wind blowing from the next integration step on, replacing the solver's
+    /// crosswind and headwind for the rest of the trajectory.
+    pub fn set_wind(&mut self, wind: WindVector) {
+        self.wind = [
+            -wind.headwind().0 * MPH_TO_FPS,
+            wind.vertical().0 * MPH_TO_FPS,
+            wind.crosswind().0 * MPH_TO_FPS,
+        ];
+    }
+
+    /// Returns the number of integration steps taken so far.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    fn advance(&mut self, range: f64) -> State {
+        let state = self.current;
+        self.steps += 1;
+
+        let Some(options) = self.solver.solver_options else {
+            return self.integrate(state, TIME_STEP);
+        };
+        let (min_step, max_step) = (options.min_step.0, options.max_step.0);
+        let to_range = (range - state.position[0]) / state.velocity[0] * RANGE_OVERSHOOT;
+
+        loop {
+            let time_step = self.time_step.min(to_range).max(min_step);
+            let full = self.integrate(state, time_step);
+            let half = self.integrate(state, time_step / 2.0);
+            let double = self.integrate(half, time_step / 2.0);
+            let error = error_ratio(&full, &double, &options);
+            let factor = (0.9 * error.powf(-0.2)).clamp(0.2, 4.0);
+
+            if error <= 1.0 || time_step <= min_step {
+                if time_step >= self.time_step {
+                    self.time_step = (time_step * factor).clamp(min_step, max_step);
+                }
+
+                return double;
+            }
+
+            self.time_step = (time_step * factor).clamp(min_step, max_step);
+        }
+    }
+
+    fn is_stopped(&self, state: &State) -> bool {
+        let past_stop_condition = match self.solver.stop_condition {
+            StopCondition::MaxRange => false,
+            StopCondition::Subsonic => speed(state.velocity) < self.speed_of_sound,
+            StopCondition::Ground(height) => state.position[1] < -height.0,
+        };
+
+        past_stop_condition || state.time > MAX_TIME_OF_FLIGHT || state.velocity[0] <= 0.0
+    }
+
+    fn acceleration(&self, velocity: [f64; 3]) -> [f64; 3] {
+        let relative = [
+            velocity[0] - self.wind[0],
+            velocity[1] - self.wind[1],
+            velocity[2] - self.wind[2],
+        ];
+        let airspeed = speed(relative);
+        let drag_coefficient = self
+            .drag
+            .drag_coefficient(MachNumber(airspeed / self.speed_of_sound));
+        let retardation = self.density_ratio * drag_coefficient.0 * DRAG_CONSTANT * airspeed
+            / self.solver.ballistic_coefficient.0;
+
+        [
+            -retardation * relative[0],
+            -retardation * relative[1] - STANDARD_GRAVITY.0,
+            -retardation * relative[2],
+        ]
+    }
+
+    fn integrate(&self, state: State, time_step: f64) -> State {
+        let offset = |v: [f64; 3], a: [f64; 3], dt: f64| {
+            [v[0] + a[0] * dt, v[1] + a[1] * dt, v[2] + a[2] * dt]
+        };
+
+        let v1 = state.velocity;
+        let a1 = self.acceleration(v1);
+        let v2 = offset(v1, a1, time_step / 2.0);
+        let a2 = self.acceleration(v2);
+        let v3 = offset(v1, a2, time_step / 2.0);
+        let a3 = self.acceleration(v3);
+        let v4 = offset(v1, a3, time_step);
+        let a4 = self.acceleration(v4);
+
+        let weighted = |k1: [f64; 3], k2: [f64; 3], k3: [f64; 3], k4: [f64; 3]| {
+            [0, 1, 2].map(|i| (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]) / 6.0)
+        };
+
+        State {
+            time: state.time + time_step,
+            position: offset(state.position, weighted(v1, v2, v3, v4), time_step),
+            velocity: offset(state.velocity, weighted(a1, a2, a3, a4), time_step),
+        }
+    }
+
+    fn interpolate(&self, range: f64) -> TrajectoryPoint {
+        let (previous, current) = (self.previous, self.current);
+        let span = current.position[0] - previous.position[0];
+        let fraction = if span > 0.0 {
+            (range - previous.position[0]) / span
+        } else {
+            1.0
+        };
+        let lerp = |a: f64, b: f64| a + (b - a) * fraction;
+
+        let velocity = Velocity(speed(
+            [0, 1, 2].map(|i| lerp(previous.velocity[i], current.velocity[i])),
+        ));
+
+        TrajectoryPoint {
+            distance: Distance(range),
+            drop: BulletDrop(
+                12.0 * lerp(previous.position[1], current.position[1]) - self.solver.sight_height.0,
+            ),
+            windage: WindDeflection(12.0 * lerp(previous.position[2], current.position[2])),
+            velocity,
+            mach: MachNumber(velocity.0 / self.speed_of_sound),
+            energy: self.solver.bullet_weight.map(|bullet_weight| {
+                KineticEnergy::calculate()
+                    .bullet_weight(bullet_weight)
+                    .velocity(velocity)
+                    .solve()
+            }),
+            time_of_flight: TimeOfFlight(lerp(previous.time, current.time)),
+        }
+    }
+}
+
+impl<D: DragCurve> Iterator for TrajectoryIter<D> {
+    type Item = TrajectoryPoint;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index > 0 && self.step <= 0.0 {
+            return None;
+        }
+
+        let point = self.advance_to(self.index as f64 * self.step)?;
+        self.index += 1;
+
+        Some(point)
+    }
+}
+
+/// Returns the largest error of a step relative to the tolerance, estimated from the difference
+/// between one full step and two half steps, which is 15 times the error of the half steps for
+/// a fourth order method.
+fn error_ratio(full: &State, double: &State, options: &SolverOptions) -> f64 {
+    let pairs = full
+        .position
+        .iter()
+        .zip(&double.position)
+        .chain(full.velocity.iter().zip(&double.velocity));
+
+    pairs.fold(0.0, |ratio: f64, (a, b)| {
+        let tolerance = options.absolute_tolerance + options.relative_tolerance * b.abs();
+
+        ratio.max((a - b).abs() / 15.0 / tolerance)
+    })
+}
+
+fn speed(velocity: [f64; 3]) -> f64 {
+    (velocity[0].powi(2) + velocity[1].powi(2) + velocity[2].powi(2)).sqrt()
+}
@@ -0,0 +1,299 @@
+use bon::bon;
+
+use crate::{
+    validation::positive, Angle, BallisticCoefficient, BallisticsError, BulletDrop, Distance,
+    StopCondition, TimeOfFlight, TrajectorySolver, Velocity,
+};
+
+/// Maximum number of bisections applied to a truing search.
+const MAX_BISECTIONS: usize = 60;
+
+/// Width of the muzzle velocity bracket at which a bisection stops (ft/s).
+const VELOCITY_TOLERANCE: f64 = 0.01;
+
+/// Smallest ballistic coefficient a truing search considers.
+const MIN_BALLISTIC_COEFFICIENT: f64 = 0.05;
+
+/// Largest ballistic coefficient a truing search considers.
+const MAX_BALLISTIC_COEFFICIENT: f64 = 2.0;
+
+/// Observed drop at a distance
+///
+/// This enum represents how the drop of a load was observed when shooting at a known distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObservedDrop {
+    /// The measured height of the group relative to the line of sight in inches, negative
+    /// values being below it, with no elevation dialed.
+    Inches(BulletDrop),
+    /// The elevation dialed in MOA that centered the group on the point of aim, available from
+    /// mils through `Angle::from_mils`.
+    Dialed(Angle),
+}
+
+impl ObservedDrop {
+    /// Returns the observed drop relative to the line of sight in inches at a distance.
+    pub fn drop_at(&self, distance: Distance) -> BulletDrop {
+        match self {
+            ObservedDrop::Inches(drop) => *drop,
+            ObservedDrop::Dialed(angle) => BulletDrop(-angle.subtension_at(distance)),
+        }
+    }
+}
+
+/// Trued muzzle velocity
+///
+/// This struct represents the muzzle velocity at which a load's predicted drop matches the drop
+/// observed at a distance, along with the drop left unmatched once the search converged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MuzzleVelocityTruing {
+    /// The trued muzzle velocity in feet per second (ft/s).
+    pub muzzle_velocity: Velocity,
+    /// The predicted drop at the trued muzzle velocity minus the observed drop in inches.
+    pub residual: BulletDrop,
+}
+
+#[bon]
+impl Velocity {
+    /// Trues the muzzle velocity of a load to a drop observed at a distance.
+    ///
+    /// Each candidate muzzle velocity re-zeroes the rifle at the zero range before predicting
+    /// the drop, as the rifle was zeroed with the true muzzle velocity. The candidates are
+    /// bisected within the bracket around the nominal muzzle velocity until they agree within
+    /// 0.01 ft/s.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load with its nominal muzzle velocity,
+    ///   sight height and atmosphere.
+    /// - `zero_range`: The distance the rifle is zeroed at in feet.
+    /// - `distance`: The distance the drop was observed at in feet, greater than 0.
+    /// - `observed`: The observed drop.
+    /// - `bracket`: The largest difference from the nominal muzzle velocity searched in ft/s
+    ///   (300 ft/s by default), greater than 0.
+    ///
+    /// # Returns
+    /// A `MuzzleVelocityTruing` instance, `BallisticsError::NoTruingSolution` if no muzzle
+    /// velocity within the bracket reproduces the observed drop, or an error if an input is
+    /// invalid or a trajectory falls short.
+    #[builder(finish_fn = solve)]
+    pub fn true_from_drop(
+        solver: TrajectorySolver,
+        zero_range: Distance,
+        distance: Distance,
+        observed: ObservedDrop,
+        #[builder(default = Velocity(300.0), into)] bracket: Velocity,
+    ) -> Result<MuzzleVelocityTruing, BallisticsError> {
+        positive("distance", distance.0)?;
+        positive("bracket", bracket.0)?;
+
+        let observed = observed.drop_at(distance);
+        let residual = |muzzle_velocity: f64| -> Result<f64, BallisticsError> {
+            let solver = TrajectorySolver {
+                muzzle_velocity: Velocity(muzzle_velocity),
+                ..solver
+            };
+            let launch_angle = solver.zero_angle().zero_range(zero_range).solve()?;
+
+            drop_at(&solver.with_launch_angle(launch_angle), distance)
+                .map(|drop| drop.0 - observed.0)
+        };
+        let (muzzle_velocity, remaining) = bisect(
+            (solver.muzzle_velocity.0 - bracket.0).max(VELOCITY_TOLERANCE),
+            solver.muzzle_velocity.0 + bracket.0,
+            VELOCITY_TOLERANCE,
+            residual,
+        )?
+        .ok_or(BallisticsError::NoTruingSolution {
+            param: "muzzle_velocity",
+            observed: observed.0,
+        })?;
+
+        Ok(MuzzleVelocityTruing {
+            muzzle_velocity: Velocity(muzzle_velocity),
+            residual: BulletDrop(remaining),
+        })
+    }
+}
+
+/// Long-range observation
+///
+/// This enum represents what was observed of a load at a long distance to true its ballistic
+/// coefficient with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Observation {
+    /// The observed drop.
+    Drop(ObservedDrop),
+    /// The observed time of flight in seconds, e.g. from the delay until an impact is heard.
+    TimeOfFlight(TimeOfFlight),
+}
+
+/// Trued ballistic coefficient
+///
+/// This struct represents the ballistic coefficient at which a load's predicted drop or time of
+/// flight matches the one observed at a long distance, holding the muzzle velocity fixed.
+///
+/// Truing follows the order in which the inputs dominate the trajectory. The muzzle velocity
+/// governs it at mid-range, while the bullet is still well supersonic, so true it first with
+/// `Velocity::true_from_drop` at a few hundred yards. The ballistic coefficient dominates
+/// further out, towards and past the end of the supersonic range, so true it second, at long
+/// range, with the trued muzzle velocity in the solver. Truing the ballistic coefficient first
+/// would absorb a muzzle velocity error into it and skew predictions at every other distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BallisticCoefficientTruing {
+    /// The trued ballistic coefficient relative to the solver's drag model.
+    pub ballistic_coefficient: BallisticCoefficient,
+    /// The observation the ballistic coefficient matches, with the drop in inches.
+    pub observation: Observation,
+    /// The prediction at the trued ballistic coefficient minus the observation, in inches for
+    /// a drop or seconds for a time of flight.
+    pub residual: f64,
+}
+
+#[bon]
+impl BallisticCoefficient {
+    /// Trues the ballistic coefficient of a load to a drop or time of flight observed at a long
+    /// distance, holding the muzzle velocity fixed.
+    ///
+    /// Each candidate ballistic coefficient re-zeroes the rifle at the zero range before
+    /// predicting the observation. The candidates are bisected between 0.05 and 2.0 until they
+    /// agree within the tolerance.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load with its trued muzzle velocity,
+    ///   sight height and atmosphere.
+    /// - `zero_range`: The distance the rifle is zeroed at in feet.
+    /// - `distance`: The distance the observation was made at in feet, greater than 0.
+    /// - `observation`: The observed drop or time of flight.
+    /// - `tolerance`: The width of the bracket at which the search stops (0.0001 by default),
+    ///   greater than 0.
+    ///
+    /// # Returns
+    /// A `BallisticCoefficientTruing` instance, `BallisticsError::NoTruingSolution` if no
+    /// ballistic coefficient between 0.05 and 2.0 reproduces the observation, or an error if an
+    /// input is invalid or the zero range cannot be reached.
+    #[builder(finish_fn = solve)]
+    pub fn true_from_observation(
+        solver: TrajectorySolver,
+        zero_range: Distance,
+        distance: Distance,
+        observation: Observation,
+        #[builder(default = 1e-4)] tolerance: f64,
+    ) -> Result<BallisticCoefficientTruing, BallisticsError> {
+        positive("distance", distance.0)?;
+        positive("tolerance", tolerance)?;
+
+        let observation = match observation {
+            Observation::Drop(drop) => {
+                Observation::Drop(ObservedDrop::Inches(drop.drop_at(distance)))
+            }
+            Observation::TimeOfFlight(time_of_flight) => Observation::TimeOfFlight(time_of_flight),
+        };
+        let residual = |ballistic_coefficient: f64| -> Result<f64, BallisticsError> {
+            let solver = TrajectorySolver {
+                ballistic_coefficient: BallisticCoefficient(ballistic_coefficient),
+                max_range: distance,
+                stop_condition: StopCondition::MaxRange,
+                ..solver
+            };
+            let launch_angle = solver.zero_angle().zero_range(zero_range).solve()?;
+            let Some(point) = solver.with_launch_angle(launch_angle).point_at(distance) else {
+                // A bullet that falls short drops and takes too long without bound.
+                return Ok(match observation {
+                    Observation::Drop(_) => f64::NEG_INFINITY,
+                    Observation::TimeOfFlight(_) => f64::INFINITY,
+                });
+            };
+
+            Ok(match observation {
+                Observation::Drop(drop) => point.drop.0 - drop.drop_at(distance).0,
+                Observation::TimeOfFlight(time_of_flight) => {
+                    point.time_of_flight.0 - time_of_flight.0
+                }
+            })
+        };
+        let (ballistic_coefficient, remaining) = bisect(
+            MIN_BALLISTIC_COEFFICIENT,
+            MAX_BALLISTIC_COEFFICIENT,
+            tolerance,
+            residual,
+        )?
+        .ok_or(BallisticsError::NoTruingSolution {
+            param: "ballistic_coefficient",
+            observed: match observation {
+                Observation::Drop(drop) => drop.drop_at(distance).0,
+                Observation::TimeOfFlight(time_of_flight) => time_of_flight.0,
+            },
+        })?;
+
+        Ok(BallisticCoefficientTruing {
+            ballistic_coefficient: BallisticCoefficient(ballistic_coefficient),
+            observation,
+            residual: remaining,
+        })
+    }
+}
+
+/// Integrates a trajectory to a distance and returns its drop (in).
+pub(crate) fn drop_at(
+    solver: &TrajectorySolver,
+    distance: Distance,
+) -> Result<BulletDrop, BallisticsError> {
+    TrajectorySolver {
+        max_range: distance,
+        stop_condition: StopCondition::MaxRange,
+        ..*solver
+    }
+    .point_at(distance)
+    .map(|point| point.drop)
+    .ok_or(BallisticsError::BeyondMaxRange(distance))
+}
+
+/// Bisects `residual` for a root between `low` and `high`, stopping once the bracket is
+/// narrower than `tolerance`.
+///
+/// Returns the root with its residual, or `None` if the residual has the same sign at both ends.
+pub(crate) fn bisect(
+    mut low: f64,
+    mut high: f64,
+    tolerance: f64,
+    mut residual: impl FnMut(f64) -> Result<f64, BallisticsError>,
+) -> Result<Option<(f64, f64)>, BallisticsError> {
+    let low_residual = residual(low)?;
+    let high_residual = residual(high)?;
+
+    if low_residual == 0.0 {
+        return Ok(Some((low, low_residual)));
+    }
+
+    if high_residual == 0.0 {
+        return Ok(Some((high, high_residual)));
+    }
+
+    if low_residual.signum() == high_residual.signum() {
+        return Ok(None);
+    }
+
+    let rising = high_residual > 0.0;
+    let mut middle = (low + high) / 2.0;
+    let mut middle_residual = residual(middle)?;
+
+    for _ in 0..MAX_BISECTIONS {
+        if high - low < tolerance || middle_residual == 0.0 {
+            break;
+        }
+
+        if (middle_residual > 0.0) == rising {
+            high = middle;
+        } else {
+            low = middle;
+        }
+
+        middle = (low + high) / 2.0;
+        middle_residual = residual(middle)?;
+    }
+
+    Ok(Some((middle, middle_residual)))
+}
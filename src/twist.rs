@@ -0,0 +1,28 @@
+/// Rifling twist direction
+///
+/// This enum represents the direction in which the rifling spins the bullet, as seen
+/// from behind the gun. It sets the sign of spin drift, aerodynamic jump and yaw of repose,
+/// which mirror when the twist is reversed.
+///
+/// **The default is `Right`**, the twist of most factory rifle barrels. Calculations taking a
+/// twist direction assume right-hand twist when none is given, so pass `Left` explicitly for
+/// left-hand barrels, as on some CZ and many European rifles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TwistDirection {
+    /// Clockwise rifling (the default).
+    #[default]
+    Right,
+    /// Counter-clockwise rifling.
+    Left,
+}
+
+impl TwistDirection {
+    /// Returns 1 for right-hand twist and -1 for left-hand twist.
+    pub(crate) fn sign(&self) -> f64 {
+        match self {
+            TwistDirection::Right => 1.0,
+            TwistDirection::Left => -1.0,
+        }
+    }
+}
@@ -0,0 +1,333 @@
+use bon::bon;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    AerodynamicJump, AirDensity, Altitude, Angle, ApertureSightCalibration, BallisticCoefficient,
+    BallisticsError, BarrelLength, BarrelVelocityEstimate, BulletDiameter, BulletDrop,
+    BulletLength, BulletWeight, Deflection, DensityRatio, Distance, DragCoefficient, FormFactor,
+    Gravity, GyroscopicStability, HitProbability, InclineCorrection, KineticEnergy, LagTime,
+    MachNumber, Pressure, ProjectileLength, RelativeHumidity, RetardationCoefficient, RiflingTwist,
+    SectionalDensity, SightCalibration, SightHeight, SightRadius, SpeedOfSound, SpinDrift,
+    SpinRate, TargetSize, Temperature, ThresholdDistance, TimeOfFlight, TrajectorySolver,
+    TwistRate, Velocity, VelocityProjection, VerticalWindDeflection, WindDeflection, WindSpeed,
+    YawOfRepose, STANDARD_GRAVITY,
+};
+
+/// Quantity backed by a single `f64`
+///
+/// This trait converts a quantity to and from its plain value, letting `Uncertain` work with
+/// any of them.
+pub trait Scalar: Copy {
+    /// Creates the quantity from its value.
+    fn from_f64(value: f64) -> Self;
+
+    /// Returns the value of the quantity.
+    fn to_f64(self) -> f64;
+}
+
+impl Scalar for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+/// Implements `Scalar` for newtypes through their inner `f64`.
+macro_rules! impl_scalar {
+    ($($newtype:ident),* $(,)?) => {
+        $(
+            impl Scalar for $newtype {
+                fn from_f64(value: f64) -> Self {
+                    $newtype(value)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self.0
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(
+    Gravity,
+    SpeedOfSound,
+    TimeOfFlight,
+    Distance,
+    WindSpeed,
+    SpinDrift,
+    DragCoefficient,
+    RiflingTwist,
+    BulletLength,
+    BulletDiameter,
+    SightCalibration,
+    SightRadius,
+    AirDensity,
+    LagTime,
+    WindDeflection,
+    VerticalWindDeflection,
+    VelocityProjection,
+    ApertureSightCalibration,
+    FormFactor,
+    AerodynamicJump,
+    BulletWeight,
+    Temperature,
+    Pressure,
+    Velocity,
+    GyroscopicStability,
+    KineticEnergy,
+    BallisticCoefficient,
+    MachNumber,
+    BulletDrop,
+    Angle,
+    SightHeight,
+    TargetSize,
+    Deflection,
+    InclineCorrection,
+    ThresholdDistance,
+    BarrelLength,
+    BarrelVelocityEstimate,
+    RelativeHumidity,
+    Altitude,
+    ProjectileLength,
+    SectionalDensity,
+    SpinRate,
+    YawOfRepose,
+    TwistRate,
+    HitProbability,
+    DensityRatio,
+    RetardationCoefficient,
+);
+
+/// Uncertain quantity
+///
+/// This struct represents a quantity known up to a standard deviation, such as a muzzle
+/// velocity of 2800 ± 12 ft/s. The `calculate_uncertain` equations propagate it to first order:
+/// each input's standard deviation is scaled by the partial derivative of the result with
+/// respect to that input, estimated by central differences, and the contributions of
+/// independent inputs are added in quadrature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Uncertain<T> {
+    value: T,
+    sd: T,
+}
+
+impl<T: Scalar> Uncertain<T> {
+    /// Creates an uncertain quantity.
+    ///
+    /// # Parameters
+    /// - `value`: The best estimate of the quantity.
+    /// - `sd`: The standard deviation of the quantity, in the same unit.
+    pub fn new(value: T, sd: T) -> Self {
+        Uncertain { value, sd }
+    }
+
+    /// Creates a quantity known exactly, with a standard deviation of zero.
+    pub fn exact(value: T) -> Self {
+        Uncertain::new(value, T::from_f64(0.0))
+    }
+
+    /// Returns the best estimate of the quantity.
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the standard deviation of the quantity.
+    pub fn sd(&self) -> T {
+        self.sd
+    }
+
+    /// Returns the interval of `k_sigma` standard deviations either side of the value, e.g.
+    /// 2 for about 95% of the outcomes of a normally distributed quantity.
+    ///
+    /// # Returns
+    /// The lower and upper bounds, in this order.
+    pub fn bounds(&self, k_sigma: f64) -> (T, T) {
+        let value = self.value.to_f64();
+        let spread = k_sigma * self.sd.to_f64();
+
+        (T::from_f64(value - spread), T::from_f64(value + spread))
+    }
+}
+
+impl<T: Scalar> From<T> for Uncertain<T> {
+    fn from(value: T) -> Self {
+        Uncertain::exact(value)
+    }
+}
+
+/// Propagates the uncertainty of independent inputs through a function to first order.
+///
+/// The partial derivative for each uncertain input is a central difference over a step of a
+/// thousandth of its standard deviation, but no finer than a millionth of its value.
+fn propagate<T: Scalar, const N: usize>(
+    inputs: [(f64, f64); N],
+    f: impl Fn([f64; N]) -> f64,
+) -> Uncertain<T> {
+    let values = inputs.map(|input| input.0);
+    let variance = (0..N)
+        .filter(|&i| inputs[i].1 != 0.0)
+        .map(|i| {
+            let (value, sd) = inputs[i];
+            let step = (sd.abs() * 1e-3).max(value.abs() * 1e-6);
+            let (mut below, mut above) = (values, values);
+            below[i] -= step;
+            above[i] += step;
+
+            ((f(above) - f(below)) / (2.0 * step) * sd).powi(2)
+        })
+        .fold(0.0, |variance, contribution| variance + contribution);
+
+    Uncertain::new(T::from_f64(f(values)), T::from_f64(variance.sqrt()))
+}
+
+/// Returns the value and standard deviation of an uncertain quantity.
+fn input<T: Scalar>(quantity: Uncertain<T>) -> (f64, f64) {
+    (quantity.value.to_f64(), quantity.sd.to_f64())
+}
+
+#[bon]
+impl KineticEnergy {
+    /// Calculates the kinetic energy of a bullet with uncertain weight and velocity.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains.
+    /// - `velocity`: The velocity of the bullet in feet per second (ft/s).
+    /// - `gravity`: The gravitational acceleration in ft/s² (`STANDARD_GRAVITY` by default).
+    ///
+    /// # Returns
+    /// An `Uncertain<KineticEnergy>` in foot-pounds; the relative standard deviation is about
+    /// twice that of the velocity, plus that of the weight in quadrature.
+    #[builder(finish_fn = solve)]
+    pub fn calculate_uncertain(
+        #[builder(into)] bullet_weight: Uncertain<BulletWeight>,
+        #[builder(into)] velocity: Uncertain<Velocity>,
+        #[builder(default = STANDARD_GRAVITY, into)] gravity: Gravity,
+    ) -> Uncertain<KineticEnergy> {
+        propagate(
+            [input(bullet_weight), input(velocity)],
+            |[weight, velocity]| {
+                KineticEnergy::calculate()
+                    .bullet_weight(BulletWeight(weight))
+                    .velocity(velocity)
+                    .gravity(gravity)
+                    .solve()
+                    .0
+            },
+        )
+    }
+}
+
+#[bon]
+impl GyroscopicStability {
+    /// Calculates the gyroscopic stability factor of a bullet with uncertain dimensions using
+    /// Miller's stability formula.
+    ///
+    /// # Parameters
+    /// - `bullet_weight`: The weight of the bullet in grains.
+    /// - `rifling_twist`: The rifling twist rate of the barrel in calibers per turn.
+    /// - `bullet_diameter`: The diameter (caliber) of the bullet in inches.
+    /// - `bullet_length`: The length of the bullet in calibers.
+    ///
+    /// # Returns
+    /// An `Uncertain<GyroscopicStability>` at 2800 ft/s.
+    #[builder(finish_fn = solve)]
+    pub fn calculate_uncertain(
+        #[builder(into)] bullet_weight: Uncertain<BulletWeight>,
+        #[builder(into)] rifling_twist: Uncertain<RiflingTwist>,
+        #[builder(into)] bullet_diameter: Uncertain<BulletDiameter>,
+        #[builder(into)] bullet_length: Uncertain<BulletLength>,
+    ) -> Uncertain<GyroscopicStability> {
+        propagate(
+            [
+                input(bullet_weight),
+                input(rifling_twist),
+                input(bullet_diameter),
+                input(bullet_length),
+            ],
+            |[weight, twist, diameter, length]| {
+                GyroscopicStability::calculate_const(
+                    BulletWeight(weight),
+                    RiflingTwist(twist),
+                    BulletDiameter(diameter),
+                    BulletLength(length),
+                )
+                .0
+            },
+        )
+    }
+}
+
+#[bon]
+impl WindDeflection {
+    /// Calculates the wind deflection of a bullet with an uncertain lag time and crosswind.
+    ///
+    /// # Parameters
+    /// - `lag_time`: The lag time of the bullet in seconds.
+    /// - `crosswind_speed`: The speed of the crosswind in miles per hour (mph).
+    ///
+    /// # Returns
+    /// An `Uncertain<WindDeflection>` in inches.
+    #[builder(finish_fn = solve)]
+    pub fn calculate_uncertain(
+        #[builder(into)] lag_time: Uncertain<LagTime>,
+        #[builder(into)] crosswind_speed: Uncertain<WindSpeed>,
+    ) -> Uncertain<WindDeflection> {
+        propagate(
+            [input(lag_time), input(crosswind_speed)],
+            |[lag_time, crosswind_speed]| {
+                WindDeflection::calculate_const(LagTime(lag_time), WindSpeed(crosswind_speed)).0
+            },
+        )
+    }
+}
+
+#[bon]
+impl BulletDrop {
+    /// Calculates the drop at a distance of a bullet with an uncertain muzzle velocity and
+    /// ballistic coefficient.
+    ///
+    /// The trajectory is integrated with the solver's launch angle, sight height, wind and
+    /// atmosphere, replacing its muzzle velocity and ballistic coefficient.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the zero and atmosphere.
+    /// - `distance`: The distance downrange in feet.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet.
+    ///
+    /// # Returns
+    /// An `Uncertain<BulletDrop>` in inches relative to the line of sight, or
+    /// `BallisticsError::BeyondMaxRange` if a trajectory does not reach the distance.
+    #[builder(finish_fn = solve)]
+    pub fn calculate_uncertain(
+        solver: TrajectorySolver,
+        distance: Distance,
+        #[builder(into)] muzzle_velocity: Uncertain<Velocity>,
+        #[builder(into)] ballistic_coefficient: Uncertain<BallisticCoefficient>,
+    ) -> Result<Uncertain<BulletDrop>, BallisticsError> {
+        let drop: Uncertain<BulletDrop> = propagate(
+            [input(muzzle_velocity), input(ballistic_coefficient)],
+            |[muzzle_velocity, ballistic_coefficient]| {
+                TrajectorySolver {
+                    muzzle_velocity: Velocity(muzzle_velocity),
+                    ballistic_coefficient: BallisticCoefficient(ballistic_coefficient),
+                    ..solver
+                }
+                .point_at(distance)
+                .map_or(f64::NAN, |point| point.drop.0)
+            },
+        );
+
+        if drop.value.0.is_nan() || drop.sd.0.is_nan() {
+            Err(BallisticsError::BeyondMaxRange(solver.max_range))
+        } else {
+            Ok(drop)
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{Distance, RelativeHumidity, SightHeight};
+
+/// Number of centimeters in one inch.
+pub(crate) const CENTIMETERS_PER_INCH: f64 = 2.54;
+
+/// Number of joules in one foot-pound.
+pub(crate) const JOULES_PER_FOOT_POUND: f64 = 1.3558179;
+
+/// Number of grains in one pound.
+pub(crate) const GRAINS_PER_POUND: f64 = 7000.0;
+
+/// Number of feet in one yard.
+const FEET_PER_YARD: f64 = 3.0;
+
+/// Number of meters in one foot.
+pub(crate) const METERS_PER_FOOT: f64 = 0.3048;
+
+impl SightHeight {
+    /// Creates a sight height from centimeters.
+    pub fn from_cm(centimeters: f64) -> Self {
+        SightHeight(centimeters / CENTIMETERS_PER_INCH)
+    }
+}
+
+impl Distance {
+    /// Creates a distance from yards.
+    pub fn from_yards(yards: f64) -> Self {
+        Distance(yards * FEET_PER_YARD)
+    }
+
+    /// Creates a distance from meters.
+    pub fn from_meters(meters: f64) -> Self {
+        Distance(meters / METERS_PER_FOOT)
+    }
+
+    /// Returns the distance in yards.
+    pub fn yards(&self) -> f64 {
+        self.0 / FEET_PER_YARD
+    }
+
+    /// Returns the distance in meters.
+    pub fn meters(&self) -> f64 {
+        self.0 * METERS_PER_FOOT
+    }
+}
+
+impl RelativeHumidity {
+    /// Creates a relative humidity from a percentage, from 0 to 100.
+    pub fn from_percent(percent: f64) -> Self {
+        RelativeHumidity(percent)
+    }
+
+    /// Creates a relative humidity from a fraction, from 0 to 1.
+    pub fn from_fraction(fraction: f64) -> Self {
+        RelativeHumidity(fraction * 100.0)
+    }
+
+    /// Returns the relative humidity as a percentage, from 0 to 100.
+    pub fn percent(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the relative humidity as a fraction, from 0 to 1.
+    pub fn fraction(&self) -> f64 {
+        self.0 / 100.0
+    }
+}
@@ -0,0 +1,78 @@
+use bon::Builder;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{Angle, Distance, Gravity, TimeOfFlight, Velocity, STANDARD_GRAVITY};
+
+/// Vacuum trajectory
+///
+/// This struct represents the closed-form trajectory of a bullet without air resistance, a
+/// parabola set by the muzzle velocity, the launch angle and the gravitational acceleration.
+/// Heights and times are measured from the muzzle, and the trajectory ends where the bullet
+/// returns to the height of the muzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Builder)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VacuumTrajectory {
+    /// The muzzle velocity of the bullet in feet per second (ft/s).
+    #[builder(into)]
+    pub velocity: Velocity,
+    /// The angle of the bore above the horizontal in MOA.
+    #[builder(into)]
+    pub launch_angle: Angle,
+    /// The gravitational acceleration in ft/s² (`STANDARD_GRAVITY` by default).
+    #[builder(default = STANDARD_GRAVITY, into)]
+    pub gravity: Gravity,
+}
+
+impl VacuumTrajectory {
+    /// Returns the distance at which the bullet returns to the height of the muzzle in feet,
+    /// `v² × sin(2θ) / g`, which is greatest at a launch angle of 45 degrees.
+    pub fn max_range(&self) -> Distance {
+        let launch_angle = self.launch_angle.radians();
+
+        Distance(self.velocity.0.powi(2) * (2.0 * launch_angle).sin() / self.gravity.0)
+    }
+
+    /// Returns the height of the highest point above the muzzle in feet, `(v × sin θ)² / 2g`.
+    pub fn apex_height(&self) -> Distance {
+        Distance(self.vertical_velocity().powi(2) / (2.0 * self.gravity.0))
+    }
+
+    /// Returns the time the bullet takes to reach the highest point in seconds, `v × sin θ / g`,
+    /// half of the time of flight.
+    pub fn apex_time(&self) -> TimeOfFlight {
+        TimeOfFlight(self.vertical_velocity() / self.gravity.0)
+    }
+
+    /// Returns the time the bullet takes to return to the height of the muzzle in seconds,
+    /// `2 × v × sin θ / g`.
+    pub fn time_of_flight(&self) -> TimeOfFlight {
+        TimeOfFlight(2.0 * self.apex_time().0)
+    }
+
+    /// Returns the angle of the bullet's path below the horizontal when it returns to the
+    /// height of the muzzle in MOA, equal to the launch angle.
+    pub fn impact_angle(&self) -> Angle {
+        self.launch_angle
+    }
+
+    /// Returns the position of the bullet at a time after it leaves the muzzle.
+    ///
+    /// # Parameters
+    /// - `time`: The time since the bullet left the muzzle in seconds.
+    ///
+    /// # Returns
+    /// The distance downrange and the height above the muzzle, both in feet.
+    pub fn position_at(&self, time: TimeOfFlight) -> (Distance, Distance) {
+        let horizontal_velocity = self.velocity.0 * self.launch_angle.radians().cos();
+
+        (
+            Distance(horizontal_velocity * time.0),
+            Distance(self.vertical_velocity() * time.0 - self.gravity.0 * time.0.powi(2) / 2.0),
+        )
+    }
+
+    fn vertical_velocity(&self) -> f64 {
+        self.velocity.0 * self.launch_angle.radians().sin()
+    }
+}
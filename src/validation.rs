@@ -0,0 +1,109 @@
+use crate::{
+    BallisticsError, BulletDiameter, BulletWeight, Distance, DragCoefficient, Float, Pressure,
+    RelativeHumidity, Temperature,
+};
+
+/// Absolute zero in degrees Fahrenheit.
+const ABSOLUTE_ZERO: f64 = -459.67;
+
+/// Implements `TryFrom<f64>` for newtypes, accepting the values a check allows.
+macro_rules! impl_try_from {
+    ($($newtype:ident => $check:ident($param:literal)),* $(,)?) => {
+        $(
+            impl TryFrom<f64> for $newtype {
+                type Error = BallisticsError;
+
+                fn try_from(value: f64) -> Result<Self, Self::Error> {
+                    $check($param, value).map($newtype)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from!(
+    BulletWeight => positive("bullet_weight"),
+    BulletDiameter => positive("bullet_diameter"),
+    Distance => positive("distance"),
+    Pressure => positive("pressure"),
+    Temperature => above_absolute_zero("temperature"),
+    DragCoefficient => non_negative("drag_coefficient"),
+    RelativeHumidity => percentage("relative_humidity"),
+);
+
+/// Checks that an input is neither NaN nor infinite.
+pub(crate) fn finite<T: Float>(param: &'static str, value: T) -> Result<T, BallisticsError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(BallisticsError::NonFinite {
+            param,
+            value: value.to_f64(),
+        })
+    }
+}
+
+/// Checks that an input is finite and greater than zero.
+pub(crate) fn positive<T: Float>(param: &'static str, value: T) -> Result<T, BallisticsError> {
+    if finite(param, value)?.to_f64() > 0.0 {
+        Ok(value)
+    } else {
+        Err(BallisticsError::OutOfRange {
+            param,
+            value: value.to_f64(),
+            allowed: "greater than 0",
+        })
+    }
+}
+
+/// Checks that an input is finite and not negative.
+pub(crate) fn non_negative<T: Float>(param: &'static str, value: T) -> Result<T, BallisticsError> {
+    if finite(param, value)?.to_f64() >= 0.0 {
+        Ok(value)
+    } else {
+        Err(BallisticsError::OutOfRange {
+            param,
+            value: value.to_f64(),
+            allowed: "at least 0",
+        })
+    }
+}
+
+/// Checks that an input divided by is finite and greater than zero, reporting zero as a
+/// division by zero.
+pub(crate) fn divisor<T: Float>(param: &'static str, value: T) -> Result<T, BallisticsError> {
+    if finite(param, value)?.to_f64() == 0.0 {
+        Err(BallisticsError::DivisionByZero { param })
+    } else {
+        positive(param, value)
+    }
+}
+
+/// Checks that a temperature in degrees Fahrenheit is finite and above absolute zero.
+pub(crate) fn above_absolute_zero<T: Float>(
+    param: &'static str,
+    value: T,
+) -> Result<T, BallisticsError> {
+    if finite(param, value)?.to_f64() > ABSOLUTE_ZERO {
+        Ok(value)
+    } else {
+        Err(BallisticsError::OutOfRange {
+            param,
+            value: value.to_f64(),
+            allowed: "above absolute zero (-459.67 °F)",
+        })
+    }
+}
+
+/// Checks that a percentage is finite and from 0 to 100.
+pub(crate) fn percentage(param: &'static str, value: f64) -> Result<f64, BallisticsError> {
+    if (0.0..=100.0).contains(&finite(param, value)?) {
+        Ok(value)
+    } else {
+        Err(BallisticsError::OutOfRange {
+            param,
+            value,
+            allowed: "from 0 to 100",
+        })
+    }
+}
@@ -0,0 +1,200 @@
+//! JavaScript bindings
+//!
+//! Plain-number entry points for the main calculations, exported through `wasm-bindgen` for use
+//! from JavaScript. Inputs and outputs are imperial units: weights in grains, lengths in
+//! inches, velocities in ft/s, energies in ft-lbs, wind speeds in mph, ranges in yards and
+//! angles in MOA unless a name says otherwise. Invalid inputs throw an `Error` whose message
+//! describes the first invalid input.
+//!
+//! The exports are ordinary Rust functions too, so `cargo test --features wasm` exercises them
+//! natively; `cargo build --target wasm32-unknown-unknown --features wasm` checks the bindings
+//! themselves once the target is installed with `rustup target add wasm32-unknown-unknown`.
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    Angle, Atmosphere, BallisticCoefficient, BallisticsError, BulletDiameter, BulletLength,
+    BulletWeight, ClickValue, Distance, DopeCard, DragModel, GyroscopicStability, KineticEnergy,
+    Load, Pressure, Projectile, ProjectileLength, RiflingTwist, SightHeight, Temperature, Velocity,
+    WindDeflection, WindSpeed,
+};
+
+/// Converts a calculation error into a JavaScript `Error`.
+fn js_error(error: BallisticsError) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+/// Parses the name of a standard drag model, `"G1"` or `"G7"` in either case.
+fn parse_drag_model(name: &str) -> Result<DragModel, JsError> {
+    match name {
+        "G1" | "g1" => Ok(DragModel::G1),
+        "G7" | "g7" => Ok(DragModel::G7),
+        _ => Err(JsError::new(&format!(
+            "unknown drag model `{name}`, expected `G1` or `G7`"
+        ))),
+    }
+}
+
+/// Returns the kinetic energy in ft-lbs of a bullet weighing `bullet_weight` grains at
+/// `velocity` ft/s.
+#[wasm_bindgen(js_name = kineticEnergy)]
+pub fn kinetic_energy(bullet_weight: f64, velocity: f64) -> f64 {
+    KineticEnergy::calculate()
+        .bullet_weight(BulletWeight(bullet_weight))
+        .velocity(Velocity(velocity))
+        .solve()
+        .0
+}
+
+/// Returns the gyroscopic stability factor corrected for the muzzle velocity and atmosphere.
+///
+/// The bullet weighs `bullet_weight` grains and measures `bullet_diameter` by `bullet_length`
+/// inches, fired at `muzzle_velocity` ft/s from a barrel twisting one turn in `twist_rate`
+/// inches, through air at `temperature` °F and `pressure` inHg.
+#[wasm_bindgen(js_name = correctedStability)]
+pub fn corrected_stability(
+    bullet_weight: f64,
+    bullet_diameter: f64,
+    bullet_length: f64,
+    twist_rate: f64,
+    muzzle_velocity: f64,
+    temperature: f64,
+    pressure: f64,
+) -> f64 {
+    GyroscopicStability::calculate_corrected()
+        .bullet_weight(BulletWeight(bullet_weight))
+        .rifling_twist(RiflingTwist(twist_rate / bullet_diameter))
+        .bullet_diameter(BulletDiameter(bullet_diameter))
+        .bullet_length(BulletLength(bullet_length / bullet_diameter))
+        .muzzle_velocity(Velocity(muzzle_velocity))
+        .atmosphere(
+            Atmosphere::builder()
+                .temperature(Temperature(temperature))
+                .pressure(Pressure(pressure))
+                .build(),
+        )
+        .solve()
+        .gyro_stability
+        .0
+}
+
+/// Returns the wind deflection in inches at `distance` yards for a full-value crosswind of
+/// `crosswind_speed` mph, from a muzzle velocity in ft/s and a ballistic coefficient relative
+/// to `drag_model` (`"G1"` or `"G7"`) in the ICAO standard atmosphere.
+///
+/// Throws if the drag model is unknown or the bullet does not reach the distance.
+#[wasm_bindgen(js_name = windDeflection)]
+pub fn wind_deflection(
+    crosswind_speed: f64,
+    muzzle_velocity: f64,
+    ballistic_coefficient: f64,
+    drag_model: &str,
+    distance: f64,
+) -> Result<f64, JsError> {
+    WindDeflection::from_ballistics()
+        .crosswind_speed(WindSpeed(crosswind_speed))
+        .muzzle_velocity(Velocity(muzzle_velocity))
+        .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+        .drag_model(parse_drag_model(drag_model)?)
+        .distance(Distance::from_yards(distance))
+        .solve()
+        .map(|deflection| deflection.0)
+        .map_err(js_error)
+}
+
+/// One row of a DOPE card, in yards, MOA, mils, ft/s, ft-lbs and seconds.
+#[wasm_bindgen(js_name = DopeRow)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JsDopeRow {
+    /// The distance to the target in yards.
+    pub range: f64,
+    /// The elevation to dial over the zero in MOA, positive values being up.
+    pub elevation_moa: f64,
+    /// The elevation to dial over the zero in mils.
+    pub elevation_mil: f64,
+    /// The elevation in whole turret clicks.
+    pub elevation_clicks: f64,
+    /// The windage to dial into a 10 mph full-value crosswind in MOA.
+    pub windage_moa: f64,
+    /// The windage to dial into a 10 mph full-value crosswind in mils.
+    pub windage_mil: f64,
+    /// The windage in whole turret clicks.
+    pub windage_clicks: f64,
+    /// The remaining velocity in ft/s.
+    pub velocity: f64,
+    /// The remaining kinetic energy in ft-lbs.
+    pub energy: f64,
+    /// The time of flight in seconds.
+    pub time_of_flight: f64,
+}
+
+/// Returns the rows of a DOPE card from `start` to `end` yards every `step` yards, in the ICAO
+/// standard atmosphere.
+///
+/// The bullet weighs `bullet_weight` grains, measures `bullet_diameter` by `bullet_length`
+/// inches and has a ballistic coefficient relative to `drag_model` (`"G1"` or `"G7"`). It is
+/// fired at `muzzle_velocity` ft/s from a barrel twisting one turn in `twist_rate` inches, with
+/// the sight `sight_height` inches above the bore, zeroed at `zero_range` yards, on a turret
+/// of `click_moa` MOA per click.
+///
+/// Throws if an input is invalid, the zero range cannot be reached or the bullet does not reach
+/// a distance.
+#[allow(clippy::too_many_arguments)]
+#[wasm_bindgen(js_name = dopeCard)]
+pub fn dope_card(
+    bullet_weight: f64,
+    bullet_diameter: f64,
+    bullet_length: f64,
+    ballistic_coefficient: f64,
+    drag_model: &str,
+    muzzle_velocity: f64,
+    twist_rate: f64,
+    sight_height: f64,
+    zero_range: f64,
+    start: f64,
+    end: f64,
+    step: f64,
+    click_moa: f64,
+) -> Result<Vec<JsDopeRow>, JsError> {
+    let projectile = Projectile::builder()
+        .bullet_weight(BulletWeight(bullet_weight))
+        .bullet_diameter(BulletDiameter(bullet_diameter))
+        .length(ProjectileLength(bullet_length))
+        .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+        .drag_model(parse_drag_model(drag_model)?)
+        .build()
+        .map_err(js_error)?;
+    let load = Load::builder()
+        .projectile(projectile)
+        .muzzle_velocity(Velocity(muzzle_velocity))
+        .rifling_twist(RiflingTwist(twist_rate / bullet_diameter))
+        .sight_height(SightHeight(sight_height))
+        .zero_range(Distance::from_yards(zero_range))
+        .build()
+        .map_err(js_error)?;
+    let card = DopeCard::over_range()
+        .load(&load)
+        .start(Distance::from_yards(start))
+        .end(Distance::from_yards(end))
+        .step(Distance::from_yards(step))
+        .click(ClickValue::Custom(Angle(click_moa)))
+        .solve()
+        .map_err(js_error)?;
+
+    Ok(card
+        .rows()
+        .iter()
+        .map(|row| JsDopeRow {
+            range: row.distance.yards(),
+            elevation_moa: row.elevation.0,
+            elevation_mil: row.elevation.mils(),
+            elevation_clicks: row.elevation_clicks as f64,
+            windage_moa: row.windage.0,
+            windage_mil: row.windage.mils(),
+            windage_clicks: row.windage_clicks as f64,
+            velocity: row.velocity.0,
+            energy: row.energy.0,
+            time_of_flight: row.time_of_flight.0,
+        })
+        .collect())
+}
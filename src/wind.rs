@@ -0,0 +1,693 @@
+use core::ops::Add;
+
+use bon::bon;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    validation::{non_negative, positive},
+    Angle, Atmosphere, BallisticCoefficient, BallisticsError, BulletDrop, Distance, DragModel,
+    GunNumber, LagTime, StopCondition, TimeOfFlight, TrajectorySolver, Velocity,
+    VerticalWindDeflection, WindDeflection, WindSpeed,
+};
+
+/// Clock position of the wind
+///
+/// This enum represents the direction the wind blows from as a clock position, with
+/// 12 o'clock pointing downrange along the line of fire and 3 o'clock to the shooter's right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClockPosition {
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Eleven,
+    Twelve,
+}
+
+impl ClockPosition {
+    /// Returns the bearing of the clock position clockwise from the line of fire in MOA.
+    pub fn bearing(&self) -> Angle {
+        let hour = match self {
+            ClockPosition::One => 1.0,
+            ClockPosition::Two => 2.0,
+            ClockPosition::Three => 3.0,
+            ClockPosition::Four => 4.0,
+            ClockPosition::Five => 5.0,
+            ClockPosition::Six => 6.0,
+            ClockPosition::Seven => 7.0,
+            ClockPosition::Eight => 8.0,
+            ClockPosition::Nine => 9.0,
+            ClockPosition::Ten => 10.0,
+            ClockPosition::Eleven => 11.0,
+            ClockPosition::Twelve => 0.0,
+        };
+
+        Angle::from_degrees(hour * 30.0)
+    }
+}
+
+/// Direction the wind blows from
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindDirection {
+    /// A clock position relative to the line of fire.
+    Clock(ClockPosition),
+    /// A bearing clockwise from the line of fire in MOA.
+    Bearing(Angle),
+}
+
+impl From<ClockPosition> for WindDirection {
+    fn from(clock: ClockPosition) -> Self {
+        WindDirection::Clock(clock)
+    }
+}
+
+impl From<Angle> for WindDirection {
+    fn from(bearing: Angle) -> Self {
+        WindDirection::Bearing(bearing)
+    }
+}
+
+impl WindDirection {
+    /// Returns the bearing of the wind clockwise from the line of fire in MOA.
+    pub fn bearing(&self) -> Angle {
+        match self {
+            WindDirection::Clock(clock) => clock.bearing(),
+            WindDirection::Bearing(bearing) => *bearing,
+        }
+    }
+}
+
+/// Wind components
+///
+/// This struct represents a wind split into its components across and along the line of fire.
+/// A positive crosswind blows from the left, pushing the bullet right, and a positive headwind
+/// blows into the shooter's face; negative values are the opposite directions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindComponents {
+    /// The crosswind component in miles per hour (mph).
+    pub crosswind: WindSpeed,
+    /// The headwind component in miles per hour (mph).
+    pub headwind: WindSpeed,
+}
+
+#[bon]
+impl WindComponents {
+    /// Splits a wind into its crosswind and headwind components.
+    ///
+    /// # Parameters
+    /// - `wind_speed`: The speed of the wind in miles per hour (mph).
+    /// - `direction`: The direction the wind blows from, as a clock position or a bearing.
+    ///
+    /// # Returns
+    /// A `WindComponents` instance representing the signed crosswind and headwind.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] wind_speed: WindSpeed,
+        #[builder(into)] direction: WindDirection,
+    ) -> Self {
+        let bearing = direction.bearing().radians();
+
+        WindComponents {
+            crosswind: WindSpeed(-wind_speed.0 * bearing.sin()),
+            headwind: WindSpeed(wind_speed.0 * bearing.cos()),
+        }
+    }
+}
+
+/// Wind vector
+///
+/// This struct represents a wind by its crosswind, headwind and vertical components, which
+/// add like vectors. The crosswind and headwind follow the sign convention of `WindComponents`,
+/// and a positive vertical component is an updraft.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindVector {
+    crosswind: WindSpeed,
+    headwind: WindSpeed,
+    vertical: WindSpeed,
+}
+
+impl WindVector {
+    /// Creates a wind vector from its components in miles per hour (mph).
+    pub fn new(crosswind: WindSpeed, headwind: WindSpeed, vertical: WindSpeed) -> Self {
+        WindVector {
+            crosswind,
+            headwind,
+            vertical,
+        }
+    }
+
+    /// Creates a horizontal wind vector from a wind speed and the direction it blows from.
+    pub fn from_direction(wind_speed: WindSpeed, direction: impl Into<WindDirection>) -> Self {
+        let components = WindComponents::calculate()
+            .wind_speed(wind_speed)
+            .direction(direction.into())
+            .solve();
+
+        WindVector::new(components.crosswind, components.headwind, WindSpeed(0.0))
+    }
+
+    /// Returns the crosswind component in miles per hour (mph).
+    pub fn crosswind(&self) -> WindSpeed {
+        self.crosswind
+    }
+
+    /// Returns the headwind component in miles per hour (mph).
+    pub fn headwind(&self) -> WindSpeed {
+        self.headwind
+    }
+
+    /// Returns the vertical component in miles per hour (mph).
+    pub fn vertical(&self) -> WindSpeed {
+        self.vertical
+    }
+
+    /// Returns the total wind speed in miles per hour (mph).
+    pub fn magnitude(&self) -> WindSpeed {
+        WindSpeed(
+            (self.crosswind.0.powi(2) + self.headwind.0.powi(2) + self.vertical.0.powi(2)).sqrt(),
+        )
+    }
+
+    /// Returns the horizontal and vertical deflections of a bullet with the given lag time
+    /// in inches.
+    pub fn deflection(&self, lag_time: LagTime) -> (WindDeflection, VerticalWindDeflection) {
+        (
+            WindDeflection::from_wind_vector()
+                .lag_time(lag_time)
+                .wind(*self)
+                .solve(),
+            VerticalWindDeflection::from_wind_vector()
+                .lag_time(lag_time)
+                .wind(*self)
+                .solve(),
+        )
+    }
+}
+
+impl Add for WindVector {
+    type Output = WindVector;
+
+    fn add(self, other: WindVector) -> WindVector {
+        WindVector::new(
+            WindSpeed(self.crosswind.0 + other.crosswind.0),
+            WindSpeed(self.headwind.0 + other.headwind.0),
+            WindSpeed(self.vertical.0 + other.vertical.0),
+        )
+    }
+}
+
+/// Wind zones
+///
+/// This struct represents a wind that changes along the range, such as 5 mph at the muzzle and
+/// 10 mph past a ridge at 600 yards. Each zone holds the distance it starts at and the wind
+/// blowing from there to the start of the next zone, or beyond for the last one; the air is
+/// calm before the first zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindZones<'a> {
+    zones: &'a [(Distance, WindVector)],
+}
+
+impl<'a> WindZones<'a> {
+    /// Creates wind zones from their start distances in feet and winds.
+    ///
+    /// # Returns
+    /// A `WindZones` instance, `BallisticsError::OutOfRange` if a zone starts before the
+    /// muzzle, or `BallisticsError::UnsortedWindZones` if a zone does not start after the one
+    /// before it.
+    pub fn new(zones: &'a [(Distance, WindVector)]) -> Result<Self, BallisticsError> {
+        let mut previous: Option<Distance> = None;
+
+        for &(start, _) in zones {
+            non_negative("start", start.0)?;
+
+            if previous.is_some_and(|previous| start.0 <= previous.0) {
+                return Err(BallisticsError::UnsortedWindZones(start));
+            }
+
+            previous = Some(start);
+        }
+
+        Ok(WindZones { zones })
+    }
+
+    /// Returns the zones in order of increasing start distance.
+    pub fn zones(&self) -> &'a [(Distance, WindVector)] {
+        self.zones
+    }
+
+    /// Returns the wind blowing at a distance in feet.
+    pub fn wind_at(&self, distance: Distance) -> WindVector {
+        self.zones
+            .iter()
+            .take_while(|zone| zone.0 .0 <= distance.0)
+            .last()
+            .map_or(
+                WindVector::new(WindSpeed(0.0), WindSpeed(0.0), WindSpeed(0.0)),
+                |zone| zone.1,
+            )
+    }
+
+    /// Returns the start, end and wind of each zone up to a distance in feet, with the last
+    /// zone ending at the distance.
+    pub(crate) fn segments(
+        &self,
+        distance: Distance,
+    ) -> impl Iterator<Item = (Distance, Distance, WindVector)> + 'a {
+        let zones = self.zones;
+
+        zones
+            .iter()
+            .enumerate()
+            .take_while(move |(_, zone)| zone.0 .0 < distance.0)
+            .map(move |(i, &(start, wind))| {
+                let end = zones
+                    .get(i + 1)
+                    .map_or(distance.0, |next| next.0 .0.min(distance.0));
+
+                (start, Distance(end), wind)
+            })
+    }
+}
+
+#[bon]
+impl WindDeflection {
+    /// Calculates the wind deflection of a bullet from a wind vector.
+    ///
+    /// Only the crosswind component of the wind deflects the bullet sideways.
+    ///
+    /// # Parameters
+    /// - `lag_time`: The lag time of the bullet in seconds.
+    /// - `wind`: The wind vector.
+    ///
+    /// # Returns
+    /// A `WindDeflection` instance representing the wind deflection in inches, positive to the
+    /// right.
+    #[builder(finish_fn = solve)]
+    pub fn from_wind_vector(#[builder(into)] lag_time: LagTime, wind: WindVector) -> Self {
+        WindDeflection::calculate()
+            .lag_time(lag_time)
+            .crosswind_speed(wind.crosswind())
+            .solve()
+    }
+
+    /// Calculates the wind deflection of a bullet from a wind speed and the direction it blows
+    /// from, so that a wind from 9 o'clock pushes the bullet right and one from 3 o'clock
+    /// pushes it left.
+    ///
+    /// # Parameters
+    /// - `lag_time`: The lag time of the bullet in seconds.
+    /// - `wind_speed`: The speed of the wind in miles per hour (mph).
+    /// - `direction`: The direction the wind blows from, as a clock position or a bearing.
+    ///
+    /// # Returns
+    /// A `WindDeflection` instance representing the wind deflection in inches, positive to the
+    /// right.
+    #[builder(finish_fn = solve)]
+    pub fn from_direction(
+        #[builder(into)] lag_time: LagTime,
+        #[builder(into)] wind_speed: WindSpeed,
+        #[builder(into)] direction: WindDirection,
+    ) -> Self {
+        WindDeflection::from_wind_vector()
+            .lag_time(lag_time)
+            .wind(WindVector::from_direction(wind_speed, direction))
+            .solve()
+    }
+}
+
+impl WindDeflection {
+    /// Returns the deflection as an angle in true minutes of angle (1.047" at 100 yards) at a
+    /// distance in feet.
+    pub fn as_moa(&self, distance: Distance) -> Angle {
+        Angle::from_subtension(self.0, distance)
+    }
+
+    /// Returns the deflection as an angle in milliradians at a distance in feet.
+    pub fn as_mil(&self, distance: Distance) -> f64 {
+        self.as_moa(distance).mils()
+    }
+}
+
+/// Wind bracket
+///
+/// This struct represents the spread of wind deflections at a distance for a wind gusting
+/// between two speeds, such as "8 to 14 mph": the deflection to hold for the mean wind and those
+/// at the lull and the gust either side of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindBracket {
+    /// The deflection at the minimum wind speed in inches.
+    pub low: WindDeflection,
+    /// The deflection at the mean of the minimum and maximum wind speeds in inches.
+    pub mean: WindDeflection,
+    /// The deflection at the maximum wind speed in inches.
+    pub high: WindDeflection,
+    /// The distance to the target in feet.
+    pub distance: Distance,
+}
+
+#[bon]
+impl WindBracket {
+    /// Calculates the wind deflections of a bullet for a wind gusting between two speeds.
+    ///
+    /// # Parameters
+    /// - `min_wind_speed`: The speed of the wind in its lulls in miles per hour (mph).
+    /// - `max_wind_speed`: The speed of the wind in its gusts in miles per hour (mph).
+    /// - `direction`: The direction the wind blows from.
+    /// - `lag_time`: The lag time of the bullet at the target in seconds.
+    /// - `distance`: The distance to the target in feet.
+    ///
+    /// # Returns
+    /// A `WindBracket` instance, or `BallisticsError::OutOfRange` if the minimum wind speed
+    /// exceeds the maximum.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] min_wind_speed: WindSpeed,
+        #[builder(into)] max_wind_speed: WindSpeed,
+        #[builder(into)] direction: WindDirection,
+        #[builder(into)] lag_time: LagTime,
+        distance: Distance,
+    ) -> Result<Self, BallisticsError> {
+        if min_wind_speed.0.is_nan() || min_wind_speed.0 > max_wind_speed.0 {
+            return Err(BallisticsError::OutOfRange {
+                param: "min_wind_speed",
+                value: min_wind_speed.0,
+                allowed: "at most max_wind_speed",
+            });
+        }
+
+        let deflection = |wind_speed: f64| {
+            WindDeflection::from_wind_vector()
+                .lag_time(lag_time)
+                .wind(WindVector::from_direction(WindSpeed(wind_speed), direction))
+                .solve()
+        };
+
+        Ok(WindBracket {
+            low: deflection(min_wind_speed.0),
+            mean: deflection((min_wind_speed.0 + max_wind_speed.0) / 2.0),
+            high: deflection(max_wind_speed.0),
+            distance,
+        })
+    }
+}
+
+impl WindBracket {
+    /// Returns the width of the bracket in inches, from the low to the high deflection.
+    pub fn width(&self) -> WindDeflection {
+        WindDeflection((self.high.0 - self.low.0).abs())
+    }
+
+    /// Returns the width of the bracket as an angle in milliradians at the target distance.
+    pub fn width_mil(&self) -> f64 {
+        self.width().as_mil(self.distance)
+    }
+}
+
+/// Wind value
+///
+/// This struct represents the full-value crosswind speeds that deflect a bullet by one MOA and
+/// by 0.1 mil at a distance, the constants wind readers memorize as "1 MOA per 4 mph at 600".
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindValue {
+    /// The crosswind speed deflecting the bullet by one MOA in miles per hour (mph).
+    pub per_moa: WindSpeed,
+    /// The crosswind speed deflecting the bullet by 0.1 mil in miles per hour (mph).
+    pub per_tenth_mil: WindSpeed,
+    /// The distance to the target in feet.
+    pub distance: Distance,
+}
+
+#[bon]
+impl WindValue {
+    /// Calculates the crosswind speeds that deflect a bullet by one MOA and by 0.1 mil, by
+    /// inverting `WindDeflection::calculate`.
+    ///
+    /// # Parameters
+    /// - `lag_time`: The lag time of the bullet at the target in seconds, greater than 0 for a
+    ///   finite result.
+    /// - `distance`: The distance to the target in feet.
+    ///
+    /// # Returns
+    /// A `WindValue` instance representing the crosswind speeds in mph.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(#[builder(into)] lag_time: LagTime, distance: Distance) -> Self {
+        let deflection_per_mph = WindDeflection::calculate()
+            .lag_time(lag_time)
+            .crosswind_speed(WindSpeed(1.0))
+            .solve();
+        let wind_speed =
+            |angle: Angle| WindSpeed(angle.subtension_at(distance) / deflection_per_mph.0);
+
+        WindValue {
+            per_moa: wind_speed(Angle(1.0)),
+            per_tenth_mil: wind_speed(Angle::from_mils(0.1)),
+            distance,
+        }
+    }
+
+    /// Calculates the crosswind speeds that deflect a bullet by one MOA and by 0.1 mil at a
+    /// distance from its ballistic coefficient.
+    ///
+    /// # Parameters
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `distance`: The distance to the target in feet.
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `WindValue` instance, or `BallisticsError::BeyondMaxRange` if the bullet does not reach
+    /// the distance.
+    #[builder(finish_fn = solve)]
+    pub fn from_ballistics(
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        distance: Distance,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        let lag_time = LagTime::from_ballistics()
+            .muzzle_velocity(muzzle_velocity)
+            .ballistic_coefficient(ballistic_coefficient)
+            .drag_model(drag_model)
+            .distance(distance)
+            .atmosphere(atmosphere)
+            .solve()?;
+
+        Ok(WindValue::calculate()
+            .lag_time(lag_time)
+            .distance(distance)
+            .solve())
+    }
+}
+
+/// Number of intervals the range bracket of a gun number is sampled over.
+const GUN_NUMBER_INTERVALS: u32 = 8;
+
+#[bon]
+impl GunNumber {
+    /// Calculates the gun number of a load at a single distance, where the quick wind formula
+    /// matches the exact wind deflection.
+    ///
+    /// # Parameters
+    /// - `lag_time`: The lag time of the bullet at the target in seconds.
+    /// - `distance`: The distance to the target in feet.
+    ///
+    /// # Returns
+    /// A `GunNumber` instance, the range in hundreds of yards times the wind value per MOA.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(#[builder(into)] lag_time: LagTime, distance: Distance) -> Self {
+        let wind_value = WindValue::calculate()
+            .lag_time(lag_time)
+            .distance(distance)
+            .solve();
+
+        GunNumber(distance.yards() / 100.0 * wind_value.per_moa.0)
+    }
+
+    /// Calculates the gun number of a load over a range bracket from its ballistic coefficient.
+    ///
+    /// The exact gun number falls with distance as the bullet slows, so no single value fits
+    /// the whole bracket. The bracket is sampled at nine evenly spaced distances and the gun
+    /// number is the mean of the largest and smallest exact ones, which keeps the quick wind
+    /// formula within `(max - min) / (max + min)` of the exact deflection across the bracket.
+    /// For a 0.45 G1 bullet at 2800 ft/s over 200–800 yards the gun number is about 12.2 and
+    /// the formula stays within 13% of the exact deflection, reading high at the near end and
+    /// low at the far end; lower ballistic coefficients and wider brackets spread further.
+    ///
+    /// # Parameters
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    /// - `ballistic_coefficient`: The ballistic coefficient of the bullet relative to `drag_model`.
+    /// - `drag_model`: The standard drag model the ballistic coefficient refers to (G1 by default).
+    /// - `near_distance`: The near end of the range bracket in feet, greater than 0.
+    /// - `far_distance`: The far end of the range bracket in feet, beyond `near_distance`.
+    /// - `atmosphere`: The air the bullet flies through (ICAO standard by default).
+    ///
+    /// # Returns
+    /// A `GunNumber` instance, `BallisticsError::OutOfRange` if the bracket is empty or starts
+    /// at the muzzle, or `BallisticsError::BeyondMaxRange` if the bullet does not reach the far
+    /// end.
+    #[builder(finish_fn = solve)]
+    pub fn from_ballistics(
+        #[builder(into)] muzzle_velocity: Velocity,
+        #[builder(into)] ballistic_coefficient: BallisticCoefficient,
+        #[builder(default)] drag_model: DragModel,
+        near_distance: Distance,
+        far_distance: Distance,
+        #[builder(default)] atmosphere: Atmosphere,
+    ) -> Result<Self, BallisticsError> {
+        positive("near_distance", near_distance.0)?;
+
+        if far_distance.0.is_nan() || far_distance.0 <= near_distance.0 {
+            return Err(BallisticsError::OutOfRange {
+                param: "far_distance",
+                value: far_distance.0,
+                allowed: "greater than near_distance",
+            });
+        }
+
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+
+        for i in 0..=GUN_NUMBER_INTERVALS {
+            let distance = Distance(
+                near_distance.0
+                    + (far_distance.0 - near_distance.0) * f64::from(i)
+                        / f64::from(GUN_NUMBER_INTERVALS),
+            );
+            let lag_time = LagTime::from_ballistics()
+                .muzzle_velocity(muzzle_velocity)
+                .ballistic_coefficient(ballistic_coefficient)
+                .drag_model(drag_model)
+                .distance(distance)
+                .atmosphere(atmosphere)
+                .solve()?;
+            let gun_number = GunNumber::calculate()
+                .lag_time(lag_time)
+                .distance(distance)
+                .solve();
+
+            min = min.min(gun_number.0);
+            max = max.max(gun_number.0);
+        }
+
+        Ok(GunNumber((min + max) / 2.0))
+    }
+}
+
+impl GunNumber {
+    /// Returns the wind deflection in MOA by the quick wind formula, the range in hundreds of
+    /// yards times the crosswind speed divided by the gun number.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance to the target in feet.
+    /// - `crosswind_speed`: The speed of the crosswind in miles per hour (mph).
+    pub fn quick_wind_moa(&self, distance: Distance, crosswind_speed: WindSpeed) -> Angle {
+        Angle(distance.yards() / 100.0 * crosswind_speed.0 / self.0)
+    }
+}
+
+#[bon]
+impl VerticalWindDeflection {
+    /// Calculates the vertical deflection of a bullet by an updraft or downdraft.
+    ///
+    /// This function mirrors `WindDeflection::calculate` for the vertical axis:
+    /// an updraft raises the point of impact and a downdraft lowers it.
+    ///
+    /// # Parameters
+    /// - `lag_time`: The lag time of the bullet in seconds.
+    /// - `vertical_wind_speed`: The speed of the vertical wind in miles per hour (mph), positive for an updraft.
+    ///
+    /// # Returns
+    /// A `VerticalWindDeflection` instance representing the vertical deflection in inches.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        #[builder(into)] lag_time: LagTime,
+        #[builder(into)] vertical_wind_speed: WindSpeed,
+    ) -> Self {
+        VerticalWindDeflection(17.6 * vertical_wind_speed.0 * lag_time.0)
+    }
+
+    /// Calculates the vertical deflection of a bullet from a wind vector.
+    ///
+    /// Only the vertical component of the wind deflects the bullet up or down.
+    ///
+    /// # Parameters
+    /// - `lag_time`: The lag time of the bullet in seconds.
+    /// - `wind`: The wind vector.
+    ///
+    /// # Returns
+    /// A `VerticalWindDeflection` instance representing the vertical deflection in inches.
+    #[builder(finish_fn = solve)]
+    pub fn from_wind_vector(#[builder(into)] lag_time: LagTime, wind: WindVector) -> Self {
+        VerticalWindDeflection::calculate()
+            .lag_time(lag_time)
+            .vertical_wind_speed(wind.vertical())
+            .solve()
+    }
+}
+
+/// Headwind effect
+///
+/// This struct represents the change in drop and time of flight a headwind causes compared
+/// to still air. A headwind increases the drop and time of flight, a tailwind reduces them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeadwindEffect {
+    /// The change in height relative to the line of sight in inches, negative values being lower.
+    pub drop_change: BulletDrop,
+    /// The change in time of flight in seconds.
+    pub time_of_flight_change: TimeOfFlight,
+}
+
+#[bon]
+impl HeadwindEffect {
+    /// Calculates the effect of a headwind at a distance by differencing the trajectory
+    /// with and without it.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, zero and atmosphere.
+    /// - `distance`: The distance to the target in feet.
+    /// - `headwind_speed`: The speed of the headwind in miles per hour (mph), negative for a tailwind.
+    ///
+    /// # Returns
+    /// A `HeadwindEffect` instance, or `BallisticsError::BeyondMaxRange` if the trajectory does not
+    /// reach the distance within the solver's maximum range.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        solver: TrajectorySolver,
+        distance: Distance,
+        #[builder(into)] headwind_speed: WindSpeed,
+    ) -> Result<Self, BallisticsError> {
+        let point_at = |headwind_speed: WindSpeed| {
+            TrajectorySolver {
+                headwind_speed,
+                stop_condition: StopCondition::MaxRange,
+                ..solver
+            }
+            .point_at(distance)
+            .ok_or(BallisticsError::BeyondMaxRange(solver.max_range))
+        };
+
+        let still = point_at(WindSpeed(0.0))?;
+        let windy = point_at(headwind_speed)?;
+
+        Ok(HeadwindEffect {
+            drop_change: BulletDrop(windy.drop.0 - still.drop.0),
+            time_of_flight_change: TimeOfFlight(windy.time_of_flight.0 - still.time_of_flight.0),
+        })
+    }
+}
@@ -0,0 +1,290 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+};
+
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    units::{JOULES_PER_FOOT_POUND, METERS_PER_FOOT},
+    AerodynamicJump, AirDensity, Altitude, Angle, ApertureSightCalibration, BarrelLength,
+    BarrelVelocityEstimate, BulletDiameter, BulletDrop, BulletLength, BulletWeight, Deflection,
+    Distance, Gravity, InclineCorrection, KineticEnergy, LagTime, Pressure, ProjectileLength,
+    RelativeHumidity, RetardationCoefficient, RiflingTwist, SectionalDensity, SightCalibration,
+    SightHeight, SightRadius, SpeedOfSound, SpinDrift, SpinRate, TargetSize, Temperature,
+    ThresholdDistance, TimeOfFlight, TwistRate, Velocity, VelocityProjection,
+    VerticalWindDeflection, WindDeflection, WindSpeed, YawOfRepose,
+};
+
+/// Number of millimeters in one inch.
+const MILLIMETERS_PER_INCH: f64 = 25.4;
+
+/// Number of feet in one mile.
+const FEET_PER_MILE: f64 = 5280.0;
+
+/// Number of grains in one gram.
+const GRAINS_PER_GRAM: f64 = 15.432358;
+
+/// Number of inches of Mercury in one hectopascal.
+const INHG_PER_HPA: f64 = 0.029530;
+
+/// Number of kilograms per cubic meter in one pound per cubic foot.
+const KG_M3_PER_LB_FT3: f64 = 16.018463;
+
+/// Unit a quantity accepts when deserializing, with the function converting a value from it.
+pub type Conversion = (&'static str, fn(f64) -> f64);
+
+/// Quantity with a physical unit
+///
+/// This trait describes the unit a newtype stores its value in and the other units it can be
+/// read from, as used by `WithUnits`.
+pub trait Quantity: Sized {
+    /// The name of the quantity type, used in error messages.
+    const NAME: &'static str;
+    /// The unit the value is stored and serialized in.
+    const UNIT: &'static str;
+    /// The other units accepted when deserializing, each converting a value to `UNIT`.
+    const CONVERSIONS: &'static [Conversion];
+
+    /// Creates the quantity from a value in `UNIT`.
+    fn from_value(value: f64) -> Self;
+
+    /// Returns the value in `UNIT`.
+    fn value(&self) -> f64;
+}
+
+/// Quantity serialized with its unit
+///
+/// This struct wraps a quantity so that it serializes as an object naming its unit,
+/// `{"value": 2800.0, "unit": "ft/s"}`, instead of a bare number. Deserializing accepts any
+/// unit the quantity supports and converts the value, so `{"value": 853.44, "unit": "m/s"}`
+/// also produces a `Velocity` of 2800 ft/s.
+///
+/// An unknown unit is an error naming the type of the quantity and the units it accepts, but
+/// not the field holding it: a struct with several `WithUnits<Distance>` fields reports the
+/// same message for each. Deserialize through
+/// [`serde_path_to_error`](https://crates.io/crates/serde_path_to_error) to locate the field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WithUnits<T>(pub T);
+
+/// Object form of a `WithUnits` quantity.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "WithUnits", deny_unknown_fields)]
+struct Annotated<U> {
+    value: f64,
+    unit: U,
+}
+
+impl<T: Quantity> Serialize for WithUnits<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Annotated {
+            value: self.0.value(),
+            unit: T::UNIT,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Quantity> Deserialize<'de> for WithUnits<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let annotated = Annotated::<Unit<T>>::deserialize(deserializer)?;
+        let value = (annotated.unit.convert)(annotated.value);
+
+        if value.is_finite() {
+            Ok(WithUnits(T::from_value(value)))
+        } else {
+            Err(de::Error::custom(format_args!(
+                "invalid {}: expected a finite number, found {}",
+                T::NAME,
+                annotated.value
+            )))
+        }
+    }
+}
+
+/// Unit of a `WithUnits` quantity, resolved to its conversion while deserializing.
+struct Unit<T> {
+    convert: fn(f64) -> f64,
+    quantity: PhantomData<T>,
+}
+
+impl<'de, T: Quantity> Deserialize<'de> for Unit<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(UnitVisitor(PhantomData))
+    }
+}
+
+/// Visitor resolving the name of a unit to its conversion.
+struct UnitVisitor<T>(PhantomData<T>);
+
+impl<T: Quantity> Visitor<'_> for UnitVisitor<T> {
+    type Value = Unit<T>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "a unit of {}", T::NAME)
+    }
+
+    fn visit_str<E: de::Error>(self, unit: &str) -> Result<Self::Value, E> {
+        let convert = if unit == T::UNIT {
+            Some(identity as fn(f64) -> f64)
+        } else {
+            T::CONVERSIONS
+                .iter()
+                .find(|conversion| conversion.0 == unit)
+                .map(|conversion| conversion.1)
+        };
+
+        convert
+            .map(|convert| Unit {
+                convert,
+                quantity: PhantomData,
+            })
+            .ok_or_else(|| {
+                E::custom(format_args!(
+                    "unknown unit `{}` for a {} quantity, expected one of {}",
+                    unit,
+                    T::NAME,
+                    Units::<T>(PhantomData)
+                ))
+            })
+    }
+}
+
+/// Lists the units a quantity accepts, separated by commas.
+struct Units<T>(PhantomData<T>);
+
+impl<T: Quantity> Display for Units<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`", T::UNIT)?;
+
+        for conversion in T::CONVERSIONS {
+            write!(f, ", `{}`", conversion.0)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Conversion of a value already in the unit of its quantity.
+fn identity(value: f64) -> f64 {
+    value
+}
+
+/// Implements `Quantity` for newtypes, with the unit they store their value in followed by
+/// the other units they accept and the conversion from each.
+macro_rules! impl_quantity {
+    ($($newtype:ident => $unit:literal { $($other:literal => $convert:expr),* $(,)? }),* $(,)?) => {
+        $(
+            impl Quantity for $newtype {
+                const NAME: &'static str = stringify!($newtype);
+                const UNIT: &'static str = $unit;
+                const CONVERSIONS: &'static [Conversion] = &[$(($other, $convert)),*];
+
+                fn from_value(value: f64) -> Self {
+                    $newtype(value)
+                }
+
+                fn value(&self) -> f64 {
+                    self.0
+                }
+            }
+        )*
+    };
+}
+
+/// Implements `Quantity` for several newtypes sharing the same units.
+macro_rules! impl_quantities {
+    ([$($newtype:ident),* $(,)?] => $unit:literal $conversions:tt) => {
+        $(impl_quantity!($newtype => $unit $conversions);)*
+    };
+}
+
+impl_quantities!(
+    [Velocity, SpeedOfSound, VelocityProjection, BarrelVelocityEstimate] => "ft/s" {
+        "fps" => |fps| fps,
+        "m/s" => |mps| mps / METERS_PER_FOOT,
+        "km/h" => |kph| kph / 3.6 / METERS_PER_FOOT,
+        "mph" => |mph| mph * FEET_PER_MILE / 3600.0,
+    }
+);
+
+impl_quantities!(
+    [Distance, InclineCorrection, ThresholdDistance, Altitude, RetardationCoefficient] => "ft" {
+        "yd" => |yards| yards * 3.0,
+        "m" => |meters| meters / METERS_PER_FOOT,
+    }
+);
+
+impl_quantities!(
+    [
+        SpinDrift,
+        BulletDiameter,
+        SightCalibration,
+        SightRadius,
+        WindDeflection,
+        VerticalWindDeflection,
+        BulletDrop,
+        SightHeight,
+        TargetSize,
+        Deflection,
+        BarrelLength,
+        ProjectileLength,
+    ] => "in" {
+        "mm" => |millimeters| millimeters / MILLIMETERS_PER_INCH,
+        "cm" => |centimeters| centimeters * 10.0 / MILLIMETERS_PER_INCH,
+    }
+);
+
+impl_quantities!(
+    [TimeOfFlight, LagTime] => "s" {
+        "ms" => |milliseconds| milliseconds / 1000.0,
+    }
+);
+
+impl_quantity!(
+    Gravity => "ft/s²" {
+        "m/s²" => |mps2| mps2 / METERS_PER_FOOT,
+    },
+    WindSpeed => "mph" {
+        "ft/s" => |fps| fps * 3600.0 / FEET_PER_MILE,
+        "m/s" => |mps| mps * 3600.0 / METERS_PER_FOOT / FEET_PER_MILE,
+        "km/h" => |kph| kph / METERS_PER_FOOT / FEET_PER_MILE * 1000.0,
+    },
+    BulletWeight => "gr" {
+        "g" => |grams| grams * GRAINS_PER_GRAM,
+    },
+    Temperature => "°F" {
+        "°C" => |celsius| celsius * 1.8 + 32.0,
+        "K" => |kelvin| kelvin * 1.8 - 459.67,
+    },
+    Pressure => "inHg" {
+        "hPa" => |hpa| hpa * INHG_PER_HPA,
+        "mbar" => |millibars| millibars * INHG_PER_HPA,
+        "mmHg" => |mmhg| mmhg / MILLIMETERS_PER_INCH,
+    },
+    KineticEnergy => "ft-lb" {
+        "J" => |joules| joules / JOULES_PER_FOOT_POUND,
+    },
+    Angle => "MOA" {
+        "mil" => |mils| Angle::from_mils(mils).0,
+        "rad" => |radians| Angle::from_radians(radians).0,
+        "°" => |degrees| Angle::from_degrees(degrees).0,
+    },
+    AirDensity => "lb/ft³" {
+        "kg/m³" => |kgm3| kgm3 / KG_M3_PER_LB_FT3,
+    },
+    TwistRate => "in/turn" {
+        "mm/turn" => |millimeters| millimeters / MILLIMETERS_PER_INCH,
+    },
+    RiflingTwist => "cal/turn" {},
+    BulletLength => "cal" {},
+    ApertureSightCalibration => "MOA/click" {},
+    AerodynamicJump => "MOA/mph" {},
+    RelativeHumidity => "%" {},
+    SectionalDensity => "lb/in²" {},
+    SpinRate => "rpm" {},
+    YawOfRepose => "°" {},
+);
@@ -0,0 +1,557 @@
+use bon::bon;
+
+#[cfg(not(feature = "std"))]
+use crate::float::Float;
+use crate::{
+    Angle, BallisticsError, BulletDrop, Distance, SightHeight, StopCondition, TargetSize,
+    TrajectorySolver, Velocity, STANDARD_GRAVITY,
+};
+
+/// Range increment at which trajectories are sampled when searching them (ft).
+const SEARCH_STEP: Distance = Distance(0.5);
+
+/// Maximum number of bisections applied to a launch angle search.
+const MAX_BISECTIONS: usize = 60;
+
+/// Width of the launch angle bracket at which a bisection stops (MOA).
+const ANGLE_TOLERANCE: f64 = 1e-4;
+
+/// Launch angle at which a flat-fire search gives up, 45 degrees (MOA).
+const MAX_LAUNCH_ANGLE: Angle = Angle(2700.0);
+
+/// Maximum point blank range
+///
+/// This struct represents the farthest distance at which the bullet stays within a vital
+/// zone centered on the line of sight, along with the zero that achieves it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxPointBlankRange {
+    /// The maximum point blank range in feet.
+    pub range: Distance,
+    /// The zero range achieving the maximum point blank range in feet.
+    pub zero_range: Distance,
+    /// The launch angle achieving the maximum point blank range in MOA.
+    pub launch_angle: Angle,
+}
+
+#[bon]
+impl MaxPointBlankRange {
+    /// Calculates the maximum point blank range of a load.
+    ///
+    /// The launch angle is chosen so that the highest point of the trajectory sits exactly
+    /// one vital zone radius above the line of sight; the maximum point blank range is then
+    /// where the bullet falls one radius below it.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, sight height and atmosphere.
+    /// - `vital_zone_radius`: The radius of the vital zone in inches.
+    ///
+    /// # Returns
+    /// A `MaxPointBlankRange` instance, or `BallisticsError::BeyondMaxRange` if the bullet does
+    /// not leave the vital zone within the solver's maximum range.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        solver: TrajectorySolver,
+        #[builder(into)] vital_zone_radius: TargetSize,
+    ) -> Result<Self, BallisticsError> {
+        let solver = TrajectorySolver {
+            stop_condition: StopCondition::MaxRange,
+            ..solver
+        };
+        let apex_height = |launch_angle: Angle| {
+            apex(&solver.with_launch_angle(launch_angle)).map(|(_, height)| height)
+        };
+
+        let mut low = Angle(0.0);
+        let mut high = Angle(1.0);
+
+        while apex_height(high)? < vital_zone_radius.0 {
+            low = high;
+            high = Angle(high.0 * 2.0);
+
+            if high.0 > MAX_LAUNCH_ANGLE.0 {
+                return Err(BallisticsError::BeyondMaxRange(solver.max_range));
+            }
+        }
+
+        for _ in 0..MAX_BISECTIONS {
+            if high.0 - low.0 < ANGLE_TOLERANCE {
+                break;
+            }
+
+            let middle = Angle((low.0 + high.0) / 2.0);
+
+            if apex_height(middle)? < vital_zone_radius.0 {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        let solver = solver.with_launch_angle(high);
+
+        Ok(MaxPointBlankRange {
+            range: descending_crossing(&solver, -vital_zone_radius.0)?,
+            zero_range: descending_crossing(&solver, 0.0)?,
+            launch_angle: high,
+        })
+    }
+}
+
+/// Angle of departure
+///
+/// This struct groups the super-elevation calculations: the bore elevation above the
+/// horizontal at which the bullet returns to the height of the muzzle at a range.
+///
+/// The elevation is taken as the angle the drop of a level bore subtends at the range, which
+/// assumes tilting the bore tilts the whole trajectory without changing its shape. It falls
+/// short of the iterated `TrajectorySolver::zero_angle` as the elevation grows, because a
+/// climbing bullet works against gravity and slows down more: for a typical rifle load the
+/// two agree within 0.01 MOA up to about 45 MOA of elevation (1000 yards), but differ by 0.06
+/// MOA at 95 MOA (1500 yards). Beyond a degree or two of elevation, prefer
+/// `TrajectorySolver::zero_angle`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AngleOfDeparture;
+
+#[bon]
+impl AngleOfDeparture {
+    /// Calculates the angle of departure from the drop of a level bore at a range.
+    ///
+    /// # Parameters
+    /// - `drop`: The height of the bullet relative to the bore line at the range in inches,
+    ///   negative below it.
+    /// - `distance`: The range in feet.
+    ///
+    /// # Returns
+    /// The elevation of the bore above the horizontal in MOA, available in milliradians
+    /// through `Angle::mils`.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(#[builder(into)] drop: BulletDrop, distance: Distance) -> Angle {
+        Angle::from_radians((-drop.0 / 12.0 / distance.0).atan())
+    }
+
+    /// Calculates the angle of departure of a load by computing the drop of a level bore at
+    /// a range.
+    ///
+    /// The solver's launch angle, sight height and stop condition are ignored.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load and atmosphere.
+    /// - `distance`: The range in feet.
+    ///
+    /// # Returns
+    /// The elevation of the bore above the horizontal in MOA, or
+    /// `BallisticsError::BeyondMaxRange` if the trajectory does not reach the range within the
+    /// solver's maximum range.
+    #[builder(finish_fn = solve)]
+    pub fn from_solver(
+        solver: TrajectorySolver,
+        distance: Distance,
+    ) -> Result<Angle, BallisticsError> {
+        let point = TrajectorySolver {
+            launch_angle: Angle(0.0),
+            sight_height: SightHeight(0.0),
+            stop_condition: StopCondition::MaxRange,
+            ..solver
+        }
+        .point_at(distance)
+        .ok_or(BallisticsError::BeyondMaxRange(solver.max_range))?;
+
+        Ok(AngleOfDeparture::calculate()
+            .drop(point.drop)
+            .distance(distance)
+            .solve())
+    }
+}
+
+/// Maximum ordinate of a trajectory
+///
+/// This struct represents the highest point of a zeroed trajectory above the line of sight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxOrdinate {
+    /// The height of the highest point above the line of sight in inches.
+    pub height: BulletDrop,
+    /// The distance of the highest point from the muzzle in feet.
+    pub range: Distance,
+}
+
+#[bon]
+impl MaxOrdinate {
+    /// Calculates the maximum ordinate of a trajectory zeroed at the given range.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, sight height and atmosphere.
+    /// - `zero_range`: The distance at which the trajectory crosses the line of sight in feet.
+    ///
+    /// # Returns
+    /// A `MaxOrdinate` instance, or an error if the zero range cannot be reached.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        solver: TrajectorySolver,
+        zero_range: Distance,
+    ) -> Result<Self, BallisticsError> {
+        let launch_angle = solver.zero_angle().zero_range(zero_range).solve()?;
+        let solver = TrajectorySolver {
+            max_range: Distance(solver.max_range.0.max(zero_range.0)),
+            stop_condition: StopCondition::MaxRange,
+            ..solver.with_launch_angle(launch_angle)
+        };
+        let (range, height) = apex(&solver)?;
+
+        Ok(MaxOrdinate {
+            height: BulletDrop(height),
+            range,
+        })
+    }
+
+    /// Approximates the maximum ordinate of a trajectory zeroed at the given range without
+    /// integrating it.
+    ///
+    /// The bullet is assumed to keep its muzzle velocity, as it would in a vacuum, so the
+    /// result understates the height; the range of the highest point is typically within a
+    /// few percent of the zero range of the integrated one.
+    ///
+    /// # Parameters
+    /// - `sight_height`: The height of the line of sight above the bore in inches.
+    /// - `zero_range`: The distance at which the trajectory crosses the line of sight in feet.
+    /// - `muzzle_velocity`: The muzzle velocity of the bullet in feet per second (ft/s).
+    ///
+    /// # Returns
+    /// A `MaxOrdinate` instance representing the approximate highest point of the trajectory.
+    #[builder(finish_fn = solve)]
+    pub fn approximate(
+        #[builder(into)] sight_height: SightHeight,
+        zero_range: Distance,
+        #[builder(into)] muzzle_velocity: Velocity,
+    ) -> Self {
+        let sight_height = sight_height.0 / 12.0;
+        let drop =
+            |distance: f64| STANDARD_GRAVITY.0 * (distance / muzzle_velocity.0).powi(2) / 2.0;
+        let slope = (sight_height + drop(zero_range.0)) / zero_range.0;
+        let range = slope * muzzle_velocity.0.powi(2) / STANDARD_GRAVITY.0;
+
+        MaxOrdinate {
+            height: BulletDrop(12.0 * (slope * range - sight_height - drop(range))),
+            range: Distance(range),
+        }
+    }
+}
+
+/// Danger space
+///
+/// This struct represents the stretch of ground over which a target of a given height is hit
+/// when the sights are set for a given aim distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DangerSpace {
+    /// The nearest distance at which the target is hit in feet.
+    pub near_limit: Distance,
+    /// The farthest distance at which the target is hit in feet.
+    pub far_limit: Distance,
+    /// The length of the danger space in feet.
+    pub span: Distance,
+}
+
+#[bon]
+impl DangerSpace {
+    /// Calculates the danger space around an aim distance.
+    ///
+    /// The trajectory is zeroed at the aim distance and the target is hit wherever the bullet
+    /// stays within half the target height of the line of sight. If the bullet never leaves
+    /// that band between the muzzle and the aim distance, the near limit is the muzzle.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, sight height and atmosphere.
+    /// - `target_height`: The height of the target in inches.
+    /// - `aim_distance`: The distance the sights are set for in feet.
+    ///
+    /// # Returns
+    /// A `DangerSpace` instance, or `BallisticsError::BeyondMaxRange` if the bullet is still
+    /// within the target at the solver's maximum range.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        solver: TrajectorySolver,
+        #[builder(into)] target_height: TargetSize,
+        aim_distance: Distance,
+    ) -> Result<Self, BallisticsError> {
+        let launch_angle = solver.zero_angle().zero_range(aim_distance).solve()?;
+        let solver = TrajectorySolver {
+            stop_condition: StopCondition::MaxRange,
+            ..solver.with_launch_angle(launch_angle)
+        };
+        let half_height = target_height.0 / 2.0;
+        let mut near_limit = 0.0;
+        let mut previous: Option<(f64, f64)> = None;
+
+        for (distance, height) in path(&solver) {
+            if let Some((previous_distance, previous_height)) = previous {
+                let was_inside = previous_height.abs() <= half_height;
+                let is_inside = height.abs() <= half_height;
+
+                if was_inside != is_inside {
+                    let outside_height = if is_inside { previous_height } else { height };
+                    let boundary = half_height.copysign(outside_height);
+                    let fraction = (previous_height - boundary) / (previous_height - height);
+                    let crossing = previous_distance + fraction * (distance - previous_distance);
+
+                    if is_inside && crossing <= aim_distance.0 {
+                        near_limit = crossing;
+                    } else if !is_inside && crossing > aim_distance.0 {
+                        return Ok(DangerSpace {
+                            near_limit: Distance(near_limit),
+                            far_limit: Distance(crossing),
+                            span: Distance(crossing - near_limit),
+                        });
+                    }
+                }
+            }
+
+            previous = Some((distance, height));
+        }
+
+        Err(BallisticsError::BeyondMaxRange(solver.max_range))
+    }
+}
+
+/// A known crossing of the trajectory with the line of sight
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZeroCrossing {
+    /// The near zero (ft), where the rising bullet crosses the line of sight.
+    Near(Distance),
+    /// The far zero (ft), where the falling bullet crosses the line of sight.
+    Far(Distance),
+}
+
+/// Near and far zero
+///
+/// This struct represents the two distances at which a trajectory crosses the line of sight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZeroPair {
+    /// The near zero in feet.
+    pub near_zero: Distance,
+    /// The far zero in feet.
+    pub far_zero: Distance,
+    /// The launch angle producing both zeroes in MOA.
+    pub launch_angle: Angle,
+}
+
+#[bon]
+impl ZeroPair {
+    /// Calculates both zeroes of a trajectory from one of them.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, sight height and atmosphere.
+    /// - `zero`: The known near or far zero.
+    ///
+    /// # Returns
+    /// A `ZeroPair` instance, `BallisticsError::WrongSideOfApex` if a near zero lies beyond the
+    /// apex of its trajectory (or a far zero before it), or `BallisticsError::BeyondMaxRange`
+    /// if the far zero lies beyond the solver's maximum range.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        solver: TrajectorySolver,
+        zero: ZeroCrossing,
+    ) -> Result<Self, BallisticsError> {
+        let zero_range = match zero {
+            ZeroCrossing::Near(range) | ZeroCrossing::Far(range) => range,
+        };
+        let launch_angle = solver.zero_angle().zero_range(zero_range).solve()?;
+        let solver = TrajectorySolver {
+            stop_condition: StopCondition::MaxRange,
+            ..solver.with_launch_angle(launch_angle)
+        };
+        let (apex_range, _) = apex(&solver)?;
+
+        let (near_zero, far_zero) = match zero {
+            ZeroCrossing::Near(range) if range.0 <= apex_range.0 => {
+                (range, descending_crossing(&solver, 0.0)?)
+            }
+            ZeroCrossing::Far(range) if range.0 >= apex_range.0 => {
+                (ascending_crossing(&solver, 0.0)?, range)
+            }
+            _ => return Err(BallisticsError::WrongSideOfApex(zero_range)),
+        };
+
+        Ok(ZeroPair {
+            near_zero,
+            far_zero,
+            launch_angle,
+        })
+    }
+}
+
+/// Short-range zero
+///
+/// This struct represents a rifle sighted in at a short distance, such as an indoor range, from
+/// the offset of its point of impact from the point of aim there. The offset fixes the launch
+/// angle, from which the point of impact follows at any longer distance. Because the bullet
+/// still climbs towards the line of sight at short range, an impact an inch low at 25 yards
+/// puts a .308 rifle with its scope 2 inches above the bore within a tenth of an inch of a 100
+/// yard zero.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShortRangeZero {
+    /// The short distance the offset was observed at in feet.
+    pub distance: Distance,
+    /// The observed height of the point of impact above the point of aim in inches, negative
+    /// values being low.
+    pub offset: BulletDrop,
+    /// The launch angle implied by the offset in MOA.
+    pub launch_angle: Angle,
+    solver: TrajectorySolver,
+}
+
+#[bon]
+impl ShortRangeZero {
+    /// Back-solves the launch angle from the offset of the point of impact at a short distance.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, sight height and atmosphere.
+    /// - `distance`: The short distance the offset was observed at in feet.
+    /// - `offset`: The height of the point of impact above the point of aim in inches,
+    ///   negative values being low.
+    ///
+    /// # Returns
+    /// A `ShortRangeZero` instance, or `BallisticsError::UnreachableZero` if no launch angle
+    /// produces the offset.
+    #[builder(finish_fn = solve)]
+    pub fn calculate(
+        solver: TrajectorySolver,
+        distance: Distance,
+        #[builder(into)] offset: BulletDrop,
+    ) -> Result<Self, BallisticsError> {
+        let launch_angle = solver.launch_angle_through(distance, offset)?;
+
+        Ok(ShortRangeZero {
+            distance,
+            offset,
+            launch_angle,
+            solver: solver.with_launch_angle(launch_angle),
+        })
+    }
+
+    /// Calculates the offset to aim for at a short distance so that the rifle is zeroed at a
+    /// longer one.
+    ///
+    /// # Parameters
+    /// - `solver`: The trajectory solver describing the load, sight height and atmosphere.
+    /// - `distance`: The short distance the rifle is sighted in at in feet.
+    /// - `zero_range`: The distance the rifle should be zeroed at in feet.
+    ///
+    /// # Returns
+    /// The height of the point of impact above the point of aim at the short distance in
+    /// inches, or an error if the zero range cannot be reached.
+    #[builder(finish_fn = solve)]
+    pub fn offset_for_zero(
+        solver: TrajectorySolver,
+        distance: Distance,
+        zero_range: Distance,
+    ) -> Result<BulletDrop, BallisticsError> {
+        let launch_angle = solver.zero_angle().zero_range(zero_range).solve()?;
+
+        ShortRangeZero {
+            distance: zero_range,
+            offset: BulletDrop(0.0),
+            launch_angle,
+            solver: solver.with_launch_angle(launch_angle),
+        }
+        .offset_at(distance)
+    }
+}
+
+impl ShortRangeZero {
+    /// Predicts the height of the point of impact above the point of aim at a distance.
+    ///
+    /// # Parameters
+    /// - `distance`: The distance downrange in feet.
+    ///
+    /// # Returns
+    /// The offset in inches, negative values being low, or `BallisticsError::BeyondMaxRange` if
+    /// the trajectory ends before the distance.
+    pub fn offset_at(&self, distance: Distance) -> Result<BulletDrop, BallisticsError> {
+        TrajectorySolver {
+            max_range: distance,
+            stop_condition: StopCondition::MaxRange,
+            ..self.solver
+        }
+        .point_at(distance)
+        .map(|point| point.drop)
+        .ok_or(BallisticsError::BeyondMaxRange(distance))
+    }
+}
+
+/// Samples the height of the bullet relative to the line of sight (in) every `SEARCH_STEP`.
+fn path(solver: &TrajectorySolver) -> impl Iterator<Item = (f64, f64)> {
+    solver
+        .iter_points(SEARCH_STEP)
+        .map(|point| (point.distance.0, point.drop.0))
+}
+
+/// Finds the distance (ft) and height above the line of sight (in) of the highest point of
+/// the trajectory.
+pub(crate) fn apex(solver: &TrajectorySolver) -> Result<(Distance, f64), BallisticsError> {
+    let mut highest = (0.0, f64::NEG_INFINITY);
+
+    for (distance, height) in path(solver) {
+        if height < highest.1 {
+            return Ok((Distance(highest.0), highest.1));
+        }
+
+        highest = (distance, height);
+    }
+
+    Err(BallisticsError::BeyondMaxRange(solver.max_range))
+}
+
+/// Finds the first distance (ft) at which the bullet falls through the given height above the
+/// line of sight (in).
+pub(crate) fn descending_crossing(
+    solver: &TrajectorySolver,
+    height: f64,
+) -> Result<Distance, BallisticsError> {
+    let mut previous: Option<(f64, f64)> = None;
+
+    for (distance, path_height) in path(solver) {
+        if let Some((previous_distance, previous_height)) = previous {
+            if previous_height >= height && path_height < height {
+                let fraction = (previous_height - height) / (previous_height - path_height);
+
+                return Ok(Distance(
+                    previous_distance + fraction * (distance - previous_distance),
+                ));
+            }
+        }
+
+        previous = Some((distance, path_height));
+    }
+
+    Err(BallisticsError::BeyondMaxRange(solver.max_range))
+}
+
+/// Finds the first distance (ft) at which the bullet rises through the given height above the
+/// line of sight (in).
+pub(crate) fn ascending_crossing(
+    solver: &TrajectorySolver,
+    height: f64,
+) -> Result<Distance, BallisticsError> {
+    let mut previous: Option<(f64, f64)> = None;
+
+    for (distance, path_height) in path(solver) {
+        if let Some((previous_distance, previous_height)) = previous {
+            if previous_height < height && path_height >= height {
+                let fraction = (height - previous_height) / (path_height - previous_height);
+
+                return Ok(Distance(
+                    previous_distance + fraction * (distance - previous_distance),
+                ));
+            }
+        }
+
+        previous = Some((distance, path_height));
+    }
+
+    Err(BallisticsError::BeyondMaxRange(solver.max_range))
+}
@@ -0,0 +1,161 @@
+use ballistics_rs::{
+    Angle, ApproxEq, BallisticCoefficient, ClickValue, Deflection, Distance, SightAdjustment,
+    SightHeight, TrajectorySolver, TurretSolution, Velocity, ZeroTransfer,
+};
+
+fn clicks(correction: f64, click: ClickValue) -> i64 {
+    TurretSolution::calculate()
+        .correction(Angle(correction))
+        .click(click)
+        .solve()
+        .clicks
+}
+
+#[test]
+fn rounds_to_the_nearest_click() {
+    assert_eq!(clicks(0.3, ClickValue::QuarterMoa), 1);
+    assert_eq!(clicks(0.38, ClickValue::QuarterMoa), 2);
+    assert_eq!(clicks(-0.38, ClickValue::QuarterMoa), -2);
+    assert_eq!(clicks(0.1, ClickValue::QuarterMoa), 0);
+}
+
+#[test]
+fn ties_round_toward_zero() {
+    // Each correction lies exactly halfway between two clicks.
+    assert_eq!(clicks(0.375, ClickValue::QuarterMoa), 1);
+    assert_eq!(clicks(-0.375, ClickValue::QuarterMoa), -1);
+    assert_eq!(clicks(0.625, ClickValue::QuarterMoa), 2);
+    assert_eq!(clicks(-0.625, ClickValue::QuarterMoa), -2);
+    assert_eq!(clicks(0.0625, ClickValue::EighthMoa), 0);
+    assert_eq!(clicks(-0.0625, ClickValue::EighthMoa), 0);
+    assert_eq!(clicks(2.5, ClickValue::Custom(Angle(1.0))), 2);
+    assert_eq!(clicks(-2.5, ClickValue::Custom(Angle(1.0))), -2);
+}
+
+#[test]
+fn reports_the_residual() {
+    let solution = TurretSolution::calculate()
+        .correction(Angle(0.375))
+        .click(ClickValue::QuarterMoa)
+        .distance(Distance::from_yards(100.0))
+        .solve();
+
+    assert_eq!(solution.dialed, Angle(0.25));
+    assert_eq!(solution.residual, Angle(0.125));
+    // 0.125 MOA subtends about 0.131 inches at 100 yards.
+    let residual_inches = solution.residual_inches.unwrap().0;
+    assert!(residual_inches.approx_eq(&0.1309, 1e-3));
+}
+
+#[test]
+fn dials_mils() {
+    let solution = TurretSolution::calculate()
+        .correction(Angle::from_mils(1.23))
+        .click(ClickValue::TenthMil)
+        .solve();
+
+    assert_eq!(solution.clicks, 12);
+    assert!(solution.residual.mils().approx_eq(&0.03, 1e-9));
+    assert_eq!(solution.residual_inches, None);
+}
+
+fn adjustment(deflection: f64, yards: f64) -> SightAdjustment {
+    SightAdjustment::calculate()
+        .deflection(Deflection(deflection))
+        .distance(Distance::from_yards(yards))
+        .click(ClickValue::QuarterMoa)
+        .solve()
+}
+
+#[test]
+fn sight_adjustment_dials_whole_clicks() {
+    // 10.47 inches subtend 2.0 MOA at 500 yards: eight quarter-MOA clicks up.
+    let adjustment = adjustment(-10.47, 500.0);
+
+    assert!(adjustment.angle.0.approx_eq(&2.0, 1e-3));
+    assert_eq!(adjustment.clicks, 8);
+    assert!(adjustment.residual.0.abs() < 0.01);
+}
+
+#[test]
+fn sight_adjustment_reports_the_residual() {
+    // 3 inches low at 300 yards is 0.955 MOA, dialed as four clicks of 1.0 MOA. The extra
+    // 0.045 MOA leaves the impact about 0.14 inches high.
+    let adjustment = adjustment(-3.0, 300.0);
+    let overdialed = Angle(1.0 - adjustment.angle.0).subtension_at(Distance::from_yards(300.0));
+
+    assert_eq!(adjustment.clicks, 4);
+    assert!(adjustment.residual.0.approx_eq(&overdialed, 1e-12));
+    assert!(adjustment.residual.0.approx_eq(&0.1416, 1e-3));
+}
+
+#[test]
+fn sight_adjustment_rounds_like_the_turret() {
+    for deflection in [-10.47, -3.0, 1.3, 2.75, 7.2] {
+        let adjustment = adjustment(deflection, 200.0);
+
+        assert_eq!(
+            adjustment.clicks,
+            clicks(adjustment.angle.0, ClickValue::QuarterMoa),
+            "{deflection} in at 200 yd"
+        );
+    }
+}
+
+fn transfer(current_yards: f64, new_yards: f64) -> ZeroTransfer {
+    // The .308 175 gr Sierra MatchKing at 2600 ft/s with its scope 1.5" above the bore.
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2600.0))
+        .ballistic_coefficient(BallisticCoefficient(0.505))
+        .sight_height(SightHeight(1.5))
+        .max_range(Distance::from_yards(1000.0))
+        .build();
+
+    ZeroTransfer::calculate()
+        .solver(solver)
+        .current_zero(Distance::from_yards(current_yards))
+        .new_zero(Distance::from_yards(new_yards))
+        .click(ClickValue::QuarterMoa)
+        .solve()
+        .unwrap()
+}
+
+#[test]
+fn zero_transfer_back_is_the_negative() {
+    for (current, new) in [
+        (100.0, 400.0),
+        (100.0, 1000.0),
+        (200.0, 600.0),
+        (300.0, 50.0),
+    ] {
+        let out = transfer(current, new);
+        let back = transfer(new, current);
+
+        assert_eq!(back.elevation, Angle(-out.elevation.0));
+        assert_eq!(back.turret.clicks, -out.turret.clicks);
+        assert_eq!(back.turret.residual, Angle(-out.turret.residual.0));
+    }
+
+    assert_eq!(transfer(100.0, 100.0).elevation, Angle(0.0));
+}
+
+#[test]
+fn zero_transfer_matches_a_come_up_table() {
+    // Come-ups in MOA from a 100 yard zero, from an independent point-mass G1 integration of
+    // the same load.
+    for (yards, come_up) in [
+        (300.0, 4.992),
+        (500.0, 12.098),
+        (600.0, 16.337),
+        (1000.0, 39.338),
+    ] {
+        let out = transfer(100.0, yards);
+
+        assert!(
+            out.elevation.approx_eq(&Angle(come_up), 0.1),
+            "{} MOA at {yards} yd",
+            out.elevation.0
+        );
+        assert_eq!(out.turret.clicks, (come_up * 4.0_f64).round() as i64);
+    }
+}
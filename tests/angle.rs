@@ -0,0 +1,75 @@
+use std::f64::consts::PI;
+
+use ballistics_rs::{Angle, ApproxEq, Distance};
+
+#[test]
+fn one_moa_subtends_1_047_inches_at_100_yards() {
+    let hundred_yards = Distance::from_yards(100.0);
+    let inches = Angle(1.0).subtension_at(hundred_yards);
+
+    assert!(inches.relative_eq(&(3600.0 * (PI / 10800.0).tan()), 1e-12));
+    assert!(inches.approx_eq(&1.047, 5e-4), "{inches}");
+    assert!(Angle::from_mils(1.0)
+        .subtension_at(hundred_yards)
+        .approx_eq(&3.6, 1e-5));
+}
+
+#[test]
+fn one_mil_subtends_10_centimeters_at_100_meters() {
+    let centimeters = Angle::from_mils(1.0).subtension_cm_at(Distance::from_meters(100.0));
+
+    assert!(centimeters.approx_eq(&10.0, 1e-5), "{centimeters}");
+}
+
+#[test]
+fn subtension_round_trips() {
+    for (angle, distance) in [
+        (Angle(1.0), Distance::from_yards(100.0)),
+        (Angle(12.5), Distance::from_yards(650.0)),
+        (Angle::from_mils(4.3), Distance::from_meters(800.0)),
+    ] {
+        let inches = angle.subtension_at(distance);
+        let centimeters = angle.subtension_cm_at(distance);
+
+        assert!(Angle::from_subtension(inches, distance).relative_eq(&angle, 1e-12));
+        assert!(Angle::from_subtension_cm(centimeters, distance).relative_eq(&angle, 1e-12));
+    }
+}
+
+#[test]
+fn ranges_a_36_inch_target_reading_2_mils_at_500_yards() {
+    let distance = Distance::from_ranging(36.0, Angle::from_mils(2.0));
+
+    assert!(
+        distance.yards().approx_eq(&500.0, 1e-3),
+        "{}",
+        distance.yards()
+    );
+    assert!(Angle::from_subtension(36.0, distance).relative_eq(&Angle::from_mils(2.0), 1e-12));
+}
+
+#[test]
+fn ranges_a_1_meter_target_reading_2_mils_at_500_meters() {
+    let distance = Distance::from_ranging_cm(100.0, Angle::from_mils(2.0));
+
+    assert!(
+        distance.meters().approx_eq(&500.0, 1e-3),
+        "{}",
+        distance.meters()
+    );
+    assert!(Angle::from_subtension_cm(100.0, distance).relative_eq(&Angle::from_mils(2.0), 1e-12));
+}
+
+#[test]
+fn three_feet_at_10_yards_is_exact() {
+    // 2·atan(18" / 360"), where the small-angle shortcut gives 100 mils.
+    let angle = Angle::of_target(36.0, Distance::from_yards(10.0));
+
+    assert!(
+        angle.mils().approx_eq(&99.916_791, 1e-6),
+        "{}",
+        angle.mils()
+    );
+    assert!(angle.approx_eq(&Angle(343.488_627), 1e-6), "{}", angle.0);
+    assert!(Angle::of_target_cm(91.44, Distance::from_yards(10.0)).relative_eq(&angle, 1e-12));
+}
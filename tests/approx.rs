@@ -0,0 +1,47 @@
+use ballistics_rs::{ApproxEq, Distance, Velocity};
+
+#[test]
+fn absolute_tolerance_is_inclusive() {
+    assert!(1.0_f64.approx_eq(&1.25, 0.25));
+    assert!(1.25_f64.approx_eq(&1.0, 0.25));
+    assert!(!1.0_f64.approx_eq(&1.3, 0.25));
+    assert!(2.0_f64.approx_eq(&2.0, 0.0));
+    assert!(!2.0_f64.approx_eq(&2.0000001, 0.0));
+}
+
+#[test]
+fn relative_tolerance_scales_with_the_larger_magnitude() {
+    assert!(1000.0_f64.relative_eq(&1001.0, 1e-3));
+    assert!(1001.0_f64.relative_eq(&1000.0, 1e-3));
+    assert!(!1000.0_f64.relative_eq(&1002.0, 1e-3));
+
+    // The same absolute difference is far outside the tolerance at a smaller magnitude.
+    assert!(!1.0_f64.relative_eq(&2.0, 1e-3));
+    assert!(0.0_f64.relative_eq(&0.0, 0.0));
+    assert!(!0.0_f64.relative_eq(&1e-300, 1e-3));
+}
+
+#[test]
+fn nan_is_never_equal() {
+    assert!(!f64::NAN.approx_eq(&f64::NAN, f64::INFINITY));
+    assert!(!f64::NAN.relative_eq(&f64::NAN, f64::INFINITY));
+    assert!(!1.0_f64.approx_eq(&f64::NAN, f64::INFINITY));
+    assert!(!f64::NAN.relative_eq(&1.0, f64::INFINITY));
+}
+
+#[test]
+fn infinities_only_equal_the_same_infinity() {
+    assert!(f64::INFINITY.approx_eq(&f64::INFINITY, 0.0));
+    assert!(f64::NEG_INFINITY.relative_eq(&f64::NEG_INFINITY, 0.0));
+    assert!(!f64::INFINITY.approx_eq(&f64::NEG_INFINITY, f64::INFINITY));
+    assert!(!f64::INFINITY.relative_eq(&f64::NEG_INFINITY, f64::INFINITY));
+    assert!(!f64::INFINITY.approx_eq(&f64::MAX, f64::MAX));
+}
+
+#[test]
+fn newtypes_compare_their_values() {
+    assert!(Velocity(2800.0).approx_eq(&Velocity(2800.5), 0.5));
+    assert!(!Velocity(2800.0).approx_eq(&Velocity(2801.0), 0.5));
+    assert!(Distance(300.0).relative_eq(&Distance::from_yards(100.0), 1e-12));
+    assert!(!Distance(300.0).relative_eq(&Distance(301.0), 1e-3));
+}
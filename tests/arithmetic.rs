@@ -0,0 +1,63 @@
+use ballistics_rs::{
+    BallisticCoefficient, BulletWeight, GyroscopicStability, LagTime, SightHeight, Temperature,
+};
+
+#[test]
+fn add_keeps_the_quantity() {
+    let total: BulletWeight = BulletWeight(150.0) + BulletWeight(18.5);
+
+    assert_eq!(total, BulletWeight(168.5));
+}
+
+#[test]
+fn add_assign_keeps_the_quantity() {
+    let mut height = SightHeight(1.5);
+    height += SightHeight(0.25);
+
+    assert_eq!(height, SightHeight(1.75));
+}
+
+#[test]
+fn sub_keeps_the_quantity() {
+    let change: Temperature = Temperature(85.0) - Temperature(59.0);
+
+    assert_eq!(change, Temperature(26.0));
+}
+
+#[test]
+fn sub_assign_keeps_the_quantity() {
+    let mut lag_time = LagTime::<f32>(0.5);
+    lag_time -= LagTime(0.125);
+
+    assert_eq!(lag_time, LagTime(0.375_f32));
+}
+
+#[test]
+fn mul_keeps_the_quantity() {
+    let scaled: BallisticCoefficient = BallisticCoefficient(0.25) * BallisticCoefficient(2.0);
+
+    assert_eq!(scaled, BallisticCoefficient(0.5));
+}
+
+#[test]
+fn mul_assign_keeps_the_quantity() {
+    let mut stability = GyroscopicStability(1.5);
+    stability *= GyroscopicStability(0.5);
+
+    assert_eq!(stability, GyroscopicStability(0.75));
+}
+
+#[test]
+fn div_keeps_the_quantity() {
+    let ratio: BulletWeight = BulletWeight(175.0) / BulletWeight(7.0);
+
+    assert_eq!(ratio, BulletWeight(25.0));
+}
+
+#[test]
+fn div_assign_keeps_the_quantity() {
+    let mut lag_time = LagTime(0.75);
+    lag_time /= LagTime(3.0);
+
+    assert_eq!(lag_time, LagTime(0.25));
+}
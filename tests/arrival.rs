@@ -0,0 +1,56 @@
+use ballistics_rs::{
+    ApproxEq, BallisticCoefficient, BulletWeight, Distance, SoundArrival, StopCondition,
+    TimeOfFlight, TrajectorySolver, Velocity,
+};
+
+#[test]
+fn lead_is_the_sound_time_less_the_time_of_flight() {
+    // 1116.28 ft at the speed of sound takes exactly one second.
+    let arrival = SoundArrival::calculate()
+        .distance(Distance(1116.28))
+        .time_of_flight(TimeOfFlight(0.5))
+        .solve();
+
+    assert!(arrival.sound_time.approx_eq(&TimeOfFlight(1.0), 1e-12));
+    assert!(arrival.lead_ms().approx_eq(&500.0, 1e-9));
+    assert!(arrival.bullet_first());
+}
+
+#[test]
+fn sound_overtakes_a_subsonic_bullet_past_the_crossover() {
+    // A .308 175 gr at 2600 ft/s goes subsonic at about 1100 yards, but its average velocity
+    // stays above the speed of sound until about 2380 yards.
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2600.0))
+        .ballistic_coefficient(BallisticCoefficient(0.505))
+        .bullet_weight(BulletWeight(175.0))
+        .max_range(Distance::from_yards(3000.0))
+        .stop_condition(StopCondition::MaxRange)
+        .build();
+    let arrival_at = |yards: f64| {
+        let distance = Distance::from_yards(yards);
+
+        SoundArrival::calculate()
+            .distance(distance)
+            .time_of_flight(solver.point_at(distance).unwrap().time_of_flight)
+            .solve()
+    };
+
+    let (mut bullet_first, mut sound_first) = (2000.0, 3000.0);
+    assert!(arrival_at(bullet_first).bullet_first());
+    assert!(!arrival_at(sound_first).bullet_first());
+
+    while sound_first - bullet_first > 1.0 {
+        let middle = (bullet_first + sound_first) / 2.0;
+        if arrival_at(middle).bullet_first() {
+            bullet_first = middle;
+        } else {
+            sound_first = middle;
+        }
+    }
+
+    assert!((2300.0..2450.0).contains(&bullet_first), "{bullet_first}");
+    assert!(arrival_at(bullet_first).lead_ms().approx_eq(&0.0, 5.0));
+    assert!(arrival_at(bullet_first - 50.0).lead_ms() > 0.0);
+    assert!(arrival_at(sound_first + 50.0).lead_ms() < 0.0);
+}
@@ -0,0 +1,132 @@
+use ballistics_rs::{
+    Altitude, ApproxEq, Atmosphere, BallisticCoefficient, BallisticsError, DensityRatio, Pressure,
+    ReferenceAtmosphere, RelativeHumidity, Temperature,
+};
+
+#[test]
+fn icao_density_ratio_is_exactly_one() {
+    let icao = Atmosphere::icao();
+
+    assert_eq!(icao.density_ratio(), DensityRatio(1.0));
+    assert_eq!(
+        icao.density_ratio_to(ReferenceAtmosphere::Icao),
+        DensityRatio(1.0)
+    );
+    assert_eq!(
+        BallisticCoefficient(0.505).density_corrected(icao.density_ratio()),
+        BallisticCoefficient(0.505)
+    );
+}
+
+#[test]
+fn thin_warm_air_boosts_the_ballistic_coefficient() {
+    // 5000 feet of standard pressure at 85 °F in dry air.
+    let pressure = Atmosphere::standard_at(Altitude(5000.0)).pressure;
+    let atmosphere = Atmosphere::builder()
+        .temperature(Temperature(85.0))
+        .pressure(pressure)
+        .build();
+    let density_ratio = atmosphere.density_ratio();
+
+    assert!(pressure.approx_eq(&Pressure(24.895), 1e-3));
+    assert!(density_ratio.relative_eq(
+        &DensityRatio(pressure.0 / 29.92 * (59.0 + 459.67) / (85.0 + 459.67)),
+        1e-12
+    ));
+
+    let corrected = BallisticCoefficient(0.505).density_corrected(density_ratio);
+    let boost = corrected.0 / 0.505 - 1.0;
+
+    assert!(boost.approx_eq(&0.262, 1e-3), "{boost}");
+    assert!(corrected
+        .density_standardized(density_ratio)
+        .relative_eq(&BallisticCoefficient(0.505), 1e-12));
+
+    // Warm air alone, at sea level pressure, is worth about 5%.
+    let warm = Atmosphere::builder().temperature(Temperature(85.0)).build();
+    let boost = BallisticCoefficient(0.505)
+        .density_corrected(warm.density_ratio())
+        .0
+        / 0.505
+        - 1.0;
+    assert!(boost.approx_eq(&0.050, 1e-3), "{boost}");
+}
+
+#[test]
+fn army_standard_metro_converts_by_about_1_8_percent() {
+    let to_metro = BallisticCoefficient(1.0).convert_reference(
+        ReferenceAtmosphere::Icao,
+        ReferenceAtmosphere::ArmyStandardMetro,
+    );
+    let to_icao = BallisticCoefficient(1.0).convert_reference(
+        ReferenceAtmosphere::ArmyStandardMetro,
+        ReferenceAtmosphere::Icao,
+    );
+
+    assert!(
+        to_metro.approx_eq(&BallisticCoefficient(1.018), 1e-3),
+        "{}",
+        to_metro.0
+    );
+    assert!(to_icao.relative_eq(&BallisticCoefficient(1.0 / to_metro.0), 1e-12));
+    assert_eq!(
+        Atmosphere::army_standard_metro().density_ratio_to(ReferenceAtmosphere::ArmyStandardMetro),
+        DensityRatio(1.0)
+    );
+    assert_eq!(
+        BallisticCoefficient(0.505)
+            .convert_reference(ReferenceAtmosphere::Icao, ReferenceAtmosphere::Icao),
+        BallisticCoefficient(0.505)
+    );
+}
+
+#[test]
+fn humidity_percent_and_fraction_agree_downstream() {
+    for (percent, fraction) in [(0.0, 0.0), (50.0, 0.5), (78.0, 0.78), (100.0, 1.0)] {
+        let humid = |relative_humidity| {
+            Atmosphere::builder()
+                .temperature(Temperature(85.0))
+                .relative_humidity(relative_humidity)
+                .build()
+        };
+        let from_percent = humid(RelativeHumidity::from_percent(percent));
+        let from_fraction = humid(RelativeHumidity::from_fraction(fraction));
+
+        assert_eq!(from_percent, from_fraction);
+        assert_eq!(from_percent.density_ratio(), from_fraction.density_ratio());
+        assert_eq!(
+            from_percent.density_altitude(),
+            from_fraction.density_altitude()
+        );
+        assert_eq!(
+            from_percent.speed_of_sound(),
+            from_fraction.speed_of_sound()
+        );
+        assert_eq!(from_fraction.relative_humidity.fraction(), fraction);
+    }
+
+    // Humid air is lighter and carries sound faster.
+    let dry = Atmosphere::builder().temperature(Temperature(85.0)).build();
+    let humid = Atmosphere::builder()
+        .temperature(Temperature(85.0))
+        .relative_humidity(RelativeHumidity::from_fraction(0.6))
+        .build();
+    assert!(humid.density_ratio().0 < dry.density_ratio().0);
+    assert!(humid.speed_of_sound().0 > dry.speed_of_sound().0);
+}
+
+#[test]
+fn humidity_of_150_percent_is_rejected() {
+    for relative_humidity in [
+        RelativeHumidity::from_percent(150.0),
+        RelativeHumidity::from_fraction(1.5),
+    ] {
+        assert!(matches!(
+            RelativeHumidity::try_from(relative_humidity.0),
+            Err(BallisticsError::OutOfRange {
+                param: "relative_humidity",
+                ..
+            })
+        ));
+    }
+}
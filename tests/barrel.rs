@@ -0,0 +1,39 @@
+use ballistics_rs::{
+    BallisticsError, BarrelLength, BarrelVelocityEstimate, CartridgeClass, Velocity,
+};
+
+fn estimate(target_length: f64) -> Result<BarrelVelocityEstimate, BallisticsError> {
+    BarrelVelocityEstimate::calculate()
+        .known_velocity(Velocity(2650.0))
+        .known_length(BarrelLength(24.0))
+        .target_length(BarrelLength(target_length))
+        .cartridge_class(CartridgeClass::StandardRifle)
+        .solve()
+}
+
+#[test]
+fn shorter_barrel_loses_and_longer_barrel_gains_velocity() {
+    assert_eq!(estimate(18.0).unwrap().0, 2500.0);
+    assert_eq!(estimate(26.0).unwrap().0, 2700.0);
+}
+
+#[test]
+fn non_positive_barrel_length_is_out_of_range() {
+    for length in [0.0, -18.0] {
+        assert!(matches!(
+            estimate(length),
+            Err(BallisticsError::OutOfRange {
+                param: "target_length",
+                ..
+            })
+        ));
+    }
+
+    assert!(matches!(
+        estimate(f64::NAN),
+        Err(BallisticsError::NonFinite {
+            param: "target_length",
+            ..
+        })
+    ));
+}
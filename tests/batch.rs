@@ -0,0 +1,145 @@
+use ballistics_rs::{
+    BallisticsError, BulletWeight, Distance, KineticEnergy, LagTime, PejsaTrajectory,
+    RetardationCoefficient, Velocity, WindDeflection, WindSpeed,
+};
+
+const ELEMENTS: usize = 1_000;
+
+fn spread(i: usize, step: f64, low: f64, width: f64) -> f64 {
+    low + (i as f64 * step).fract() * width
+}
+
+fn pejsa() -> PejsaTrajectory {
+    PejsaTrajectory::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .retardation_coefficient(RetardationCoefficient(2800.0))
+        .build()
+}
+
+#[test]
+fn energy_batch_matches_per_call() {
+    let weights: Vec<BulletWeight> = (0..ELEMENTS)
+        .map(|i| BulletWeight(spread(i, 0.618_033_988_75, 100.0, 150.0)))
+        .collect();
+    let velocities: Vec<Velocity> = (0..ELEMENTS)
+        .map(|i| Velocity(spread(i, 0.414_213_562_37, 2000.0, 1500.0)))
+        .collect();
+    let expected: Vec<KineticEnergy> = weights
+        .iter()
+        .zip(&velocities)
+        .map(|(&bullet_weight, &velocity)| {
+            KineticEnergy::calculate()
+                .bullet_weight(bullet_weight)
+                .velocity(velocity)
+                .solve()
+        })
+        .collect();
+
+    assert_eq!(
+        KineticEnergy::calculate_batch(&weights, &velocities).unwrap(),
+        expected
+    );
+
+    let mut out = vec![0.0; ELEMENTS];
+    KineticEnergy::calculate_batch_into(&weights, &velocities, &mut out).unwrap();
+    assert!(out
+        .iter()
+        .zip(&expected)
+        .all(|(&out, energy)| out == energy.0));
+}
+
+#[test]
+fn deflection_batch_matches_per_call() {
+    let lag_times: Vec<LagTime> = (0..ELEMENTS)
+        .map(|i| LagTime(spread(i, 0.618_033_988_75, 0.0, 0.5)))
+        .collect();
+    let crosswinds: Vec<WindSpeed> = (0..ELEMENTS)
+        .map(|i| WindSpeed(spread(i, 0.414_213_562_37, -20.0, 40.0)))
+        .collect();
+    let expected: Vec<WindDeflection> = lag_times
+        .iter()
+        .zip(&crosswinds)
+        .map(|(&lag_time, &crosswind_speed)| {
+            WindDeflection::calculate()
+                .lag_time(lag_time)
+                .crosswind_speed(crosswind_speed)
+                .solve()
+        })
+        .collect();
+
+    assert_eq!(
+        WindDeflection::calculate_batch(&lag_times, &crosswinds).unwrap(),
+        expected
+    );
+
+    let mut out = vec![0.0; ELEMENTS];
+    WindDeflection::calculate_batch_into(&lag_times, &crosswinds, &mut out).unwrap();
+    assert!(out
+        .iter()
+        .zip(&expected)
+        .all(|(&out, deflection)| out == deflection.0));
+}
+
+#[test]
+fn velocity_batch_matches_per_call() {
+    let pejsa = pejsa();
+    let distances: Vec<Distance> = (0..ELEMENTS)
+        .map(|i| Distance::from_yards(spread(i, 0.618_033_988_75, 0.0, 800.0)))
+        .collect();
+    let expected: Vec<Velocity> = distances
+        .iter()
+        .map(|&distance| pejsa.velocity_at(distance))
+        .collect();
+
+    assert_eq!(pejsa.velocity_at_batch(&distances), expected);
+
+    let mut out = vec![0.0; ELEMENTS];
+    pejsa.velocity_at_batch_into(&distances, &mut out).unwrap();
+    assert!(out
+        .iter()
+        .zip(&expected)
+        .all(|(&out, velocity)| out == velocity.0));
+}
+
+#[test]
+fn empty_batches_are_empty() {
+    assert!(KineticEnergy::calculate_batch(&[], &[]).unwrap().is_empty());
+    assert!(pejsa().velocity_at_batch(&[]).is_empty());
+    assert!(WindDeflection::calculate_batch_into(&[], &[], &mut []).is_ok());
+}
+
+#[test]
+fn rejects_mismatched_lengths() {
+    let weights = [BulletWeight(150.0), BulletWeight(168.0)];
+    let velocities = [Velocity(2800.0)];
+
+    assert!(matches!(
+        KineticEnergy::calculate_batch(&weights, &velocities),
+        Err(BallisticsError::OutOfRange {
+            param: "velocities",
+            value,
+            ..
+        }) if value == 1.0
+    ));
+
+    let mut out = [0.0; 1];
+    assert!(matches!(
+        KineticEnergy::calculate_batch_into(&weights, &[Velocity(2800.0); 2], &mut out),
+        Err(BallisticsError::OutOfRange { param: "out", .. })
+    ));
+    assert!(matches!(
+        WindDeflection::calculate_batch(&[LagTime(0.1)], &[]),
+        Err(BallisticsError::OutOfRange {
+            param: "crosswind_speeds",
+            ..
+        })
+    ));
+    assert!(matches!(
+        WindDeflection::calculate_batch_into(&[LagTime(0.1)], &[WindSpeed(10.0)], &mut []),
+        Err(BallisticsError::OutOfRange { param: "out", .. })
+    ));
+    assert!(matches!(
+        pejsa().velocity_at_batch_into(&[Distance(300.0)], &mut [0.0; 2]),
+        Err(BallisticsError::OutOfRange { param: "out", .. })
+    ));
+}
@@ -0,0 +1,71 @@
+use ballistics_rs::{Angle, ApproxEq, BulletDrop, CantError, Distance};
+
+fn cant(degrees: f64, elevation: Angle, distance: Distance) -> CantError {
+    CantError::calculate()
+        .cant_angle(Angle::from_degrees(degrees))
+        .elevation(elevation)
+        .distance(distance)
+        .solve()
+}
+
+#[test]
+fn five_degrees_of_cant_with_8_mils_dialed_at_1000_yards() {
+    // 8 × sin 5° = 0.697 mils to the side, about 25" at 1000 yards, and
+    // 8 × (1 - cos 5°) = 0.030 mils low, about 1".
+    let error = cant(5.0, Angle::from_mils(8.0), Distance::from_yards(1000.0));
+
+    assert!(error.horizontal.as_mil().approx_eq(&0.697_246, 1e-6));
+    assert!(error.vertical.as_mil().approx_eq(&-0.030_442, 1e-6));
+    assert!(
+        error.horizontal.as_inches().approx_eq(&25.1, 0.05),
+        "{}",
+        error.horizontal.as_inches()
+    );
+    assert!(
+        error.vertical.as_inches().approx_eq(&-1.1, 0.05),
+        "{}",
+        error.vertical.as_inches()
+    );
+}
+
+#[test]
+fn no_cant_means_no_error() {
+    let error = cant(0.0, Angle::from_mils(8.0), Distance::from_yards(1000.0));
+
+    assert_eq!(error.horizontal.as_inches(), 0.0);
+    assert_eq!(error.vertical.as_inches(), 0.0);
+}
+
+#[test]
+fn horizontal_error_dominates_and_follows_the_cant() {
+    let distance = Distance::from_yards(600.0);
+
+    for degrees in [1.0, 3.0, 5.0, 10.0] {
+        let right = cant(degrees, Angle(20.0), distance);
+        let left = cant(-degrees, Angle(20.0), distance);
+
+        assert!(right.horizontal.as_inches() > 0.0);
+        assert!(right.horizontal.as_inches() > 10.0 * -right.vertical.as_inches());
+        assert_eq!(left.horizontal.as_inches(), -right.horizontal.as_inches());
+        assert_eq!(left.vertical, right.vertical);
+    }
+}
+
+#[test]
+fn from_drop_holds_the_drop_as_elevation() {
+    let distance = Distance::from_yards(1000.0);
+    let drop = BulletDrop(-Angle::from_mils(8.0).subtension_at(distance));
+
+    let error = CantError::from_drop()
+        .cant_angle(Angle::from_degrees(5.0))
+        .drop(drop)
+        .distance(distance)
+        .solve();
+
+    assert!(error.horizontal.as_mil().approx_eq(
+        &cant(5.0, Angle::from_mils(8.0), distance)
+            .horizontal
+            .as_mil(),
+        1e-9
+    ));
+}
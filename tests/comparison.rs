@@ -0,0 +1,133 @@
+use ballistics_rs::{
+    ApproxEq, Atmosphere, BallisticCoefficient, BulletDiameter, BulletDrop, BulletWeight, Distance,
+    DragModel, Load, LoadComparison, LoadComparisonRow, Projectile, ProjectileLength, RiflingTwist,
+    SightHeight, Velocity,
+};
+
+/// A .308 Winchester load in the ICAO atmosphere, from a 1:10" barrel with the sight 1.5" over
+/// the bore.
+fn load(
+    bullet_weight: f64,
+    length: f64,
+    ballistic_coefficient: f64,
+    muzzle_velocity: f64,
+    zero_yards: f64,
+) -> Load {
+    Load::builder()
+        .projectile(
+            Projectile::builder()
+                .bullet_weight(BulletWeight(bullet_weight))
+                .bullet_diameter(BulletDiameter(0.308))
+                .length(ProjectileLength(length))
+                .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+                .drag_model(DragModel::G1)
+                .build()
+                .unwrap(),
+        )
+        .muzzle_velocity(Velocity(muzzle_velocity))
+        .rifling_twist(RiflingTwist(10.0 / 0.308))
+        .sight_height(SightHeight(1.5))
+        .zero_range(Distance::from_yards(zero_yards))
+        .atmosphere(Atmosphere::icao())
+        .build()
+        .unwrap()
+}
+
+/// Compares a 168 gr Sierra MatchKing at 2650 ft/s with a 175 gr at 2600 ft/s every 25 yards
+/// out to 1000 yards.
+fn compare(zero_a: f64, zero_b: f64) -> LoadComparison {
+    let distances: Vec<_> = (1..=40)
+        .map(|step| Distance::from_yards(25.0 * step as f64))
+        .collect();
+
+    LoadComparison::generate()
+        .load_a(&load(168.0, 1.21, 0.462, 2650.0, zero_a))
+        .load_b(&load(175.0, 1.24, 0.505, 2600.0, zero_b))
+        .distances(&distances)
+        .solve()
+        .unwrap()
+}
+
+/// Returns the first distance in yards past `from` yards at which a delta changes sign, checking
+/// it keeps the new sign out to the last row.
+fn crossover(
+    comparison: &LoadComparison,
+    from: f64,
+    delta: impl Fn(&LoadComparisonRow) -> f64,
+) -> f64 {
+    let mut rows = comparison
+        .rows()
+        .iter()
+        .filter(|row| row.distance.yards() > from);
+    let sign = delta(rows.next().unwrap()).signum();
+    let first = rows.find(|row| delta(row).signum() != sign).unwrap();
+
+    for row in rows {
+        assert_eq!(delta(row).signum(), -sign, "{}", row.distance.yards());
+    }
+
+    first.distance.yards()
+}
+
+#[test]
+fn heavier_bullet_overtakes_the_faster_one_at_long_range() {
+    let comparison = compare(100.0, 100.0);
+
+    // The 168 gr starts 50 ft/s faster but sheds velocity faster, so the 175 gr is moving
+    // faster from about 300 yards, arrives sooner past about 550 and, beyond the common zero,
+    // drops less past about 675.
+    assert_eq!(
+        crossover(&comparison, 0.0, |row| row.delta.velocity.0),
+        300.0
+    );
+    assert_eq!(
+        crossover(&comparison, 0.0, |row| row.delta.time_of_flight.0),
+        550.0
+    );
+    assert_eq!(crossover(&comparison, 100.0, |row| row.delta.drop.0), 675.0);
+
+    let far = &comparison.rows()[39];
+    assert!(far.delta.velocity.approx_eq(&Velocity(52.0), 0.5));
+    assert!(far.delta.drop.approx_eq(&BulletDrop(10.3), 0.1));
+
+    for row in &comparison {
+        assert!(row.delta.windage.0 < 0.0, "{}", row.distance.yards());
+        assert!(row.delta.energy.0 > 0.0, "{}", row.distance.yards());
+    }
+}
+
+#[test]
+fn deltas_are_load_b_minus_load_a() {
+    for row in &compare(100.0, 100.0) {
+        assert_eq!(row.delta.drop, row.load_b.drop - row.load_a.drop);
+        assert_eq!(row.delta.windage, row.load_b.windage - row.load_a.windage);
+        assert_eq!(
+            row.delta.velocity,
+            row.load_b.velocity - row.load_a.velocity
+        );
+        assert_eq!(row.delta.energy, row.load_b.energy - row.load_a.energy);
+        assert_eq!(
+            row.delta.time_of_flight,
+            row.load_b.time_of_flight - row.load_a.time_of_flight
+        );
+    }
+}
+
+#[test]
+fn each_load_keeps_its_own_zero() {
+    let comparison = compare(100.0, 200.0);
+    let same_zero = compare(100.0, 100.0);
+
+    for (row, same) in comparison.rows().iter().zip(same_zero.rows()) {
+        assert_eq!(row.load_a, same.load_a);
+        assert!(row.load_b.velocity.approx_eq(&same.load_b.velocity, 0.1));
+
+        if row.distance == Distance::from_yards(100.0) {
+            assert!(row.load_a.drop.approx_eq(&BulletDrop(0.0), 0.01));
+            assert!(row.load_b.drop.0 > 1.0, "{}", row.load_b.drop.0);
+        }
+        if row.distance == Distance::from_yards(200.0) {
+            assert!(row.load_b.drop.approx_eq(&BulletDrop(0.0), 0.01));
+        }
+    }
+}
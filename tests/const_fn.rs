@@ -0,0 +1,156 @@
+use ballistics_rs::{
+    AerodynamicJump, Angle, ApertureSightCalibration, ApproxEq, BallisticCoefficient,
+    BulletDiameter, BulletLength, BulletWeight, Distance, DragCoefficient, FormFactor,
+    GyroscopicStability, KineticEnergy, LagTime, Pressure, RiflingTwist, SightCalibration,
+    SightRadius, Temperature, TimeOfFlight, Velocity, WindDeflection, WindSpeed,
+};
+
+const VELOCITIES: [f64; 4] = [2000.0, 2400.0, 2800.0, 3200.0];
+
+// Worked out by the compiler: a regression to a non-const equation fails to build.
+const ENERGIES: [KineticEnergy; 4] = {
+    let mut energies = [KineticEnergy(0.0); 4];
+    let mut i = 0;
+    while i < VELOCITIES.len() {
+        energies[i] = KineticEnergy::calculate_const(BulletWeight(175.0), Velocity(VELOCITIES[i]));
+        i += 1;
+    }
+    energies
+};
+
+const DEFLECTIONS: [WindDeflection; 3] = [
+    WindDeflection::calculate_const(LagTime(0.1), WindSpeed(10.0)),
+    WindDeflection::calculate_const(LagTime(0.2), WindSpeed(10.0)),
+    WindDeflection::calculate_const(LagTime(0.4), WindSpeed(10.0)),
+];
+
+const STABILITY: GyroscopicStability = GyroscopicStability::calculate_const(
+    BulletWeight(175.0),
+    RiflingTwist(10.0 / 0.308),
+    BulletDiameter(0.308),
+    BulletLength(1.24 / 0.308),
+);
+
+#[test]
+fn const_tables_match_the_builders() {
+    for (energy, velocity) in ENERGIES.iter().zip(VELOCITIES) {
+        let expected = KineticEnergy::calculate()
+            .bullet_weight(BulletWeight(175.0))
+            .velocity(Velocity(velocity))
+            .solve();
+
+        assert!(
+            energy.relative_eq(&expected, 1e-12),
+            "{energy:?} at {velocity} ft/s"
+        );
+    }
+
+    for (deflection, lag_time) in DEFLECTIONS.iter().zip([0.1, 0.2, 0.4]) {
+        let expected = WindDeflection::calculate()
+            .lag_time(LagTime(lag_time))
+            .crosswind_speed(WindSpeed(10.0))
+            .solve();
+
+        assert!(deflection.relative_eq(&expected, 1e-12));
+    }
+    assert!(DEFLECTIONS[1].0 > DEFLECTIONS[0].0 && DEFLECTIONS[2].0 > DEFLECTIONS[1].0);
+}
+
+#[test]
+fn const_equations_match_the_builders() {
+    let sight_radius = SightRadius(30.0);
+
+    assert!(
+        ApertureSightCalibration::calculate_const(SightCalibration(0.1), sight_radius).relative_eq(
+            &ApertureSightCalibration::calculate()
+                .sight_movement(SightCalibration(0.1))
+                .sight_radius(sight_radius)
+                .solve(),
+            1e-12
+        )
+    );
+    assert!(
+        SightCalibration::movement_for_correction_const(Angle(1.0), sight_radius).relative_eq(
+            &SightCalibration::movement_for_correction()
+                .correction(Angle(1.0))
+                .sight_radius(sight_radius)
+                .solve(),
+            1e-12
+        )
+    );
+    assert!(SightCalibration::movement_per_click_const(
+        ApertureSightCalibration(0.25),
+        sight_radius
+    )
+    .relative_eq(
+        &SightCalibration::movement_per_click()
+            .click_value(ApertureSightCalibration(0.25))
+            .sight_radius(sight_radius)
+            .solve(),
+        1e-12
+    ));
+    assert!(
+        FormFactor::calculate_const(DragCoefficient(0.3), DragCoefficient(0.5)).relative_eq(
+            &FormFactor::calculate()
+                .drag_coefficient(DragCoefficient(0.3))
+                .standard_bullet_drag_coefficient(DragCoefficient(0.5))
+                .solve(),
+            1e-12
+        )
+    );
+    assert!(
+        LagTime::calculate_const(TimeOfFlight(1.2643), Distance(2400.0), Velocity(2600.0))
+            .relative_eq(
+                &LagTime::calculate()
+                    .actual_time_of_flight(TimeOfFlight(1.2643))
+                    .distance(Distance(2400.0))
+                    .muzzle_velocity(Velocity(2600.0))
+                    .solve(),
+                1e-12
+            )
+    );
+    assert!(
+        AerodynamicJump::calculate_const(GyroscopicStability(1.5), BulletLength(4.0)).relative_eq(
+            &AerodynamicJump::calculate()
+                .gyro_stability(GyroscopicStability(1.5))
+                .bullet_length(BulletLength(4.0))
+                .solve(),
+            1e-12
+        )
+    );
+    assert!(STABILITY.relative_eq(
+        &GyroscopicStability::calculate()
+            .bullet_weight(BulletWeight(175.0))
+            .rifling_twist(RiflingTwist(10.0 / 0.308))
+            .bullet_diameter(BulletDiameter(0.308))
+            .bullet_length(BulletLength(1.24 / 0.308))
+            .solve(),
+        1e-12
+    ));
+    assert!(GyroscopicStability::atmospheric_correction_const(
+        Temperature(85.0),
+        Pressure(25.0),
+        STABILITY
+    )
+    .relative_eq(
+        &GyroscopicStability::atmospheric_correction()
+            .air_temp(Temperature(85.0))
+            .air_pressure(Pressure(25.0))
+            .gyro_stability(STABILITY)
+            .solve(),
+        1e-12
+    ));
+    assert!(BallisticCoefficient::calculate_const(
+        BulletWeight(175.0),
+        BulletDiameter(0.308),
+        FormFactor(0.52)
+    )
+    .relative_eq(
+        &BallisticCoefficient::calculate()
+            .bullet_weight(BulletWeight(175.0))
+            .bullet_diameter(BulletDiameter(0.308))
+            .form_factor(FormFactor(0.52))
+            .solve(),
+        1e-12
+    ));
+}
@@ -0,0 +1,57 @@
+use ballistics_rs::{
+    Angle, ApproxEq, BulletDrop, Correction, Deflection, Distance, SpinDrift, WindDeflection,
+};
+
+#[test]
+fn inches_moa_and_mils_agree_with_the_angle_helpers() {
+    for (inches, distance) in [
+        (1.047, Distance::from_yards(100.0)),
+        (-36.0, Distance::from_yards(500.0)),
+        (250.0, Distance::from_meters(1000.0)),
+    ] {
+        let correction = Correction::new(Deflection(inches), distance);
+        let moa = correction.as_moa();
+
+        assert_eq!(correction.as_inches(), inches);
+        assert_eq!(moa, Angle::from_subtension(inches, distance));
+        assert_eq!(correction.as_mil(), moa.mils());
+        assert!(moa.subtension_at(distance).relative_eq(&inches, 1e-12));
+        assert!(Angle::from_mils(correction.as_mil())
+            .subtension_at(distance)
+            .relative_eq(&inches, 1e-12));
+        assert!(Angle::from_subtension_cm(correction.as_cm(), distance).relative_eq(&moa, 1e-12));
+    }
+}
+
+#[test]
+fn one_moa_and_one_mil_at_100_yards() {
+    let hundred_yards = Distance::from_yards(100.0);
+    let one_moa = Correction::new(
+        Deflection(Angle(1.0).subtension_at(hundred_yards)),
+        hundred_yards,
+    );
+    let one_mil = Correction::new(Deflection(3.6), hundred_yards);
+
+    assert!(one_moa.as_moa().approx_eq(&Angle(1.0), 1e-12));
+    assert!(one_moa.as_mil().approx_eq(&0.290_888, 1e-6));
+    assert!(
+        one_mil.as_mil().approx_eq(&1.0, 1e-5),
+        "{}",
+        one_mil.as_mil()
+    );
+    assert!(one_mil.as_cm().approx_eq(&9.144, 1e-12));
+}
+
+#[test]
+fn corrections_at_the_same_distance_add_up() {
+    let distance = Distance::from_yards(600.0);
+    let drop = BulletDrop(-120.0).correction_at(distance);
+    let windage =
+        WindDeflection(20.0).correction_at(distance) + SpinDrift(5.0).correction_at(distance);
+
+    assert_eq!(drop, Correction::new(Deflection(-120.0), distance));
+    assert_eq!(windage, Correction::new(Deflection(25.0), distance));
+    assert!(windage
+        .as_moa()
+        .relative_eq(&Angle::from_subtension(25.0, distance), 1e-12));
+}
@@ -0,0 +1,98 @@
+use ballistics_rs::{
+    ApproxEq, CustomDragCurve, DragCsvError, DragCurve, DragTableSpeed, MachNumber,
+};
+
+fn read(csv: &str) -> Result<CustomDragCurve, DragCsvError> {
+    CustomDragCurve::from_csv_reader()
+        .reader(csv.as_bytes())
+        .read()
+}
+
+const POINTS: [(f64, f64); 4] = [(0.0, 0.23), (0.9, 0.25), (1.1, 0.45), (3.0, 0.30)];
+
+#[test]
+fn reads_a_well_formed_file() {
+    let curve = read("mach,cd\n0.0,0.23\n0.9,0.25\n1.1,0.45\n3.0,0.30\n").unwrap();
+
+    assert_eq!(curve.points(), POINTS);
+    assert!(curve
+        .drag_coefficient(MachNumber(1.0))
+        .0
+        .approx_eq(&0.35, 1e-12));
+}
+
+#[test]
+fn header_is_optional() {
+    let with_header = read("mach,cd\n0.0,0.23\n0.9,0.25\n1.1,0.45\n3.0,0.30\n").unwrap();
+    let without_header = read("0.0,0.23\n0.9,0.25\n1.1,0.45\n3.0,0.30\n").unwrap();
+
+    assert_eq!(with_header, without_header);
+}
+
+#[test]
+fn skips_a_header_after_blank_lines() {
+    let curve = read("\n  \nmach,cd\n\n0.0,0.23\n0.9,0.25\n1.1,0.45\n3.0,0.30\n").unwrap();
+
+    assert_eq!(curve.points(), POINTS);
+}
+
+#[test]
+fn sorts_and_dedupes_points() {
+    let curve = read("3.0,0.30\n0.9,0.25\n0.0,0.23\n0.9,0.99\n1.1,0.45\n").unwrap();
+
+    assert_eq!(curve.points(), POINTS);
+}
+
+#[test]
+fn reports_the_line_of_a_bad_row() {
+    let error = read("mach,cd\n0.9,0.25\n1.1,n/a\n3.0,0.30\n").unwrap_err();
+
+    assert!(matches!(
+        error,
+        DragCsvError::InvalidRow { line: 3, ref row } if row == "1.1,n/a"
+    ));
+    assert_eq!(
+        error.to_string(),
+        "line 3: expected a speed and a drag coefficient, found `1.1,n/a`"
+    );
+}
+
+#[test]
+fn only_the_first_row_can_be_a_header() {
+    let error = read("\nmach,cd\nspeed,drag\n0.9,0.25\n").unwrap_err();
+    assert!(matches!(error, DragCsvError::InvalidRow { line: 3, .. }));
+
+    let error = read("0.9,0.25\nmach,cd\n1.1,0.45\n").unwrap_err();
+    assert!(matches!(error, DragCsvError::InvalidRow { line: 2, .. }));
+}
+
+#[test]
+fn rejects_negative_and_extra_columns() {
+    let error = read("0.0,0.23\n0.9,-0.25\n").unwrap_err();
+    assert!(matches!(error, DragCsvError::InvalidRow { line: 2, .. }));
+
+    let error = read("0.0,0.23\n0.9,0.25,1\n").unwrap_err();
+    assert!(matches!(error, DragCsvError::InvalidRow { line: 2, .. }));
+}
+
+#[test]
+fn rejects_too_few_points() {
+    assert!(matches!(
+        read("mach,cd\n0.9,0.25\n0.9,0.26\n"),
+        Err(DragCsvError::TooFewPoints(1))
+    ));
+}
+
+#[test]
+fn velocities_match_mach_numbers() {
+    // Each velocity is the Mach number of the same row times 1117.5 ft/s.
+    let from_mach = read("0.0,0.23\n0.9,0.25\n1.1,0.45\n3.0,0.30\n").unwrap();
+    let from_velocity = CustomDragCurve::from_csv_reader()
+        .reader("fps,cd\n0.0,0.23\n1005.75,0.25\n1229.25,0.45\n3352.5,0.30\n".as_bytes())
+        .speed(DragTableSpeed::Velocity)
+        .speed_of_sound(1117.5)
+        .read()
+        .unwrap();
+
+    assert_eq!(from_mach, from_velocity);
+}
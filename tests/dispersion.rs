@@ -0,0 +1,99 @@
+use ballistics_rs::{
+    Angle, ApproxEq, BallisticCoefficient, BallisticsError, Deflection, Distance, Group,
+    SightHeight, TrajectorySolver, Velocity, VerticalDispersion,
+};
+
+// Five shots on the corners and center of a 1" square, 1.5" right and 1.5" low.
+const SHOTS: [(f64, f64); 5] = [
+    (1.0, -2.0),
+    (2.0, -2.0),
+    (1.0, -1.0),
+    (2.0, -1.0),
+    (1.5, -1.5),
+];
+
+fn dispersion_at(yards: f64) -> VerticalDispersion {
+    // A .308 168 gr match load at 2650 ft/s zeroed at 100 yards.
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .sight_height(SightHeight(1.5))
+        .build();
+    let zero_angle = solver
+        .zero_angle()
+        .zero_range(Distance::from_yards(100.0))
+        .solve()
+        .unwrap();
+
+    VerticalDispersion::calculate()
+        .solver(solver.with_launch_angle(zero_angle))
+        .velocity_sd(Velocity(10.0))
+        .distance(Distance::from_yards(yards))
+        .solve()
+        .unwrap()
+}
+
+#[test]
+fn ten_fps_sd_is_negligible_at_100_yards_and_inches_at_1000() {
+    let near = dispersion_at(100.0);
+    let far = dispersion_at(1000.0);
+
+    assert!(near.dispersion.0 < 0.05, "{}", near.dispersion);
+    assert!((3.0..5.0).contains(&far.dispersion.0), "{}", far.dispersion);
+    assert!(far.drop_per_velocity > 0.0);
+    assert!(far
+        .dispersion
+        .relative_eq(&Deflection(far.drop_per_velocity * 10.0), 1e-12));
+}
+
+#[test]
+fn group_statistics_of_a_five_shot_group() {
+    let group = Group::new(&SHOTS).unwrap();
+    let half_diagonal = 0.5_f64.sqrt();
+
+    assert!(group.extreme_spread().approx_eq(&2.0_f64.sqrt(), 1e-12));
+    assert!(group
+        .mean_radius()
+        .approx_eq(&(4.0 * half_diagonal / 5.0), 1e-12));
+
+    let (x, y) = group.center();
+    assert!(x.approx_eq(&1.5, 1e-12) && y.approx_eq(&-1.5, 1e-12));
+
+    // Four deviations of 0.5" and one of 0 over n - 1 = 4.
+    let (sd_x, sd_y) = group.standard_deviation();
+    assert!(sd_x.approx_eq(&0.5, 1e-12) && sd_y.approx_eq(&0.5, 1e-12));
+}
+
+#[test]
+fn group_statistics_convert_to_moa() {
+    let distance = Distance::from_yards(100.0);
+    let angles = Group::new(&SHOTS).unwrap().angles(distance);
+
+    assert!(angles.extreme_spread.approx_eq(&Angle(1.350_474), 1e-6));
+    assert!(angles
+        .center
+        .0
+        .relative_eq(&Angle::from_subtension(1.5, distance), 1e-12));
+    assert!(angles.center.1 .0 < 0.0);
+    assert!(angles
+        .standard_deviation
+        .1
+        .relative_eq(&Angle::from_subtension(0.5, distance), 1e-12));
+}
+
+#[test]
+fn group_needs_two_shots_and_tolerates_duplicates() {
+    assert!(matches!(
+        Group::new(&[(1.0, 1.0)]),
+        Err(BallisticsError::TooFewShots(1))
+    ));
+
+    let duplicates = Group::new(&[(1.0, 1.0), (1.0, 1.0)]).unwrap();
+    assert_eq!(duplicates.extreme_spread(), 0.0);
+    assert_eq!(duplicates.mean_radius(), 0.0);
+    assert_eq!(duplicates.standard_deviation(), (0.0, 0.0));
+
+    let collinear = Group::new(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]).unwrap();
+    assert_eq!(collinear.extreme_spread(), 2.0);
+    assert_eq!(collinear.standard_deviation(), (1.0, 0.0));
+}
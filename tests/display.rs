@@ -0,0 +1,98 @@
+use ballistics_rs::{
+    Angle, BallisticCoefficient, BulletDrop, Distance, KineticEnergy, QuantityFormatter, SpinDrift,
+    Temperature, Velocity, WindDeflection,
+};
+
+#[test]
+fn displays_the_shortest_exact_value_with_the_unit() {
+    assert_eq!(Velocity(2800.0).to_string(), "2800.0 ft/s");
+    assert_eq!(Temperature(59.0).to_string(), "59.0 °F");
+    assert_eq!(BallisticCoefficient(0.505).to_string(), "0.505 (BC)");
+    assert_eq!(WindDeflection(-3.25).to_string(), "-3.25 in");
+}
+
+#[test]
+fn precision_sets_the_decimals() {
+    assert_eq!(format!("{:.1}", Velocity(2800.04)), "2800.0 ft/s");
+    assert_eq!(format!("{:.0}", KineticEnergy(2887.6)), "2888 ft-lb");
+    assert_eq!(format!("{:.2}", Angle(1.047)), "1.05 MOA");
+    assert_eq!(format!("{:.3}", BulletDrop(-12.3456)), "-12.346 in");
+}
+
+#[test]
+fn width_pads_the_value_and_unit() {
+    assert_eq!(format!("{:>12}", Velocity(2800.0)), " 2800.0 ft/s");
+    assert_eq!(format!("{:12}", Velocity(2800.0)), " 2800.0 ft/s");
+    assert_eq!(format!("{:<8}", Temperature(59.0)), "59.0 °F ");
+    assert_eq!(format!("{:*^13.1}", Velocity(2800.0)), "*2800.0 ft/s*");
+}
+
+#[test]
+fn formatter_keeps_decimal_places() {
+    assert_eq!(
+        Velocity(2799.6)
+            .format_with(QuantityFormatter::decimals(0))
+            .to_string(),
+        "2800 ft/s"
+    );
+    assert_eq!(
+        Angle(1.0456)
+            .format_with(QuantityFormatter::decimals(2))
+            .to_string(),
+        "1.05 MOA"
+    );
+    assert_eq!(
+        BulletDrop(-12.3456)
+            .format_with(QuantityFormatter::decimals(3).without_unit())
+            .to_string(),
+        "-12.346"
+    );
+    assert_eq!(
+        Velocity(2800.0)
+            .format_with(QuantityFormatter::default())
+            .to_string(),
+        "2800.00 ft/s"
+    );
+}
+
+#[test]
+fn formatter_keeps_significant_figures() {
+    let three = QuantityFormatter::significant_figures(3);
+
+    assert_eq!(
+        KineticEnergy(2887.6).format_with(three).to_string(),
+        "2890 ft-lb"
+    );
+    assert_eq!(
+        BallisticCoefficient(0.50549).format_with(three).to_string(),
+        "0.505 (BC)"
+    );
+    assert_eq!(SpinDrift(9.996).format_with(three).to_string(), "10.0 in");
+    assert_eq!(Distance(0.0).format_with(three).to_string(), "0 ft");
+    assert_eq!(
+        Velocity(2849.0)
+            .format_with(QuantityFormatter::significant_figures(0))
+            .to_string(),
+        "3000 ft/s"
+    );
+}
+
+#[test]
+fn formatter_formats_bare_values_and_other_units() {
+    let formatter = QuantityFormatter::decimals(1);
+
+    assert_eq!(formatter.format_value(12.34).to_string(), "12.3");
+    assert_eq!(formatter.format(2.0, "mil").to_string(), "2.0 mil");
+    assert_eq!(
+        formatter.without_unit().format(2.0, "mil").to_string(),
+        "2.0"
+    );
+    assert_eq!(
+        format!("{:>10}", formatter.format(2.0, "mil")),
+        "   2.0 mil"
+    );
+    assert_eq!(
+        format!("{:<10}|", formatter.format_value(2.0)),
+        "2.0       |"
+    );
+}
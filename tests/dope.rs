@@ -0,0 +1,164 @@
+use ballistics_rs::{
+    AngularUnit, ApproxEq, Atmosphere, BallisticCoefficient, BallisticsError, BulletDiameter,
+    BulletWeight, ClickValue, ComeUpMode, ComeUps, Distance, DopeCard, DragModel, Load, Projectile,
+    ProjectileLength, RiflingTwist, SightHeight, Velocity,
+};
+
+/// A .308 Winchester 175 gr Sierra MatchKing at 2600 ft/s, zeroed at 100 yards in the ICAO
+/// atmosphere.
+fn load() -> Load {
+    Load::builder()
+        .projectile(
+            Projectile::builder()
+                .bullet_weight(BulletWeight(175.0))
+                .bullet_diameter(BulletDiameter(0.308))
+                .length(ProjectileLength(1.24))
+                .ballistic_coefficient(BallisticCoefficient(0.505))
+                .drag_model(DragModel::G1)
+                .build()
+                .unwrap(),
+        )
+        .muzzle_velocity(Velocity(2600.0))
+        .rifling_twist(RiflingTwist(10.0 / 0.308))
+        .sight_height(SightHeight(1.5))
+        .zero_range(Distance::from_yards(100.0))
+        .atmosphere(Atmosphere::icao())
+        .build()
+        .unwrap()
+}
+
+// Yards, elevation MOA, windage MOA in a 10 mph crosswind, velocity ft/s, energy ft-lb and
+// time of flight s, from an independent point-mass G1 integration of the same load. The card
+// must agree within 0.05 MOA, 1 ft/s, 2 ft-lb and 1 ms.
+const GOLDEN: [(f64, f64, f64, f64, f64, f64); 10] = [
+    (100.0, 0.000, 0.689, 2424.8, 2284.3, 0.1195),
+    (200.0, 2.111, 1.426, 2256.4, 1978.0, 0.2477),
+    (300.0, 4.992, 2.218, 2094.6, 1704.5, 0.3857),
+    (400.0, 8.328, 3.069, 1939.7, 1461.8, 0.5346),
+    (500.0, 12.098, 3.986, 1792.2, 1247.9, 0.6955),
+    (600.0, 16.337, 4.973, 1652.9, 1061.5, 0.8698),
+    (700.0, 21.104, 6.033, 1523.0, 901.1, 1.0590),
+    (800.0, 26.472, 7.168, 1403.7, 765.5, 1.2643),
+    (900.0, 32.522, 8.373, 1296.8, 653.4, 1.4868),
+    (1000.0, 39.338, 9.636, 1204.2, 563.3, 1.7272),
+];
+
+#[test]
+fn card_matches_golden_values() {
+    let card = DopeCard::over_range()
+        .load(&load())
+        .start(Distance::from_yards(100.0))
+        .end(Distance::from_yards(1000.0))
+        .step(Distance::from_yards(100.0))
+        .click(ClickValue::QuarterMoa)
+        .solve()
+        .unwrap();
+
+    assert_eq!(card.rows().len(), GOLDEN.len());
+
+    for (row, (yards, elevation, windage, velocity, energy, time_of_flight)) in
+        card.rows().iter().zip(GOLDEN)
+    {
+        assert!(row.distance.yards().approx_eq(&yards, 1e-9));
+        assert!(
+            row.elevation.0.approx_eq(&elevation, 0.05),
+            "elevation at {yards} yd"
+        );
+        assert!(
+            row.windage.0.approx_eq(&windage, 0.05),
+            "windage at {yards} yd"
+        );
+        assert!(
+            row.velocity.0.approx_eq(&velocity, 1.0),
+            "velocity at {yards} yd"
+        );
+        assert!(row.energy.0.approx_eq(&energy, 2.0), "energy at {yards} yd");
+        assert!(
+            row.time_of_flight.0.approx_eq(&time_of_flight, 1e-3),
+            "time of flight at {yards} yd"
+        );
+
+        // Whole clicks are within half a click of the exact correction.
+        assert!((row.elevation_clicks as f64 * 0.25).approx_eq(&row.elevation.0, 0.125));
+        assert!((row.windage_clicks as f64 * 0.25).approx_eq(&row.windage.0, 0.125));
+    }
+}
+
+#[test]
+fn list_and_range_agree() {
+    let distances: Vec<_> = (1..=10)
+        .map(|step| Distance::from_yards(100.0 * step as f64))
+        .collect();
+    let listed = DopeCard::generate()
+        .load(&load())
+        .distances(&distances)
+        .click(ClickValue::QuarterMoa)
+        .solve()
+        .unwrap();
+    let ranged = DopeCard::over_range()
+        .load(&load())
+        .start(Distance::from_yards(100.0))
+        .end(Distance::from_yards(1000.0))
+        .step(Distance::from_yards(100.0))
+        .click(ClickValue::QuarterMoa)
+        .solve()
+        .unwrap();
+
+    assert_eq!(listed, ranged);
+}
+
+#[test]
+fn rejects_distances_out_of_order() {
+    let result = DopeCard::generate()
+        .load(&load())
+        .distances(&[Distance::from_yards(300.0), Distance::from_yards(200.0)])
+        .click(ClickValue::QuarterMoa)
+        .solve();
+
+    assert!(matches!(result, Err(BallisticsError::OutOfRange { .. })));
+}
+
+fn come_ups(mode: ComeUpMode) -> ComeUps {
+    ComeUps::generate()
+        .load(&load())
+        .start(Distance::from_yards(100.0))
+        .end(Distance::from_yards(1000.0))
+        .step(Distance::from_yards(100.0))
+        .angular_unit(AngularUnit::Mil)
+        .mode(mode)
+        .solve()
+        .unwrap()
+}
+
+#[test]
+fn come_up_deltas_sum_to_the_absolute_elevations() {
+    let absolute = come_ups(ComeUpMode::Absolute);
+    let delta = come_ups(ComeUpMode::Delta);
+    let mut total = 0.0;
+
+    assert_eq!(absolute.entries().len(), 10);
+
+    for ((distance, elevation), (delta_distance, step)) in absolute.values().zip(delta.values()) {
+        total += step;
+
+        assert_eq!(distance, delta_distance);
+        assert!(total.approx_eq(&elevation, 1e-12), "{total} at {distance}");
+    }
+
+    // The first delta is measured from the bore line, so it equals the absolute come-up.
+    assert_eq!(delta.entries()[0], absolute.entries()[0]);
+}
+
+#[test]
+fn come_ups_match_the_golden_elevations() {
+    let come_ups = come_ups(ComeUpMode::Absolute);
+
+    for (&(distance, elevation), &(yards, moa, ..)) in come_ups.entries().iter().zip(&GOLDEN) {
+        assert_eq!(distance, Distance::from_yards(yards));
+        assert!(
+            elevation.0.approx_eq(&moa, 0.05),
+            "{} at {yards} yd",
+            elevation.0
+        );
+    }
+}
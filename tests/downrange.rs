@@ -0,0 +1,231 @@
+use ballistics_rs::{
+    Altitude, ApproxEq, Atmosphere, BallisticCoefficient, BallisticsError, BulletWeight,
+    DensityRatio, Distance, DragModel, KineticEnergy, MinimumThreshold, StopCondition,
+    SupersonicRange, Temperature, ThresholdDistance, TrajectorySolver, Velocity,
+};
+
+// A .308 168 gr match load, 2650 ft/s with a G1 BC of 0.462.
+fn energy_at(distance: Distance) -> KineticEnergy {
+    KineticEnergy::at_range()
+        .bullet_weight(BulletWeight(168.0))
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .distance(distance)
+        .solve()
+        .unwrap()
+}
+
+fn remaining_velocity(
+    muzzle_velocity: Velocity,
+    ballistic_coefficient: BallisticCoefficient,
+    distance: Distance,
+) -> Velocity {
+    TrajectorySolver::builder()
+        .muzzle_velocity(muzzle_velocity)
+        .ballistic_coefficient(ballistic_coefficient)
+        .max_range(distance)
+        .stop_condition(StopCondition::MaxRange)
+        .build()
+        .point_at(distance)
+        .unwrap()
+        .velocity
+}
+
+fn threshold_distance(
+    threshold: MinimumThreshold,
+    max_range: Distance,
+) -> Result<ThresholdDistance, BallisticsError> {
+    ThresholdDistance::calculate()
+        .bullet_weight(BulletWeight(168.0))
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .threshold(threshold)
+        .max_range(max_range)
+        .solve()
+}
+
+fn corrected_muzzle_velocity(
+    instrumental_velocity: Velocity,
+    ballistic_coefficient: BallisticCoefficient,
+) -> Velocity {
+    Velocity::muzzle_from_instrumental()
+        .instrumental_velocity(instrumental_velocity)
+        .distance(Distance(15.0))
+        .ballistic_coefficient(ballistic_coefficient)
+        .solve()
+        .unwrap()
+}
+
+fn energy_at_500_yards(atmosphere: Option<Atmosphere>) -> KineticEnergy {
+    KineticEnergy::at_range()
+        .bullet_weight(BulletWeight(168.0))
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .distance(Distance::from_yards(500.0))
+        .maybe_atmosphere(atmosphere)
+        .solve()
+        .unwrap()
+}
+
+fn supersonic_range(atmosphere: Option<Atmosphere>) -> SupersonicRange {
+    SupersonicRange::calculate()
+        .muzzle_velocity(Velocity(2700.0))
+        .ballistic_coefficient(BallisticCoefficient(0.3))
+        .drag_model(DragModel::G7)
+        .maybe_atmosphere(atmosphere)
+        .solve()
+        .unwrap()
+}
+
+#[test]
+fn icao_default_leaves_the_air_uncorrected() {
+    assert_eq!(Atmosphere::default(), Atmosphere::icao());
+    assert_eq!(Atmosphere::default().density_ratio(), DensityRatio(1.0));
+
+    let icao = Some(Atmosphere::icao());
+    assert_eq!(energy_at_500_yards(None), energy_at_500_yards(icao));
+    assert_eq!(supersonic_range(None), supersonic_range(icao));
+
+    let threshold = |atmosphere: Option<Atmosphere>| {
+        ThresholdDistance::calculate()
+            .bullet_weight(BulletWeight(168.0))
+            .muzzle_velocity(Velocity(2650.0))
+            .ballistic_coefficient(BallisticCoefficient(0.462))
+            .threshold(MinimumThreshold::Velocity(Velocity(1800.0)))
+            .maybe_atmosphere(atmosphere)
+            .solve()
+            .unwrap()
+    };
+    assert_eq!(threshold(None), threshold(icao));
+
+    let muzzle_velocity = |atmosphere: Option<Atmosphere>| {
+        Velocity::muzzle_from_instrumental()
+            .instrumental_velocity(Velocity(2620.0))
+            .distance(Distance(15.0))
+            .ballistic_coefficient(BallisticCoefficient(0.462))
+            .maybe_atmosphere(atmosphere)
+            .solve()
+            .unwrap()
+    };
+    assert_eq!(muzzle_velocity(None), muzzle_velocity(icao));
+}
+
+#[test]
+fn thin_air_carries_the_bullet_farther() {
+    let mountain = Some(
+        Atmosphere::builder()
+            .temperature(Temperature(85.0))
+            .pressure(Atmosphere::standard_at(Altitude(5000.0)).pressure)
+            .build(),
+    );
+
+    assert!(energy_at_500_yards(mountain).0 > energy_at_500_yards(None).0);
+    assert!(
+        supersonic_range(mountain).supersonic_range.0 > supersonic_range(None).supersonic_range.0
+    );
+}
+
+#[test]
+fn energy_at_the_muzzle_is_the_muzzle_energy() {
+    let muzzle_energy = KineticEnergy::calculate()
+        .bullet_weight(BulletWeight(168.0))
+        .velocity(Velocity(2650.0))
+        .solve();
+
+    assert!(energy_at(Distance(0.0)).relative_eq(&muzzle_energy, 1e-12));
+}
+
+#[test]
+fn energy_decays_monotonically_with_distance() {
+    let mut previous = energy_at(Distance(0.0));
+
+    for yards in (50..=1000).step_by(50) {
+        let energy = energy_at(Distance::from_yards(f64::from(yards)));
+
+        assert!(energy.0 < previous.0, "{energy} at {yards} yd");
+        previous = energy;
+    }
+}
+
+#[test]
+fn threshold_distance_is_where_the_minimum_is_reached() {
+    let max_range = Distance::from_yards(1000.0);
+    let velocity =
+        threshold_distance(MinimumThreshold::Velocity(Velocity(1800.0)), max_range).unwrap();
+    let at_threshold = remaining_velocity(
+        Velocity(2650.0),
+        BallisticCoefficient(0.462),
+        Distance(velocity.0),
+    );
+
+    // About 478 yards for expansion at 1800 ft/s.
+    assert!(
+        at_threshold.approx_eq(&Velocity(1800.0), 0.1),
+        "{at_threshold}"
+    );
+    assert!((470.0..490.0).contains(&(velocity.0 / 3.0)));
+
+    let energy =
+        threshold_distance(MinimumThreshold::Energy(KineticEnergy(1000.0)), max_range).unwrap();
+
+    assert!(energy_at(Distance(energy.0)).approx_eq(&KineticEnergy(1000.0), 0.5));
+    assert!(energy.0 > velocity.0);
+}
+
+#[test]
+fn threshold_distance_is_zero_or_beyond_max_range_at_the_limits() {
+    let max_range = Distance::from_yards(1000.0);
+
+    assert_eq!(
+        threshold_distance(MinimumThreshold::Velocity(Velocity(2700.0)), max_range).unwrap(),
+        ThresholdDistance(0.0)
+    );
+    assert!(matches!(
+        threshold_distance(
+            MinimumThreshold::Velocity(Velocity(1800.0)),
+            Distance::from_yards(300.0)
+        ),
+        Err(BallisticsError::BeyondMaxRange(range)) if range == Distance::from_yards(300.0)
+    ));
+}
+
+#[test]
+fn a_0_3_g7_bullet_at_2700_fps_is_supersonic_to_about_1300_yards() {
+    let range = supersonic_range(None);
+    let yards = range.supersonic_range.yards();
+
+    assert!(yards.approx_eq(&1300.0, 65.0), "{yards}");
+    assert!(range.transonic_range.0 < range.supersonic_range.0);
+}
+
+#[test]
+fn a_15_foot_chronograph_reads_5_to_15_fps_low() {
+    for (instrumental_velocity, ballistic_coefficient) in [(2620.0, 0.462), (2400.0, 0.5)] {
+        let muzzle_velocity = corrected_muzzle_velocity(
+            Velocity(instrumental_velocity),
+            BallisticCoefficient(ballistic_coefficient),
+        );
+        let correction = muzzle_velocity.0 - instrumental_velocity;
+
+        assert!((5.0..15.0).contains(&correction), "{correction}");
+    }
+}
+
+#[test]
+fn muzzle_velocity_round_trips_through_the_remaining_velocity() {
+    let ballistic_coefficient = BallisticCoefficient(0.462);
+    let corrected = corrected_muzzle_velocity(Velocity(2620.0), ballistic_coefficient);
+
+    assert!(
+        remaining_velocity(corrected, ballistic_coefficient, Distance(15.0))
+            .approx_eq(&Velocity(2620.0), 1e-5)
+    );
+
+    let instrumental_velocity =
+        remaining_velocity(Velocity(2650.0), ballistic_coefficient, Distance(15.0));
+
+    assert!(
+        corrected_muzzle_velocity(instrumental_velocity, ballistic_coefficient)
+            .approx_eq(&Velocity(2650.0), 1e-5)
+    );
+}
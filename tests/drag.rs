@@ -0,0 +1,151 @@
+use ballistics_rs::{
+    ApproxEq, BallisticCoefficient, BallisticsError, Distance, DragCurve, DragModel, Interpolation,
+    MachNumber, PreparedDrag, TrajectorySolver, Velocity,
+};
+
+/// Returns the largest difference between two drag curves over Mach 0 to 5.
+fn largest_difference(exact: impl DragCurve, prepared: &PreparedDrag) -> f64 {
+    (0..100_000)
+        .map(|i| MachNumber((i as f64 * 0.618_033_988_75).fract() * 5.0))
+        .map(|mach| (exact.drag_coefficient(mach).0 - prepared.drag_coefficient(mach).0).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn monotone_cubic_passes_through_every_knot() {
+    let spline = Interpolation::MonotoneCubic;
+
+    for model in [DragModel::G1, DragModel::G7] {
+        for &(mach, drag_coefficient) in model.table() {
+            assert_eq!(
+                spline.drag_coefficient(model.table(), MachNumber(mach)).0,
+                drag_coefficient,
+                "Mach {mach}"
+            );
+        }
+    }
+}
+
+#[test]
+fn monotone_cubic_stays_within_neighbouring_knots() {
+    let spline = Interpolation::MonotoneCubic;
+
+    for model in [DragModel::G1, DragModel::G7] {
+        for pair in model.table().windows(2) {
+            let ((m0, cd0), (m1, cd1)) = (pair[0], pair[1]);
+            // Allowing for rounding where the table is flat.
+            let (low, high) = (cd0.min(cd1) - 1e-12, cd0.max(cd1) + 1e-12);
+
+            for i in 1..100 {
+                let mach = m0 + (m1 - m0) * i as f64 / 100.0;
+                let drag_coefficient = spline.drag_coefficient(model.table(), MachNumber(mach)).0;
+
+                assert!(
+                    (low..=high).contains(&drag_coefficient),
+                    "{drag_coefficient} at Mach {mach}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn prepared_matches_the_linear_table() {
+    for model in [DragModel::G1, DragModel::G7] {
+        assert!(largest_difference(model, &PreparedDrag::from(model)) < 1e-9);
+    }
+}
+
+#[test]
+fn prepared_follows_the_monotone_cubic_table() {
+    let drag = DragModel::G7.interpolated(Interpolation::MonotoneCubic);
+
+    // Between the resampled Mach numbers the curve is linear, so the difference is bounded by
+    // the curvature of the cubic in the transonic region.
+    assert!(largest_difference(drag, &PreparedDrag::from(drag)) < 5e-4);
+}
+
+#[test]
+fn prepared_falls_back_beyond_max_mach() {
+    let table = [
+        (0.0, 0.23),
+        (0.9, 0.25),
+        (1.1, 0.45),
+        (3.0, 0.30),
+        (4.0, 0.26),
+    ];
+    let prepared = PreparedDrag::builder()
+        .table(&table)
+        .max_mach(MachNumber(3.0))
+        .resolution(0.01)
+        .build()
+        .unwrap();
+
+    assert!(prepared
+        .drag_coefficient(MachNumber(1.0))
+        .0
+        .approx_eq(&0.35, 1e-9));
+    assert!(prepared
+        .drag_coefficient(MachNumber(3.5))
+        .0
+        .approx_eq(&0.28, 1e-9));
+}
+
+#[test]
+fn prepared_trajectory_matches_the_table() {
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2800.0))
+        .ballistic_coefficient(BallisticCoefficient(0.243))
+        .drag_model(DragModel::G7)
+        .build();
+    let distance = Distance::from_yards(1000.0);
+    let exact = solver.point_at(distance).unwrap();
+    let prepared = solver
+        .point_at_with(distance, PreparedDrag::from(DragModel::G7))
+        .unwrap();
+
+    assert!(exact.drop.0.approx_eq(&prepared.drop.0, 1e-3));
+    assert!(exact.velocity.0.approx_eq(&prepared.velocity.0, 1e-3));
+}
+
+#[test]
+fn rejects_non_positive_resolution_and_range() {
+    for resolution in [0.0, -0.01] {
+        let result = PreparedDrag::builder()
+            .table(DragModel::G1.table())
+            .resolution(resolution)
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(BallisticsError::OutOfRange {
+                param: "resolution",
+                ..
+            })
+        ));
+    }
+
+    let result = PreparedDrag::builder()
+        .table(DragModel::G1.table())
+        .resolution(f64::NAN)
+        .build();
+    assert!(matches!(
+        result,
+        Err(BallisticsError::NonFinite {
+            param: "resolution",
+            ..
+        })
+    ));
+
+    let result = PreparedDrag::builder()
+        .table(DragModel::G1.table())
+        .max_mach(MachNumber(0.0))
+        .build();
+    assert!(matches!(
+        result,
+        Err(BallisticsError::OutOfRange {
+            param: "max_mach",
+            ..
+        })
+    ));
+}
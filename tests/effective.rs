@@ -0,0 +1,93 @@
+use ballistics_rs::{
+    BallisticCoefficient, BulletDiameter, BulletWeight, Distance, EffectiveRange, KineticEnergy,
+    LimitingFactor, Projectile, ProjectileLength, RiflingTwist, Velocity,
+};
+
+fn projectile(
+    bullet_weight: f64,
+    bullet_diameter: f64,
+    length: f64,
+    ballistic_coefficient: f64,
+) -> Projectile {
+    Projectile::builder()
+        .bullet_weight(BulletWeight(bullet_weight))
+        .bullet_diameter(BulletDiameter(bullet_diameter))
+        .length(ProjectileLength(length))
+        .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+        .build()
+        .unwrap()
+}
+
+/// A .308 175 gr Sierra MatchKing at 2600 ft/s.
+fn match_king(
+    twist: f64,
+    expansion_velocity: f64,
+    energy_floor: f64,
+    max_yards: f64,
+) -> EffectiveRange {
+    EffectiveRange::calculate()
+        .projectile(projectile(175.0, 0.308, 1.24, 0.505))
+        .rifling_twist(RiflingTwist(twist / 0.308))
+        .muzzle_velocity(Velocity(2600.0))
+        .expansion_velocity(Velocity(expansion_velocity))
+        .energy_floor(KineticEnergy(energy_floor))
+        .max_range(Distance::from_yards(max_yards))
+        .solve()
+        .unwrap()
+}
+
+#[test]
+fn slow_twist_limits_by_stability() {
+    let report = match_king(18.0, 1800.0, 1000.0, 1000.0);
+
+    assert!(report.gyro_stability.0 < 1.0, "{}", report.gyro_stability.0);
+    assert_eq!(report.limiting_factor, LimitingFactor::Stability);
+    assert_eq!(report.range, Distance(0.0));
+}
+
+#[test]
+fn low_thresholds_limit_by_supersonic_range() {
+    let report = match_king(10.0, 1000.0, 100.0, 2000.0);
+
+    assert_eq!(report.limiting_factor, LimitingFactor::SupersonicRange);
+    assert_eq!(Some(report.range), report.supersonic_range);
+    assert!((1050.0..1200.0).contains(&report.range.yards()));
+}
+
+#[test]
+fn default_expansion_velocity_limits_a_heavy_bullet() {
+    let report = match_king(10.0, 1800.0, 1000.0, 2000.0);
+
+    assert_eq!(report.limiting_factor, LimitingFactor::ExpansionVelocity);
+    assert_eq!(Some(report.range), report.expansion_range);
+    assert!(report.range.0 < report.energy_range.unwrap().0);
+    assert!(report.range.0 < report.supersonic_range.unwrap().0);
+}
+
+#[test]
+fn energy_floor_limits_a_light_bullet() {
+    // A .223 55 gr at 3240 ft/s carries only about 1280 ft-lb from the muzzle, and
+    // falls below 1000 ft-lb short of 100 yards.
+    let report = EffectiveRange::calculate()
+        .projectile(projectile(55.0, 0.224, 0.75, 0.25))
+        .rifling_twist(RiflingTwist(12.0 / 0.224))
+        .muzzle_velocity(Velocity(3240.0))
+        .max_range(Distance::from_yards(2000.0))
+        .solve()
+        .unwrap();
+
+    assert_eq!(report.limiting_factor, LimitingFactor::Energy);
+    assert_eq!(Some(report.range), report.energy_range);
+    assert!((80.0..110.0).contains(&report.range.yards()));
+}
+
+#[test]
+fn short_max_range_limits_when_nothing_else_does() {
+    let report = match_king(10.0, 1800.0, 1000.0, 300.0);
+
+    assert_eq!(report.limiting_factor, LimitingFactor::MaxRange);
+    assert_eq!(report.range, Distance::from_yards(300.0));
+    assert_eq!(report.supersonic_range, None);
+    assert_eq!(report.expansion_range, None);
+    assert_eq!(report.energy_range, None);
+}
@@ -0,0 +1,148 @@
+use ballistics_rs::{
+    ApproxEq, BallisticsError, BulletWeight, Gravity, KineticEnergy, PayloadEnergy, Velocity,
+    STANDARD_GRAVITY,
+};
+
+fn energy(bullet_weight: f64, velocity: f64) -> KineticEnergy {
+    KineticEnergy::calculate()
+        .bullet_weight(BulletWeight(bullet_weight))
+        .velocity(Velocity(velocity))
+        .solve()
+}
+
+fn out_of_range<T>(result: Result<T, BallisticsError>, expected: &str) -> bool {
+    matches!(result, Err(BallisticsError::OutOfRange { param, .. }) if param == expected)
+}
+
+#[test]
+fn energy_divisor_comes_from_standard_gravity() {
+    assert_eq!(2.0 * 7000.0 * STANDARD_GRAVITY.0, 450_436.0);
+
+    // A 175 gr bullet at 2600 ft/s.
+    let energy = energy(175.0, 2600.0);
+
+    assert!(energy.relative_eq(&KineticEnergy(175.0 * 2600.0 * 2600.0 / 450_436.0), 1e-12));
+    assert!(
+        energy.approx_eq(&KineticEnergy(2626.344), 1e-3),
+        "{}",
+        energy.0
+    );
+}
+
+#[test]
+fn energy_with_the_older_32_2_convention_is_0_08_percent_lower() {
+    for (bullet_weight, velocity) in [(55.0, 3240.0), (175.0, 2600.0), (300.0, 2700.0)] {
+        let standard = energy(bullet_weight, velocity);
+        let older = KineticEnergy::calculate()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .velocity(Velocity(velocity))
+            .gravity(Gravity(32.2))
+            .solve();
+        let difference = (standard.0 - older.0) / standard.0;
+
+        assert!(older.relative_eq(
+            &KineticEnergy(bullet_weight * velocity * velocity / 450_800.0),
+            1e-12
+        ));
+        assert!(difference.approx_eq(&(1.0 - 450_436.0 / 450_800.0), 1e-12));
+        assert!(difference.approx_eq(&0.000_807, 1e-6), "{difference}");
+    }
+}
+
+#[test]
+fn velocity_for_energy_round_trips() {
+    // 1000 ft-lb at the muzzle with a 150 gr bullet needs about 1733 ft/s.
+    for (target, bullet_weight) in [(1000.0, 150.0), (2626.344, 175.0), (4000.0, 300.0)] {
+        let velocity = Velocity::for_energy()
+            .energy(KineticEnergy(target))
+            .bullet_weight(BulletWeight(bullet_weight))
+            .solve()
+            .unwrap();
+
+        // Exact to the last bit or two of rounding.
+        assert!(energy(bullet_weight, velocity.0).relative_eq(&KineticEnergy(target), 1e-15));
+    }
+
+    let velocity = Velocity::for_energy()
+        .energy(KineticEnergy(1000.0))
+        .bullet_weight(BulletWeight(150.0))
+        .solve()
+        .unwrap();
+    assert!(velocity.approx_eq(&Velocity(1732.9), 0.1), "{}", velocity.0);
+}
+
+#[test]
+fn bullet_weight_for_energy_round_trips() {
+    for (target, velocity) in [(1000.0, 1732.9), (2626.344, 2600.0), (4000.0, 2700.0)] {
+        let bullet_weight = BulletWeight::for_energy()
+            .energy(KineticEnergy(target))
+            .velocity(Velocity(velocity))
+            .solve()
+            .unwrap();
+
+        assert!(energy(bullet_weight.0, velocity).relative_eq(&KineticEnergy(target), 1e-15));
+    }
+}
+
+#[test]
+fn for_energy_rejects_zero_and_negative_targets() {
+    for target in [0.0, -1000.0] {
+        assert!(out_of_range(
+            Velocity::for_energy()
+                .energy(KineticEnergy(target))
+                .bullet_weight(BulletWeight(150.0))
+                .solve(),
+            "energy"
+        ));
+        assert!(out_of_range(
+            BulletWeight::for_energy()
+                .energy(KineticEnergy(target))
+                .velocity(Velocity(2600.0))
+                .solve(),
+            "energy"
+        ));
+    }
+}
+
+#[test]
+fn nine_pellets_of_00_buck_carry_about_1890_ft_lb() {
+    // 9 pellets of 53.8 gr 00 buck at 1325 ft/s; published figures are about 210 ft-lb per
+    // pellet and 1890 ft-lb in total.
+    let payload = PayloadEnergy::calculate()
+        .pellet_weight(BulletWeight(53.8))
+        .pellet_count(9)
+        .velocity(Velocity(1325.0))
+        .solve()
+        .unwrap();
+
+    assert_eq!(payload.per_pellet, energy(53.8, 1325.0));
+    assert!(payload.per_pellet.approx_eq(&KineticEnergy(209.69), 0.01));
+    assert!(payload.total.approx_eq(&KineticEnergy(1887.22), 0.01));
+    assert!(payload.total.relative_eq(&KineticEnergy(1890.0), 2e-3));
+    assert!(payload.payload_weight.approx_eq(&BulletWeight(484.2), 1e-9));
+}
+
+#[test]
+fn payload_of_one_pellet_is_a_single_projectile() {
+    let payload = PayloadEnergy::calculate()
+        .pellet_weight(BulletWeight(175.0))
+        .pellet_count(1)
+        .velocity(Velocity(2600.0))
+        .solve()
+        .unwrap();
+
+    assert_eq!(payload.per_pellet, payload.total);
+    assert_eq!(payload.total, energy(175.0, 2600.0));
+    assert_eq!(payload.payload_weight, BulletWeight(175.0));
+}
+
+#[test]
+fn payload_rejects_zero_pellets() {
+    let result = PayloadEnergy::calculate()
+        .pellet_weight(BulletWeight(53.8))
+        .pellet_count(0)
+        .velocity(Velocity(1325.0))
+        .solve();
+
+    assert!(out_of_range(result, "pellet_count"));
+}
@@ -0,0 +1,128 @@
+use ballistics_rs::{
+    ApproxEq, BallisticCoefficient, BallisticsError, BulletDiameter, BulletWeight, DragCoefficient,
+    FallAttitude, KineticEnergy, TerminalVelocity, Velocity,
+};
+
+fn falling(bullet_weight: f64, bullet_diameter: f64, attitude: FallAttitude) -> TerminalVelocity {
+    TerminalVelocity::calculate()
+        .bullet_weight(BulletWeight(bullet_weight))
+        .bullet_diameter(BulletDiameter(bullet_diameter))
+        .drag_coefficient(DragCoefficient(0.3))
+        .attitude(attitude)
+        .solve()
+        .unwrap()
+}
+
+fn out_of_range<T>(result: Result<T, BallisticsError>, expected: &str) -> bool {
+    matches!(result, Err(BallisticsError::OutOfRange { param, .. }) if param == expected)
+}
+
+#[test]
+fn tumbling_rifle_bullets_fall_at_150_to_250_ft_s() {
+    // 5.56 mm 55 gr, .308 150 and 175 gr, .338 250 gr and .45 ACP 230 gr.
+    for (bullet_weight, bullet_diameter) in [
+        (55.0, 0.224),
+        (150.0, 0.308),
+        (175.0, 0.308),
+        (250.0, 0.338),
+        (230.0, 0.451),
+    ] {
+        let tumbling = falling(bullet_weight, bullet_diameter, FallAttitude::Tumbling);
+
+        assert!(
+            (150.0..250.0).contains(&tumbling.velocity.0),
+            "{} ft/s for {bullet_weight} gr",
+            tumbling.velocity.0
+        );
+    }
+}
+
+#[test]
+fn tumbling_is_the_default_attitude() {
+    let tumbling = falling(175.0, 0.308, FallAttitude::default());
+
+    assert_eq!(tumbling, falling(175.0, 0.308, FallAttitude::Tumbling));
+    assert!(tumbling
+        .drag_coefficient
+        .approx_eq(&DragCoefficient(0.9), 1e-12));
+    // √(2 · 0.025 lb / (0.002377 slug/ft³ · 0.9 · 5.174e-4 ft²)) in the ICAO atmosphere.
+    assert!(tumbling.velocity.approx_eq(&Velocity(212.5), 0.5));
+}
+
+#[test]
+fn energy_comes_from_the_kinetic_energy_math() {
+    let tumbling = falling(175.0, 0.308, FallAttitude::Tumbling);
+
+    assert_eq!(
+        tumbling.energy,
+        KineticEnergy::calculate()
+            .bullet_weight(BulletWeight(175.0))
+            .velocity(tumbling.velocity)
+            .solve()
+    );
+    assert!(tumbling.energy.approx_eq(&KineticEnergy(17.5), 0.1));
+}
+
+#[test]
+fn more_drag_falls_slower() {
+    let point_first = falling(175.0, 0.308, FallAttitude::PointFirst);
+    let base_first = falling(175.0, 0.308, FallAttitude::BaseFirst);
+    let tumbling = falling(175.0, 0.308, FallAttitude::Tumbling);
+
+    assert!(point_first.velocity.0 > base_first.velocity.0);
+    assert!(base_first.velocity.0 > tumbling.velocity.0);
+    // Velocity goes with the inverse square root of the drag.
+    assert!(point_first
+        .velocity
+        .relative_eq(&Velocity(tumbling.velocity.0 * 3.0_f64.sqrt()), 1e-12));
+    assert_eq!(falling(175.0, 0.308, FallAttitude::Custom(3.0)), tumbling);
+}
+
+#[test]
+fn ballistic_coefficient_overstates_the_terminal_velocity() {
+    let from_ballistic_coefficient = TerminalVelocity::from_ballistic_coefficient()
+        .bullet_weight(BulletWeight(175.0))
+        .bullet_diameter(BulletDiameter(0.308))
+        .ballistic_coefficient(BallisticCoefficient(0.505))
+        .solve()
+        .unwrap();
+
+    assert!(
+        from_ballistic_coefficient.velocity.0
+            > falling(175.0, 0.308, FallAttitude::Tumbling).velocity.0
+    );
+    assert!(from_ballistic_coefficient.velocity.0 < 1000.0);
+}
+
+#[test]
+fn rejects_zero_mass_and_zero_area() {
+    let terminal = |bullet_weight: f64, bullet_diameter: f64, attitude: FallAttitude| {
+        TerminalVelocity::calculate()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .bullet_diameter(BulletDiameter(bullet_diameter))
+            .drag_coefficient(DragCoefficient(0.3))
+            .attitude(attitude)
+            .solve()
+    };
+
+    assert!(out_of_range(
+        terminal(0.0, 0.308, FallAttitude::Tumbling),
+        "bullet_weight"
+    ));
+    assert!(out_of_range(
+        terminal(175.0, 0.0, FallAttitude::Tumbling),
+        "bullet_diameter"
+    ));
+    assert!(out_of_range(
+        terminal(175.0, 0.308, FallAttitude::Custom(0.0)),
+        "attitude"
+    ));
+    assert!(out_of_range(
+        TerminalVelocity::from_ballistic_coefficient()
+            .bullet_weight(BulletWeight(175.0))
+            .bullet_diameter(BulletDiameter(0.0))
+            .ballistic_coefficient(BallisticCoefficient(0.505))
+            .solve(),
+        "bullet_diameter"
+    ));
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "ffi")]
+
+use ballistics_rs::ffi::{
+    self, BALLISTICS_DRAG_G1, BALLISTICS_DRAG_G7, BALLISTICS_INVALID_INPUT, BALLISTICS_NO_SOLUTION,
+    BALLISTICS_NULL_POINTER, BALLISTICS_OK,
+};
+use ballistics_rs::{
+    BallisticCoefficient, BulletDiameter, BulletLength, BulletWeight, Distance, DragModel,
+    FormFactor, GyroscopicStability, KineticEnergy, LagTime, RiflingTwist, SpeedOfSound, SpinDrift,
+    Temperature, TimeOfFlight, Velocity, WindDeflection, WindSpeed,
+};
+
+/// Calls an FFI function with an out pointer, returning its status and result.
+fn call(function: impl FnOnce(*mut f64) -> i32) -> (i32, f64) {
+    let mut out = f64::NAN;
+    let status = function(&mut out);
+
+    (status, out)
+}
+
+#[test]
+fn speed_of_sound_matches() {
+    let expected = SpeedOfSound::calculate()
+        .temperature(Temperature(59.0))
+        .solve();
+
+    assert_eq!(
+        call(|out| unsafe { ffi::ballistics_speed_of_sound(59.0, out) }),
+        (BALLISTICS_OK, expected.0)
+    );
+}
+
+#[test]
+fn kinetic_energy_matches() {
+    let expected = KineticEnergy::calculate()
+        .bullet_weight(BulletWeight(175.0))
+        .velocity(Velocity(2600.0))
+        .solve();
+
+    assert_eq!(
+        call(|out| unsafe { ffi::ballistics_kinetic_energy(175.0, 2600.0, out) }),
+        (BALLISTICS_OK, expected.0)
+    );
+}
+
+#[test]
+fn ballistic_coefficient_matches() {
+    let expected = BallisticCoefficient::calculate()
+        .bullet_weight(BulletWeight(175.0))
+        .bullet_diameter(BulletDiameter(0.308))
+        .form_factor(FormFactor(0.52))
+        .solve();
+
+    assert_eq!(
+        call(|out| unsafe { ffi::ballistics_ballistic_coefficient(175.0, 0.308, 0.52, out) }),
+        (BALLISTICS_OK, expected.0)
+    );
+}
+
+#[test]
+fn lag_time_matches() {
+    let expected = LagTime::calculate()
+        .actual_time_of_flight(TimeOfFlight(1.2643))
+        .distance(Distance(2400.0))
+        .muzzle_velocity(Velocity(2600.0))
+        .solve();
+
+    assert_eq!(
+        call(|out| unsafe { ffi::ballistics_lag_time(1.2643, 2400.0, 2600.0, out) }),
+        (BALLISTICS_OK, expected.0)
+    );
+}
+
+#[test]
+fn wind_deflection_matches() {
+    let expected = WindDeflection::calculate()
+        .lag_time(LagTime(0.34))
+        .crosswind_speed(WindSpeed(10.0))
+        .solve();
+
+    assert_eq!(
+        call(|out| unsafe { ffi::ballistics_wind_deflection(0.34, 10.0, out) }),
+        (BALLISTICS_OK, expected.0)
+    );
+}
+
+#[test]
+fn gyroscopic_stability_matches() {
+    let expected = GyroscopicStability::calculate()
+        .bullet_weight(BulletWeight(175.0))
+        .rifling_twist(RiflingTwist(10.0 / 0.308))
+        .bullet_diameter(BulletDiameter(0.308))
+        .bullet_length(BulletLength(1.24 / 0.308))
+        .solve();
+
+    assert_eq!(
+        call(|out| unsafe {
+            ffi::ballistics_gyroscopic_stability(175.0, 10.0 / 0.308, 0.308, 1.24 / 0.308, out)
+        }),
+        (BALLISTICS_OK, expected.0)
+    );
+}
+
+#[test]
+fn spin_drift_matches() {
+    let expected = SpinDrift::calculate()
+        .gyro_stability(GyroscopicStability(1.5))
+        .actual_time_of_flight(TimeOfFlight(1.2643))
+        .solve();
+
+    assert_eq!(
+        call(|out| unsafe { ffi::ballistics_spin_drift(1.5, 1.2643, out) }),
+        (BALLISTICS_OK, expected.0)
+    );
+}
+
+#[test]
+fn time_of_flight_matches() {
+    for (code, drag_model, ballistic_coefficient) in [
+        (BALLISTICS_DRAG_G1, DragModel::G1, 0.505),
+        (BALLISTICS_DRAG_G7, DragModel::G7, 0.243),
+    ] {
+        let expected = TimeOfFlight::at_range()
+            .muzzle_velocity(Velocity(2600.0))
+            .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+            .drag_model(drag_model)
+            .distance(Distance::from_yards(800.0))
+            .solve()
+            .unwrap();
+
+        assert_eq!(
+            call(|out| unsafe {
+                ffi::ballistics_time_of_flight(2600.0, ballistic_coefficient, code, 2400.0, out)
+            }),
+            (BALLISTICS_OK, expected.0)
+        );
+    }
+}
+
+#[test]
+fn reports_invalid_input() {
+    let (status, out) = call(|out| unsafe { ffi::ballistics_speed_of_sound(-500.0, out) });
+    assert_eq!(status, BALLISTICS_INVALID_INPUT);
+    assert!(out.is_nan(), "out must be left untouched");
+
+    let (status, _) = call(|out| unsafe { ffi::ballistics_kinetic_energy(f64::NAN, 2600.0, out) });
+    assert_eq!(status, BALLISTICS_INVALID_INPUT);
+
+    let (status, _) =
+        call(|out| unsafe { ffi::ballistics_time_of_flight(2600.0, 0.505, 2, 2400.0, out) });
+    assert_eq!(status, BALLISTICS_INVALID_INPUT);
+}
+
+#[test]
+fn reports_no_solution() {
+    // A slow, draggy bullet is still in the air after the longest time of flight integrated.
+    let (status, out) = call(|out| unsafe {
+        ffi::ballistics_time_of_flight(1000.0, 0.05, BALLISTICS_DRAG_G1, 264_000.0, out)
+    });
+
+    assert_eq!(status, BALLISTICS_NO_SOLUTION);
+    assert!(out.is_nan(), "out must be left untouched");
+}
+
+#[test]
+fn reports_a_null_pointer() {
+    let null = std::ptr::null_mut();
+
+    unsafe {
+        assert_eq!(
+            ffi::ballistics_speed_of_sound(59.0, null),
+            BALLISTICS_NULL_POINTER
+        );
+        assert_eq!(
+            ffi::ballistics_kinetic_energy(175.0, 2600.0, null),
+            BALLISTICS_NULL_POINTER
+        );
+        assert_eq!(
+            ffi::ballistics_time_of_flight(2600.0, 0.505, BALLISTICS_DRAG_G1, 2400.0, null),
+            BALLISTICS_NULL_POINTER
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use std::f64::consts::PI;
+
+use ballistics_rs::{
+    Angle, ApproxEq, Distance, HitProbability, ImpactDispersion, TargetShape, TargetSize,
+};
+
+fn hit_probability(dispersion: ImpactDispersion, target: TargetShape) -> HitProbability {
+    HitProbability::calculate()
+        .dispersion(dispersion)
+        .target(target)
+        .distance(Distance::from_yards(100.0))
+        .solve()
+}
+
+#[test]
+fn half_the_hits_fall_within_the_cep() {
+    // The circular error probable is σ√(2 ln 2), with 1 MOA being 1.047" at 100 yards.
+    let sigma = Angle(1.0).subtension_at(Distance::from_yards(100.0));
+    let cep = sigma * (2.0 * 2.0_f64.ln()).sqrt();
+    let target = TargetShape::Circle {
+        diameter: TargetSize(2.0 * cep),
+    };
+
+    for dispersion in [
+        ImpactDispersion::Circular(Angle(1.0)),
+        ImpactDispersion::Elliptical {
+            horizontal: Angle(1.0),
+            vertical: Angle(1.0),
+        },
+        ImpactDispersion::MeanRadius(Angle((PI / 2.0).sqrt())),
+    ] {
+        let probability = hit_probability(dispersion, target);
+
+        assert!(
+            probability.approx_eq(&HitProbability(0.5), 1e-12),
+            "{dispersion:?} gave {probability:?}"
+        );
+    }
+}
+
+#[test]
+fn rectangle_is_the_product_of_the_error_functions() {
+    // erf(4 / 2√2σx) × erf(6 / 2√2σy) with σx = 1 MOA and σy = 2 MOA at 100 yards.
+    let probability = hit_probability(
+        ImpactDispersion::Elliptical {
+            horizontal: Angle(1.0),
+            vertical: Angle(2.0),
+        },
+        TargetShape::Rectangle {
+            width: TargetSize(4.0),
+            height: TargetSize(6.0),
+        },
+    );
+
+    assert!(
+        probability.approx_eq(&HitProbability(0.800_354), 1e-6),
+        "{probability:?}"
+    );
+}
+
+#[test]
+fn without_dispersion_every_shot_hits() {
+    for target in [
+        TargetShape::Circle {
+            diameter: TargetSize(1.0),
+        },
+        TargetShape::Rectangle {
+            width: TargetSize(1.0),
+            height: TargetSize(1.0),
+        },
+    ] {
+        assert_eq!(
+            hit_probability(ImpactDispersion::Circular(Angle(0.0)), target),
+            HitProbability(1.0)
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use ballistics_rs::{
+    Angle, ApproxEq, BallisticCoefficient, BallisticsError, BulletDrop, BulletWeight, Distance,
+    InclineCorrection, InclinedDrop, ShotAngle, SightHeight, TrajectorySolver, Velocity,
+};
+
+fn correct(slant_distance: Distance, degrees: f64) -> InclineCorrection {
+    InclineCorrection::calculate()
+        .slant_distance(slant_distance)
+        .shot_angle(Angle::from_degrees(degrees))
+        .solve()
+}
+
+#[test]
+fn rifleman_rule_scales_the_slant_distance_by_the_cosine() {
+    let slant_distance = Distance(1800.0);
+
+    assert_eq!(correct(slant_distance, 0.0), InclineCorrection(1800.0));
+    assert!(correct(slant_distance, 30.0).approx_eq(&InclineCorrection(1_558.845_727), 1e-6));
+    assert!(correct(slant_distance, 45.0)
+        .relative_eq(&InclineCorrection(1800.0 * 0.5_f64.sqrt()), 1e-12));
+}
+
+#[test]
+fn rifleman_rule_is_the_same_uphill_and_downhill() {
+    for degrees in [10.0, 30.0, 45.0, 89.0] {
+        assert_eq!(
+            correct(Distance(1800.0), -degrees),
+            correct(Distance(1800.0), degrees)
+        );
+    }
+}
+
+#[test]
+fn rifleman_rule_goes_to_zero_when_vertical() {
+    for degrees in [90.0, -90.0, 95.0] {
+        let correction = correct(Distance(1800.0), degrees);
+
+        assert!(
+            correction.approx_eq(&InclineCorrection(0.0), 1e-9),
+            "{degrees}°"
+        );
+    }
+}
+
+#[test]
+fn improved_rule_dials_for_more_drop_than_the_basic_rule() {
+    // A .308 168 gr zeroed at 100 yards, 600 yards slant range at 30°.
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .bullet_weight(BulletWeight(168.0))
+        .sight_height(SightHeight(1.5))
+        .build();
+    let zero_angle = solver
+        .zero_angle()
+        .zero_range(Distance::from_yards(100.0))
+        .solve()
+        .unwrap();
+    let solver = solver.with_launch_angle(zero_angle);
+    let slant_distance = Distance::from_yards(600.0);
+    let shot_angle = Angle::from_degrees(30.0);
+
+    let basic = correct(slant_distance, 30.0);
+    let basic_drop = solver.point_at(Distance(basic.0)).unwrap().drop;
+    let improved = InclinedDrop::calculate()
+        .solver(solver)
+        .slant_distance(slant_distance)
+        .shot_angle(shot_angle)
+        .solve()
+        .unwrap();
+    let level_drop = solver.point_at(slant_distance).unwrap().drop;
+
+    assert!(improved
+        .drop
+        .relative_eq(&BulletDrop(level_drop.0 * 0.75_f64.sqrt()), 1e-12));
+    assert!(
+        (basic.0..slant_distance.0).contains(&improved.dial_distance.0),
+        "{}",
+        improved.dial_distance.yards()
+    );
+
+    // The basic rule dials for about 520 yards, under-estimating the drop by about 19".
+    let difference = basic_drop.0 - improved.drop.0;
+    assert!((18.0..20.0).contains(&difference), "{difference}");
+}
+
+#[test]
+fn shot_angle_from_the_elevation_difference() {
+    // (elevation difference ft, slant distance ft, angle °, cosine)
+    let cases = [
+        (0.0, 2700.0, 0.0, 1.0),
+        (400.0, 2700.0, 8.519_624, 0.988_965),
+        (-400.0, 2700.0, -8.519_624, 0.988_965),
+        (2699.0, 2700.0, 88.440_558, 0.027_214),
+        (-2700.0, 2700.0, -90.0, 0.0),
+    ];
+
+    for (elevation_difference, slant_distance, degrees, cosine) in cases {
+        let shot_angle = ShotAngle::calculate()
+            .elevation_difference(Distance(elevation_difference))
+            .slant_distance(Distance(slant_distance))
+            .solve()
+            .unwrap();
+
+        assert!(
+            shot_angle.angle.degrees().approx_eq(&degrees, 1e-6),
+            "{} at {elevation_difference} ft",
+            shot_angle.angle.degrees()
+        );
+        assert!(shot_angle.cosine.approx_eq(&cosine, 1e-6));
+        assert!(
+            correct(Distance(slant_distance), shot_angle.angle.degrees())
+                .approx_eq(&InclineCorrection(slant_distance * cosine), 1e-2)
+        );
+    }
+}
+
+#[test]
+fn shot_angle_rejects_a_rise_beyond_the_slant_distance() {
+    for elevation_difference in [2700.1, -2700.1] {
+        assert!(matches!(
+            ShotAngle::calculate()
+                .elevation_difference(Distance(elevation_difference))
+                .slant_distance(Distance(2700.0))
+                .solve(),
+            Err(BallisticsError::OutOfRange {
+                param: "elevation_difference",
+                ..
+            })
+        ));
+    }
+}
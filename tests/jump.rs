@@ -0,0 +1,39 @@
+use ballistics_rs::{
+    AerodynamicJump, Angle, ApproxEq, Deflection, Distance, JumpDeflection, WindSpeed,
+};
+
+fn jump(crosswind_speed: f64, distance: Distance) -> JumpDeflection {
+    AerodynamicJump::deflection_at()
+        .jump(AerodynamicJump(0.35))
+        .crosswind_speed(WindSpeed(crosswind_speed))
+        .distance(distance)
+        .solve()
+}
+
+#[test]
+fn ten_mph_at_0_35_moa_per_mph_is_3_5_moa_at_any_distance() {
+    for yards in [100.0, 300.0, 600.0, 1000.0] {
+        let distance = Distance::from_yards(yards);
+        let jump = jump(10.0, distance);
+
+        assert!(jump.angle.approx_eq(&Angle(3.5), 1e-12), "{}", jump.angle);
+        assert!(jump.angle.mils().approx_eq(&1.018, 1e-3));
+        assert!(jump
+            .deflection
+            .relative_eq(&Deflection(Angle(3.5).subtension_at(distance)), 1e-12));
+    }
+    assert!(jump(10.0, Distance::from_yards(100.0))
+        .deflection
+        .approx_eq(&Deflection(3.665), 1e-3));
+}
+
+#[test]
+fn reversing_the_wind_flips_the_jump() {
+    let distance = Distance::from_yards(600.0);
+    let left_to_right = jump(10.0, distance);
+    let right_to_left = jump(-10.0, distance);
+
+    assert!(left_to_right.angle.0 > 0.0);
+    assert_eq!(right_to_left.angle.0, -left_to_right.angle.0);
+    assert_eq!(right_to_left.deflection.0, -left_to_right.deflection.0);
+}
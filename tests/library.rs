@@ -0,0 +1,101 @@
+#![cfg(feature = "bullet-library")]
+
+use ballistics_rs::{
+    BallisticCoefficient, BulletDiameter, BulletLibrary, BulletWeight, DragModel, KineticEnergy,
+    ProjectileLength, Velocity,
+};
+
+#[test]
+fn looks_up_bullets_by_exact_name() {
+    let matchking = BulletLibrary::get("Sierra 168gr MatchKing .308").expect("a library bullet");
+    assert_eq!(matchking.bullet_weight(), BulletWeight(168.0));
+    assert_eq!(matchking.bullet_diameter(), BulletDiameter(0.308));
+    assert_eq!(matchking.length(), ProjectileLength(1.215));
+    assert_eq!(
+        matchking.ballistic_coefficient(),
+        Some(BallisticCoefficient(0.462))
+    );
+    assert_eq!(matchking.drag_model(), DragModel::G1);
+
+    let eld_match = BulletLibrary::get("Hornady 140gr ELD Match .264").expect("a library bullet");
+    assert_eq!(
+        eld_match.ballistic_coefficient(),
+        Some(BallisticCoefficient(0.326))
+    );
+    assert_eq!(eld_match.drag_model(), DragModel::G7);
+
+    let g1 = BulletLibrary::entry("Hornady 140gr ELD Match .264")
+        .and_then(|bullet| bullet.projectile_with(DragModel::G1));
+    assert_eq!(
+        g1.and_then(|bullet| bullet.ballistic_coefficient()),
+        Some(BallisticCoefficient(0.646))
+    );
+
+    let hybrid = BulletLibrary::entry("Berger 300gr Hybrid OTM Tactical .338").unwrap();
+    assert_eq!(hybrid.bullet_weight, BulletWeight(300.0));
+    assert_eq!(hybrid.g1, BallisticCoefficient(0.818));
+    assert_eq!(hybrid.g7, Some(BallisticCoefficient(0.419)));
+    assert_eq!(
+        BulletLibrary::entry("Sierra 77gr MatchKing .224")
+            .unwrap()
+            .projectile_with(DragModel::G7),
+        None
+    );
+}
+
+#[test]
+fn exact_lookup_is_case_sensitive_and_search_is_not() {
+    assert!(BulletLibrary::get("sierra 168gr matchking .308").is_none());
+    assert!(BulletLibrary::get("Sierra 168gr MatchKing").is_none());
+
+    let names: Vec<&str> = BulletLibrary::search("matchking .308")
+        .map(|bullet| bullet.name)
+        .collect();
+    assert_eq!(
+        names,
+        ["Sierra 168gr MatchKing .308", "Sierra 175gr MatchKing .308"]
+    );
+    assert_eq!(
+        BulletLibrary::search("").count(),
+        BulletLibrary::bullets().len()
+    );
+}
+
+#[test]
+fn filters_by_caliber() {
+    let six_five: Vec<&str> = BulletLibrary::by_caliber(BulletDiameter(0.264))
+        .map(|bullet| bullet.name)
+        .collect();
+
+    assert_eq!(six_five.len(), 6);
+    assert!(six_five.iter().all(|name| name.ends_with(".264")));
+    assert_eq!(BulletLibrary::by_caliber(BulletDiameter(0.2644)).count(), 6);
+    assert_eq!(BulletLibrary::by_caliber(BulletDiameter(0.5)).count(), 0);
+}
+
+#[test]
+fn every_entry_plugs_into_the_equations() {
+    assert!(BulletLibrary::bullets().len() >= 30);
+
+    for bullet in BulletLibrary::bullets() {
+        let projectile = bullet.projectile();
+
+        assert!(
+            projectile.ballistic_coefficient().is_some(),
+            "{}",
+            bullet.name
+        );
+        assert_eq!(
+            projectile.kinetic_energy(Velocity(2600.0)),
+            KineticEnergy::calculate()
+                .bullet_weight(bullet.bullet_weight)
+                .velocity(Velocity(2600.0))
+                .solve()
+        );
+        assert!(
+            (3.0..6.5).contains(&projectile.length_calibers().0),
+            "{}",
+            bullet.name
+        );
+    }
+}
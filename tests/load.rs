@@ -0,0 +1,161 @@
+use ballistics_rs::{
+    Altitude, Angle, ApproxEq, Atmosphere, BallisticCoefficient, BallisticsError, BulletDiameter,
+    BulletDrop, BulletWeight, Distance, DragModel, GyroscopicStability, Load, Projectile,
+    ProjectileLength, RiflingTwist, SightHeight, StabilityRating, Temperature, Velocity, WindSpeed,
+    WindVector, ZeroShift,
+};
+
+fn projectile(ballistic_coefficient: Option<BallisticCoefficient>) -> Projectile {
+    Projectile::builder()
+        .bullet_weight(BulletWeight(175.0))
+        .bullet_diameter(BulletDiameter(0.308))
+        .length(ProjectileLength(1.24))
+        .maybe_ballistic_coefficient(ballistic_coefficient)
+        .drag_model(DragModel::G1)
+        .build()
+        .unwrap()
+}
+
+/// A .308 Winchester 175 gr Sierra MatchKing at 2600 ft/s from a 1:10" barrel, zeroed at
+/// 100 yards in the ICAO atmosphere.
+fn load() -> Load {
+    Load::builder()
+        .projectile(projectile(Some(BallisticCoefficient(0.505))))
+        .muzzle_velocity(Velocity(2600.0))
+        .rifling_twist(RiflingTwist(10.0 / 0.308))
+        .sight_height(SightHeight(1.5))
+        .zero_range(Distance::from_yards(100.0))
+        .atmosphere(Atmosphere::icao())
+        .build()
+        .unwrap()
+}
+
+// Yards, drop MOA, windage MOA in a 10 mph crosswind, velocity ft/s, energy ft-lb and time of
+// flight s, from an independent point-mass G1 integration of the same load.
+const REFERENCE: [(f64, f64, f64, f64, f64, f64); 3] = [
+    (300.0, -4.992, 2.218, 2094.6, 1704.5, 0.3857),
+    (600.0, -16.337, 4.973, 1652.9, 1061.5, 0.8698),
+    (1000.0, -39.338, 9.636, 1204.2, 563.3, 1.7272),
+];
+
+#[test]
+fn solution_matches_the_reference_load() {
+    let load = load();
+    let wind = WindVector::new(WindSpeed(10.0), WindSpeed(0.0), WindSpeed(0.0));
+
+    for (yards, drop, windage, velocity, energy, time_of_flight) in REFERENCE {
+        let solution = load
+            .solution_at()
+            .distance(Distance::from_yards(yards))
+            .wind(wind)
+            .solve()
+            .unwrap();
+
+        assert!(
+            solution.drop_angle.0.approx_eq(&drop, 0.05),
+            "drop at {yards} yd"
+        );
+        assert!(
+            solution.windage_angle.0.approx_eq(&windage, 0.05),
+            "windage at {yards} yd"
+        );
+        assert!(solution.velocity.0.approx_eq(&velocity, 1.0));
+        assert!(solution.energy.0.approx_eq(&energy, 2.0));
+        assert!(solution.time_of_flight.0.approx_eq(&time_of_flight, 1e-3));
+        assert!(solution.drop_angle.relative_eq(
+            &Angle::from_subtension(solution.drop.0, solution.distance),
+            1e-12
+        ));
+        assert_eq!(solution.stability.rating, StabilityRating::Stable);
+        assert_eq!(solution.stability.gyro_stability, load.gyro_stability());
+    }
+}
+
+#[test]
+fn solution_at_the_zero_range_is_on_the_line_of_sight() {
+    let solution = load()
+        .solution_at()
+        .distance(Distance::from_yards(100.0))
+        .solve()
+        .unwrap();
+
+    assert!(solution.drop.approx_eq(&BulletDrop(0.0), 1e-3));
+    assert_eq!(solution.windage.0, 0.0);
+}
+
+#[test]
+fn load_keeps_the_zero_and_corrected_stability() {
+    let load = load();
+
+    assert!(load.zero_angle().0 > 0.0);
+    assert_eq!(
+        load.gyro_stability(),
+        GyroscopicStability::calculate_corrected()
+            .bullet_weight(BulletWeight(175.0))
+            .rifling_twist(RiflingTwist(10.0 / 0.308))
+            .bullet_diameter(BulletDiameter(0.308))
+            .bullet_length(projectile(None).length_calibers())
+            .muzzle_velocity(Velocity(2600.0))
+            .solve()
+            .gyro_stability
+    );
+}
+
+#[test]
+fn load_needs_a_ballistic_coefficient() {
+    let result = Load::builder()
+        .projectile(projectile(None))
+        .muzzle_velocity(Velocity(2600.0))
+        .rifling_twist(RiflingTwist(10.0 / 0.308))
+        .sight_height(SightHeight(1.5))
+        .zero_range(Distance::from_yards(100.0))
+        .build();
+
+    assert!(matches!(
+        result,
+        Err(BallisticsError::MissingBallisticCoefficient)
+    ));
+}
+
+#[test]
+fn zero_shift_impacts_high_in_thinner_air() {
+    // Zeroed at 100 yards at sea level on a 70 °F day, hunting at 9000 feet.
+    let load = Load::builder()
+        .projectile(projectile(Some(BallisticCoefficient(0.505))))
+        .muzzle_velocity(Velocity(2600.0))
+        .rifling_twist(RiflingTwist(10.0 / 0.308))
+        .sight_height(SightHeight(1.5))
+        .zero_range(Distance::from_yards(100.0))
+        .atmosphere(Atmosphere::builder().temperature(Temperature(70.0)).build())
+        .build()
+        .unwrap();
+    let shift_at = |yards| {
+        ZeroShift::calculate()
+            .load(&load)
+            .atmosphere(Atmosphere::standard_at(Altitude(9000.0)))
+            .distance(Distance::from_yards(yards))
+            .solve()
+            .unwrap()
+    };
+
+    let at_zero = shift_at(100.0);
+    assert!(at_zero.zero_drop.approx_eq(&BulletDrop(0.0), 1e-3));
+    assert!((0.0..0.05).contains(&at_zero.shift().as_moa().0));
+
+    let mut previous = at_zero.shift().as_moa().0;
+    for yards in [300.0, 500.0, 800.0, 1000.0] {
+        let shift = shift_at(yards).shift().as_moa().0;
+
+        assert!(shift > previous, "{yards} yd");
+        previous = shift;
+    }
+
+    // About 6 MOA less come-up at 1000 yards: 33 rather than 39 MOA.
+    let far = shift_at(1000.0);
+    assert!((5.5..6.5).contains(&far.shift().as_moa().0));
+    assert!((32.0..34.0).contains(&far.come_up().as_moa().0));
+    assert!((far.come_up() + far.shift()).as_moa().relative_eq(
+        &Angle::from_subtension(-far.zero_drop.0, far.distance),
+        1e-12
+    ));
+}
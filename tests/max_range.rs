@@ -0,0 +1,59 @@
+use ballistics_rs::{
+    Angle, ApproxEq, BallisticCoefficient, BulletWeight, MaxRange, TrajectorySolver,
+    VacuumTrajectory, Velocity,
+};
+
+fn max_range(muzzle_velocity: f64, ballistic_coefficient: f64, bullet_weight: f64) -> MaxRange {
+    MaxRange::calculate()
+        .solver(
+            TrajectorySolver::builder()
+                .muzzle_velocity(Velocity(muzzle_velocity))
+                .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+                .bullet_weight(BulletWeight(bullet_weight))
+                .build(),
+        )
+        .solve()
+        .unwrap()
+}
+
+#[test]
+fn small_arms_carry_about_their_published_maximum_range() {
+    // (muzzle velocity ft/s, G1 BC, weight gr, published maximum range m)
+    let cases = [
+        // 5.56×45mm 62 gr M855, published at 3600 m.
+        (3025.0, 0.304, 62.0, 3600.0),
+        // .308 Winchester 175 gr match, commonly published at 4500 m.
+        (2600.0, 0.505, 175.0, 4500.0),
+    ];
+
+    for (muzzle_velocity, ballistic_coefficient, bullet_weight, published) in cases {
+        let max_range = max_range(muzzle_velocity, ballistic_coefficient, bullet_weight);
+        let meters = max_range.range.meters();
+
+        assert!(
+            meters.relative_eq(&published, 0.1),
+            "{meters} m against {published} m"
+        );
+        assert!(
+            (30.0..38.0).contains(&max_range.launch_angle.degrees()),
+            "{}°",
+            max_range.launch_angle.degrees()
+        );
+    }
+}
+
+#[test]
+fn drag_shortens_the_range_and_steepens_the_fall() {
+    let max_range = max_range(2600.0, 0.505, 175.0);
+    let vacuum = VacuumTrajectory::builder()
+        .velocity(Velocity(2600.0))
+        .launch_angle(Angle::from_degrees(45.0))
+        .build();
+
+    assert!(max_range.range.0 < vacuum.max_range().0 / 10.0);
+    assert!(max_range.launch_angle.0 < Angle::from_degrees(45.0).0);
+    assert!(max_range.impact_angle.0 > max_range.launch_angle.0);
+    assert!(max_range.impact_velocity.0 < 1000.0);
+    assert!(max_range.apex_height.0 > 0.0);
+    assert!(max_range.time_of_flight.0 > 0.0);
+}
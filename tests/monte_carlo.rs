@@ -0,0 +1,107 @@
+use ballistics_rs::{
+    ApproxEq, BallisticCoefficient, BallisticsError, Distance, MonteCarlo, RandomSource,
+    SplitMix64, TrajectorySolver, Velocity, WindSpeed,
+};
+
+const SHOTS: usize = 10_000;
+
+fn solver(muzzle_velocity: f64, crosswind_speed: f64) -> TrajectorySolver {
+    TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(muzzle_velocity))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .crosswind_speed(WindSpeed(crosswind_speed))
+        .build()
+}
+
+fn simulate(shots: usize, seed: u64) -> MonteCarlo {
+    MonteCarlo::simulate()
+        .solver(solver(2650.0, 5.0))
+        .distance(Distance::from_yards(600.0))
+        .shots(shots)
+        .rng(&mut SplitMix64::new(seed))
+        .velocity_sd(Velocity(10.0))
+        .crosswind_sd(WindSpeed(2.0))
+        .solve()
+        .unwrap()
+}
+
+/// Returns the windage and drop of the deterministic solver at 600 yards.
+fn impact(muzzle_velocity: f64, crosswind_speed: f64) -> (f64, f64) {
+    let point = solver(muzzle_velocity, crosswind_speed)
+        .point_at(Distance::from_yards(600.0))
+        .unwrap();
+
+    (point.windage.0, point.drop.0)
+}
+
+#[test]
+fn split_mix_matches_the_reference_sequence() {
+    let mut rng = SplitMix64::new(0);
+
+    // The first outputs of the reference SplitMix64 for seed 0, 0xe220a8397b1dcdaf and on,
+    // scaled to [0, 1).
+    assert_eq!(rng.next_f64(), 0.8833108082136426);
+    assert_eq!(rng.next_f64(), 0.43152799704850997);
+    assert_eq!(rng.next_f64(), 0.026433771592597743);
+}
+
+#[test]
+fn same_seed_gives_same_impacts() {
+    assert_eq!(simulate(100, 7), simulate(100, 7));
+    assert_ne!(simulate(100, 7), simulate(100, 8));
+}
+
+#[test]
+fn seeded_run_has_stable_statistics() {
+    let simulation = simulate(SHOTS, 42);
+    let horizontal = simulation.horizontal();
+    let vertical = simulation.vertical();
+
+    // The spreads expected from the sensitivity of the deterministic solution to each input,
+    // by central differences over one standard deviation.
+    let (windage, drop) = impact(2650.0, 5.0);
+    let (fast_windage, fast_drop) = impact(2660.0, 5.0);
+    let (slow_windage, slow_drop) = impact(2640.0, 5.0);
+    let (gusty_windage, _) = impact(2650.0, 7.0);
+    let (calm_windage, _) = impact(2650.0, 3.0);
+    let windage_from_velocity = (fast_windage - slow_windage) / 2.0;
+    let windage_from_wind = (gusty_windage - calm_windage) / 2.0;
+    let horizontal_sd = windage_from_velocity.hypot(windage_from_wind);
+    let vertical_sd = ((fast_drop - slow_drop) / 2.0).abs();
+
+    assert_eq!(simulation.impacts().len(), SHOTS);
+    assert!(horizontal.mean().approx_eq(&windage, 0.05 * horizontal_sd));
+    assert!(vertical.mean().approx_eq(&drop, 0.05 * vertical_sd));
+    assert!((horizontal.standard_deviation() / horizontal_sd).approx_eq(&1.0, 0.03));
+    assert!((vertical.standard_deviation() / vertical_sd).approx_eq(&1.0, 0.03));
+
+    // The 2.5th and 97.5th percentiles of a normal distribution lie 1.96 deviations out.
+    for distribution in [&horizontal, &vertical] {
+        let (mean, sd) = (distribution.mean(), distribution.standard_deviation());
+
+        assert!(distribution.percentile(50.0).approx_eq(&mean, 0.05 * sd));
+        assert!(distribution
+            .percentile(2.5)
+            .approx_eq(&(mean - 1.96 * sd), 0.1 * sd));
+        assert!(distribution
+            .percentile(97.5)
+            .approx_eq(&(mean + 1.96 * sd), 0.1 * sd));
+        assert_eq!(distribution.percentile(0.0), distribution.values()[0]);
+        assert_eq!(
+            distribution.percentile(100.0),
+            distribution.values()[SHOTS - 1]
+        );
+    }
+}
+
+#[test]
+fn rejects_fewer_than_two_shots() {
+    let result = MonteCarlo::simulate()
+        .solver(solver(2650.0, 5.0))
+        .distance(Distance::from_yards(600.0))
+        .shots(1)
+        .rng(&mut SplitMix64::new(42))
+        .solve();
+
+    assert!(matches!(result, Err(BallisticsError::TooFewShots(1))));
+}
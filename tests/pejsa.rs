@@ -0,0 +1,68 @@
+use ballistics_rs::{
+    ApproxEq, BallisticCoefficient, BulletDrop, BulletWeight, Distance, PejsaTrajectory,
+    RetardationCoefficient, SightHeight, TimeOfFlight, TrajectorySolver, Velocity,
+};
+
+#[test]
+fn velocity_and_drop_follow_the_closed_form() {
+    // F₀ = 2800 ft and N = 0.5 at 2650 ft/s, so the retardation coefficient at 300 yards is
+    // 2800 - 0.5 × 900 = 2350 ft.
+    let trajectory = PejsaTrajectory::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .retardation_coefficient(RetardationCoefficient(2800.0))
+        .build();
+    let distance = Distance::from_yards(300.0);
+    let remaining: f64 = 2350.0 / 2800.0;
+
+    assert!(trajectory
+        .velocity_at(distance)
+        .relative_eq(&Velocity(2650.0 * remaining.powi(2)), 1e-12));
+    assert!(trajectory.time_of_flight_at(distance).relative_eq(
+        &TimeOfFlight(2800.0 * (1.0 / remaining - 1.0) / 1325.0),
+        1e-12
+    ));
+    assert_eq!(trajectory.velocity_at(Distance(0.0)), Velocity(2650.0));
+    assert_eq!(trajectory.drop_at(Distance(0.0)), BulletDrop(0.0));
+}
+
+#[test]
+fn velocity_and_drop_agree_with_a_point_mass_solution() {
+    // A .308 168 gr at 2650 ft/s with a G1 BC of 0.462, fired from a level bore.
+    let ballistic_coefficient = BallisticCoefficient(0.462);
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(ballistic_coefficient)
+        .bullet_weight(BulletWeight(168.0))
+        .sight_height(SightHeight(0.0))
+        .build();
+    let retardation_coefficient = RetardationCoefficient::from_ballistic_coefficient()
+        .ballistic_coefficient(ballistic_coefficient)
+        .velocity(Velocity(2650.0))
+        .solve();
+    let trajectory = PejsaTrajectory::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .retardation_coefficient(retardation_coefficient)
+        .build();
+
+    assert!(retardation_coefficient.approx_eq(&RetardationCoefficient(4023.9), 0.1));
+
+    // Pejsa's model holds within about 1% for flat fire.
+    for yards in [100.0, 300.0, 500.0] {
+        let distance = Distance::from_yards(yards);
+        let point = solver.point_at(distance).unwrap();
+
+        assert!(
+            trajectory
+                .velocity_at(distance)
+                .relative_eq(&point.velocity, 0.01),
+            "{yards} yd"
+        );
+        assert!(
+            trajectory.drop_at(distance).relative_eq(&point.drop, 0.01),
+            "{yards} yd"
+        );
+        assert!(trajectory
+            .time_of_flight_at(distance)
+            .relative_eq(&point.time_of_flight, 0.01));
+    }
+}
@@ -0,0 +1,94 @@
+use ballistics_rs::{
+    BallisticCoefficient, BulletDiameter, BulletWeight, Distance, DragModel, PlausibilityWarning,
+    PlausibleRange, Projectile, ProjectileLength, TrajectorySolver, Velocity,
+    PLAUSIBLE_BULLET_LENGTH, PLAUSIBLE_G7_BALLISTIC_COEFFICIENT, PLAUSIBLE_MUZZLE_VELOCITY,
+    PLAUSIBLE_PRESSURE, PLAUSIBLE_TEMPERATURE,
+};
+
+fn warnings(length: f64, ballistic_coefficient: f64) -> Vec<PlausibilityWarning> {
+    Projectile::builder()
+        .bullet_weight(BulletWeight(175.0))
+        .bullet_diameter(BulletDiameter(0.308))
+        .length(ProjectileLength(length))
+        .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+        .drag_model(DragModel::G7)
+        .build()
+        .unwrap()
+        .check_plausibility()
+        .collect()
+}
+
+#[test]
+fn warning_names_the_parameter_value_and_range() {
+    let warning = PLAUSIBLE_MUZZLE_VELOCITY
+        .check("muzzle_velocity", 12000.0)
+        .unwrap();
+
+    assert_eq!(
+        warning,
+        PlausibilityWarning {
+            parameter: "muzzle_velocity",
+            value: 12000.0,
+            range: PLAUSIBLE_MUZZLE_VELOCITY,
+        }
+    );
+    assert_eq!(
+        warning.to_string(),
+        "`muzzle_velocity` is 12000 ft/s, outside the plausible range of 300 to 4500 ft/s; \
+         check its unit"
+    );
+    assert_eq!(PLAUSIBLE_TEMPERATURE.to_string(), "-60 to 130 °F");
+}
+
+#[test]
+fn ranges_include_their_bounds() {
+    let range = PlausibleRange {
+        min: 15.0,
+        max: 32.0,
+        unit: "inHg",
+    };
+
+    assert_eq!(range, PLAUSIBLE_PRESSURE);
+    for pressure in [15.0, 29.92, 32.0] {
+        assert_eq!(range.check("pressure", pressure), None, "{pressure}");
+    }
+    for pressure in [14.99, 32.01, 760.0] {
+        assert_eq!(
+            range
+                .check("pressure", pressure)
+                .map(|warning| warning.value),
+            Some(pressure)
+        );
+    }
+}
+
+#[test]
+fn projectile_warns_around_the_boundaries() {
+    // 7 calibers, 2.156" for a .308, is the longest plausible bullet.
+    assert!(warnings(2.15, 0.243).is_empty());
+    assert_eq!(warnings(2.16, 0.243).len(), 1);
+
+    let too_long = warnings(12.0 * 0.308, 0.243);
+    assert_eq!(too_long.len(), 1);
+    assert_eq!(too_long[0].parameter, "length");
+    assert_eq!(too_long[0].range, PLAUSIBLE_BULLET_LENGTH);
+    assert!((too_long[0].value - 12.0).abs() < 1e-9);
+
+    // A G1 coefficient entered against the G7 model.
+    assert!(warnings(1.24, 0.6).is_empty());
+    let g1_as_g7 = warnings(1.24, 0.61);
+    assert_eq!(g1_as_g7.len(), 1);
+    assert_eq!(g1_as_g7[0].parameter, "ballistic_coefficient");
+    assert_eq!(g1_as_g7[0].range, PLAUSIBLE_G7_BALLISTIC_COEFFICIENT);
+}
+
+#[test]
+fn checks_leave_the_calculation_unchanged() {
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(12000.0))
+        .ballistic_coefficient(BallisticCoefficient(0.505))
+        .build();
+
+    assert_eq!(solver.check_plausibility().count(), 1);
+    assert!(solver.point_at(Distance(300.0)).is_some());
+}
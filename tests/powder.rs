@@ -0,0 +1,30 @@
+use ballistics_rs::{ApproxEq, PowderType, Temperature, Velocity};
+
+fn adjusted(actual: f64, powder_type: PowderType, velocity_per_degree: Option<f64>) -> Velocity {
+    Velocity::temperature_adjusted()
+        .reference_velocity(Velocity(2650.0))
+        .reference_temperature(Temperature(70.0))
+        .actual_temperature(Temperature(actual))
+        .powder_type(powder_type)
+        .maybe_velocity_per_degree(velocity_per_degree)
+        .solve()
+}
+
+#[test]
+fn velocity_changes_linearly_with_powder_temperature() {
+    // 1.2 ft/s per °F, 30 °F warmer than the chronograph session.
+    assert!(adjusted(100.0, PowderType::Extruded, Some(1.2)).approx_eq(&Velocity(2686.0), 1e-9));
+    assert!(adjusted(85.0, PowderType::Extruded, Some(1.2)).approx_eq(&Velocity(2668.0), 1e-9));
+    assert_eq!(adjusted(70.0, PowderType::Ball, None), Velocity(2650.0));
+}
+
+#[test]
+fn cold_powder_loses_velocity() {
+    // Chronographed at 70 °F, hunting at 10 °F.
+    assert_eq!(adjusted(10.0, PowderType::Extruded, None), Velocity(2620.0));
+    assert_eq!(adjusted(10.0, PowderType::Ball, None), Velocity(2560.0));
+    assert_eq!(
+        adjusted(10.0, PowderType::Ball, Some(0.2)),
+        Velocity(2638.0)
+    );
+}
@@ -0,0 +1,177 @@
+use ballistics_rs::{
+    AerodynamicJump, ApertureSightCalibration, ApproxEq, BallisticCoefficient, BulletDiameter,
+    BulletLength, BulletWeight, Distance, DragCoefficient, Float, FormFactor, GyroscopicStability,
+    KineticEnergy, LagTime, Pressure, RiflingTwist, SightCalibration, SightRadius, SpeedOfSound,
+    SpinDrift, Temperature, TimeOfFlight, TwistDirection, Velocity, VelocityProjection,
+    WindDeflection, WindSpeed,
+};
+
+/// Asserts that an equation solved in `f32` agrees with the same equation solved in `f64`.
+fn assert_agrees(single: f32, double: f64) {
+    assert!(
+        single
+            .to_f64()
+            .relative_eq(&double, f32::RELATIVE_TOLERANCE),
+        "{single} differs from {double}"
+    );
+}
+
+fn speed_of_sound<T: Float>(temperature: f64) -> T {
+    SpeedOfSound::calculate()
+        .temperature(Temperature(T::from_f64(temperature)))
+        .solve()
+        .0
+}
+
+fn kinetic_energy<T: Float>(weight: f64, velocity: f64) -> T {
+    KineticEnergy::calculate()
+        .bullet_weight(BulletWeight(T::from_f64(weight)))
+        .velocity(Velocity(T::from_f64(velocity)))
+        .solve()
+        .0
+}
+
+fn aperture_sight_calibration<T: Float>() -> T {
+    ApertureSightCalibration::calculate()
+        .sight_movement(SightCalibration(T::from_f64(0.125)))
+        .sight_radius(SightRadius(T::from_f64(30.0)))
+        .solve()
+        .0
+}
+
+fn ballistic_coefficient<T: Float>() -> T {
+    let form_factor = FormFactor::calculate()
+        .drag_coefficient(DragCoefficient(T::from_f64(0.28)))
+        .standard_bullet_drag_coefficient(DragCoefficient(T::from_f64(0.51)))
+        .solve();
+
+    BallisticCoefficient::calculate()
+        .bullet_weight(BulletWeight(T::from_f64(168.0)))
+        .bullet_diameter(BulletDiameter(T::from_f64(0.308)))
+        .form_factor(form_factor)
+        .solve()
+        .0
+}
+
+fn velocity_projection<T: Float>() -> T {
+    VelocityProjection::calculate()
+        .bullet_weight_1(BulletWeight(T::from_f64(150.0)))
+        .bullet_weight_2(BulletWeight(T::from_f64(180.0)))
+        .bullet_velocity_1(Velocity(T::from_f64(2820.0)))
+        .solve()
+        .0
+}
+
+fn wind_deflection<T: Float>() -> T {
+    let lag_time = LagTime::calculate()
+        .actual_time_of_flight(TimeOfFlight(T::from_f64(0.75)))
+        .distance(Distance(T::from_f64(1500.0)))
+        .muzzle_velocity(Velocity(T::from_f64(2650.0)))
+        .solve();
+
+    WindDeflection::calculate()
+        .lag_time(lag_time)
+        .crosswind_speed(WindSpeed(T::from_f64(10.0)))
+        .solve()
+        .0
+}
+
+fn gyroscopic_stability<T: Float>() -> GyroscopicStability<T> {
+    GyroscopicStability::calculate()
+        .bullet_weight(BulletWeight(T::from_f64(168.0)))
+        .rifling_twist(RiflingTwist(T::from_f64(32.47)))
+        .bullet_diameter(BulletDiameter(T::from_f64(0.308)))
+        .bullet_length(BulletLength(T::from_f64(3.9)))
+        .solve()
+}
+
+fn velocity_correction<T: Float>(velocity: f64) -> T {
+    GyroscopicStability::velocity_correction()
+        .muzzle_velocity(Velocity(T::from_f64(velocity)))
+        .gyro_stability(gyroscopic_stability())
+        .solve()
+        .unwrap()
+        .0
+}
+
+fn atmospheric_correction<T: Float>() -> T {
+    GyroscopicStability::atmospheric_correction()
+        .air_temp(Temperature(T::from_f64(20.0)))
+        .air_pressure(Pressure(T::from_f64(25.5)))
+        .gyro_stability(gyroscopic_stability())
+        .solve()
+        .0
+}
+
+fn spin_drift<T: Float>(twist_direction: TwistDirection) -> T {
+    SpinDrift::calculate()
+        .gyro_stability(gyroscopic_stability())
+        .actual_time_of_flight(TimeOfFlight(T::from_f64(1.2)))
+        .twist_direction(twist_direction)
+        .solve()
+        .0
+}
+
+fn aerodynamic_jump<T: Float>() -> T {
+    AerodynamicJump::calculate()
+        .gyro_stability(gyroscopic_stability())
+        .bullet_length(BulletLength(T::from_f64(3.9)))
+        .solve()
+        .0
+}
+
+#[test]
+fn speed_of_sound_agrees() {
+    for temperature in [-40.0, 0.0, 59.0, 120.0] {
+        assert_agrees(speed_of_sound(temperature), speed_of_sound(temperature));
+    }
+}
+
+#[test]
+fn kinetic_energy_agrees() {
+    for (weight, velocity) in [(55.0, 3240.0), (168.0, 2650.0), (230.0, 850.0)] {
+        assert_agrees(
+            kinetic_energy(weight, velocity),
+            kinetic_energy(weight, velocity),
+        );
+    }
+}
+
+#[test]
+fn sight_and_bullet_equations_agree() {
+    assert_agrees(aperture_sight_calibration(), aperture_sight_calibration());
+    assert_agrees(ballistic_coefficient(), ballistic_coefficient());
+    assert_agrees(velocity_projection(), velocity_projection());
+}
+
+#[test]
+fn wind_equations_agree() {
+    assert_agrees(wind_deflection(), wind_deflection());
+}
+
+#[test]
+fn stability_equations_agree() {
+    assert_agrees(gyroscopic_stability().0, gyroscopic_stability().0);
+
+    for velocity in [1500.0, 2800.0, 3500.0] {
+        assert_agrees(velocity_correction(velocity), velocity_correction(velocity));
+    }
+
+    assert_agrees(atmospheric_correction(), atmospheric_correction());
+
+    for twist_direction in [TwistDirection::Right, TwistDirection::Left] {
+        assert_agrees(spin_drift(twist_direction), spin_drift(twist_direction));
+    }
+
+    assert_agrees(aerodynamic_jump(), aerodynamic_jump());
+}
+
+#[test]
+fn single_precision_quantities_read_like_double_precision() {
+    let lag_time = LagTime(0.25_f32) + LagTime(0.125);
+
+    assert_eq!(lag_time, LagTime(0.375));
+    assert_eq!(f32::from(lag_time), 0.375);
+    assert_eq!(LagTime::from(0.375_f32), lag_time);
+    assert_eq!("0.375".parse::<LagTime<f32>>().unwrap(), lag_time);
+}
@@ -0,0 +1,101 @@
+use ballistics_rs::{
+    ApproxEq, BallisticCoefficient, BallisticsError, BulletDiameter, BulletLength, BulletWeight,
+    DragModel, GyroscopicStability, KineticEnergy, Projectile, ProjectileLength, RiflingTwist,
+    SectionalDensity, Velocity,
+};
+
+/// A .308 175 gr Sierra MatchKing, 1.24" long.
+fn projectile() -> Projectile {
+    Projectile::builder()
+        .bullet_weight(BulletWeight(175.0))
+        .bullet_diameter(BulletDiameter(0.308))
+        .length(ProjectileLength(1.24))
+        .ballistic_coefficient(BallisticCoefficient(0.505))
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn keeps_the_length_in_inches_and_converts_to_calibers() {
+    let projectile = projectile();
+
+    assert_eq!(projectile.length(), ProjectileLength(1.24));
+    assert!(projectile
+        .length_calibers()
+        .approx_eq(&BulletLength(4.025_974), 1e-6));
+    assert_eq!(
+        projectile.ballistic_coefficient(),
+        Some(BallisticCoefficient(0.505))
+    );
+    assert_eq!(projectile.drag_model(), DragModel::G1);
+}
+
+#[test]
+fn delegates_to_the_equations() {
+    let projectile = projectile();
+    let rifling_twist = RiflingTwist(10.0 / 0.308);
+
+    assert_eq!(
+        projectile.gyroscopic_stability(rifling_twist),
+        GyroscopicStability::calculate()
+            .bullet_weight(BulletWeight(175.0))
+            .rifling_twist(rifling_twist)
+            .bullet_diameter(BulletDiameter(0.308))
+            .bullet_length(BulletLength(1.24 / 0.308))
+            .solve()
+    );
+    assert_eq!(
+        projectile.kinetic_energy(Velocity(2600.0)),
+        KineticEnergy::calculate()
+            .bullet_weight(BulletWeight(175.0))
+            .velocity(Velocity(2600.0))
+            .solve()
+    );
+    // The published sectional density of a 175 gr .308 bullet is 0.264.
+    assert!(projectile
+        .sectional_density()
+        .approx_eq(&SectionalDensity(0.264), 5e-4));
+}
+
+#[test]
+fn rejects_invalid_properties() {
+    let build = |bullet_weight, bullet_diameter, length, ballistic_coefficient| {
+        Projectile::builder()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .bullet_diameter(BulletDiameter(bullet_diameter))
+            .length(ProjectileLength(length))
+            .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+            .build()
+    };
+
+    for (result, expected) in [
+        (build(0.0, 0.308, 1.24, 0.505), "bullet_weight"),
+        (build(175.0, -0.308, 1.24, 0.505), "bullet_diameter"),
+        (build(175.0, 0.308, 0.0, 0.505), "length"),
+        (build(175.0, 0.308, 1.24, 0.0), "ballistic_coefficient"),
+    ] {
+        assert!(
+            matches!(result, Err(BallisticsError::OutOfRange { param, .. }) if param == expected),
+            "{expected}"
+        );
+    }
+    assert!(matches!(
+        build(f64::NAN, 0.308, 1.24, 0.505),
+        Err(BallisticsError::NonFinite {
+            param: "bullet_weight",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn ballistic_coefficient_is_optional() {
+    let projectile = Projectile::builder()
+        .bullet_weight(BulletWeight(175.0))
+        .bullet_diameter(BulletDiameter(0.308))
+        .length(ProjectileLength(1.24))
+        .build()
+        .unwrap();
+
+    assert_eq!(projectile.ballistic_coefficient(), None);
+}
@@ -0,0 +1,135 @@
+use ballistics_rs::{
+    AngularUnit, ApproxEq, BulletDrop, Distance, KineticEnergy, MachNumber, RangeUnit,
+    TimeOfFlight, Trajectory, TrajectoryPoint, Units, Velocity, WindDeflection,
+};
+
+/// Builds a short trajectory from fixed points, so the golden files depend only on the unit
+/// conversions and the format, not on the integrator.
+fn trajectory() -> Trajectory {
+    [
+        (0.0, -1.5, 0.0, 2800.0, 2924.9, 0.0),
+        (100.0, 1.2, 0.8, 2600.0, 2522.1, 0.1134),
+        (200.0, -3.1, 3.4, 2410.0, 2166.7, 0.2362),
+        (300.0, -14.7, 7.9, 2230.0, 1855.3, 0.3703),
+    ]
+    .into_iter()
+    .map(
+        |(meters, drop, windage, velocity, energy, time_of_flight)| TrajectoryPoint {
+            distance: Distance::from_meters(meters),
+            drop: BulletDrop(drop),
+            windage: WindDeflection(windage),
+            velocity: Velocity(velocity),
+            mach: MachNumber(velocity / 1116.45),
+            energy: Some(KineticEnergy(energy)),
+            time_of_flight: TimeOfFlight(time_of_flight),
+        },
+    )
+    .collect()
+}
+
+#[test]
+fn metric_card_matches_golden_file() {
+    let mut csv = Vec::new();
+    trajectory()
+        .to_csv()
+        .writer(&mut csv)
+        .units(Units::Metric)
+        .angular_unit(AngularUnit::Mil)
+        .write()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        include_str!("golden/metric_range_card.csv")
+    );
+}
+
+#[test]
+fn mixed_card_matches_golden_file() {
+    let mut csv = Vec::new();
+    trajectory()
+        .to_csv()
+        .writer(&mut csv)
+        .range_unit(RangeUnit::Yards)
+        .angular_unit(AngularUnit::Mil)
+        .write()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        include_str!("golden/yards_mil_range_card.csv")
+    );
+}
+
+/// Parses a CSV card back into its header and rows of values, an empty field reading as `None`.
+fn read_csv(csv: &[u8]) -> (String, Vec<Vec<Option<f64>>>) {
+    let csv = std::str::from_utf8(csv).unwrap();
+    let mut lines = csv.lines();
+    let header = lines.next().unwrap().to_string();
+    let rows = lines
+        .map(|line| {
+            line.split(',')
+                .map(|field| (!field.is_empty()).then(|| field.parse().unwrap()))
+                .collect()
+        })
+        .collect();
+
+    (header, rows)
+}
+
+#[test]
+fn card_reads_back_to_the_trajectory() {
+    let mut trajectory: Vec<TrajectoryPoint> = trajectory().points().to_vec();
+    trajectory[2].energy = None;
+    let trajectory: Trajectory = trajectory.into_iter().collect();
+
+    let mut csv = Vec::new();
+    trajectory
+        .to_csv()
+        .writer(&mut csv)
+        .precision(6)
+        .write()
+        .unwrap();
+    let (header, rows) = read_csv(&csv);
+
+    assert_eq!(
+        header,
+        "range,drop_in,drop_moa,windage_in,velocity_fps,energy_ftlb,tof_s"
+    );
+    assert_eq!(rows.len(), trajectory.points().len());
+    for (row, point) in rows.iter().zip(trajectory.points()) {
+        let close = |field: usize, expected: f64| {
+            let value = row[field].unwrap();
+            assert!(
+                value.approx_eq(&expected, 5e-7),
+                "column {field}: {value} read back for {expected}"
+            );
+        };
+
+        close(0, point.distance.yards());
+        close(1, point.drop.0);
+        close(3, point.windage.0);
+        close(4, point.velocity.0);
+        close(6, point.time_of_flight.0);
+        match point.energy {
+            Some(energy) => close(5, energy.0),
+            None => assert_eq!(row[5], None),
+        }
+    }
+}
+
+#[test]
+fn empty_trajectory_writes_only_the_header() {
+    let mut csv = Vec::new();
+    Trajectory::default()
+        .to_csv()
+        .writer(&mut csv)
+        .units(Units::Metric)
+        .write()
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "range,drop_cm,drop_moa,windage_cm,velocity_mps,energy_j,tof_s\n"
+    );
+}
@@ -0,0 +1,112 @@
+use ballistics_rs::{
+    ApproxEq, BallisticCoefficient, BulletDiameter, BulletLength, Distance, GyroscopicStability,
+    RiflingTwist, SpinRate, StopCondition, TrajectorySolver, Velocity, YawOfRepose,
+};
+
+// A .308 168 gr match bullet from a 1:10" barrel.
+const MUZZLE_VELOCITY: Velocity = Velocity(2650.0);
+const DIAMETER: BulletDiameter = BulletDiameter(0.308);
+
+fn remaining_velocity(distance: Distance) -> Velocity {
+    TrajectorySolver::builder()
+        .muzzle_velocity(MUZZLE_VELOCITY)
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .max_range(distance)
+        .stop_condition(StopCondition::MaxRange)
+        .build()
+        .point_at(distance)
+        .unwrap()
+        .velocity
+}
+
+fn muzzle_spin_rate() -> SpinRate {
+    SpinRate::calculate()
+        .muzzle_velocity(MUZZLE_VELOCITY)
+        .rifling_twist(RiflingTwist(10.0 / DIAMETER.0))
+        .bullet_diameter(DIAMETER)
+        .solve()
+}
+
+fn spin_rate_at(distance: Distance) -> SpinRate {
+    SpinRate::at_range()
+        .muzzle_spin_rate(muzzle_spin_rate())
+        .distance(distance)
+        .bullet_diameter(DIAMETER)
+        .solve()
+}
+
+fn gyro_stability_at(distance: Distance) -> GyroscopicStability {
+    GyroscopicStability::at_range()
+        .muzzle_gyro_stability(GyroscopicStability(1.8))
+        .muzzle_velocity(MUZZLE_VELOCITY)
+        .velocity(remaining_velocity(distance))
+        .muzzle_spin_rate(muzzle_spin_rate())
+        .spin_rate(spin_rate_at(distance))
+        .solve()
+        .gyro_stability
+}
+
+fn yaw(
+    gyro_stability: GyroscopicStability,
+    spin_rate: SpinRate,
+    velocity: Velocity,
+) -> YawOfRepose {
+    YawOfRepose::calculate()
+        .gyro_stability(gyro_stability)
+        .spin_rate(spin_rate)
+        .velocity(velocity)
+        .bullet_length(BulletLength(1.215 / DIAMETER.0))
+        .solve()
+}
+
+#[test]
+fn yaw_of_repose_grows_as_the_velocity_decays() {
+    let spin_rate = muzzle_spin_rate();
+    let fast = yaw(GyroscopicStability(1.8), spin_rate, Velocity(2400.0));
+    let slow = yaw(GyroscopicStability(1.8), spin_rate, Velocity(1600.0));
+
+    assert!(slow.relative_eq(&YawOfRepose(fast.0 * 1.5), 1e-12));
+
+    let mut previous = yaw(GyroscopicStability(1.8), spin_rate, MUZZLE_VELOCITY);
+
+    for yards in [200.0, 400.0, 600.0, 800.0, 1000.0] {
+        let distance = Distance::from_yards(yards);
+        let downrange = yaw(
+            gyro_stability_at(distance),
+            spin_rate_at(distance),
+            remaining_velocity(distance),
+        );
+
+        assert!(downrange.0 > previous.0, "{downrange:?} at {yards} yd");
+        previous = downrange;
+    }
+}
+
+#[test]
+fn spin_loses_a_few_percent_while_the_velocity_drops_40_percent() {
+    let distance = Distance::from_yards(600.0);
+    let velocity_retained = remaining_velocity(distance).0 / MUZZLE_VELOCITY.0;
+    let spin_retained = spin_rate_at(distance).0 / muzzle_spin_rate().0;
+
+    assert!(
+        velocity_retained.approx_eq(&0.6, 0.02),
+        "{velocity_retained}"
+    );
+    assert!((0.9..0.97).contains(&spin_retained), "{spin_retained}");
+    assert!(spin_retained.relative_eq(&(-1e-6_f64 * 1800.0 * 12.0 / DIAMETER.0).exp(), 1e-12));
+}
+
+#[test]
+fn spin_decay_composes_with_the_downrange_stability() {
+    let distance = Distance::from_yards(600.0);
+    let velocity = remaining_velocity(distance);
+    let spin_retained = spin_rate_at(distance).0 / muzzle_spin_rate().0;
+    let gyro_stability = gyro_stability_at(distance);
+
+    assert!(gyro_stability.relative_eq(
+        &GyroscopicStability(1.8 * MUZZLE_VELOCITY.0 / velocity.0 * spin_retained * spin_retained),
+        1e-12
+    ));
+    // Still well up on the muzzle value, since the velocity decays far faster than the spin.
+    assert!(gyro_stability.0 > 2.5, "{gyro_stability:?}");
+}
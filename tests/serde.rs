@@ -0,0 +1,86 @@
+#![cfg(feature = "serde")]
+
+use ballistics_rs::{
+    Angle, Atmosphere, BallisticCoefficient, BulletDiameter, BulletDrop, BulletWeight, Distance,
+    KineticEnergy, MachNumber, Pressure, RiflingTwist, SightHeight, Temperature, TimeOfFlight,
+    TrajectoryPoint, Velocity, WindDeflection, WindSpeed,
+};
+use serde::{
+    de::{value, IntoDeserializer},
+    Deserialize, Serialize,
+};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Profile {
+    muzzle_velocity: Velocity,
+    bullet_weight: BulletWeight,
+    bullet_diameter: BulletDiameter,
+    ballistic_coefficient: BallisticCoefficient,
+    rifling_twist: RiflingTwist,
+    sight_height: SightHeight,
+    zero_range: Distance,
+    temperature: Temperature,
+    pressure: Pressure,
+    wind_speed: WindSpeed,
+    zero_angle: Angle,
+    atmosphere: Atmosphere,
+    point: TrajectoryPoint,
+}
+
+fn profile() -> Profile {
+    Profile {
+        muzzle_velocity: Velocity(2650.0),
+        bullet_weight: BulletWeight(168.0),
+        bullet_diameter: BulletDiameter(0.308),
+        ballistic_coefficient: BallisticCoefficient(0.462),
+        rifling_twist: RiflingTwist(10.0 / 0.308),
+        sight_height: SightHeight(1.5),
+        zero_range: Distance(300.0),
+        temperature: Temperature(41.5),
+        pressure: Pressure(29.53),
+        wind_speed: WindSpeed(-7.25),
+        zero_angle: Angle(2.875),
+        atmosphere: Atmosphere::icao(),
+        point: TrajectoryPoint {
+            distance: Distance(1500.0),
+            drop: BulletDrop(-63.3),
+            windage: WindDeflection(20.9),
+            velocity: Velocity(1792.0),
+            mach: MachNumber(1.6),
+            energy: Some(KineticEnergy(1248.0)),
+            time_of_flight: TimeOfFlight(0.696),
+        },
+    }
+}
+
+#[test]
+fn round_trips_through_json() {
+    let json = serde_json::to_string(&profile()).unwrap();
+
+    assert_eq!(serde_json::from_str::<Profile>(&json).unwrap(), profile());
+}
+
+#[test]
+fn serializes_newtypes_as_bare_numbers() {
+    let json = serde_json::to_value(profile()).unwrap();
+
+    assert_eq!(json["muzzle_velocity"], serde_json::json!(2650.0));
+    assert_eq!(json["point"]["drop"], serde_json::json!(-63.3));
+    assert_eq!(
+        serde_json::from_str::<Velocity>("2800.0").unwrap(),
+        Velocity(2800.0)
+    );
+}
+
+#[test]
+fn rejects_non_finite_values() {
+    for number in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let error =
+            Velocity::deserialize(IntoDeserializer::<value::Error>::into_deserializer(number))
+                .unwrap_err();
+
+        assert!(error.to_string().contains("invalid Velocity"), "{error}");
+    }
+
+    assert!(serde_json::from_str::<Velocity>("null").is_err());
+}
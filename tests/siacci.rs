@@ -0,0 +1,45 @@
+use ballistics_rs::{
+    ApproxEq, BallisticCoefficient, BulletDrop, BulletWeight, Distance, SiacciFunctions,
+    SiacciTrajectory, SightHeight, TimeOfFlight, TrajectorySolver, Velocity,
+};
+
+#[test]
+fn worked_example_from_the_ingalls_table() {
+    // C = 0.5 at 2800 ft/s, worked by hand from the table rows at 2800 and 2000 ft/s:
+    //   S(2800) = 3314.6619, T(2800) = 0.99369700, I(2800) = 0.0193716306, A(2800) = 28.32201221
+    //   S(2000) = 6106.2914, T(2000) = 2.17408822, A(2000) = 122.60375667
+    // The bullet slows to 2000 ft/s at X = C × (S - S₀) = 1395.81475 ft, in
+    // t = C × (T - T₀) = 0.59019561 s, having dropped
+    // y = C × X / 2 × ((A - A₀) / (S - S₀) - I₀) = 60.305" below the bore line.
+    let muzzle = SiacciFunctions::at(Velocity(2800.0));
+    assert_eq!(muzzle.space, 3314.6619);
+    assert_eq!(muzzle.time, 0.993_697);
+
+    let trajectory = SiacciTrajectory::builder()
+        .muzzle_velocity(Velocity(2800.0))
+        .ballistic_coefficient(BallisticCoefficient(0.5))
+        .build();
+    let distance = Distance(1395.81475);
+
+    assert!(trajectory
+        .velocity_at(distance)
+        .approx_eq(&Velocity(2000.0), 1e-5));
+    assert!(trajectory
+        .time_of_flight_at(distance)
+        .approx_eq(&TimeOfFlight(0.590_195_61), 1e-8));
+    assert!(trajectory
+        .drop_at(distance)
+        .approx_eq(&BulletDrop(-60.305), 1e-3));
+
+    // The point-mass G1 solver integrates the same drag.
+    let point = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2800.0))
+        .ballistic_coefficient(BallisticCoefficient(0.5))
+        .bullet_weight(BulletWeight(168.0))
+        .sight_height(SightHeight(0.0))
+        .build()
+        .point_at(distance)
+        .unwrap();
+    assert!(point.velocity.approx_eq(&Velocity(2000.0), 0.5));
+    assert!(point.drop.approx_eq(&BulletDrop(-60.305), 0.01));
+}
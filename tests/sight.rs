@@ -0,0 +1,61 @@
+use ballistics_rs::{Angle, ApertureSightCalibration, ApproxEq, SightCalibration, SightRadius};
+
+fn calibrate(sight_movement: f64, sight_radius: f64, clicks: u32) -> ApertureSightCalibration {
+    ApertureSightCalibration::calculate()
+        .sight_movement(SightCalibration(sight_movement))
+        .sight_radius(SightRadius(sight_radius))
+        .clicks(clicks)
+        .solve()
+}
+
+#[test]
+fn twice_the_movement_over_twice_the_clicks_is_identical() {
+    for (sight_movement, sight_radius) in [(0.1, 30.0), (0.0625, 34.5), (0.2, 26.0)] {
+        let over_ten = calibrate(sight_movement / 2.0, sight_radius, 10);
+        let over_twenty = calibrate(sight_movement, sight_radius, 20);
+        let over_forty = calibrate(sight_movement * 2.0, sight_radius, 40);
+
+        assert_eq!(over_ten, over_twenty);
+        assert_eq!(over_forty, over_twenty);
+    }
+}
+
+#[test]
+fn twenty_clicks_is_the_default() {
+    // 0.1" over 20 clicks on a 30" sight radius, the old 171.89 / 30 × 0.1 shortcut.
+    let calibration = ApertureSightCalibration::calculate()
+        .sight_movement(SightCalibration(0.1))
+        .sight_radius(SightRadius(30.0))
+        .solve();
+
+    assert_eq!(calibration, calibrate(0.1, 30.0, 20));
+    assert!(calibration.approx_eq(&ApertureSightCalibration(0.572_958), 1e-6));
+}
+
+#[test]
+fn movement_for_correction_round_trips_through_the_calibration() {
+    for (moa, sight_radius) in [(1.0, 30.0), (0.25, 34.5), (-4.5, 26.0)] {
+        let movement = SightCalibration::movement_for_correction()
+            .correction(Angle(moa))
+            .sight_radius(SightRadius(sight_radius))
+            .solve();
+
+        assert!(calibrate(movement.0, sight_radius, 1)
+            .relative_eq(&ApertureSightCalibration(moa), 1e-12));
+    }
+}
+
+#[test]
+fn movement_per_click_round_trips_through_the_calibration() {
+    for (click_value, sight_radius, clicks) in [(0.25, 30.0, 20), (0.5, 34.5, 10), (0.1, 26.0, 40)]
+    {
+        let per_click = SightCalibration::movement_per_click()
+            .click_value(ApertureSightCalibration(click_value))
+            .sight_radius(SightRadius(sight_radius))
+            .solve();
+        let measured = per_click.0 * f64::from(clicks);
+
+        assert!(calibrate(measured, sight_radius, clicks)
+            .relative_eq(&ApertureSightCalibration(click_value), 1e-12));
+    }
+}
@@ -0,0 +1,254 @@
+use ballistics_rs::{
+    Altitude, ApproxEq, Atmosphere, BallisticCoefficient, BallisticsError, BulletDiameter,
+    BulletLength, BulletWeight, Distance, GyroscopicStability, MachNumber, ProjectileLength,
+    RiflingTwist, SpinRate, StabilityAssessment, StabilityRating, TwistRate, Velocity,
+    SPEED_OF_SOUND_SEA_LEVEL,
+};
+
+// The cube roots of the velocity ratios to 2800 ft/s, worked out by hand.
+const CUBE_ROOTS: [(f64, f64); 6] = [
+    (1500.0, 0.812165),
+    (2000.0, 0.893904),
+    (2500.0, 0.962928),
+    (2800.0, 1.0),
+    (3000.0, 1.023264),
+    (3500.0, 1.077217),
+];
+
+fn rating(gyro_stability: f64, mach: f64) -> StabilityRating {
+    StabilityAssessment::evaluate()
+        .gyro_stability(GyroscopicStability(gyro_stability))
+        .mach(MachNumber(mach))
+        .solve()
+        .rating
+}
+
+fn stability_adjusted(gyro_stability: f64) -> Result<BallisticCoefficient, BallisticsError> {
+    BallisticCoefficient::stability_adjusted()
+        .ballistic_coefficient(BallisticCoefficient(0.5))
+        .gyro_stability(GyroscopicStability(gyro_stability))
+        .solve()
+}
+
+#[test]
+fn velocity_correction_scales_by_the_cube_root() {
+    for (velocity, cube_root) in CUBE_ROOTS {
+        let corrected = GyroscopicStability::velocity_correction()
+            .muzzle_velocity(Velocity(velocity))
+            .gyro_stability(GyroscopicStability(1.5))
+            .solve()
+            .unwrap();
+
+        assert!(
+            corrected.0.approx_eq(&(1.5 * cube_root), 1e-5),
+            "{velocity} ft/s gave {}",
+            corrected.0
+        );
+    }
+}
+
+#[test]
+fn velocity_correction_rejects_non_positive_velocities() {
+    for velocity in [0.0, -2800.0] {
+        let result = GyroscopicStability::velocity_correction()
+            .muzzle_velocity(Velocity(velocity))
+            .gyro_stability(GyroscopicStability(1.5))
+            .solve();
+
+        assert!(matches!(
+            result,
+            Err(BallisticsError::OutOfRange {
+                param: "muzzle_velocity",
+                ..
+            })
+        ));
+    }
+
+    let result = GyroscopicStability::velocity_correction()
+        .muzzle_velocity(Velocity(f64::NAN))
+        .gyro_stability(GyroscopicStability(1.5))
+        .solve();
+    assert!(matches!(result, Err(BallisticsError::NonFinite { .. })));
+}
+
+#[test]
+fn velocity_correction_rejects_negative_stability() {
+    let result = GyroscopicStability::velocity_correction()
+        .muzzle_velocity(Velocity(2800.0))
+        .gyro_stability(GyroscopicStability(-1.0))
+        .solve();
+
+    assert!(matches!(
+        result,
+        Err(BallisticsError::OutOfRange {
+            param: "gyro_stability",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn assessment_rates_each_branch() {
+    assert_eq!(rating(2.0, 2.2), StabilityRating::Stable);
+    assert_eq!(rating(1.5, 1.0), StabilityRating::Stable);
+    assert_eq!(rating(1.3, 2.2), StabilityRating::MarginallyStable);
+    assert_eq!(rating(1.3, 0.8), StabilityRating::MarginallyStable);
+    assert_eq!(rating(1.3, 1.05), StabilityRating::TransonicRisk);
+    assert_eq!(rating(1.0, 0.9), StabilityRating::TransonicRisk);
+    assert_eq!(rating(1.3, 1.2), StabilityRating::TransonicRisk);
+    assert_eq!(rating(0.9, 2.2), StabilityRating::Unstable);
+    assert_eq!(rating(0.9, 1.05), StabilityRating::Unstable);
+}
+
+#[test]
+fn assessment_keeps_its_inputs() {
+    let assessment = StabilityAssessment::evaluate()
+        .gyro_stability(GyroscopicStability(1.3))
+        .mach(MachNumber(1.05))
+        .distance(Distance::from_yards(1000.0))
+        .solve();
+
+    assert_eq!(assessment.gyro_stability, GyroscopicStability(1.3));
+    assert_eq!(assessment.mach, MachNumber(1.05));
+    assert_eq!(assessment.distance, Some(Distance::from_yards(1000.0)));
+}
+
+#[test]
+fn stability_adjusted_bc_is_unchanged_from_1_5() {
+    for gyro_stability in [1.5, 2.0, 3.5] {
+        assert_eq!(
+            stability_adjusted(gyro_stability).unwrap(),
+            BallisticCoefficient(0.5)
+        );
+    }
+}
+
+#[test]
+fn stability_adjusted_bc_loses_up_to_10_percent_down_to_1_0() {
+    // Linear from no loss at 1.5 to a 10% loss at 1.0.
+    for (gyro_stability, ballistic_coefficient) in [(1.0, 0.45), (1.25, 0.475), (1.4, 0.49)] {
+        let adjusted = stability_adjusted(gyro_stability).unwrap();
+
+        assert!(
+            adjusted.approx_eq(&BallisticCoefficient(ballistic_coefficient), 1e-12),
+            "{adjusted} at {gyro_stability}"
+        );
+    }
+
+    for gyro_stability in [0.999, 0.5, f64::NAN] {
+        assert!(matches!(
+            stability_adjusted(gyro_stability),
+            Err(BallisticsError::OutOfRange {
+                param: "gyro_stability",
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn inch_and_caliber_entry_points_agree_exactly() {
+    for (bullet_weight, twist, diameter, length) in [
+        (175.0, 10.0, 0.308, 1.24),
+        (77.0, 8.0, 0.224, 0.995),
+        (300.0, 9.0, 0.338, 1.7),
+    ] {
+        let from_calibers = GyroscopicStability::calculate()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .rifling_twist(RiflingTwist(twist / diameter))
+            .bullet_diameter(BulletDiameter(diameter))
+            .bullet_length(BulletLength(length / diameter))
+            .solve();
+        let from_inches = GyroscopicStability::calculate_from_inches()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .twist_rate(TwistRate(twist))
+            .bullet_diameter(BulletDiameter(diameter))
+            .bullet_length(ProjectileLength(length))
+            .solve();
+
+        assert_eq!(from_inches, from_calibers);
+    }
+}
+
+#[test]
+fn corrected_stability_chains_miller_velocity_and_atmosphere() {
+    // A .308 175 gr at 2600 ft/s from a 1:10" barrel, at 5000 feet.
+    let atmosphere = Atmosphere::standard_at(Altitude(5000.0));
+    let corrected = GyroscopicStability::calculate_corrected()
+        .bullet_weight(BulletWeight(175.0))
+        .rifling_twist(RiflingTwist(10.0 / 0.308))
+        .bullet_diameter(BulletDiameter(0.308))
+        .bullet_length(BulletLength(1.24 / 0.308))
+        .muzzle_velocity(Velocity(2600.0))
+        .atmosphere(atmosphere)
+        .solve();
+
+    let uncorrected = GyroscopicStability::calculate()
+        .bullet_weight(BulletWeight(175.0))
+        .rifling_twist(RiflingTwist(10.0 / 0.308))
+        .bullet_diameter(BulletDiameter(0.308))
+        .bullet_length(BulletLength(1.24 / 0.308))
+        .solve();
+    let velocity_corrected = GyroscopicStability::velocity_correction()
+        .muzzle_velocity(Velocity(2600.0))
+        .gyro_stability(uncorrected)
+        .solve()
+        .unwrap();
+    let gyro_stability = GyroscopicStability::atmospheric_correction()
+        .air_temp(atmosphere.temperature)
+        .air_pressure(atmosphere.pressure)
+        .gyro_stability(velocity_corrected)
+        .solve();
+
+    assert_eq!(corrected.uncorrected, uncorrected);
+    assert_eq!(corrected.velocity_corrected, velocity_corrected);
+    assert_eq!(corrected.gyro_stability, gyro_stability);
+    assert!(corrected.velocity_corrected.0 < corrected.uncorrected.0);
+    assert!(corrected.gyro_stability.0 > corrected.velocity_corrected.0);
+}
+
+#[test]
+fn stability_roughly_doubles_by_half_velocity() {
+    let downrange = GyroscopicStability::at_range()
+        .muzzle_gyro_stability(GyroscopicStability(1.5))
+        .muzzle_velocity(Velocity(2800.0))
+        .velocity(Velocity(1400.0))
+        .solve();
+
+    assert!(downrange
+        .gyro_stability
+        .approx_eq(&GyroscopicStability(3.0), 1e-12));
+    assert!(!downrange.transonic);
+
+    // A few percent of spin lost on the way still leaves it close to double.
+    let with_spin_decay = GyroscopicStability::at_range()
+        .muzzle_gyro_stability(GyroscopicStability(1.5))
+        .muzzle_velocity(Velocity(2800.0))
+        .velocity(Velocity(1400.0))
+        .muzzle_spin_rate(SpinRate(200_000.0))
+        .spin_rate(SpinRate(194_000.0))
+        .solve();
+
+    assert!(with_spin_decay
+        .gyro_stability
+        .approx_eq(&GyroscopicStability(3.0 * 0.97 * 0.97), 1e-12));
+}
+
+#[test]
+fn downrange_stability_flags_the_transonic_band() {
+    let at = |velocity| {
+        GyroscopicStability::at_range()
+            .muzzle_gyro_stability(GyroscopicStability(1.2))
+            .muzzle_velocity(Velocity(2800.0))
+            .velocity(Velocity(velocity))
+            .solve()
+    };
+
+    assert!(at(1100.0).transonic);
+    assert!(at(1300.0).transonic);
+    assert!(!at(1400.0).transonic);
+    assert!(!at(900.0).transonic);
+    assert!(at(1100.0)
+        .mach
+        .relative_eq(&MachNumber(1100.0 / SPEED_OF_SOUND_SEA_LEVEL.0), 1e-12));
+}
@@ -0,0 +1,63 @@
+use ballistics_rs::{
+    ApproxEq, BallisticCoefficient, BulletDrop, Distance, SteppedBC, TrajectorySolver, Velocity,
+};
+
+// 0.465 above 2600 ft/s, 0.458 down to 2100 ft/s and 0.447 below.
+const BANDS: [(Velocity, BallisticCoefficient); 3] = [
+    (Velocity(2600.0), BallisticCoefficient(0.465)),
+    (Velocity(2100.0), BallisticCoefficient(0.458)),
+    (Velocity(0.0), BallisticCoefficient(0.447)),
+];
+
+fn solver(ballistic_coefficient: f64) -> TrajectorySolver {
+    TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2800.0))
+        .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+        .build()
+}
+
+#[test]
+fn stepped_drop_at_800_yards_falls_between_the_single_bands() {
+    let distance = Distance::from_yards(800.0);
+    let stepped = SteppedBC::new(&BANDS).unwrap();
+    let stepped_drop = solver(0.465)
+        .point_at_stepped(distance, stepped)
+        .unwrap()
+        .drop;
+    let drop = |ballistic_coefficient| {
+        solver(ballistic_coefficient)
+            .point_at(distance)
+            .unwrap()
+            .drop
+    };
+
+    // The muzzle band alone under-predicts the drop by about 2", the slowest band alone
+    // over-predicts it by about 3".
+    assert!((drop(0.465).0 - stepped_drop.0).approx_eq(&2.06, 0.05));
+    assert!((stepped_drop.0 - drop(0.447).0).approx_eq(&3.02, 0.05));
+    assert!(
+        stepped_drop.approx_eq(&BulletDrop(-227.07), 0.01),
+        "{}",
+        stepped_drop.0
+    );
+
+    // The solver's own coefficient only sets the reference the bands are scaled from.
+    for ballistic_coefficient in [0.447, 0.5] {
+        assert!(solver(ballistic_coefficient)
+            .point_at_stepped(distance, stepped)
+            .unwrap()
+            .drop
+            .relative_eq(&stepped_drop, 1e-9));
+    }
+}
+
+#[test]
+fn single_band_matches_the_scalar_coefficient() {
+    let bands = [(Velocity(0.0), BallisticCoefficient(0.458))];
+    let distance = Distance::from_yards(800.0);
+
+    assert_eq!(
+        solver(0.458).point_at_stepped(distance, SteppedBC::new(&bands).unwrap()),
+        solver(0.458).point_at(distance)
+    );
+}
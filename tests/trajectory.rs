@@ -0,0 +1,151 @@
+use ballistics_rs::{
+    Angle, ApproxEq, BallisticCoefficient, BulletWeight, Distance, DragModel, SightHeight,
+    SolverOptions, StopCondition, TimeOfFlight, TrajectorySolver, Velocity,
+};
+
+fn solver() -> TrajectorySolver {
+    TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .drag_model(DragModel::G1)
+        .bullet_weight(BulletWeight(168.0))
+        .sight_height(SightHeight(1.5))
+        .launch_angle(Angle(3.0))
+        .build()
+}
+
+#[test]
+fn iterator_matches_solve() {
+    let points: Vec<_> = solver().iter_points(Distance(300.0)).collect();
+    let trajectory = solver().solve(Distance(300.0));
+
+    assert_eq!(points.len(), 11);
+    assert_eq!(points.as_slice(), trajectory.points());
+}
+
+#[test]
+fn point_at_matches_solve() {
+    for expected in solver().solve(Distance(300.0)).points().iter().skip(1) {
+        assert_eq!(solver().point_at(expected.distance), Some(*expected));
+    }
+}
+
+#[test]
+fn iterator_resumes_after_point_at() {
+    let trajectory = solver().solve(Distance(300.0));
+    let mut points = solver().iter_points(Distance(300.0));
+
+    assert_eq!(
+        points.point_at(Distance(1000.0)),
+        solver().point_at(Distance(1000.0))
+    );
+    assert_eq!(points.next().as_ref(), trajectory.points().get(4));
+    assert_eq!(points.next().as_ref(), trajectory.points().get(5));
+    assert_eq!(points.point_at(Distance(1000.0)), None);
+}
+
+#[test]
+fn stops_at_max_range() {
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .max_range(Distance(1500.0))
+        .build();
+    let last = solver.iter_points(Distance(100.0)).last().unwrap();
+
+    assert_eq!(last.distance.0, 1500.0);
+    assert_eq!(solver.point_at(Distance(1500.1)), None);
+}
+
+#[test]
+fn stops_when_subsonic() {
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .max_range(Distance(6000.0))
+        .stop_condition(StopCondition::Subsonic)
+        .build();
+    let points: Vec<_> = solver.iter_points(Distance(30.0)).collect();
+    let (last, supersonic) = points.split_last().unwrap();
+
+    assert!(last.distance.0 < 6000.0);
+    assert!(last.mach.0 < 1.05);
+    assert!(supersonic.iter().all(|point| point.mach.0 >= 1.0));
+}
+
+#[test]
+fn stops_at_the_ground() {
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .stop_condition(StopCondition::Ground(Distance(5.0)))
+        .build();
+    let points: Vec<_> = solver.iter_points(Distance(3.0)).collect();
+    let (last, airborne) = points.split_last().unwrap();
+
+    assert!(last.distance.0 < 3000.0);
+    assert!(last.drop.0 < -55.0);
+    assert!(airborne.iter().all(|point| point.drop.0 >= -60.0));
+}
+
+#[test]
+fn starts_one_sight_height_below_the_line_of_sight() {
+    for sight_height in [
+        SightHeight(0.0),
+        SightHeight(1.5),
+        SightHeight::from_cm(7.0),
+    ] {
+        let muzzle = TrajectorySolver::builder()
+            .muzzle_velocity(Velocity(2650.0))
+            .ballistic_coefficient(BallisticCoefficient(0.462))
+            .sight_height(sight_height)
+            .launch_angle(Angle(3.0))
+            .build()
+            .iter_points(Distance(300.0))
+            .next()
+            .unwrap();
+
+        assert_eq!(muzzle.distance.0, 0.0);
+        assert_eq!(muzzle.drop.0, -sight_height.0);
+    }
+}
+
+#[test]
+fn adaptive_steps_match_a_fine_fixed_step_run_in_fewer_steps() {
+    // 1500 yards takes the bullet through the transonic region, where the step shrinks.
+    let range = Distance::from_yards(1500.0);
+    let run = |solver_options: Option<SolverOptions>| {
+        let solver = TrajectorySolver::builder()
+            .muzzle_velocity(Velocity(2650.0))
+            .ballistic_coefficient(BallisticCoefficient(0.462))
+            .launch_angle(Angle(30.0))
+            .max_range(range)
+            .maybe_solver_options(solver_options)
+            .build();
+        let mut points = solver.iter_points(range);
+        let point = points.point_at(range).unwrap();
+
+        (point, points.steps())
+    };
+    // A step the error control never changes: 10 µs throughout.
+    let (reference, reference_steps) = run(Some(SolverOptions {
+        min_step: TimeOfFlight(1e-5),
+        max_step: TimeOfFlight(1e-5),
+        ..SolverOptions::default()
+    }));
+    let (fixed, fixed_steps) = run(None);
+    let (adaptive, adaptive_steps) = run(Some(SolverOptions::default()));
+
+    assert!(reference.velocity.0 < 1000.0);
+    assert!(adaptive.drop.approx_eq(&reference.drop, 1e-3));
+    assert!(adaptive.velocity.approx_eq(&reference.velocity, 1e-3));
+    assert!(adaptive
+        .time_of_flight
+        .approx_eq(&reference.time_of_flight, 1e-6));
+    assert!(fixed.drop.approx_eq(&reference.drop, 1e-3));
+    assert!(
+        adaptive_steps * 10 < fixed_steps,
+        "{adaptive_steps} adaptive steps, {fixed_steps} fixed"
+    );
+    assert!(fixed_steps * 10 < reference_steps);
+}
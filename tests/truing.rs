@@ -0,0 +1,141 @@
+use ballistics_rs::{
+    Angle, ApproxEq, BallisticCoefficient, BallisticsError, BulletDrop, Distance,
+    MuzzleVelocityTruing, Observation, ObservedDrop, SightHeight, StopCondition, TimeOfFlight,
+    TrajectoryPoint, TrajectorySolver, Velocity,
+};
+
+fn solver(muzzle_velocity: f64, ballistic_coefficient: f64) -> TrajectorySolver {
+    TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(muzzle_velocity))
+        .ballistic_coefficient(BallisticCoefficient(ballistic_coefficient))
+        .sight_height(SightHeight(1.5))
+        .max_range(Distance::from_yards(1000.0))
+        .stop_condition(StopCondition::MaxRange)
+        .build()
+}
+
+/// Zeroes a load at 100 yards and returns the point at a distance.
+fn observe(
+    muzzle_velocity: f64,
+    ballistic_coefficient: f64,
+    distance: Distance,
+) -> TrajectoryPoint {
+    let solver = solver(muzzle_velocity, ballistic_coefficient);
+    let launch_angle = solver
+        .zero_angle()
+        .zero_range(Distance::from_yards(100.0))
+        .solve()
+        .unwrap();
+
+    solver
+        .with_launch_angle(launch_angle)
+        .point_at(distance)
+        .unwrap()
+}
+
+fn true_muzzle_velocity(
+    nominal: f64,
+    observed: ObservedDrop,
+) -> Result<MuzzleVelocityTruing, BallisticsError> {
+    Velocity::true_from_drop()
+        .solver(solver(nominal, 0.462))
+        .zero_range(Distance::from_yards(100.0))
+        .distance(Distance::from_yards(500.0))
+        .observed(observed)
+        .solve()
+}
+
+#[test]
+fn muzzle_velocity_round_trips_from_a_perturbed_nominal() {
+    // The load really leaves the muzzle at 2650 ft/s.
+    let distance = Distance::from_yards(500.0);
+    let drop = observe(2650.0, 0.462, distance).drop;
+
+    for nominal in [2450.0, 2600.0, 2750.0, 2900.0] {
+        let trued = true_muzzle_velocity(nominal, ObservedDrop::Inches(drop)).unwrap();
+
+        assert!(
+            trued.muzzle_velocity.approx_eq(&Velocity(2650.0), 0.05),
+            "{}",
+            trued.muzzle_velocity.0
+        );
+        assert!(trued.residual.approx_eq(&BulletDrop(0.0), 0.01));
+    }
+
+    let dialed = Angle::from_subtension(-drop.0, distance);
+    let trued = true_muzzle_velocity(2750.0, ObservedDrop::Dialed(dialed)).unwrap();
+    assert!(trued.muzzle_velocity.approx_eq(&Velocity(2650.0), 0.05));
+}
+
+#[test]
+fn muzzle_velocity_outside_the_bracket_has_no_solution() {
+    let drop = observe(2650.0, 0.462, Distance::from_yards(500.0)).drop;
+
+    assert!(matches!(
+        true_muzzle_velocity(3000.0, ObservedDrop::Inches(drop)),
+        Err(BallisticsError::NoTruingSolution {
+            param: "muzzle_velocity",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn ballistic_coefficient_trues_to_an_observed_drop() {
+    // The bullet really has a BC of 0.505 at 2600 ft/s; the box says 0.45.
+    let distance = Distance::from_yards(1000.0);
+    let drop = observe(2600.0, 0.505, distance).drop;
+
+    let trued = BallisticCoefficient::true_from_observation()
+        .solver(solver(2600.0, 0.45))
+        .zero_range(Distance::from_yards(100.0))
+        .distance(distance)
+        .observation(Observation::Drop(ObservedDrop::Dialed(
+            Angle::from_subtension(-drop.0, distance),
+        )))
+        .solve()
+        .unwrap();
+
+    assert!(trued
+        .ballistic_coefficient
+        .approx_eq(&BallisticCoefficient(0.505), 1e-4));
+    assert!(matches!(
+        trued.observation,
+        Observation::Drop(ObservedDrop::Inches(observed)) if observed.approx_eq(&drop, 1e-6)
+    ));
+    assert!(trued.residual.abs() < 0.1, "{}", trued.residual);
+}
+
+#[test]
+fn ballistic_coefficient_trues_to_an_observed_time_of_flight() {
+    let distance = Distance::from_yards(1000.0);
+    let time_of_flight = observe(2600.0, 0.505, distance).time_of_flight;
+
+    let trued = BallisticCoefficient::true_from_observation()
+        .solver(solver(2600.0, 0.55))
+        .zero_range(Distance::from_yards(100.0))
+        .distance(distance)
+        .observation(Observation::TimeOfFlight(time_of_flight))
+        .tolerance(1e-5)
+        .solve()
+        .unwrap();
+
+    assert!(trued
+        .ballistic_coefficient
+        .approx_eq(&BallisticCoefficient(0.505), 1e-5));
+    assert_eq!(trued.observation, Observation::TimeOfFlight(time_of_flight));
+    assert!(trued.residual.abs() < 1e-3, "{}", trued.residual);
+
+    assert!(matches!(
+        BallisticCoefficient::true_from_observation()
+            .solver(solver(2600.0, 0.55))
+            .zero_range(Distance::from_yards(100.0))
+            .distance(distance)
+            .observation(Observation::TimeOfFlight(TimeOfFlight(0.5)))
+            .solve(),
+        Err(BallisticsError::NoTruingSolution {
+            param: "ballistic_coefficient",
+            ..
+        })
+    ));
+}
@@ -0,0 +1,81 @@
+use ballistics_rs::{
+    AerodynamicJump, BulletLength, Distance, GyroscopicStability, SpinDrift, SpinRate,
+    TimeOfFlight, TwistDirection, Velocity, WindSpeed, YawOfRepose,
+};
+
+fn spin_drift(twist_direction: TwistDirection) -> SpinDrift {
+    SpinDrift::calculate()
+        .gyro_stability(GyroscopicStability(1.8))
+        .actual_time_of_flight(TimeOfFlight(1.4))
+        .twist_direction(twist_direction)
+        .solve()
+}
+
+#[test]
+fn right_twist_is_the_default() {
+    assert_eq!(TwistDirection::default(), TwistDirection::Right);
+    assert_eq!(
+        SpinDrift::calculate()
+            .gyro_stability(GyroscopicStability(1.8))
+            .actual_time_of_flight(TimeOfFlight(1.4))
+            .solve(),
+        spin_drift(TwistDirection::Right)
+    );
+}
+
+#[test]
+fn left_twist_mirrors_the_spin_drift() {
+    let right = spin_drift(TwistDirection::Right);
+    let left = spin_drift(TwistDirection::Left);
+
+    assert!(right.0 > 0.0);
+    assert_eq!(left.0, -right.0);
+
+    let checked = |twist_direction| {
+        SpinDrift::try_calculate()
+            .gyro_stability(GyroscopicStability(1.8))
+            .actual_time_of_flight(TimeOfFlight(1.4))
+            .twist_direction(twist_direction)
+            .solve()
+            .unwrap()
+    };
+
+    assert_eq!(checked(TwistDirection::Right), right);
+    assert_eq!(checked(TwistDirection::Left), left);
+}
+
+#[test]
+fn left_twist_mirrors_the_aerodynamic_jump() {
+    let jump = |twist_direction| {
+        AerodynamicJump::deflection_at()
+            .jump(AerodynamicJump(0.35))
+            .crosswind_speed(WindSpeed(10.0))
+            .distance(Distance::from_yards(600.0))
+            .twist_direction(twist_direction)
+            .solve()
+    };
+    let right = jump(TwistDirection::Right);
+    let left = jump(TwistDirection::Left);
+
+    assert!(right.angle.0 > 0.0);
+    assert_eq!(left.angle.0, -right.angle.0);
+    assert_eq!(left.deflection.0, -right.deflection.0);
+}
+
+#[test]
+fn left_twist_mirrors_the_yaw_of_repose() {
+    let yaw = |twist_direction| {
+        YawOfRepose::calculate()
+            .gyro_stability(GyroscopicStability(1.8))
+            .spin_rate(SpinRate(190_000.0))
+            .velocity(Velocity(1800.0))
+            .bullet_length(BulletLength(4.0))
+            .twist_direction(twist_direction)
+            .solve()
+    };
+    let right = yaw(TwistDirection::Right);
+    let left = yaw(TwistDirection::Left);
+
+    assert!(right.0 > 0.0);
+    assert_eq!(left.0, -right.0);
+}
@@ -0,0 +1,56 @@
+use ballistics_rs::{ApproxEq, BulletWeight, KineticEnergy, Uncertain, Velocity};
+
+#[test]
+fn energy_uncertainty_is_twice_the_relative_velocity_uncertainty() {
+    for (velocity, sd) in [(2800.0, 12.0), (2650.0, 5.0), (1100.0, 30.0)] {
+        let energy = KineticEnergy::calculate_uncertain()
+            .bullet_weight(BulletWeight(168.0))
+            .velocity(Uncertain::new(Velocity(velocity), Velocity(sd)))
+            .solve();
+
+        assert_eq!(
+            energy.value(),
+            KineticEnergy::calculate()
+                .bullet_weight(BulletWeight(168.0))
+                .velocity(Velocity(velocity))
+                .solve()
+        );
+        assert!(
+            (energy.sd().0 / energy.value().0).relative_eq(&(2.0 * sd / velocity), 1e-9),
+            "{velocity} ± {sd} ft/s"
+        );
+    }
+}
+
+#[test]
+fn energy_uncertainty_adds_the_weight_in_quadrature() {
+    // 168 ± 0.5 gr at 2800 ± 12 ft/s.
+    let energy = KineticEnergy::calculate_uncertain()
+        .bullet_weight(Uncertain::new(BulletWeight(168.0), BulletWeight(0.5)))
+        .velocity(Uncertain::new(Velocity(2800.0), Velocity(12.0)))
+        .solve();
+    let relative = ((0.5_f64 / 168.0).powi(2) + (2.0 * 12.0_f64 / 2800.0).powi(2)).sqrt();
+
+    assert!((energy.sd().0 / energy.value().0).relative_eq(&relative, 1e-9));
+
+    let (low, high) = energy.bounds(2.0);
+    assert!(low.relative_eq(
+        &KineticEnergy(energy.value().0 - 2.0 * energy.sd().0),
+        1e-12
+    ));
+    assert!(high.relative_eq(
+        &KineticEnergy(energy.value().0 + 2.0 * energy.sd().0),
+        1e-12
+    ));
+}
+
+#[test]
+fn exact_inputs_carry_no_uncertainty() {
+    let energy = KineticEnergy::calculate_uncertain()
+        .bullet_weight(BulletWeight(168.0))
+        .velocity(Velocity(2800.0))
+        .solve();
+
+    assert_eq!(energy.sd(), KineticEnergy(0.0));
+    assert_eq!(energy.bounds(3.0), (energy.value(), energy.value()));
+}
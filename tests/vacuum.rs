@@ -0,0 +1,59 @@
+use ballistics_rs::{Angle, ApproxEq, Distance, TimeOfFlight, VacuumTrajectory, Velocity};
+
+fn vacuum(degrees: f64) -> VacuumTrajectory {
+    VacuumTrajectory::builder()
+        .velocity(Velocity(1000.0))
+        .launch_angle(Angle::from_degrees(degrees))
+        .build()
+}
+
+#[test]
+fn forty_five_degrees_maximizes_the_range() {
+    let best = vacuum(45.0).max_range();
+
+    // v² / g for 1000 ft/s.
+    assert!(best.approx_eq(&Distance(1_000_000.0 / 32.174), 1e-6));
+    for degrees in [1.0, 15.0, 30.0, 44.0, 44.9, 45.1, 46.0, 60.0, 89.0] {
+        assert!(vacuum(degrees).max_range().0 < best.0, "{degrees}°");
+    }
+    for degrees in [10.0, 30.0, 44.0] {
+        assert!(vacuum(degrees)
+            .max_range()
+            .relative_eq(&vacuum(90.0 - degrees).max_range(), 1e-12));
+    }
+}
+
+#[test]
+fn ascent_and_descent_are_symmetric() {
+    for degrees in [5.0, 30.0, 45.0, 70.0] {
+        let trajectory = vacuum(degrees);
+        let time_of_flight = trajectory.time_of_flight();
+
+        assert!(trajectory
+            .apex_time()
+            .relative_eq(&TimeOfFlight(time_of_flight.0 / 2.0), 1e-12));
+        assert_eq!(trajectory.impact_angle(), trajectory.launch_angle);
+
+        let (apex_distance, apex_height) = trajectory.position_at(trajectory.apex_time());
+        assert!(apex_height.relative_eq(&trajectory.apex_height(), 1e-12));
+        assert!(apex_distance.relative_eq(&Distance(trajectory.max_range().0 / 2.0), 1e-12));
+
+        let (range, height) = trajectory.position_at(time_of_flight);
+        assert!(range.relative_eq(&trajectory.max_range(), 1e-12));
+        assert!(height.approx_eq(&Distance(0.0), 1e-6));
+
+        for fraction in [0.1, 0.25, 0.4] {
+            let (rising_distance, rising) =
+                trajectory.position_at(TimeOfFlight(time_of_flight.0 * fraction));
+            let (falling_distance, falling) =
+                trajectory.position_at(TimeOfFlight(time_of_flight.0 * (1.0 - fraction)));
+
+            assert!(
+                rising.relative_eq(&falling, 1e-9),
+                "{degrees}° at {fraction}"
+            );
+            assert!((apex_distance.0 - rising_distance.0)
+                .approx_eq(&(falling_distance.0 - apex_distance.0), 1e-6));
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use ballistics_rs::{
+    AerodynamicJump, Angle, ApertureSightCalibration, BallisticCoefficient, BallisticsError,
+    BulletDiameter, BulletLength, BulletWeight, Distance, DragCoefficient, FormFactor, Gravity,
+    GyroscopicStability, HitProbability, ImpactDispersion, KineticEnergy, LagTime, Pressure,
+    RelativeHumidity, RiflingTwist, SightCalibration, SightRadius, SpeedOfSound, SpinDrift,
+    TargetShape, TargetSize, Temperature, TimeOfFlight, Velocity, VelocityProjection,
+    WindDeflection, WindSpeed,
+};
+
+fn out_of_range<T>(result: Result<T, BallisticsError>, expected: &str) -> bool {
+    matches!(result, Err(BallisticsError::OutOfRange { param, .. }) if param == expected)
+}
+
+fn non_finite<T>(result: Result<T, BallisticsError>, expected: &str) -> bool {
+    matches!(result, Err(BallisticsError::NonFinite { param, .. }) if param == expected)
+}
+
+fn division_by_zero<T>(result: Result<T, BallisticsError>, expected: &str) -> bool {
+    matches!(result, Err(BallisticsError::DivisionByZero { param }) if param == expected)
+}
+
+#[test]
+fn temperature_must_be_above_absolute_zero() {
+    assert!(out_of_range(Temperature::try_from(-459.67), "temperature"));
+    assert!(out_of_range(Temperature::try_from(-500.0), "temperature"));
+    assert_eq!(Temperature::try_from(-459.66).unwrap().0, -459.66);
+}
+
+#[test]
+fn weights_lengths_and_pressures_must_be_positive() {
+    assert!(out_of_range(BulletWeight::try_from(0.0), "bullet_weight"));
+    assert!(out_of_range(
+        BulletDiameter::try_from(-0.308),
+        "bullet_diameter"
+    ));
+    assert!(out_of_range(Distance::try_from(0.0), "distance"));
+    assert!(out_of_range(Pressure::try_from(0.0), "pressure"));
+
+    assert_eq!(
+        BulletWeight::try_from(f64::MIN_POSITIVE).unwrap().0,
+        f64::MIN_POSITIVE
+    );
+    assert_eq!(Distance::try_from(300.0).unwrap().0, 300.0);
+}
+
+#[test]
+fn drag_coefficient_may_be_zero_but_not_negative() {
+    assert_eq!(DragCoefficient::try_from(0.0).unwrap().0, 0.0);
+    assert!(out_of_range(
+        DragCoefficient::try_from(-1e-9),
+        "drag_coefficient"
+    ));
+}
+
+#[test]
+fn relative_humidity_is_a_percentage() {
+    assert_eq!(RelativeHumidity::try_from(0.0).unwrap().0, 0.0);
+    assert_eq!(RelativeHumidity::try_from(100.0).unwrap().0, 100.0);
+    assert!(out_of_range(
+        RelativeHumidity::try_from(100.1),
+        "relative_humidity"
+    ));
+}
+
+#[test]
+fn non_finite_values_are_rejected() {
+    assert!(matches!(
+        BulletWeight::try_from(f64::NAN),
+        Err(BallisticsError::NonFinite {
+            param: "bullet_weight",
+            ..
+        })
+    ));
+    assert!(matches!(
+        Temperature::try_from(f64::INFINITY),
+        Err(BallisticsError::NonFinite {
+            param: "temperature",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn speed_of_sound_rejects_each_input() {
+    let speed_of_sound = |temperature| {
+        SpeedOfSound::try_calculate()
+            .temperature(Temperature(temperature))
+            .solve()
+    };
+
+    assert!(out_of_range(speed_of_sound(-500.0), "temperature"));
+    assert!(speed_of_sound(59.0).is_ok());
+}
+
+#[test]
+fn kinetic_energy_rejects_each_input() {
+    let kinetic_energy = |bullet_weight, velocity, gravity| {
+        KineticEnergy::try_calculate()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .velocity(Velocity(velocity))
+            .gravity(Gravity(gravity))
+            .solve()
+    };
+
+    assert!(out_of_range(
+        kinetic_energy(0.0, 2600.0, 32.174),
+        "bullet_weight"
+    ));
+    assert!(non_finite(
+        kinetic_energy(175.0, f64::NAN, 32.174),
+        "velocity"
+    ));
+    assert!(division_by_zero(
+        kinetic_energy(175.0, 2600.0, 0.0),
+        "gravity"
+    ));
+    assert!(kinetic_energy(175.0, 2600.0, 32.174).is_ok());
+}
+
+#[test]
+fn aperture_sight_calibration_rejects_each_input() {
+    let calibration = |sight_movement, sight_radius, clicks| {
+        ApertureSightCalibration::try_calculate()
+            .sight_movement(SightCalibration(sight_movement))
+            .sight_radius(SightRadius(sight_radius))
+            .clicks(clicks)
+            .solve()
+    };
+
+    assert!(non_finite(
+        calibration(f64::INFINITY, 30.0, 20),
+        "sight_movement"
+    ));
+    assert!(division_by_zero(calibration(0.1, 0.0, 20), "sight_radius"));
+    assert!(division_by_zero(calibration(0.1, 30.0, 0), "clicks"));
+    assert!(calibration(0.1, 30.0, 20).is_ok());
+}
+
+#[test]
+fn sight_calibration_rejects_each_input() {
+    let for_correction = |correction, sight_radius| {
+        SightCalibration::try_movement_for_correction()
+            .correction(Angle(correction))
+            .sight_radius(SightRadius(sight_radius))
+            .solve()
+    };
+    let per_click = |click_value, sight_radius| {
+        SightCalibration::try_movement_per_click()
+            .click_value(ApertureSightCalibration(click_value))
+            .sight_radius(SightRadius(sight_radius))
+            .solve()
+    };
+
+    assert!(non_finite(for_correction(f64::NAN, 30.0), "correction"));
+    assert!(out_of_range(for_correction(1.0, -30.0), "sight_radius"));
+    assert!(for_correction(1.0, 30.0).is_ok());
+    assert!(non_finite(per_click(f64::NAN, 30.0), "click_value"));
+    assert!(out_of_range(per_click(0.25, 0.0), "sight_radius"));
+    assert!(per_click(0.25, 30.0).is_ok());
+}
+
+#[test]
+fn form_factor_rejects_each_input() {
+    let form_factor = |drag_coefficient, standard_bullet_drag_coefficient| {
+        FormFactor::try_calculate()
+            .drag_coefficient(DragCoefficient(drag_coefficient))
+            .standard_bullet_drag_coefficient(DragCoefficient(standard_bullet_drag_coefficient))
+            .solve()
+    };
+
+    assert!(out_of_range(form_factor(-0.3, 0.5), "drag_coefficient"));
+    assert!(division_by_zero(
+        form_factor(0.3, 0.0),
+        "standard_bullet_drag_coefficient"
+    ));
+    assert!(form_factor(0.3, 0.5).is_ok());
+}
+
+#[test]
+fn velocity_projection_rejects_each_input() {
+    let projection = |bullet_weight_1, bullet_weight_2, bullet_velocity_1| {
+        VelocityProjection::try_calculate()
+            .bullet_weight_1(BulletWeight(bullet_weight_1))
+            .bullet_weight_2(BulletWeight(bullet_weight_2))
+            .bullet_velocity_1(Velocity(bullet_velocity_1))
+            .solve()
+    };
+
+    assert!(out_of_range(
+        projection(-150.0, 168.0, 2800.0),
+        "bullet_weight_1"
+    ));
+    assert!(division_by_zero(
+        projection(150.0, 0.0, 2800.0),
+        "bullet_weight_2"
+    ));
+    assert!(non_finite(
+        projection(150.0, 168.0, f64::INFINITY),
+        "bullet_velocity_1"
+    ));
+    assert!(projection(150.0, 168.0, 2800.0).is_ok());
+}
+
+#[test]
+fn lag_time_rejects_each_input() {
+    let lag_time = |actual_time_of_flight, distance, muzzle_velocity| {
+        LagTime::try_calculate()
+            .actual_time_of_flight(TimeOfFlight(actual_time_of_flight))
+            .distance(Distance(distance))
+            .muzzle_velocity(Velocity(muzzle_velocity))
+            .solve()
+    };
+
+    assert!(out_of_range(
+        lag_time(-1.0, 2400.0, 2600.0),
+        "actual_time_of_flight"
+    ));
+    assert!(out_of_range(lag_time(1.2643, -2400.0, 2600.0), "distance"));
+    assert!(division_by_zero(
+        lag_time(1.2643, 2400.0, 0.0),
+        "muzzle_velocity"
+    ));
+    assert!(lag_time(1.2643, 2400.0, 2600.0).is_ok());
+}
+
+#[test]
+fn wind_deflection_rejects_each_input() {
+    let deflection = |lag_time, crosswind_speed| {
+        WindDeflection::try_calculate()
+            .lag_time(LagTime(lag_time))
+            .crosswind_speed(WindSpeed(crosswind_speed))
+            .solve()
+    };
+
+    assert!(out_of_range(deflection(-0.1, 10.0), "lag_time"));
+    assert!(non_finite(deflection(0.34, f64::NAN), "crosswind_speed"));
+    assert!(deflection(0.34, -10.0).is_ok());
+}
+
+#[test]
+fn aerodynamic_jump_rejects_each_input() {
+    let jump = |gyro_stability, bullet_length| {
+        AerodynamicJump::try_calculate()
+            .gyro_stability(GyroscopicStability(gyro_stability))
+            .bullet_length(BulletLength(bullet_length))
+            .solve()
+    };
+
+    assert!(out_of_range(jump(-1.5, 4.0), "gyro_stability"));
+    assert!(out_of_range(jump(1.5, 0.0), "bullet_length"));
+    assert!(jump(1.5, 4.0).is_ok());
+}
+
+#[test]
+fn gyroscopic_stability_rejects_each_input() {
+    let stability = |bullet_weight, rifling_twist, bullet_diameter, bullet_length| {
+        GyroscopicStability::try_calculate()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .rifling_twist(RiflingTwist(rifling_twist))
+            .bullet_diameter(BulletDiameter(bullet_diameter))
+            .bullet_length(BulletLength(bullet_length))
+            .solve()
+    };
+
+    assert!(out_of_range(
+        stability(0.0, 32.5, 0.308, 4.0),
+        "bullet_weight"
+    ));
+    assert!(division_by_zero(
+        stability(175.0, 0.0, 0.308, 4.0),
+        "rifling_twist"
+    ));
+    assert!(division_by_zero(
+        stability(175.0, 32.5, 0.0, 4.0),
+        "bullet_diameter"
+    ));
+    assert!(non_finite(
+        stability(175.0, 32.5, 0.308, f64::NAN),
+        "bullet_length"
+    ));
+    assert!(stability(175.0, 32.5, 0.308, 4.0).is_ok());
+}
+
+#[test]
+fn stability_corrections_reject_each_input() {
+    let velocity_correction = |muzzle_velocity, gyro_stability| {
+        GyroscopicStability::velocity_correction()
+            .muzzle_velocity(Velocity(muzzle_velocity))
+            .gyro_stability(GyroscopicStability(gyro_stability))
+            .solve()
+    };
+    let atmospheric_correction = |air_temp, air_pressure, gyro_stability| {
+        GyroscopicStability::try_atmospheric_correction()
+            .air_temp(Temperature(air_temp))
+            .air_pressure(Pressure(air_pressure))
+            .gyro_stability(GyroscopicStability(gyro_stability))
+            .solve()
+    };
+
+    assert!(out_of_range(
+        velocity_correction(0.0, 1.5),
+        "muzzle_velocity"
+    ));
+    assert!(out_of_range(
+        velocity_correction(2800.0, -1.5),
+        "gyro_stability"
+    ));
+    assert!(velocity_correction(2800.0, 1.5).is_ok());
+    assert!(out_of_range(
+        atmospheric_correction(-460.0, 29.92, 1.5),
+        "air_temp"
+    ));
+    assert!(division_by_zero(
+        atmospheric_correction(59.0, 0.0, 1.5),
+        "air_pressure"
+    ));
+    assert!(non_finite(
+        atmospheric_correction(59.0, 29.92, f64::INFINITY),
+        "gyro_stability"
+    ));
+    assert!(atmospheric_correction(59.0, 29.92, 1.5).is_ok());
+}
+
+#[test]
+fn spin_drift_rejects_each_input() {
+    let spin_drift = |gyro_stability, actual_time_of_flight| {
+        SpinDrift::try_calculate()
+            .gyro_stability(GyroscopicStability(gyro_stability))
+            .actual_time_of_flight(TimeOfFlight(actual_time_of_flight))
+            .solve()
+    };
+
+    assert!(out_of_range(spin_drift(-1.5, 1.2643), "gyro_stability"));
+    assert!(out_of_range(spin_drift(1.5, -1.0), "actual_time_of_flight"));
+    assert!(spin_drift(1.5, 1.2643).is_ok());
+}
+
+#[test]
+fn ballistic_coefficient_rejects_each_input() {
+    let coefficient = |bullet_weight, bullet_diameter, form_factor| {
+        BallisticCoefficient::try_calculate()
+            .bullet_weight(BulletWeight(bullet_weight))
+            .bullet_diameter(BulletDiameter(bullet_diameter))
+            .form_factor(FormFactor(form_factor))
+            .solve()
+    };
+
+    assert!(non_finite(
+        coefficient(f64::NAN, 0.308, 0.52),
+        "bullet_weight"
+    ));
+    assert!(division_by_zero(
+        coefficient(175.0, 0.0, 0.52),
+        "bullet_diameter"
+    ));
+    assert!(out_of_range(
+        coefficient(175.0, 0.308, -0.52),
+        "form_factor"
+    ));
+    assert!(coefficient(175.0, 0.308, 0.52).is_ok());
+}
+
+#[test]
+fn hit_probability_rejects_each_input() {
+    let circle = TargetShape::Circle {
+        diameter: TargetSize(10.0),
+    };
+    let hit_probability = |dispersion, target, distance| {
+        HitProbability::try_calculate()
+            .dispersion(dispersion)
+            .target(target)
+            .distance(Distance(distance))
+            .solve()
+    };
+
+    assert!(out_of_range(
+        hit_probability(ImpactDispersion::Circular(Angle(-0.5)), circle, 300.0),
+        "sigma"
+    ));
+    assert!(out_of_range(
+        hit_probability(
+            ImpactDispersion::Elliptical {
+                horizontal: Angle(-0.5),
+                vertical: Angle(0.5),
+            },
+            circle,
+            300.0
+        ),
+        "horizontal"
+    ));
+    assert!(non_finite(
+        hit_probability(
+            ImpactDispersion::Elliptical {
+                horizontal: Angle(0.5),
+                vertical: Angle(f64::NAN),
+            },
+            circle,
+            300.0
+        ),
+        "vertical"
+    ));
+    assert!(out_of_range(
+        hit_probability(ImpactDispersion::MeanRadius(Angle(-0.5)), circle, 300.0),
+        "mean_radius"
+    ));
+
+    let sigma = ImpactDispersion::Circular(Angle(0.5));
+    assert!(out_of_range(
+        hit_probability(
+            sigma,
+            TargetShape::Circle {
+                diameter: TargetSize(-10.0),
+            },
+            300.0
+        ),
+        "diameter"
+    ));
+    assert!(out_of_range(
+        hit_probability(
+            sigma,
+            TargetShape::Rectangle {
+                width: TargetSize(-10.0),
+                height: TargetSize(20.0),
+            },
+            300.0
+        ),
+        "width"
+    ));
+    assert!(out_of_range(
+        hit_probability(
+            sigma,
+            TargetShape::Rectangle {
+                width: TargetSize(10.0),
+                height: TargetSize(-20.0),
+            },
+            300.0
+        ),
+        "height"
+    ));
+    assert!(out_of_range(
+        hit_probability(sigma, circle, 0.0),
+        "distance"
+    ));
+    assert!(hit_probability(sigma, circle, 300.0).is_ok());
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "wasm")]
+
+// The exports are plain Rust functions, so the façade runs natively. Errors are JavaScript
+// values that only exist on `wasm32`, so only the successful paths are exercised here.
+
+use ballistics_rs::wasm;
+use ballistics_rs::{
+    ApproxEq, Atmosphere, BallisticCoefficient, BulletDiameter, BulletLength, BulletWeight,
+    ClickValue, Distance, DopeCard, DragModel, GyroscopicStability, KineticEnergy, Load, Pressure,
+    Projectile, ProjectileLength, RiflingTwist, SightHeight, Temperature, Velocity, WindDeflection,
+    WindSpeed,
+};
+
+#[test]
+fn kinetic_energy_matches_the_safe_api() {
+    let energy = wasm::kinetic_energy(150.0, 3000.0);
+
+    assert_eq!(
+        energy,
+        KineticEnergy::calculate()
+            .bullet_weight(BulletWeight(150.0))
+            .velocity(Velocity(3000.0))
+            .solve()
+            .0
+    );
+    assert!(energy.approx_eq(&2997.1, 0.1), "{energy}");
+}
+
+#[test]
+fn corrected_stability_takes_the_twist_and_length_in_inches() {
+    let stability = wasm::corrected_stability(175.0, 0.308, 1.24, 10.0, 2600.0, 59.0, 29.92);
+
+    assert_eq!(
+        stability,
+        GyroscopicStability::calculate_corrected()
+            .bullet_weight(BulletWeight(175.0))
+            .rifling_twist(RiflingTwist(10.0 / 0.308))
+            .bullet_diameter(BulletDiameter(0.308))
+            .bullet_length(BulletLength(1.24 / 0.308))
+            .muzzle_velocity(Velocity(2600.0))
+            .atmosphere(
+                Atmosphere::builder()
+                    .temperature(Temperature(59.0))
+                    .pressure(Pressure(29.92))
+                    .build(),
+            )
+            .solve()
+            .gyro_stability
+            .0
+    );
+    assert!(stability > 1.5, "{stability}");
+}
+
+#[test]
+fn wind_deflection_takes_the_distance_in_yards() {
+    let deflection = wasm::wind_deflection(10.0, 2800.0, 0.45, "G1", 600.0).unwrap();
+
+    assert_eq!(
+        deflection,
+        WindDeflection::from_ballistics()
+            .crosswind_speed(WindSpeed(10.0))
+            .muzzle_velocity(Velocity(2800.0))
+            .ballistic_coefficient(BallisticCoefficient(0.45))
+            .drag_model(DragModel::G1)
+            .distance(Distance::from_yards(600.0))
+            .solve()
+            .unwrap()
+            .0
+    );
+    assert!(deflection > 0.0);
+}
+
+#[test]
+fn drag_model_names_ignore_case() {
+    assert_eq!(
+        wasm::wind_deflection(10.0, 2800.0, 0.243, "g7", 600.0).unwrap(),
+        wasm::wind_deflection(10.0, 2800.0, 0.243, "G7", 600.0).unwrap()
+    );
+}
+
+#[test]
+fn dope_card_rows_match_the_safe_api() {
+    let rows = wasm::dope_card(
+        175.0, 0.308, 1.24, 0.505, "G1", 2600.0, 10.0, 1.5, 100.0, 100.0, 1000.0, 100.0, 0.25,
+    )
+    .unwrap();
+    let load = Load::builder()
+        .projectile(
+            Projectile::builder()
+                .bullet_weight(BulletWeight(175.0))
+                .bullet_diameter(BulletDiameter(0.308))
+                .length(ProjectileLength(1.24))
+                .ballistic_coefficient(BallisticCoefficient(0.505))
+                .drag_model(DragModel::G1)
+                .build()
+                .unwrap(),
+        )
+        .muzzle_velocity(Velocity(2600.0))
+        .rifling_twist(RiflingTwist(10.0 / 0.308))
+        .sight_height(SightHeight(1.5))
+        .zero_range(Distance::from_yards(100.0))
+        .build()
+        .unwrap();
+    let card = DopeCard::over_range()
+        .load(&load)
+        .start(Distance::from_yards(100.0))
+        .end(Distance::from_yards(1000.0))
+        .step(Distance::from_yards(100.0))
+        .click(ClickValue::QuarterMoa)
+        .solve()
+        .unwrap();
+
+    assert_eq!(rows.len(), card.rows().len());
+
+    for (js, row) in rows.iter().zip(card.rows()) {
+        assert_eq!(js.range, row.distance.yards());
+        assert_eq!(js.elevation_moa, row.elevation.0);
+        assert_eq!(js.elevation_mil, row.elevation.mils());
+        assert_eq!(js.elevation_clicks, row.elevation_clicks as f64);
+        assert_eq!(js.windage_moa, row.windage.0);
+        assert_eq!(js.windage_mil, row.windage.mils());
+        assert_eq!(js.windage_clicks, row.windage_clicks as f64);
+        assert_eq!(js.velocity, row.velocity.0);
+        assert_eq!(js.energy, row.energy.0);
+        assert_eq!(js.time_of_flight, row.time_of_flight.0);
+    }
+
+    assert!(rows[9].elevation_moa.approx_eq(&39.3, 0.5));
+}
@@ -0,0 +1,434 @@
+use ballistics_rs::{
+    Angle, ApproxEq, BallisticCoefficient, BallisticsError, BulletDrop, ClockPosition, Distance,
+    GunNumber, HeadwindEffect, LagTime, SightHeight, TrajectorySolver, Velocity,
+    VerticalWindDeflection, WindBracket, WindComponents, WindDeflection, WindDirection, WindSpeed,
+    WindValue, WindVector, WindZones,
+};
+
+const LAG_TIME: LagTime = LagTime(0.2);
+
+fn components(direction: impl Into<WindDirection>) -> WindComponents {
+    WindComponents::calculate()
+        .wind_speed(WindSpeed(12.0))
+        .direction(direction)
+        .solve()
+}
+
+fn wind(crosswind: f64, headwind: f64, vertical: f64) -> WindVector {
+    WindVector::new(
+        WindSpeed(crosswind),
+        WindSpeed(headwind),
+        WindSpeed(vertical),
+    )
+}
+
+fn deflection(crosswind_speed: f64) -> WindDeflection {
+    WindDeflection::calculate()
+        .lag_time(LAG_TIME)
+        .crosswind_speed(WindSpeed(crosswind_speed))
+        .solve()
+}
+
+// A .308 168 gr match load, 2650 ft/s with a G1 BC of 0.462.
+fn table_deflection(crosswind_speed: f64, distance: Distance) -> WindDeflection {
+    WindDeflection::from_ballistics()
+        .crosswind_speed(WindSpeed(crosswind_speed))
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .distance(distance)
+        .solve()
+        .unwrap()
+}
+
+fn zoned_deflection(zones: &[(Distance, WindVector)]) -> WindDeflection {
+    WindDeflection::from_wind_zones()
+        .zones(WindZones::new(zones).unwrap())
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .distance(Distance::from_yards(800.0))
+        .solve()
+        .unwrap()
+}
+
+fn bracket(min_wind_speed: f64, max_wind_speed: f64) -> Result<WindBracket, BallisticsError> {
+    WindBracket::calculate()
+        .min_wind_speed(WindSpeed(min_wind_speed))
+        .max_wind_speed(WindSpeed(max_wind_speed))
+        .direction(ClockPosition::Nine)
+        .lag_time(LAG_TIME)
+        .distance(Distance::from_yards(600.0))
+        .solve()
+}
+
+fn headwind_effect(headwind_speed: f64, yards: f64) -> HeadwindEffect {
+    let solver = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .sight_height(SightHeight(1.5))
+        .build();
+    let zero_angle = solver
+        .zero_angle()
+        .zero_range(Distance::from_yards(100.0))
+        .solve()
+        .unwrap();
+
+    HeadwindEffect::calculate()
+        .solver(solver.with_launch_angle(zero_angle))
+        .distance(Distance::from_yards(yards))
+        .headwind_speed(WindSpeed(headwind_speed))
+        .solve()
+        .unwrap()
+}
+
+#[test]
+fn three_o_clock_is_a_full_value_crosswind() {
+    let wind = components(ClockPosition::Three);
+
+    assert!(
+        wind.crosswind.approx_eq(&WindSpeed(-12.0), 1e-12),
+        "{}",
+        wind.crosswind
+    );
+    assert!(
+        wind.headwind.approx_eq(&WindSpeed(0.0), 1e-12),
+        "{}",
+        wind.headwind
+    );
+    assert!(components(ClockPosition::Nine)
+        .crosswind
+        .approx_eq(&WindSpeed(12.0), 1e-12));
+}
+
+#[test]
+fn twelve_o_clock_is_a_full_value_headwind() {
+    let wind = components(ClockPosition::Twelve);
+
+    assert!(wind.crosswind.approx_eq(&WindSpeed(0.0), 1e-12));
+    assert_eq!(wind.headwind, WindSpeed(12.0));
+    assert!(components(ClockPosition::Six)
+        .headwind
+        .approx_eq(&WindSpeed(-12.0), 1e-12));
+}
+
+#[test]
+fn one_o_clock_is_a_half_value_crosswind() {
+    let wind = components(ClockPosition::One);
+
+    assert!(
+        wind.crosswind.approx_eq(&WindSpeed(-6.0), 1e-12),
+        "{}",
+        wind.crosswind
+    );
+    assert!(wind
+        .headwind
+        .relative_eq(&WindSpeed(12.0 * 0.75_f64.sqrt()), 1e-12));
+    assert_eq!(components(Angle::from_degrees(30.0)), wind);
+}
+
+#[test]
+fn crosswind_component_drives_the_wind_deflection() {
+    let crosswind = components(ClockPosition::Nine).crosswind;
+    let expected = WindDeflection::calculate()
+        .lag_time(LAG_TIME)
+        .crosswind_speed(crosswind)
+        .solve();
+
+    assert!(expected.approx_eq(&WindDeflection(17.6 * 12.0 * 0.2), 1e-9));
+    assert_eq!(
+        WindDeflection::from_direction()
+            .lag_time(LAG_TIME)
+            .wind_speed(WindSpeed(12.0))
+            .direction(ClockPosition::Nine)
+            .solve(),
+        expected
+    );
+}
+
+#[test]
+fn two_winds_add_component_by_component() {
+    assert_eq!(
+        wind(5.0, 2.0, 0.0) + wind(-3.0, 4.0, 1.5),
+        wind(2.0, 6.0, 1.5)
+    );
+    assert_eq!(wind(3.0, 4.0, 12.0).magnitude(), WindSpeed(13.0));
+
+    let from_right = WindVector::from_direction(WindSpeed(10.0), ClockPosition::Three);
+    let from_left = WindVector::from_direction(WindSpeed(10.0), ClockPosition::Nine);
+    let head_on = WindVector::from_direction(WindSpeed(10.0), ClockPosition::Twelve);
+
+    assert!((from_right + from_left)
+        .magnitude()
+        .approx_eq(&WindSpeed(0.0), 1e-12));
+
+    let quartering = from_right + head_on;
+    let expected =
+        WindVector::from_direction(WindSpeed(10.0 * 2.0_f64.sqrt()), Angle::from_degrees(45.0));
+
+    assert!(quartering
+        .crosswind()
+        .relative_eq(&expected.crosswind(), 1e-12));
+    assert!(quartering
+        .headwind()
+        .relative_eq(&expected.headwind(), 1e-12));
+    assert!(quartering
+        .magnitude()
+        .relative_eq(&WindSpeed(10.0 * 2.0_f64.sqrt()), 1e-12));
+}
+
+#[test]
+fn zero_wind_is_the_identity() {
+    let zero = wind(0.0, 0.0, 0.0);
+    let breeze = wind(-4.0, 7.5, 1.0);
+
+    assert_eq!(breeze + zero, breeze);
+    assert_eq!(zero + breeze, breeze);
+    assert_eq!(zero.magnitude(), WindSpeed(0.0));
+    assert_eq!(
+        zero.deflection(LAG_TIME),
+        (WindDeflection(0.0), VerticalWindDeflection(0.0))
+    );
+}
+
+#[test]
+fn vertical_deflection_mirrors_the_horizontal() {
+    for (lag_time, wind_speed) in [(0.05, 5.0), (0.2, 10.0), (0.45, -12.5)] {
+        let horizontal = WindDeflection::calculate()
+            .lag_time(LagTime(lag_time))
+            .crosswind_speed(WindSpeed(wind_speed))
+            .solve();
+        let vertical = VerticalWindDeflection::calculate()
+            .lag_time(LagTime(lag_time))
+            .vertical_wind_speed(WindSpeed(wind_speed))
+            .solve();
+
+        assert_eq!(vertical.0, horizontal.0, "{wind_speed} mph");
+    }
+}
+
+#[test]
+fn updraft_raises_and_downdraft_lowers_the_impact() {
+    let vertical = |wind_speed| {
+        VerticalWindDeflection::calculate()
+            .lag_time(LAG_TIME)
+            .vertical_wind_speed(WindSpeed(wind_speed))
+            .solve()
+    };
+
+    assert!(vertical(10.0).approx_eq(&VerticalWindDeflection(35.2), 1e-9));
+    assert!(vertical(-10.0).approx_eq(&VerticalWindDeflection(-35.2), 1e-9));
+}
+
+#[test]
+fn wind_vector_deflects_both_axes() {
+    let (horizontal, vertical) = wind(10.0, 5.0, -10.0).deflection(LAG_TIME);
+
+    assert!(horizontal.approx_eq(&WindDeflection(35.2), 1e-9));
+    assert_eq!(vertical.0, -horizontal.0);
+}
+
+#[test]
+fn ten_and_a_half_inches_at_500_yards_is_2_moa() {
+    let distance = Distance::from_yards(500.0);
+    let deflection = WindDeflection(10.47);
+    let moa = deflection.as_moa(distance);
+    let mils = deflection.as_mil(distance);
+
+    assert!(moa.approx_eq(&Angle(2.0), 1e-3), "{moa}");
+    assert!(mils.approx_eq(&0.58, 2e-3), "{mils}");
+    assert!(WindDeflection(18.0).as_mil(distance).approx_eq(&1.0, 1e-5));
+}
+
+#[test]
+fn lag_time_deflection_is_within_10_percent_of_a_published_table() {
+    // Factory table for a 168 gr .308 match load at 2650 ft/s: inches of deflection in a
+    // 10 mph full-value crosswind.
+    for (yards, published) in [(200.0, 3.0), (300.0, 7.0), (400.0, 13.0), (500.0, 21.4)] {
+        let deflection = table_deflection(10.0, Distance::from_yards(yards));
+
+        assert!(
+            deflection.approx_eq(&WindDeflection(published), 0.1 * published),
+            "{deflection} at {yards} yd"
+        );
+    }
+}
+
+#[test]
+fn two_half_range_zones_bracket_the_single_zone_results() {
+    let (light, strong) = (wind(5.0, 0.0, 0.0), wind(10.0, 0.0, 0.0));
+    let half = Distance::from_yards(400.0);
+
+    let light_only = zoned_deflection(&[(Distance(0.0), light)]);
+    let strong_only = zoned_deflection(&[(Distance(0.0), strong)]);
+    let strong_downrange = zoned_deflection(&[(Distance(0.0), light), (half, strong)]);
+    let strong_at_muzzle = zoned_deflection(&[(Distance(0.0), strong), (half, light)]);
+
+    for split in [strong_downrange, strong_at_muzzle] {
+        assert!(
+            light_only.0 < split.0 && split.0 < strong_only.0,
+            "{split} between {light_only} and {strong_only}"
+        );
+    }
+    // Wind near the muzzle counts for more than the same wind near the target.
+    assert!(strong_at_muzzle.0 > strong_downrange.0);
+    assert!(light_only.relative_eq(&table_deflection(5.0, Distance::from_yards(800.0)), 1e-3));
+}
+
+#[test]
+fn wind_zones_must_be_sorted() {
+    let breeze = wind(5.0, 0.0, 0.0);
+
+    assert!(matches!(
+        WindZones::new(&[
+            (Distance(0.0), breeze),
+            (Distance(1200.0), breeze),
+            (Distance(1200.0), breeze)
+        ]),
+        Err(BallisticsError::UnsortedWindZones(Distance(1200.0)))
+    ));
+    assert!(matches!(
+        WindZones::new(&[(Distance(900.0), breeze), (Distance(300.0), breeze)]),
+        Err(BallisticsError::UnsortedWindZones(Distance(300.0)))
+    ));
+}
+
+#[test]
+fn bracket_width_scales_linearly_with_the_spread() {
+    let base = bracket(8.0, 14.0).unwrap();
+
+    assert!(base
+        .width()
+        .approx_eq(&WindDeflection(17.6 * 6.0 * 0.2), 1e-9));
+    assert!(base
+        .mean
+        .relative_eq(&WindDeflection((base.low.0 + base.high.0) / 2.0), 1e-12));
+
+    for factor in [0.5, 2.0, 3.0] {
+        let wider = bracket(8.0, 8.0 + 6.0 * factor).unwrap();
+
+        assert!(wider
+            .width()
+            .relative_eq(&WindDeflection(base.width().0 * factor), 1e-12));
+        assert!(wider
+            .width_mil()
+            .relative_eq(&(base.width_mil() * factor), 1e-5));
+    }
+    assert_eq!(bracket(10.0, 10.0).unwrap().width(), WindDeflection(0.0));
+}
+
+#[test]
+fn bracket_rejects_a_minimum_above_the_maximum() {
+    assert!(matches!(
+        bracket(14.0, 8.0),
+        Err(BallisticsError::OutOfRange {
+            param: "min_wind_speed",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn wind_value_deflects_one_moa_and_a_tenth_mil() {
+    for yards in [300.0, 600.0, 1000.0] {
+        let distance = Distance::from_yards(yards);
+        let value = WindValue::calculate()
+            .lag_time(LAG_TIME)
+            .distance(distance)
+            .solve();
+
+        let per_moa = deflection(value.per_moa.0);
+        let per_tenth_mil = deflection(value.per_tenth_mil.0);
+
+        assert!(
+            per_moa.relative_eq(&WindDeflection(1.047 * yards / 100.0), 1e-3),
+            "{per_moa} at {yards} yd"
+        );
+        assert!(per_moa.as_moa(distance).approx_eq(&Angle(1.0), 1e-12));
+        assert!(per_tenth_mil.as_mil(distance).approx_eq(&0.1, 1e-12));
+    }
+}
+
+#[test]
+fn quick_wind_stays_within_13_percent_from_200_to_800_yards() {
+    let gun_number = GunNumber::from_ballistics()
+        .muzzle_velocity(Velocity(2800.0))
+        .ballistic_coefficient(BallisticCoefficient(0.45))
+        .near_distance(Distance::from_yards(200.0))
+        .far_distance(Distance::from_yards(800.0))
+        .solve()
+        .unwrap();
+
+    assert!((12.0..12.5).contains(&gun_number.0), "{}", gun_number.0);
+
+    for yards in (200..=800).step_by(50) {
+        let distance = Distance::from_yards(f64::from(yards));
+        let exact = WindDeflection::from_ballistics()
+            .crosswind_speed(WindSpeed(10.0))
+            .muzzle_velocity(Velocity(2800.0))
+            .ballistic_coefficient(BallisticCoefficient(0.45))
+            .distance(distance)
+            .solve()
+            .unwrap()
+            .as_moa(distance);
+        let quick = gun_number.quick_wind_moa(distance, WindSpeed(10.0));
+
+        assert!(
+            quick.approx_eq(&exact, 0.13 * exact.0),
+            "{quick} vs {exact} at {yards} yd"
+        );
+    }
+}
+
+#[test]
+fn opposite_winds_give_exactly_opposite_deflections() {
+    for wind_speed in [2.5, 10.0, 17.3] {
+        let from_left = deflection(wind_speed);
+
+        assert!(from_left.0 > 0.0);
+        assert_eq!(deflection(-wind_speed).0, -from_left.0);
+
+        let (right, _) = WindVector::from_direction(WindSpeed(wind_speed), ClockPosition::Nine)
+            .deflection(LAG_TIME);
+        let (left, _) = WindVector::from_direction(WindSpeed(wind_speed), ClockPosition::Three)
+            .deflection(LAG_TIME);
+
+        assert_eq!(left.0, -right.0);
+
+        let distance = Distance::from_yards(600.0);
+
+        assert_eq!(
+            table_deflection(-wind_speed, distance).0,
+            -table_deflection(wind_speed, distance).0
+        );
+    }
+}
+
+#[test]
+fn headwind_is_small_at_600_yards_and_grows_toward_1000() {
+    let at_600 = headwind_effect(10.0, 600.0);
+    let at_800 = headwind_effect(10.0, 800.0);
+    let at_1000 = headwind_effect(10.0, 1000.0);
+
+    // About 0.6" against some 100" of drop at 600 yards, and over 5" at 1000.
+    assert!(
+        (-1.0..-0.25).contains(&at_600.drop_change.0),
+        "{}",
+        at_600.drop_change
+    );
+    assert!(at_600.time_of_flight_change.0 > 0.0);
+    assert!(at_800.drop_change.0 < at_600.drop_change.0);
+    assert!(at_1000.drop_change.0 < 4.0 * at_600.drop_change.0);
+    assert!(at_1000.time_of_flight_change.0 > at_800.time_of_flight_change.0);
+}
+
+#[test]
+fn tailwind_reverses_the_headwind_effect() {
+    for yards in [600.0, 1000.0] {
+        let headwind = headwind_effect(10.0, yards);
+        let tailwind = headwind_effect(-10.0, yards);
+
+        assert!(tailwind.drop_change.0 > 0.0 && tailwind.time_of_flight_change.0 < 0.0);
+        assert!(tailwind
+            .drop_change
+            .relative_eq(&BulletDrop(-headwind.drop_change.0), 0.1));
+    }
+}
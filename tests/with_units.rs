@@ -0,0 +1,95 @@
+#![cfg(feature = "serde")]
+
+use ballistics_rs::{
+    Angle, ApproxEq, BulletWeight, Distance, Pressure, Temperature, Velocity, WindSpeed, WithUnits,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Zero {
+    near: WithUnits<Distance>,
+    far: WithUnits<Distance>,
+}
+
+fn read<T: DeserializeOwned>(value: f64, unit: &str) -> T {
+    serde_json::from_value(serde_json::json!({ "value": value, "unit": unit })).unwrap()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        actual.relative_eq(&expected, 1e-6),
+        "{actual} is not {expected}"
+    );
+}
+
+#[test]
+fn serializes_with_the_unit() {
+    let json = serde_json::to_string(&WithUnits(Velocity(2800.0))).unwrap();
+
+    assert_eq!(json, r#"{"value":2800.0,"unit":"ft/s"}"#);
+}
+
+#[test]
+fn round_trips() {
+    let zero = Zero {
+        near: WithUnits(Distance(75.0)),
+        far: WithUnits(Distance::from_yards(300.0)),
+    };
+    let json = serde_json::to_string(&zero).unwrap();
+
+    assert_eq!(serde_json::from_str::<Zero>(&json).unwrap(), zero);
+
+    let temperature: WithUnits<Temperature> = read(-40.0, "°F");
+    assert_eq!(temperature, WithUnits(Temperature(-40.0)));
+}
+
+#[test]
+fn converts_other_units() {
+    assert_close(read::<WithUnits<Velocity>>(853.44, "m/s").0 .0, 2800.0);
+    assert_close(read::<WithUnits<Velocity>>(2800.0, "fps").0 .0, 2800.0);
+    assert_close(read::<WithUnits<Distance>>(100.0, "yd").0 .0, 300.0);
+    assert_close(read::<WithUnits<Distance>>(100.0, "m").0 .0, 328.083_989_5);
+    assert_close(read::<WithUnits<Temperature>>(100.0, "°C").0 .0, 212.0);
+    assert_close(read::<WithUnits<Temperature>>(273.15, "K").0 .0, 32.0);
+    assert_close(read::<WithUnits<Pressure>>(1000.0, "hPa").0 .0, 29.53);
+    assert_close(read::<WithUnits<BulletWeight>>(10.0, "g").0 .0, 154.32358);
+    assert_close(read::<WithUnits<WindSpeed>>(4.4704, "m/s").0 .0, 10.0);
+    assert_close(
+        read::<WithUnits<Angle>>(1.0, "mil").0 .0,
+        Angle::from_mils(1.0).0,
+    );
+}
+
+#[test]
+fn rejects_an_unknown_unit() {
+    let error = serde_json::from_str::<WithUnits<Velocity>>(r#"{"value":2800.0,"unit":"m/h"}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with(
+        "unknown unit `m/h` for a Velocity quantity, expected one of `ft/s`, `fps`, `m/s`, \
+         `km/h`, `mph`"
+    ));
+}
+
+#[test]
+fn rejects_a_non_finite_value() {
+    let error = serde_json::from_str::<WithUnits<Velocity>>(r#"{"value":1.5e308,"unit":"mph"}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("invalid Velocity: expected a finite number"));
+}
+
+#[test]
+fn path_to_error_names_the_field() {
+    let json = r#"{"near":{"value":25.0,"unit":"m"},"far":{"value":300.0,"unit":"miles"}}"#;
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    let error = serde_path_to_error::deserialize::<_, Zero>(deserializer).unwrap_err();
+
+    assert_eq!(error.path().to_string(), "far.unit");
+    assert!(error
+        .inner()
+        .to_string()
+        .starts_with("unknown unit `miles` for a Distance quantity"));
+}
@@ -0,0 +1,326 @@
+use ballistics_rs::{
+    Angle, AngleOfDeparture, ApproxEq, BallisticCoefficient, BallisticsError, BulletDrop,
+    DangerSpace, Distance, MaxOrdinate, MaxPointBlankRange, ShortRangeZero, SightHeight,
+    TargetSize, TrajectorySolver, Velocity, ZeroCrossing, ZeroPair,
+};
+
+fn rifle() -> TrajectorySolver {
+    TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(3060.0))
+        .ballistic_coefficient(BallisticCoefficient(0.436))
+        .sight_height(SightHeight(1.5))
+        .build()
+}
+
+#[test]
+fn zero_angle_crosses_the_line_of_sight_at_the_zero_range() {
+    for yards in [25.0, 100.0, 200.0, 300.0, 600.0, 1000.0] {
+        let zero_range = Distance::from_yards(yards);
+        let launch_angle = rifle().zero_angle().zero_range(zero_range).solve().unwrap();
+        let point = rifle()
+            .with_launch_angle(launch_angle)
+            .point_at(zero_range)
+            .unwrap();
+
+        assert!(
+            Angle::from_subtension(point.drop.0, zero_range).0.abs() < 0.01,
+            "{} in at {yards} yd",
+            point.drop.0
+        );
+    }
+}
+
+#[test]
+fn zero_angle_rejects_unreachable_zeroes() {
+    let pistol = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(900.0))
+        .ballistic_coefficient(BallisticCoefficient(0.1))
+        .build();
+
+    for zero_range in [Distance(0.0), Distance::from_yards(5000.0)] {
+        assert!(matches!(
+            pistol.zero_angle().zero_range(zero_range).solve(),
+            Err(BallisticsError::UnreachableZero(_))
+        ));
+    }
+}
+
+#[test]
+fn max_point_blank_range_of_a_270_winchester() {
+    // 130 gr at 3060 ft/s with a 6" vital zone: about 300 yards zeroed at about 255, as
+    // published and as an independent G1 point-mass integration gives (299 and 255 yards).
+    let mpbr = MaxPointBlankRange::calculate()
+        .solver(rifle())
+        .vital_zone_radius(TargetSize(3.0))
+        .solve()
+        .unwrap();
+
+    assert!(
+        (290.0..310.0).contains(&mpbr.range.yards()),
+        "{}",
+        mpbr.range.yards()
+    );
+    assert!(
+        (250.0..262.0).contains(&mpbr.zero_range.yards()),
+        "{}",
+        mpbr.zero_range.yards()
+    );
+    assert!(mpbr.zero_range.0 < mpbr.range.0);
+}
+
+#[test]
+fn max_point_blank_range_of_a_handgun_is_under_100_yards() {
+    // 950 ft/s, BC 0.15, sights 0.5" above the bore, 4" vital zone: 78 yards zeroed at 66.
+    let handgun = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(950.0))
+        .ballistic_coefficient(BallisticCoefficient(0.15))
+        .sight_height(SightHeight(0.5))
+        .build();
+    let mpbr = MaxPointBlankRange::calculate()
+        .solver(handgun)
+        .vital_zone_radius(TargetSize(2.0))
+        .solve()
+        .unwrap();
+
+    assert!(
+        (72.0..84.0).contains(&mpbr.range.yards()),
+        "{}",
+        mpbr.range.yards()
+    );
+    assert!(
+        (61.0..71.0).contains(&mpbr.zero_range.yards()),
+        "{}",
+        mpbr.zero_range.yards()
+    );
+}
+
+#[test]
+fn danger_space_around_500_yards() {
+    // An independent G1 point-mass integration of the same load hits a 20" target from 1262
+    // to 1666 ft with the sights set for 1500 ft.
+    let danger_space = DangerSpace::calculate()
+        .solver(rifle())
+        .target_height(TargetSize(20.0))
+        .aim_distance(Distance(1500.0))
+        .solve()
+        .unwrap();
+
+    assert!(danger_space.near_limit.0.approx_eq(&1261.5, 10.0));
+    assert!(danger_space.far_limit.0.approx_eq(&1666.0, 10.0));
+    assert_eq!(
+        danger_space.span.0,
+        danger_space.far_limit.0 - danger_space.near_limit.0
+    );
+}
+
+#[test]
+fn danger_space_starts_at_the_muzzle_for_a_flat_trajectory() {
+    let danger_space = DangerSpace::calculate()
+        .solver(rifle())
+        .target_height(TargetSize(20.0))
+        .aim_distance(Distance(300.0))
+        .solve()
+        .unwrap();
+
+    assert_eq!(danger_space.near_limit.0, 0.0);
+    assert!(danger_space.far_limit.0 > 300.0);
+}
+
+#[test]
+fn danger_space_beyond_the_max_range_is_an_error() {
+    let short = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(3060.0))
+        .ballistic_coefficient(BallisticCoefficient(0.436))
+        .sight_height(SightHeight(1.5))
+        .max_range(Distance(1800.0))
+        .build();
+
+    assert!(matches!(
+        DangerSpace::calculate()
+            .solver(short)
+            .target_height(TargetSize(50.0))
+            .aim_distance(Distance(1500.0))
+            .solve(),
+        Err(BallisticsError::BeyondMaxRange(_))
+    ));
+}
+
+fn carbine() -> TrajectorySolver {
+    TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(3100.0))
+        .ballistic_coefficient(BallisticCoefficient(0.243))
+        .sight_height(SightHeight(2.6))
+        .build()
+}
+
+#[test]
+fn near_zero_of_25_meters_gives_a_far_zero_of_about_300_meters() {
+    // 5.56 with a 2.6" sight height: an independent G1 point-mass integration puts the far
+    // zero at 326 m.
+    let zeroes = ZeroPair::calculate()
+        .solver(carbine())
+        .zero(ZeroCrossing::Near(Distance::from_meters(25.0)))
+        .solve()
+        .unwrap();
+
+    assert!(zeroes.near_zero.meters().approx_eq(&25.0, 1e-9));
+    assert!(
+        (300.0..350.0).contains(&zeroes.far_zero.meters()),
+        "{}",
+        zeroes.far_zero.meters()
+    );
+
+    let reverse = ZeroPair::calculate()
+        .solver(carbine())
+        .zero(ZeroCrossing::Far(zeroes.far_zero))
+        .solve()
+        .unwrap();
+
+    assert!(
+        reverse.near_zero.meters().approx_eq(&25.0, 0.5),
+        "{}",
+        reverse.near_zero.meters()
+    );
+}
+
+#[test]
+fn zero_on_the_wrong_side_of_the_apex_is_an_error() {
+    for zero in [
+        ZeroCrossing::Near(Distance::from_meters(300.0)),
+        ZeroCrossing::Far(Distance::from_meters(25.0)),
+    ] {
+        assert!(matches!(
+            ZeroPair::calculate().solver(carbine()).zero(zero).solve(),
+            Err(BallisticsError::WrongSideOfApex(_))
+        ));
+    }
+}
+
+#[test]
+fn max_ordinate_of_a_100_yard_zero_is_past_midrange() {
+    // A .45-70 at 2050 ft/s, BC 0.23, over iron sights 0.8" above the bore: drag slows the
+    // bullet on the way out, pushing the apex past the midpoint to 55-60% of the zero range.
+    let lever_gun = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2050.0))
+        .ballistic_coefficient(BallisticCoefficient(0.23))
+        .sight_height(SightHeight(0.8))
+        .build();
+    let zero_range = Distance::from_yards(100.0);
+    let max_ordinate = MaxOrdinate::calculate()
+        .solver(lever_gun)
+        .zero_range(zero_range)
+        .solve()
+        .unwrap();
+    let fraction = max_ordinate.range.0 / zero_range.0;
+
+    assert!(
+        (0.5..1.0).contains(&max_ordinate.height.0),
+        "{}",
+        max_ordinate.height.0
+    );
+    assert!((0.55..0.60).contains(&fraction), "{fraction}");
+
+    let approximate = MaxOrdinate::approximate()
+        .sight_height(SightHeight(0.8))
+        .zero_range(zero_range)
+        .muzzle_velocity(Velocity(2050.0))
+        .solve();
+
+    assert!(approximate.height.0 < max_ordinate.height.0);
+}
+
+#[test]
+fn angle_of_departure_matches_the_zero_angle_without_sight_height() {
+    // With the line of sight through the bore, zeroing returns the bullet to the muzzle's
+    // height; the small-angle estimate stays within 0.01 MOA up to 1000 yards.
+    let level = TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(3060.0))
+        .ballistic_coefficient(BallisticCoefficient(0.436))
+        .build();
+
+    for yards in [100.0, 300.0, 600.0, 1000.0] {
+        let distance = Distance::from_yards(yards);
+        let zero_angle = level.zero_angle().zero_range(distance).solve().unwrap();
+        let departure = AngleOfDeparture::from_solver()
+            .solver(level)
+            .distance(distance)
+            .solve()
+            .unwrap();
+
+        assert!(
+            departure.approx_eq(&zero_angle, 0.01),
+            "{} and {} MOA at {yards} yd",
+            departure.0,
+            zero_angle.0
+        );
+    }
+}
+
+#[test]
+fn angle_of_departure_is_the_angle_the_drop_subtends() {
+    // 10.47" of drop at 100 yards subtends 10 MOA.
+    let departure = AngleOfDeparture::calculate()
+        .drop(BulletDrop(-10.47))
+        .distance(Distance::from_yards(100.0))
+        .solve();
+
+    assert!(departure.approx_eq(&Angle(10.0), 0.01), "{}", departure.0);
+}
+
+fn scoped_308() -> TrajectorySolver {
+    TrajectorySolver::builder()
+        .muzzle_velocity(Velocity(2650.0))
+        .ballistic_coefficient(BallisticCoefficient(0.462))
+        .sight_height(SightHeight(2.0))
+        .build()
+}
+
+#[test]
+fn one_inch_low_at_25_yards_is_on_at_100_yards() {
+    // The indoor range rule of thumb for a .308 with its scope 2" above the bore.
+    let zero = ShortRangeZero::calculate()
+        .solver(scoped_308())
+        .distance(Distance::from_yards(25.0))
+        .offset(BulletDrop(-1.0))
+        .solve()
+        .unwrap();
+
+    assert!(zero
+        .offset_at(Distance::from_yards(25.0))
+        .unwrap()
+        .approx_eq(&BulletDrop(-1.0), 1e-3));
+    let at_100 = zero.offset_at(Distance::from_yards(100.0)).unwrap();
+    assert!(at_100.approx_eq(&BulletDrop(0.0), 0.1), "{}", at_100.0);
+    assert!(zero.offset_at(Distance::from_yards(200.0)).unwrap().0 < -3.0);
+}
+
+#[test]
+fn offset_for_a_200_yard_zero_round_trips() {
+    let offset = ShortRangeZero::offset_for_zero()
+        .solver(scoped_308())
+        .distance(Distance::from_yards(25.0))
+        .zero_range(Distance::from_yards(200.0))
+        .solve()
+        .unwrap();
+
+    // About half an inch low at 25 yards, and an inch low for a 100 yard zero.
+    assert!(offset.approx_eq(&BulletDrop(-0.53), 0.01), "{}", offset.0);
+    assert!(ShortRangeZero::offset_for_zero()
+        .solver(scoped_308())
+        .distance(Distance::from_yards(25.0))
+        .zero_range(Distance::from_yards(100.0))
+        .solve()
+        .unwrap()
+        .approx_eq(&BulletDrop(-1.0), 0.01));
+
+    let zero = ShortRangeZero::calculate()
+        .solver(scoped_308())
+        .distance(Distance::from_yards(25.0))
+        .offset(offset)
+        .solve()
+        .unwrap();
+    assert!(zero
+        .offset_at(Distance::from_yards(200.0))
+        .unwrap()
+        .approx_eq(&BulletDrop(0.0), 1e-2));
+}